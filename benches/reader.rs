@@ -0,0 +1,86 @@
+//! Compares reading owned [Document]s with reading borrowed [DocumentRef]s,
+//! on single-line content (fully borrowed) and on `tests/res/data.jsonl` (multi-line content, that has to be unescaped).
+//!
+//! Run with `cargo bench --bench reader`.
+use std::collections::HashMap;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use oscar_io::{
+    oscar_doc::{Reader, Writer},
+    v3::{Document, DocumentRef, Metadata},
+};
+use warc::WarcHeader;
+
+const NB_DOCS: usize = 1000;
+
+/// Serialized documents with a single-line, multi-kilobyte content.
+fn single_line_corpus() -> Vec<u8> {
+    let content = "lorem ipsum dolor sit amet ".repeat(150);
+    let docs: Vec<Document> = (0..NB_DOCS)
+        .map(|i| {
+            let headers = HashMap::from([
+                (
+                    WarcHeader::RecordID,
+                    format!("<urn:uuid:00000000-0000-0000-0000-{i:012}>").into_bytes(),
+                ),
+                (
+                    WarcHeader::TargetURI,
+                    format!("https://example.com/{i}").into_bytes(),
+                ),
+                (WarcHeader::Date, b"2021-09-16T11:37:01Z".to_vec()),
+                (WarcHeader::ContentType, b"text/plain".to_vec()),
+            ]);
+            Document::new(content.clone(), headers, Metadata::default())
+        })
+        .collect();
+
+    let mut buf = vec![];
+    Writer::new(&mut buf).write_multiple(&docs).unwrap();
+    buf
+}
+
+/// Serialized documents with multi-line content, from the test corpus.
+fn multi_line_corpus() -> Vec<u8> {
+    std::fs::read("tests/res/data.jsonl").unwrap()
+}
+
+fn bench_corpus(c: &mut Criterion, name: &str, corpus: &[u8]) {
+    let mut group = c.benchmark_group(format!("oscar_doc_reader/{name}"));
+    group.throughput(Throughput::Bytes(corpus.len() as u64));
+
+    group.bench_function("owned", |b| {
+        b.iter_batched(
+            || Reader::new(corpus),
+            |r| {
+                r.map(|doc| doc.unwrap().url().map_or(0, |url| url.len()))
+                    .sum::<usize>()
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("borrowed", |b| {
+        b.iter_batched(
+            || Reader::new(corpus),
+            |mut r| {
+                let mut total = 0;
+                while let Some(doc) = r.next_ref() {
+                    let doc: DocumentRef = doc.unwrap();
+                    total += doc.url().map_or(0, str::len);
+                }
+                total
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+fn bench_reader(c: &mut Criterion) {
+    bench_corpus(c, "single_line", &single_line_corpus());
+    bench_corpus(c, "multi_line", &multi_line_corpus());
+}
+
+criterion_group!(benches, bench_reader);
+criterion_main!(benches);
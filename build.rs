@@ -0,0 +1,148 @@
+//! Generates the [Lang] enumeration and its metadata from `data/lang.tsv`.
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const LANG_TSV: &str = "data/lang.tsv";
+
+/// A row of `data/lang.tsv`.
+struct Row<'a> {
+    tag: &'a str,
+    iso639_3: &'a str,
+    iso639_1: &'a str,
+    script: &'a str,
+    name: &'a str,
+    macrolanguage: &'a str,
+}
+
+impl Row<'_> {
+    /// Get the variant name (`eng-Latn` is `Englatn`).
+    fn variant(&self) -> String {
+        let mut chars = self.tag.chars().filter(|c| *c != '-');
+        match chars.next() {
+            Some(first) => std::iter::once(first.to_ascii_uppercase())
+                .chain(chars.map(|c| c.to_ascii_lowercase()))
+                .collect(),
+            None => panic!("empty tag in {}", LANG_TSV),
+        }
+    }
+}
+
+/// Format an empty column as `None`.
+fn option(value: &str) -> String {
+    if value.is_empty() {
+        "None".to_string()
+    } else {
+        format!("Some({:?})", value)
+    }
+}
+
+fn parse(tsv: &str) -> Vec<Row<'_>> {
+    tsv.lines()
+        .skip(1)
+        .enumerate()
+        .map(|(idx, line)| {
+            let cols: Vec<&str> = line.split('\t').collect();
+            match cols.as_slice() {
+                [tag, iso639_3, iso639_1, script, name, macrolanguage] => {
+                    // every language has an ISO 639-3 code, see Lang::iso639_3
+                    if iso639_3.is_empty() {
+                        panic!("{}:{}: missing ISO 639-3 code", LANG_TSV, idx + 2);
+                    }
+                    Row {
+                        tag,
+                        iso639_3,
+                        iso639_1,
+                        script,
+                        name,
+                        macrolanguage,
+                    }
+                }
+                _ => panic!("{}:{}: expected 6 columns", LANG_TSV, idx + 2),
+            }
+        })
+        .collect()
+}
+
+fn generate(rows: &[Row]) -> String {
+    let mut out = String::new();
+
+    out.push_str("/// (currently) possible languages. Corresponds to languages identifiable by [fasttext](https://fasttext.cc).\n");
+    out.push_str("///\n/// Generated from `data/lang.tsv`.\n");
+    out.push_str(
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]\n",
+    );
+    out.push_str("pub enum Lang {\n");
+    for row in rows {
+        writeln!(out, "    {},", row.variant()).unwrap();
+    }
+    out.push_str("}\n\n");
+
+    writeln!(out, "const ALL: [Lang; {}] = [", rows.len()).unwrap();
+    for row in rows {
+        writeln!(out, "    Lang::{},", row.variant()).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    writeln!(out, "static LANG_INFO: [LangInfo; {}] = [", rows.len()).unwrap();
+    for row in rows {
+        writeln!(
+            out,
+            "    LangInfo {{ tag: {:?}, iso639_3: {:?}, iso639_1: {}, script: {}, name: {:?}, macrolanguage: {} }},",
+            row.tag,
+            row.iso639_3,
+            option(row.iso639_1),
+            option(row.script),
+            row.name,
+            option(row.macrolanguage),
+        )
+        .unwrap();
+    }
+    out.push_str("];\n\n");
+
+    let iso639_1: BTreeMap<&str, &str> = rows
+        .iter()
+        .filter(|row| !row.iso639_1.is_empty())
+        .map(|row| (row.iso639_1, row.iso639_3))
+        .collect();
+    writeln!(
+        out,
+        "const ISO_639_1: [(&str, &str); {}] = [",
+        iso639_1.len()
+    )
+    .unwrap();
+    for (iso1, iso3) in iso639_1 {
+        writeln!(out, "    ({:?}, {:?}),", iso1, iso3).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("impl FromStr for Lang {\n    type Err = Error;\n\n");
+    out.push_str("    fn from_str(s: &str) -> Result<Self, Self::Err> {\n        match s {\n");
+    for row in rows {
+        writeln!(
+            out,
+            "            {:?} => Ok(Self::{}),",
+            row.tag,
+            row.variant()
+        )
+        .unwrap();
+    }
+    out.push_str("            other => Err(Error::UnknownLang(other.to_string())),\n");
+    out.push_str("        }\n    }\n}\n");
+
+    out
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", LANG_TSV);
+
+    let tsv = fs::read_to_string(Path::new(LANG_TSV)).expect("could not read lang table");
+    let rows = parse(&tsv);
+
+    let dst = PathBuf::from(env::var("OUT_DIR").unwrap()).join("lang.rs");
+    fs::write(dst, generate(&rows)).expect("could not write generated lang.rs");
+}
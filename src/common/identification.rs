@@ -5,6 +5,7 @@ All identifiers should implement [Identifier] to be useable in processing and pi
 use std::ops::Deref;
 
 use crate::error::Error;
+use crate::lang::Lang;
 
 use oxilangtag::{LanguageTag, LanguageTagParseError};
 
@@ -56,6 +57,53 @@ impl<T: Deref<Target = str> + Clone> Identification<T> {
     pub fn prob(&self) -> &f32 {
         &self.prob
     }
+
+    /// Get the identification's label as a [Lang]. See [Lang::from_tag].
+    pub fn lang(&self) -> Result<Lang, Error> {
+        Lang::try_from(&self.label)
+    }
+}
+
+/// An identification whose label is a [Lang].
+///
+/// Serialized with the same shape as [Identification], but deserialization fails on unknown labels.
+/// Labels are normalized when read (see [Lang::from_tag]) and written back in their canonical form,
+/// meaning that a label read as `en` is written as `eng`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct LangIdentification {
+    #[serde(with = "crate::lang::as_tag")]
+    label: Lang,
+    prob: f32,
+}
+
+impl LangIdentification {
+    pub fn new(label: Lang, prob: f32) -> Self {
+        Self { label, prob }
+    }
+
+    /// Get the identification's label.
+    pub fn label(&self) -> Lang {
+        self.label
+    }
+
+    /// Get a reference to the identification's prob.
+    pub fn prob(&self) -> &f32 {
+        &self.prob
+    }
+}
+
+impl<T: Deref<Target = str> + Clone> TryFrom<&Identification<T>> for LangIdentification {
+    type Error = Error;
+
+    fn try_from(id: &Identification<T>) -> Result<Self, Self::Error> {
+        Ok(Self::new(id.lang()?, id.prob))
+    }
+}
+
+impl From<LangIdentification> for Identification<String> {
+    fn from(id: LangIdentification) -> Self {
+        Self::new(id.label.into(), id.prob)
+    }
 }
 
 pub trait Identifier<T: Deref<Target = str> + Clone> {
@@ -65,5 +113,31 @@ pub trait Identifier<T: Deref<Target = str> + Clone> {
 
 #[cfg(test)]
 mod tests {
-    // TODO tests
+    use oxilangtag::LanguageTag;
+
+    use super::{Identification, LangIdentification};
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_lang_identification() {
+        let id: LangIdentification = serde_json::from_str(r#"{"label":"en","prob":0.5}"#).unwrap();
+        assert_eq!(id.label(), Lang::Eng);
+        assert_eq!(
+            serde_json::to_string(&id).unwrap(),
+            r#"{"label":"eng","prob":0.5}"#
+        );
+
+        assert!(
+            serde_json::from_str::<LangIdentification>(r#"{"label":"xx","prob":0.5}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn test_conversion() {
+        let id = Identification::new(LanguageTag::parse("eng-Latn".to_string()).unwrap(), 0.8);
+        assert_eq!(id.lang().unwrap(), Lang::Englatn);
+
+        let lang_id = LangIdentification::try_from(&id).unwrap();
+        assert_eq!(Identification::from(lang_id), id);
+    }
 }
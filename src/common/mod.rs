@@ -1,3 +1,5 @@
 //! Common types used in multiple (if not all) different OSCAR Corpus versions.
 mod identification;
 pub use identification::Identification;
+pub use identification::Identifier;
+pub use identification::LangIdentification;
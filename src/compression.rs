@@ -0,0 +1,173 @@
+/*! Compression detection.
+
+Provides a way to detect the compression of a file from its first bytes, and to open a decompressed [BufRead] from it.
+
+Supported compressions are:
+- gzip (multi-member), always available.
+- zstd (multi-frame), with the `zstd` feature.
+- bzip2 (multi-stream), with the `bzip2` feature.
+- xz (multi-stream), with the `xz` feature.
+
+Opening a file whose compression support has not been enabled returns an error.
+!*/
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+use flate2::bufread::MultiGzDecoder;
+
+use crate::error::Error;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+const BZIP2_MAGIC: &[u8] = b"BZh";
+const XZ_MAGIC: &[u8] = &[0xfd, b'7', b'z', b'X', b'Z', 0x00];
+
+/// Compression of a file, detected by [Compression::detect].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    Gzip,
+    Zstd,
+    Bzip2,
+    Xz,
+    /// Uncompressed file.
+    None,
+}
+
+impl Compression {
+    /// Detect compression from the first bytes of a file.
+    ///
+    /// Defaults to [Compression::None] if no magic bytes are found.
+    pub fn detect(header: &[u8]) -> Self {
+        if header.starts_with(GZIP_MAGIC) {
+            Self::Gzip
+        } else if header.starts_with(ZSTD_MAGIC) {
+            Self::Zstd
+        } else if header.starts_with(BZIP2_MAGIC) {
+            Self::Bzip2
+        } else if header.starts_with(XZ_MAGIC) {
+            Self::Xz
+        } else {
+            Self::None
+        }
+    }
+
+    /// Wraps a [BufRead] into the corresponding decoder.
+    ///
+    /// Returns an error if support for the compression has not been enabled.
+    pub fn decoder<'a, R: BufRead + Send + 'a>(
+        self,
+        r: R,
+    ) -> Result<Box<dyn BufRead + Send + 'a>, Error> {
+        match self {
+            Self::None => Ok(Box::new(r)),
+            Self::Gzip => Ok(Box::new(BufReader::new(MultiGzDecoder::new(r)))),
+            #[cfg(feature = "zstd")]
+            Self::Zstd => Ok(Box::new(BufReader::new(
+                zstd::stream::read::Decoder::with_buffer(r)?,
+            ))),
+            #[cfg(feature = "bzip2")]
+            Self::Bzip2 => Ok(Box::new(BufReader::new(
+                bzip2::bufread::MultiBzDecoder::new(r),
+            ))),
+            #[cfg(feature = "xz")]
+            Self::Xz => Ok(Box::new(BufReader::new(
+                xz2::bufread::XzDecoder::new_multi_decoder(r),
+            ))),
+            #[allow(unreachable_patterns)]
+            other => Err(Error::Custom(format!(
+                "{:?} support is not enabled (see crate features)",
+                other
+            ))),
+        }
+    }
+}
+
+/// Opens the file at `path`, detecting its compression and returning a decompressed [BufRead].
+pub fn open(path: &Path) -> Result<Box<dyn BufRead + Send>, Error> {
+    let mut br = BufReader::new(File::open(path)?);
+    let compression = Compression::detect(br.fill_buf()?);
+    compression.decoder(br)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use flate2::write::GzEncoder;
+
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(Compression::detect(&[0x1f, 0x8b, 0x08]), Compression::Gzip);
+        assert_eq!(
+            Compression::detect(&[0x28, 0xb5, 0x2f, 0xfd, 0x00]),
+            Compression::Zstd
+        );
+        assert_eq!(Compression::detect(b"BZh91AY"), Compression::Bzip2);
+        assert_eq!(
+            Compression::detect(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]),
+            Compression::Xz
+        );
+        assert_eq!(Compression::detect(b"{\"content\""), Compression::None);
+        assert_eq!(Compression::detect(b""), Compression::None);
+    }
+
+    #[test]
+    fn test_open_gzip() {
+        let content = "foo\nbar\n";
+        let mut f = tempfile::NamedTempFile::new().unwrap();
+        let mut enc = GzEncoder::new(&mut f, flate2::Compression::fast());
+        enc.write_all(content.as_bytes()).unwrap();
+        enc.finish().unwrap();
+
+        let mut decompressed = String::new();
+        open(f.path())
+            .unwrap()
+            .read_to_string(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, content);
+    }
+
+    #[test]
+    fn test_open_plain() {
+        let content = "foo\nbar\n";
+        let mut f = tempfile::NamedTempFile::new().unwrap();
+        f.write_all(content.as_bytes()).unwrap();
+
+        let mut decompressed = String::new();
+        open(f.path())
+            .unwrap()
+            .read_to_string(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, content);
+    }
+
+    #[cfg(all(feature = "bzip2", feature = "xz"))]
+    #[test]
+    fn test_open_bzip2_xz() {
+        let content = "foo\nbar\n";
+
+        let mut bz = tempfile::NamedTempFile::new().unwrap();
+        let mut enc = bzip2::write::BzEncoder::new(&mut bz, bzip2::Compression::fast());
+        enc.write_all(content.as_bytes()).unwrap();
+        enc.finish().unwrap();
+
+        let mut xz = tempfile::NamedTempFile::new().unwrap();
+        let mut enc = xz2::write::XzEncoder::new(&mut xz, 6);
+        enc.write_all(content.as_bytes()).unwrap();
+        enc.finish().unwrap();
+
+        for f in [bz, xz] {
+            let mut decompressed = String::new();
+            open(f.path())
+                .unwrap()
+                .read_to_string(&mut decompressed)
+                .unwrap();
+            assert_eq!(decompressed, content);
+        }
+    }
+}
@@ -0,0 +1,343 @@
+/*! Schema version detection.
+
+Inspects the first record of a JSON(Lines) stream to find which OSCAR schema it follows:
+- v1.1 metadata entries have `headers`, `offset` and `nb_sentences` keys,
+- v3 (23.01) documents have `quality_warnings`, `categories`, `harmful_pp` or `tlsh` keys in their metadata,
+- v2 (22.01) documents have an `annotation` key in their metadata, and none of the v3 ones.
+
+[DetectingReader] then reads every record with the detected schema, yielding [AnyDocument]s,
+and can upgrade documents to [crate::v3::Document] with [DetectingReader::into_v3].
+!*/
+use std::{io::BufRead, path::Path};
+
+use serde_json::{Map, Value};
+
+use crate::compression;
+use crate::error::Error;
+use crate::{oscar_doc, oscar_v1_1, v3};
+
+/// OSCAR schema versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaVersion {
+    V1_1,
+    V2,
+    V3,
+}
+
+impl SchemaVersion {
+    /// Detect the schema version of a single record.
+    ///
+    /// Returns [Error::UnknownSchema] if the record does not look like any known schema.
+    pub fn detect(record: &Value) -> Result<Self, Error> {
+        let obj = record
+            .as_object()
+            .ok_or_else(|| Error::UnknownSchema(format!("record is not an object: {}", record)))?;
+
+        if ["headers", "offset", "nb_sentences"]
+            .iter()
+            .all(|k| obj.contains_key(*k))
+        {
+            return Ok(Self::V1_1);
+        }
+
+        if ["content", "warc_headers", "metadata"]
+            .iter()
+            .all(|k| obj.contains_key(*k))
+        {
+            if let Some(metadata) = obj.get("metadata").and_then(Value::as_object) {
+                // v3 keys take precedence, since v3 documents may carry extensions.
+                if ["quality_warnings", "categories", "harmful_pp", "tlsh"]
+                    .iter()
+                    .any(|k| metadata.contains_key(*k))
+                {
+                    return Ok(Self::V3);
+                }
+                if metadata.contains_key("annotation") {
+                    return Ok(Self::V2);
+                }
+                // v3 documents with every optional field skipped
+                if metadata.contains_key("identification")
+                    && metadata.contains_key("sentence_identifications")
+                {
+                    return Ok(Self::V3);
+                }
+            }
+        }
+
+        Err(Error::UnknownSchema(format!(
+            "unrecognized record keys: {:?}",
+            keys(obj)
+        )))
+    }
+}
+
+/// Get the keys of a JSON object, for error messages.
+fn keys(obj: &Map<String, Value>) -> Vec<&str> {
+    obj.keys().map(String::as_str).collect()
+}
+
+/// A record of any supported schema version.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnyDocument {
+    /// v1.1 records only hold metadata, text has to be read with [oscar_v1_1::Reader].
+    V1_1(oscar_v1_1::Metadata),
+    V2(oscar_doc::Document),
+    V3(v3::Document),
+}
+
+impl AnyDocument {
+    /// Get the schema version of the record.
+    pub fn version(&self) -> SchemaVersion {
+        match self {
+            Self::V1_1(_) => SchemaVersion::V1_1,
+            Self::V2(_) => SchemaVersion::V2,
+            Self::V3(_) => SchemaVersion::V3,
+        }
+    }
+
+    /// Upgrade the record to a [v3::Document].
+    ///
+    /// Fails on v1.1 records, since they do not hold any content.
+    pub fn into_v3(self) -> Result<v3::Document, Error> {
+        match self {
+            Self::V1_1(_) => Err(Error::Custom(
+                "v1.1 metadata records have no content, use oscar_v1_1::Reader instead".to_string(),
+            )),
+            Self::V2(d) => Ok(d.into()),
+            Self::V3(d) => Ok(d),
+        }
+    }
+}
+
+/// Schema-detecting reader.
+///
+/// Reads JSONLines or JSON arrays with one record per line.
+/// The schema version is detected on the first record, and subsequent records are expected to follow it.
+pub struct DetectingReader<R: BufRead> {
+    r: R,
+    version: Option<SchemaVersion>,
+    // first record, parsed while detecting the version
+    first: Option<Value>,
+}
+
+impl<R: BufRead> DetectingReader<R> {
+    /// Create a new [DetectingReader].
+    pub fn new(r: R) -> Self {
+        Self {
+            r,
+            version: None,
+            first: None,
+        }
+    }
+
+    /// Get the schema version, reading the first record if needed.
+    ///
+    /// Returns `Ok(None)` on empty streams.
+    pub fn version(&mut self) -> Result<Option<SchemaVersion>, Error> {
+        if self.version.is_none() {
+            if let Some(line) = self.next_line() {
+                let record: Value = serde_json::from_str(&line?)?;
+                self.version = Some(SchemaVersion::detect(&record)?);
+                self.first = Some(record);
+            }
+        }
+        Ok(self.version)
+    }
+
+    /// Consume the reader, upgrading each record to a [v3::Document].
+    ///
+    /// See [AnyDocument::into_v3].
+    pub fn into_v3(self) -> impl Iterator<Item = Result<v3::Document, Error>> {
+        self.map(|doc| doc.and_then(AnyDocument::into_v3))
+    }
+
+    /// Get the next record line, skipping JSON array delimiters and blank lines.
+    fn next_line(&mut self) -> Option<Result<String, Error>> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match self.r.read_line(&mut line) {
+                Ok(0) => return None,
+                Ok(_) => match line.trim() {
+                    "[" | "]" | "" => continue,
+                    record => {
+                        let record = record.strip_suffix(',').unwrap_or(record);
+                        return Some(Ok(record.to_string()));
+                    }
+                },
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+    }
+}
+
+impl DetectingReader<Box<dyn BufRead + Send>> {
+    /// Open the file at `path`, detecting its compression.
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        Ok(Self::new(compression::open(path)?))
+    }
+}
+
+/// Deserialize a record into its schema type.
+fn parse<T: serde::de::DeserializeOwned>(record: Value) -> Result<T, Error> {
+    Ok(serde_json::from_value(record)?)
+}
+
+impl<R: BufRead> Iterator for DetectingReader<R> {
+    type Item = Result<AnyDocument, Error>;
+
+    /// Yields [Result]<[AnyDocument], [Error]>.
+    fn next(&mut self) -> Option<Self::Item> {
+        let version = match self.version() {
+            Ok(Some(version)) => version,
+            Ok(None) => return None,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let record = match self.first.take() {
+            Some(record) => record,
+            None => match self.next_line()? {
+                Ok(line) => match serde_json::from_str(&line) {
+                    Ok(record) => record,
+                    Err(e) => return Some(Err(e.into())),
+                },
+                Err(e) => return Some(Err(e)),
+            },
+        };
+
+        Some(match version {
+            SchemaVersion::V1_1 => parse(record).map(AnyDocument::V1_1),
+            SchemaVersion::V2 => parse(record).map(AnyDocument::V2),
+            SchemaVersion::V3 => parse(record).map(AnyDocument::V3),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::BufReader};
+
+    use serde_json::json;
+
+    use super::{AnyDocument, DetectingReader, SchemaVersion};
+    use crate::error::Error;
+
+    #[test]
+    fn test_detect_v2() {
+        let f = File::open("tests/res/data.jsonl").unwrap();
+        let mut r = DetectingReader::new(BufReader::new(f));
+        assert_eq!(r.version().unwrap(), Some(SchemaVersion::V2));
+
+        let docs: Vec<AnyDocument> = r.map(|x| x.unwrap()).collect();
+        assert_eq!(docs.len(), 63);
+        for doc in docs {
+            let v2 = match &doc {
+                AnyDocument::V2(d) => d.clone(),
+                x => panic!("wrong version: {:?}", x.version()),
+            };
+            let v3 = doc.into_v3().unwrap();
+            assert_eq!(v2.content(), v3.content());
+            let v3_annotation: Option<Vec<String>> = v3
+                .metadata()
+                .annotation()
+                .map(|annotation| annotation.iter().map(|a| a.to_string()).collect());
+            assert_eq!(v2.metadata().annotation(), v3_annotation.as_ref());
+            assert_eq!(v2.warc_headers().len(), v3.warc_headers().len());
+        }
+    }
+
+    #[test]
+    fn test_detect_v3() {
+        let f = File::open("tests/res/data.jsonl").unwrap();
+        let docs: Vec<_> = DetectingReader::new(BufReader::new(f))
+            .into_v3()
+            .map(|x| x.unwrap())
+            .collect();
+
+        let mut buf = Vec::new();
+        crate::oscar_doc::Writer::new(&mut buf)
+            .write_multiple(&docs)
+            .unwrap();
+
+        let mut r = DetectingReader::new(buf.as_slice());
+        assert_eq!(r.version().unwrap(), Some(SchemaVersion::V3));
+        let docs_from_reader: Vec<_> = r.into_v3().map(|x| x.unwrap()).collect();
+        assert_eq!(docs, docs_from_reader);
+    }
+
+    #[test]
+    fn test_detect_v2_read_as_v3() {
+        // v2 documents read by the v3 reader have their `annotation` mapped to `quality_warnings`
+        let f = File::open("tests/res/data.jsonl").unwrap();
+        let docs: Vec<crate::v3::Document> = crate::oscar_doc::Reader::new(BufReader::new(f))
+            .map(|x| x.unwrap())
+            .collect();
+        assert!(docs.iter().any(|doc| doc.metadata().annotation().is_some()));
+
+        let mut buf = Vec::new();
+        crate::oscar_doc::Writer::new(&mut buf)
+            .write_multiple(&docs)
+            .unwrap();
+
+        let mut r = DetectingReader::new(buf.as_slice());
+        assert_eq!(r.version().unwrap(), Some(SchemaVersion::V3));
+        let docs_from_reader: Vec<_> = r.into_v3().map(|x| x.unwrap()).collect();
+        assert_eq!(docs, docs_from_reader);
+
+        // and back to v2 annotations, without dropping anything
+        for doc in docs {
+            let (v2, dropped) = crate::oscar_doc::Document::from_v3(doc.clone()).unwrap();
+            assert!(dropped.is_empty());
+            let annotation: Option<Vec<String>> = doc
+                .metadata()
+                .annotation()
+                .map(|annotation| annotation.iter().map(|a| a.to_string()).collect());
+            assert_eq!(v2.metadata().annotation(), annotation.as_ref());
+        }
+    }
+
+    #[test]
+    fn test_detect_v3_with_annotation_extension() {
+        let record = json!({
+            "content": "a",
+            "warc_headers": {},
+            "metadata": {"annotation": ["header"], "quality_warnings": null, "tlsh": null}
+        });
+        assert_eq!(SchemaVersion::detect(&record).unwrap(), SchemaVersion::V3);
+    }
+
+    #[test]
+    fn test_detect_v1_1() {
+        let meta = r#"[
+{"headers":{"warc-record-id":"<urn:uuid:1>"},"offset":0,"nb_sentences":2},
+{"headers":{"warc-record-id":"<urn:uuid:2>"},"offset":3,"nb_sentences":1}
+]"#;
+        let mut r = DetectingReader::new(meta.as_bytes());
+        assert_eq!(r.version().unwrap(), Some(SchemaVersion::V1_1));
+        let docs: Vec<_> = r.map(|x| x.unwrap()).collect();
+        assert_eq!(docs.len(), 2);
+        assert!(docs[0].clone().into_v3().is_err());
+    }
+
+    #[test]
+    fn test_detect_unknown() {
+        assert!(matches!(
+            SchemaVersion::detect(&json!({"foo": "bar"})),
+            Err(Error::UnknownSchema(_))
+        ));
+        assert!(matches!(
+            SchemaVersion::detect(&json!([1, 2])),
+            Err(Error::UnknownSchema(_))
+        ));
+
+        let mut r = DetectingReader::new(r#"{"content":"a","metadata":{}}"#.as_bytes());
+        assert!(matches!(r.next(), Some(Err(Error::UnknownSchema(_)))));
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut r = DetectingReader::new("\n".as_bytes());
+        assert_eq!(r.version().unwrap(), None);
+        assert!(r.next().is_none());
+    }
+}
@@ -4,12 +4,32 @@ use std::string::FromUtf8Error;
 pub enum Error {
     Io(std::io::Error),
     UnknownLang(String),
+    UnknownSchema(String),
+    MissingWarcHeader(warc::WarcHeader),
+    InvalidWarcHeader(warc::WarcHeader, String),
+    /// The document-level identification is missing.
+    MissingIdentification,
+    /// A probability is not in `[0, 1]`.
+    InvalidProbability(f32),
+    /// The number of content lines does not match the number of sentence identifications.
+    SentenceCountMismatch {
+        lines: usize,
+        identifications: usize,
+    },
     MetadataConversion(FromUtf8Error),
     Custom(String),
+    #[cfg(feature = "avro")]
     Avro(avro_rs::DeError),
     SerdeJson(serde_json::Error),
+    #[cfg(feature = "parquet")]
+    Parquet(parquet::errors::ParquetError),
+    #[cfg(feature = "parquet")]
+    Arrow(arrow_schema::ArrowError),
+    #[cfg(feature = "rayon")]
+    ThreadPool(rayon::ThreadPoolBuildError),
 }
 
+#[cfg(feature = "avro")]
 impl From<avro_rs::DeError> for Error {
     fn from(v: avro_rs::DeError) -> Self {
         Self::Avro(v)
@@ -39,3 +59,24 @@ impl From<serde_json::Error> for Error {
         Error::SerdeJson(e)
     }
 }
+
+#[cfg(feature = "parquet")]
+impl From<parquet::errors::ParquetError> for Error {
+    fn from(e: parquet::errors::ParquetError) -> Error {
+        Error::Parquet(e)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<arrow_schema::ArrowError> for Error {
+    fn from(e: arrow_schema::ArrowError) -> Error {
+        Error::Arrow(e)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl From<rayon::ThreadPoolBuildError> for Error {
+    fn from(e: rayon::ThreadPoolBuildError) -> Error {
+        Error::ThreadPool(e)
+    }
+}
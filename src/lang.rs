@@ -1,11901 +1,67 @@
 //! Language enumerations
-use std::{fmt::Display, str::FromStr};
+//!
+//! [Lang] and its metadata are generated at build time from `data/lang.tsv`,
+//! which holds a row per language with its tag, ISO 639-3 and ISO 639-1 codes, script, English name and macrolanguage.
+use std::{fmt::Display, ops::Deref, str::FromStr};
 
-use log::warn;
+use oxilangtag::LanguageTag;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::error::Error;
 
-/// (currently) possible languages. Corresponds to languages identifiable by [fasttext](https://fasttext.cc).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
-pub enum Lang {
-    Aahlatn,
-    Aailatn,
-    Aaklatn,
-    Aaulatn,
-    Aazlatn,
-    Abalatn,
-    Abilatn,
-    Abkcyrl,
-    Abnlatn,
-    Abqcyrl,
-    Abslatn,
-    Abtlatn,
-    Abxlatn,
-    Abylatn,
-    Abzlatn,
-    Acalatn,
-    Acdlatn,
-    Acearab,
-    Acelatn,
-    Acflatn,
-    Achlatn,
-    Acmarab,
-    Acnlatn,
-    Acqarab,
-    Acrlatn,
-    Aculatn,
-    Adalatn,
-    Adelatn,
-    Adhlatn,
-    Adilatn,
-    Adjlatn,
-    Adllatn,
-    Adxtibt,
-    Adycyrl,
-    Adzlatn,
-    Aebarab,
-    Aerlatn,
-    Aeulatn,
-    Aeylatn,
-    Afrlatn,
-    Agdlatn,
-    Agglatn,
-    Agmlatn,
-    Agnlatn,
-    Agrlatn,
-    Agtlatn,
-    Agulatn,
-    Agwlatn,
-    Agxcyrl,
-    Ahalatn,
-    Ahklatn,
-    Aialatn,
-    Aiisyrc,
-    Aimlatn,
-    Ainlatn,
-    Ajglatn,
-    Ajilatn,
-    Ajparab,
-    Ajzlatn,
-    Akalatn,
-    Akblatn,
-    Akelatn,
-    Akhlatn,
-    Akplatn,
-    Aldlatn,
-    Aljlatn,
-    Alnlatn,
-    Alplatn,
-    Alqlatn,
-    Alslatn,
-    Altcyrl,
-    Alylatn,
-    Alzlatn,
-    Amelatn,
-    Amflatn,
-    Amhethi,
-    Amilatn,
-    Amklatn,
-    Ammlatn,
-    Amnlatn,
-    Amplatn,
-    Amrlatn,
-    Amulatn,
-    Amxlatn,
-    Anglatn,
-    Anmlatn,
-    Annlatn,
-    Anpdeva,
-    Anvlatn,
-    Anylatn,
-    Aoilatn,
-    Aojlatn,
-    Aomlatn,
-    Aonlatn,
-    Aozlatn,
-    Apblatn,
-    Apcarab,
-    Apelatn,
-    Apnlatn,
-    Aprlatn,
-    Aptlatn,
-    Apulatn,
-    Apwlatn,
-    Apylatn,
-    Apzlatn,
-    Aqzlatn,
-    Araarab,
-    Arbarab,
-    Arblatn,
-    Arelatn,
-    Arglatn,
-    Arllatn,
-    Arnlatn,
-    Arplatn,
-    Arqarab,
-    Arrlatn,
-    Arsarab,
-    Aryarab,
-    Arzarab,
-    Asglatn,
-    Asmbeng,
-    Asmlatn,
-    Asolatn,
-    Astlatn,
-    Atalatn,
-    Atblatn,
-    Atdlatn,
-    Atglatn,
-    Atilatn,
-    Atjlatn,
-    Atqlatn,
-    Attlatn,
-    Auclatn,
-    Auilatn,
-    Auylatn,
-    Avacyrl,
-    Avklatn,
-    Avnlatn,
-    Avtlatn,
-    Avulatn,
-    Awadeva,
-    Awblatn,
-    Awilatn,
-    Awxlatn,
-    Aymlatn,
-    Ayolatn,
-    Ayparab,
-    Ayrlatn,
-    Azbarab,
-    Azecyrl,
-    Azelatn,
-    Azglatn,
-    Azjcyrl,
-    Azjlatn,
-    Azzlatn,
-    Bakcyrl,
-    Balarab,
-    Bamlatn,
-    Banlatn,
-    Baolatn,
-    Barlatn,
-    Baslatn,
-    Bavlatn,
-    Bbalatn,
-    Bbblatn,
-    Bbclatn,
-    Bbjlatn,
-    Bbklatn,
-    Bbolatn,
-    Bbrlatn,
-    Bccarab,
-    Bchlatn,
-    Bcilatn,
-    Bcllatn,
-    Bcolatn,
-    Bcwlatn,
-    Bddlatn,
-    Bdhlatn,
-    Bdqlatn,
-    Bealatn,
-    Beflatn,
-    Belcyrl,
-    Bemlatn,
-    Benbeng,
-    Benlatn,
-    Beqlatn,
-    Berlatn,
-    Bewlatn,
-    Bexlatn,
-    Bfdlatn,
-    Bfolatn,
-    Bgrlatn,
-    Bgslatn,
-    Bgtlatn,
-    Bgzlatn,
-    Bhglatn,
-    Bhllatn,
-    Bhodeva,
-    Bhplatn,
-    Bhwlatn,
-    Bhzlatn,
-    Biblatn,
-    Biglatn,
-    Biklatn,
-    Bimlatn,
-    Binlatn,
-    Bislatn,
-    Biulatn,
-    Bivlatn,
-    Bjnarab,
-    Bjnlatn,
-    Bjplatn,
-    Bjrlatn,
-    Bjvlatn,
-    Bkdlatn,
-    Bkllatn,
-    Bkqlatn,
-    Bkulatn,
-    Bkvlatn,
-    Blalatn,
-    Blhlatn,
-    Blkmymr,
-    Bltlatn,
-    Blwlatn,
-    Blzlatn,
-    Bmhlatn,
-    Bmklatn,
-    Bmqlatn,
-    Bmrlatn,
-    Bmulatn,
-    Bmvlatn,
-    Bnjlatn,
-    Bnolatn,
-    Bnplatn,
-    Boalatn,
-    Bodtibt,
-    Bojlatn,
-    Bomlatn,
-    Bonlatn,
-    Borlatn,
-    Boslatn,
-    Bovlatn,
-    Boxlatn,
-    Bprlatn,
-    Bpslatn,
-    Bpybeng,
-    Bqclatn,
-    Bqjlatn,
-    Bqplatn,
-    Brelatn,
-    Brharab,
-    Brulatn,
-    Brxdeva,
-    Brxlatn,
-    Bsclatn,
-    Bsnlatn,
-    Bsplatn,
-    Bsqlatn,
-    Bsslatn,
-    Btdlatn,
-    Bthlatn,
-    Btslatn,
-    Bttlatn,
-    Btxlatn,
-    Buacyrl,
-    Budlatn,
-    Buglatn,
-    Buklatn,
-    Bulcyrl,
-    Bumlatn,
-    Buslatn,
-    Bvclatn,
-    Bvdlatn,
-    Bvrlatn,
-    Bvzlatn,
-    Bwdlatn,
-    Bwilatn,
-    Bwqlatn,
-    Bwulatn,
-    Bxhlatn,
-    Bxrcyrl,
-    Byrlatn,
-    Byvlatn,
-    Byxlatn,
-    Bzdlatn,
-    Bzhlatn,
-    Bzithai,
-    Bzjlatn,
-    Caalatn,
-    Cablatn,
-    Caclatn,
-    Caflatn,
-    Caglatn,
-    Caklatn,
-    Caolatn,
-    Caplatn,
-    Caqlatn,
-    Carlatn,
-    Caslatn,
-    Catlatn,
-    Cavlatn,
-    Caxlatn,
-    Cbclatn,
-    Cbilatn,
-    Cbklatn,
-    Cbrlatn,
-    Cbslatn,
-    Cbtlatn,
-    Cbulatn,
-    Cbvlatn,
-    Ccelatn,
-    Ccolatn,
-    Ccplatn,
-    Cdflatn,
-    Ceblatn,
-    Ceglatn,
-    Ceklatn,
-    Ceslatn,
-    Cfmlatn,
-    Cgclatn,
-    Cgglatn,
-    Chalatn,
-    Chdlatn,
-    Checyrl,
-    Chflatn,
-    Chjlatn,
-    Chklatn,
-    Cholatn,
-    Chqlatn,
-    Chrcher,
-    Chrlatn,
-    Chucyrl,
-    Chvcyrl,
-    Chwlatn,
-    Chzlatn,
-    Cjklatn,
-    Cjolatn,
-    Cjplatn,
-    Cjscyrl,
-    Cjvlatn,
-    Ckbarab,
-    Ckmlatn,
-    Ckolatn,
-    Cktcyrl,
-    Clelatn,
-    Clulatn,
-    Clylatn,
-    Cmelatn,
-    Cmnhani,
-    Cmokhmr,
-    Cmolatn,
-    Cmrlatn,
-    Cnhlatn,
-    Cnilatn,
-    Cnklatn,
-    Cnllatn,
-    Cnrlatn,
-    Cntlatn,
-    Cnwlatn,
-    Coelatn,
-    Coflatn,
-    Coklatn,
-    Conlatn,
-    Copcopt,
-    Corlatn,
-    Coslatn,
-    Cotlatn,
-    Coulatn,
-    Cpalatn,
-    Cpblatn,
-    Cpclatn,
-    Cpulatn,
-    Cpylatn,
-    Crhcyrl,
-    Crhlatn,
-    Crilatn,
-    Crjcans,
-    Crkcans,
-    Crklatn,
-    Crlcans,
-    Crmcans,
-    Crnlatn,
-    Crslatn,
-    Crtlatn,
-    Crxlatn,
-    Csblatn,
-    Csklatn,
-    Csolatn,
-    Cswcans,
-    Cswlatn,
-    Csylatn,
-    Ctalatn,
-    Ctdlatn,
-    Ctolatn,
-    Ctplatn,
-    Ctulatn,
-    Cublatn,
-    Cuclatn,
-    Cuilatn,
-    Cuklatn,
-    Cullatn,
-    Cutlatn,
-    Cuxlatn,
-    Cwdcans,
-    Cwelatn,
-    Cwtlatn,
-    Cyalatn,
-    Cymlatn,
-    Cztlatn,
-    Daalatn,
-    Dadlatn,
-    Daflatn,
-    Daglatn,
-    Dahlatn,
-    Daklatn,
-    Danlatn,
-    Darcyrl,
-    Dbqlatn,
-    Ddglatn,
-    Ddnlatn,
-    Dedlatn,
-    Deslatn,
-    Deulatn,
-    Dgalatn,
-    Dgclatn,
-    Dgilatn,
-    Dgrlatn,
-    Dgzlatn,
-    Dhglatn,
-    Dhmlatn,
-    Dhvlatn,
-    Didlatn,
-    Diglatn,
-    Diklatn,
-    Diplatn,
-    Diqlatn,
-    Dislatn,
-    Diulatn,
-    Divthaa,
-    Djelatn,
-    Djklatn,
-    Djrlatn,
-    Dkslatn,
-    Dlnlatn,
-    Dngcyrl,
-    Dnjlatn,
-    Dnwlatn,
-    Doblatn,
-    Doideva,
-    Doplatn,
-    Doslatn,
-    Dowlatn,
-    Drglatn,
-    Drulatn,
-    Dsblatn,
-    Dshlatn,
-    Dtblatn,
-    Dtplatn,
-    Dtslatn,
-    Dtydeva,
-    Dualatn,
-    Duelatn,
-    Duglatn,
-    Duolatn,
-    Durlatn,
-    Dwrlatn,
-    Dwwlatn,
-    Dyilatn,
-    Dyolatn,
-    Dyulatn,
-    Dzotibt,
-    Ebklatn,
-    Efilatn,
-    Ekalatn,
-    Ekklatn,
-    Ekolatn,
-    Ellgrek,
-    Emelatn,
-    Emilatn,
-    Emllatn,
-    Emplatn,
-    Enblatn,
-    Englatn,
-    Enllatn,
-    Enmlatn,
-    Enqlatn,
-    Enxlatn,
-    Epolatn,
-    Erilatn,
-    Eselatn,
-    Esilatn,
-    Esklatn,
-    Esslatn,
-    Estlatn,
-    Esulatn,
-    Etolatn,
-    Etrlatn,
-    Etulatn,
-    Euslatn,
-    Evecyrl,
-    Ewelatn,
-    Ewolatn,
-    Extlatn,
-    Ezalatn,
-    Faalatn,
-    Fadlatn,
-    Failatn,
-    Fallatn,
-    Fanlatn,
-    Faolatn,
-    Farlatn,
-    Fasarab,
-    Fatlatn,
-    Ffmlatn,
-    Fijlatn,
-    Fillatn,
-    Finlatn,
-    Fitlatn,
-    Fkvlatn,
-    Fmudeva,
-    Fonlatn,
-    Forlatn,
-    Fralatn,
-    Frdlatn,
-    Frmlatn,
-    Frolatn,
-    Frplatn,
-    Frrlatn,
-    Frylatn,
-    Fublatn,
-    Fudlatn,
-    Fuelatn,
-    Fuflatn,
-    Fuhlatn,
-    Fuqlatn,
-    Furlatn,
-    Fuvarab,
-    Fuvlatn,
-    Gaalatn,
-    Gagcyrl,
-    Gaglatn,
-    Gahlatn,
-    Gailatn,
-    Gamlatn,
-    Gawlatn,
-    Gazlatn,
-    Gbilatn,
-    Gbolatn,
-    Gbrlatn,
-    Gcflatn,
-    Gcrlatn,
-    Gdelatn,
-    Gdglatn,
-    Gdnlatn,
-    Gdrlatn,
-    Geblatn,
-    Gejlatn,
-    Gfklatn,
-    Ghedeva,
-    Ghslatn,
-    Gidlatn,
-    Gillatn,
-    Gizlatn,
-    Gjnlatn,
-    Gknlatn,
-    Glalatn,
-    Glelatn,
-    Glglatn,
-    Glkarab,
-    Glvlatn,
-    Gmhlatn,
-    Gmvethi,
-    Gmvlatn,
-    Gnalatn,
-    Gnblatn,
-    Gndlatn,
-    Gnglatn,
-    Gnnlatn,
-    Gnwlatn,
-    Goalatn,
-    Gofethi,
-    Goflatn,
-    Goglatn,
-    Gohlatn,
-    Gomdeva,
-    Gomlatn,
-    Gorlatn,
-    Goslatn,
-    Gotgoth,
-    Gotlatn,
-    Gqrlatn,
-    Grcgrek,
-    Grnlatn,
-    Grtbeng,
-    Gsolatn,
-    Gswlatn,
-    Gublatn,
-    Guclatn,
-    Gudlatn,
-    Guglatn,
-    Guhlatn,
-    Guilatn,
-    Gujgujr,
-    Gujlatn,
-    Gukethi,
-    Gullatn,
-    Gumlatn,
-    Gunlatn,
-    Guolatn,
-    Guqlatn,
-    Gurlatn,
-    Guulatn,
-    Guwlatn,
-    Guxlatn,
-    Guzlatn,
-    Gvclatn,
-    Gvflatn,
-    Gvllatn,
-    Gvnlatn,
-    Gwilatn,
-    Gwrlatn,
-    Gyalatn,
-    Gymlatn,
-    Gyrlatn,
-    Hacarab,
-    Haelatn,
-    Haglatn,
-    Hakhani,
-    Haklatn,
-    Hatlatn,
-    Haulatn,
-    Havlatn,
-    Hawlatn,
-    Haylatn,
-    Hbohebr,
-    Hbscyrl,
-    Hbslatn,
-    Hchlatn,
-    Hebhebr,
-    Heglatn,
-    Hehlatn,
-    Herlatn,
-    Hiflatn,
-    Higlatn,
-    Hillatn,
-    Hindeva,
-    Hinlatn,
-    Hixlatn,
-    Hlalatn,
-    Hltlatn,
-    Hmnlatn,
-    Hmolatn,
-    Hmrlatn,
-    Hnedeva,
-    Hnjlatn,
-    Hnnlatn,
-    Hnslatn,
-    Hoclatn,
-    Hocwara,
-    Hoplatn,
-    Hotlatn,
-    Hralatn,
-    Hrvlatn,
-    Hrxlatn,
-    Hsblatn,
-    Htolatn,
-    Hublatn,
-    Huilatn,
-    Hunlatn,
-    Huslatn,
-    Huulatn,
-    Huvlatn,
-    Hvnlatn,
-    Hwclatn,
-    Hyearmn,
-    Hywarmn,
-    Ianlatn,
-    Ibalatn,
-    Ibglatn,
-    Ibolatn,
-    Icrlatn,
-    Idolatn,
-    Idulatn,
-    Ifalatn,
-    Ifblatn,
-    Ifelatn,
-    Ifklatn,
-    Ifulatn,
-    Ifylatn,
-    Igelatn,
-    Ignlatn,
-    Ikecans,
-    Ikklatn,
-    Iktlatn,
-    Ikwlatn,
-    Ilblatn,
-    Ilelatn,
-    Ilolatn,
-    Imolatn,
-    Inalatn,
-    Inblatn,
-    Indlatn,
-    Inhcyrl,
-    Inolatn,
-    Ioulatn,
-    Ipilatn,
-    Iqwlatn,
-    Irilatn,
-    Irklatn,
-    Irylatn,
-    Isdlatn,
-    Ishlatn,
-    Isllatn,
-    Isolatn,
-    Italatn,
-    Itlcyrl,
-    Itvlatn,
-    Iumlatn,
-    Ivblatn,
-    Ivvlatn,
-    Iwslatn,
-    Ixllatn,
-    Izrlatn,
-    Izzlatn,
-    Jaalatn,
-    Jaclatn,
-    Jaelatn,
-    Jamlatn,
-    Javjava,
-    Javlatn,
-    Jbolatn,
-    Jbulatn,
-    Jiclatn,
-    Jivlatn,
-    Jmclatn,
-    Jpnjpan,
-    Jralatn,
-    Junorya,
-    Jvnlatn,
-    Kaacyrl,
-    Kaalatn,
-    Kablatn,
-    Kaclatn,
-    Kaklatn,
-    Kallatn,
-    Kamlatn,
-    Kanknda,
-    Kanlatn,
-    Kaolatn,
-    Kapcyrl,
-    Kaqlatn,
-    Kasarab,
-    Kasdeva,
-    Kaslatn,
-    Katgeor,
-    Kazcyrl,
-    Kbclatn,
-    Kbdcyrl,
-    Kbhlatn,
-    Kbmlatn,
-    Kbolatn,
-    Kbplatn,
-    Kbqlatn,
-    Kbrlatn,
-    Kbylatn,
-    Kcacyrl,
-    Kcglatn,
-    Kcklatn,
-    Kdclatn,
-    Kdelatn,
-    Kdhlatn,
-    Kdilatn,
-    Kdjlatn,
-    Kdllatn,
-    Kdplatn,
-    Kdrlatn,
-    Kealatn,
-    Keilatn,
-    Keklatn,
-    Kenlatn,
-    Keolatn,
-    Kerlatn,
-    Kewlatn,
-    Kexdeva,
-    Kezlatn,
-    Kfftelu,
-    Kgflatn,
-    Kgklatn,
-    Kgplatn,
-    Kgrlatn,
-    Khalatn,
-    Khgtibt,
-    Khkcyrl,
-    Khmkhmr,
-    Khqlatn,
-    Khslatn,
-    Khylatn,
-    Khzlatn,
-    Kialatn,
-    Kijlatn,
-    Kiklatn,
-    Kinlatn,
-    Kircyrl,
-    Kirlatn,
-    Kiulatn,
-    Kixlatn,
-    Kjblatn,
-    Kjelatn,
-    Kjhcyrl,
-    Kjslatn,
-    Kkclatn,
-    Kkilatn,
-    Kkjlatn,
-    Kkllatn,
-    Kledeva,
-    Klnlatn,
-    Kltlatn,
-    Klvlatn,
-    Kmalatn,
-    Kmblatn,
-    Kmdlatn,
-    Kmglatn,
-    Kmhlatn,
-    Kmklatn,
-    Kmmlatn,
-    Kmolatn,
-    Kmrcyrl,
-    Kmrlatn,
-    Kmslatn,
-    Kmulatn,
-    Kmylatn,
-    Kncarab,
-    Knclatn,
-    Knelatn,
-    Knflatn,
-    Knglatn,
-    Knjlatn,
-    Knklatn,
-    Knolatn,
-    Knvlatn,
-    Knxlatn,
-    Knylatn,
-    Koglatn,
-    Koicyrl,
-    Komcyrl,
-    Konlatn,
-    Koolatn,
-    Korhang,
-    Koslatn,
-    Kpelatn,
-    Kpflatn,
-    Kpglatn,
-    Kpjlatn,
-    Kpqlatn,
-    Kprlatn,
-    Kpvcyrl,
-    Kpwlatn,
-    Kpxlatn,
-    Kpzlatn,
-    Kqalatn,
-    Kqclatn,
-    Kqelatn,
-    Kqflatn,
-    Kqllatn,
-    Kqnlatn,
-    Kqolatn,
-    Kqplatn,
-    Kqslatn,
-    Kqwlatn,
-    Kqyethi,
-    Krccyrl,
-    Krilatn,
-    Krjlatn,
-    Krllatn,
-    Krudeva,
-    Krxlatn,
-    Ksblatn,
-    Ksclatn,
-    Ksdlatn,
-    Ksflatn,
-    Kshlatn,
-    Ksjlatn,
-    Ksplatn,
-    Ksrlatn,
-    Ksslatn,
-    Kswmymr,
-    Ktbethi,
-    Ktjlatn,
-    Ktmlatn,
-    Ktolatn,
-    Ktulatn,
-    Ktzlatn,
-    Kualatn,
-    Kublatn,
-    Kudlatn,
-    Kuelatn,
-    Kujlatn,
-    Kumcyrl,
-    Kuplatn,
-    Kuslatn,
-    Kvglatn,
-    Kvjlatn,
-    Kvnlatn,
-    Kwdlatn,
-    Kwflatn,
-    Kwilatn,
-    Kwjlatn,
-    Kwnlatn,
-    Kwylatn,
-    Kxcethi,
-    Kxmthai,
-    Kxwlatn,
-    Kyclatn,
-    Kyflatn,
-    Kyglatn,
-    Kyqlatn,
-    Kyukali,
-    Kyulatn,
-    Kyumymr,
-    Kyzlatn,
-    Kzelatn,
-    Kzflatn,
-    Kzjlatn,
-    Kznlatn,
-    Laclatn,
-    Ladhebr,
-    Ladlatn,
-    Lailatn,
-    Lajlatn,
-    Lamlatn,
-    Laolaoo,
-    Laplatn,
-    Laslatn,
-    Latlatn,
-    Lavlatn,
-    Lawlatn,
-    Lbblatn,
-    Lbecyrl,
-    Lbjtibt,
-    Lbklatn,
-    Lcmlatn,
-    Lcpthai,
-    Ldilatn,
-    Ldnlatn,
-    Lealatn,
-    Ledlatn,
-    Leelatn,
-    Leflatn,
-    Lehlatn,
-    Lemlatn,
-    Leulatn,
-    Lewlatn,
-    Lexlatn,
-    Lezcyrl,
-    Lfncyrl,
-    Lfnlatn,
-    Lgglatn,
-    Lgllatn,
-    Lgmlatn,
-    Lhilatn,
-    Lhulatn,
-    Lialatn,
-    Lidlatn,
-    Lifdeva,
-    Liflimb,
-    Lijlatn,
-    Limlatn,
-    Linlatn,
-    Liplatn,
-    Lislisu,
-    Litlatn,
-    Livlatn,
-    Ljplatn,
-    Lkiarab,
-    Llblatn,
-    Lldlatn,
-    Llglatn,
-    Llnlatn,
-    Lmklatn,
-    Lmolatn,
-    Lmplatn,
-    Lndlatn,
-    Loblatn,
-    Loelatn,
-    Loglatn,
-    Loklatn,
-    Lollatn,
-    Lomlatn,
-    Loqlatn,
-    Lozlatn,
-    Lrcarab,
-    Lsilatn,
-    Lsmlatn,
-    Ltglatn,
-    Ltzlatn,
-    Lualatn,
-    Lublatn,
-    Luclatn,
-    Ludlatn,
-    Luelatn,
-    Luglatn,
-    Lunlatn,
-    Luolatn,
-    Luslatn,
-    Lvslatn,
-    Lwglatn,
-    Lwolatn,
-    Lwwlatn,
-    Lzhhani,
-    Maalatn,
-    Madlatn,
-    Maflatn,
-    Magdeva,
-    Mahlatn,
-    Maideva,
-    Majlatn,
-    Maklatn,
-    Mallatn,
-    Malmlym,
-    Mamlatn,
-    Maqlatn,
-    Mardeva,
-    Marlatn,
-    Maslatn,
-    Maulatn,
-    Mavlatn,
-    Mawlatn,
-    Maxlatn,
-    Mazlatn,
-    Mbblatn,
-    Mbclatn,
-    Mbdlatn,
-    Mbflatn,
-    Mbhlatn,
-    Mbilatn,
-    Mbjlatn,
-    Mbllatn,
-    Mbslatn,
-    Mbtlatn,
-    Mcalatn,
-    Mcblatn,
-    Mcdlatn,
-    Mcflatn,
-    Mcklatn,
-    Mcnlatn,
-    Mcolatn,
-    Mcplatn,
-    Mcqlatn,
-    Mculatn,
-    Mdalatn,
-    Mdfcyrl,
-    Mdyethi,
-    Medlatn,
-    Meelatn,
-    Mejlatn,
-    Meklatn,
-    Menlatn,
-    Meqlatn,
-    Merlatn,
-    Metlatn,
-    Meulatn,
-    Mevlatn,
-    Mfelatn,
-    Mfglatn,
-    Mfhlatn,
-    Mfilatn,
-    Mfklatn,
-    Mfqlatn,
-    Mfylatn,
-    Mfzlatn,
-    Mgclatn,
-    Mghlatn,
-    Mgmlatn,
-    Mgolatn,
-    Mgrlatn,
-    Mhilatn,
-    Mhllatn,
-    Mhrcyrl,
-    Mhwlatn,
-    Mhxlatn,
-    Mhylatn,
-    Miblatn,
-    Miclatn,
-    Mielatn,
-    Miflatn,
-    Miglatn,
-    Mihlatn,
-    Millatn,
-    Mimlatn,
-    Minarab,
-    Minlatn,
-    Miolatn,
-    Miplatn,
-    Miqlatn,
-    Mirlatn,
-    Mitlatn,
-    Miylatn,
-    Mizlatn,
-    Mjclatn,
-    Mjwlatn,
-    Mkdcyrl,
-    Mkllatn,
-    Mknlatn,
-    Mkslatn,
-    Mkzlatn,
-    Mlglatn,
-    Mlhlatn,
-    Mlplatn,
-    Mltlatn,
-    Mlulatn,
-    Mmnlatn,
-    Mmolatn,
-    Mmxlatn,
-    Mnalatn,
-    Mnblatn,
-    Mnflatn,
-    Mnibeng,
-    Mnilatn,
-    Mnimtei,
-    Mnklatn,
-    Mnscyrl,
-    Mnwmymr,
-    Mnxlatn,
-    Mnylatn,
-    Moalatn,
-    Moclatn,
-    Moglatn,
-    Mohlatn,
-    Moncyrl,
-    Moplatn,
-    Morlatn,
-    Moslatn,
-    Moxlatn,
-    Mpglatn,
-    Mphlatn,
-    Mpmlatn,
-    Mpplatn,
-    Mpslatn,
-    Mptlatn,
-    Mpxlatn,
-    Mqblatn,
-    Mqjlatn,
-    Mqylatn,
-    Mrglatn,
-    Mrilatn,
-    Mrjcyrl,
-    Mrqlatn,
-    Mrvlatn,
-    Mrwlatn,
-    Msalatn,
-    Msblatn,
-    Msclatn,
-    Mselatn,
-    Msklatn,
-    Msmlatn,
-    Msylatn,
-    Mtalatn,
-    Mtglatn,
-    Mtilatn,
-    Mtjlatn,
-    Mtolatn,
-    Mtplatn,
-    Mualatn,
-    Muglatn,
-    Muhlatn,
-    Muilatn,
-    Mupdeva,
-    Murlatn,
-    Muslatn,
-    Muxlatn,
-    Muylatn,
-    Mvalatn,
-    Mvnlatn,
-    Mvplatn,
-    Mwclatn,
-    Mwflatn,
-    Mwllatn,
-    Mwmlatn,
-    Mwnlatn,
-    Mwplatn,
-    Mwqlatn,
-    Mwvlatn,
-    Mwwlatn,
-    Mxblatn,
-    Mxplatn,
-    Mxqlatn,
-    Mxtlatn,
-    Mxvlatn,
-    Myamymr,
-    Myblatn,
-    Myklatn,
-    Myulatn,
-    Myvcyrl,
-    Mywlatn,
-    Myxlatn,
-    Myylatn,
-    Mzalatn,
-    Mzhlatn,
-    Mzklatn,
-    Mzllatn,
-    Mzmlatn,
-    Mznarab,
-    Mzwlatn,
-    Mzzlatn,
-    Nablatn,
-    Naflatn,
-    Nahlatn,
-    Naklatn,
-    Nanhani,
-    Nanlatn,
-    Naplatn,
-    Naqlatn,
-    Naslatn,
-    Navlatn,
-    Nawlatn,
-    Nbalatn,
-    Nbclatn,
-    Nbelatn,
-    Nbllatn,
-    Nbqlatn,
-    Nbulatn,
-    Ncalatn,
-    Nchlatn,
-    Ncjlatn,
-    Ncllatn,
-    Ncqlaoo,
-    Nctlatn,
-    Nculatn,
-    Ncxlatn,
-    Ndclatn,
-    Ndelatn,
-    Ndhlatn,
-    Ndilatn,
-    Ndjlatn,
-    Ndolatn,
-    Ndplatn,
-    Ndslatn,
-    Ndylatn,
-    Ndzlatn,
-    Neblatn,
-    Nepdeva,
-    Newdeva,
-    Nfalatn,
-    Nfrlatn,
-    Ngblatn,
-    Ngclatn,
-    Ngllatn,
-    Ngplatn,
-    Ngulatn,
-    Nhdlatn,
-    Nhelatn,
-    Nhglatn,
-    Nhilatn,
-    Nhklatn,
-    Nholatn,
-    Nhrlatn,
-    Nhulatn,
-    Nhwlatn,
-    Nhxlatn,
-    Nhylatn,
-    Nialatn,
-    Niflatn,
-    Niilatn,
-    Nijlatn,
-    Nimlatn,
-    Ninlatn,
-    Niocyrl,
-    Niqlatn,
-    Niulatn,
-    Niylatn,
-    Njblatn,
-    Njmlatn,
-    Njnlatn,
-    Njolatn,
-    Njzlatn,
-    Nkflatn,
-    Nkilatn,
-    Nkolatn,
-    Nlalatn,
-    Nlclatn,
-    Nldlatn,
-    Nlglatn,
-    Nmalatn,
-    Nmflatn,
-    Nmhlatn,
-    Nmolatn,
-    Nmwlatn,
-    Nmzlatn,
-    Nnblatn,
-    Nnglatn,
-    Nnhlatn,
-    Nnllatn,
-    Nnolatn,
-    Nnplatn,
-    Nnqlatn,
-    Nnwlatn,
-    Noalatn,
-    Noblatn,
-    Nodthai,
-    Nogcyrl,
-    Nonlatn,
-    Noplatn,
-    Norlatn,
-    Notlatn,
-    Noulatn,
-    Novlatn,
-    Nphlatn,
-    Npideva,
-    Npilatn,
-    Npllatn,
-    Npolatn,
-    Npylatn,
-    Nqonkoo,
-    Nrelatn,
-    Nrflatn,
-    Nrilatn,
-    Nrmlatn,
-    Nsalatn,
-    Nselatn,
-    Nsmlatn,
-    Nsnlatn,
-    Nsolatn,
-    Nsslatn,
-    Nstlatn,
-    Nsulatn,
-    Ntplatn,
-    Ntrlatn,
-    Ntulatn,
-    Nujlatn,
-    Nuslatn,
-    Nuylatn,
-    Nuzlatn,
-    Nvmlatn,
-    Nwblatn,
-    Nwilatn,
-    Nwxdeva,
-    Nxdlatn,
-    Nyalatn,
-    Nyflatn,
-    Nyklatn,
-    Nynlatn,
-    Nyolatn,
-    Nyulatn,
-    Nyylatn,
-    Nzalatn,
-    Nzilatn,
-    Nzmlatn,
-    Obolatn,
-    Ocilatn,
-    Ogolatn,
-    Ojbcans,
-    Ojblatn,
-    Ojilatn,
-    Okelatn,
-    Okulatn,
-    Okvlatn,
-    Oldlatn,
-    Ololatn,
-    Omblatn,
-    Omwlatn,
-    Onglatn,
-    Onslatn,
-    Oodlatn,
-    Opmlatn,
-    Oriorya,
-    Ormlatn,
-    Orvcyrl,
-    Orylatn,
-    Oryorya,
-    Osscyrl,
-    Otaarab,
-    Otdlatn,
-    Otelatn,
-    Otmlatn,
-    Otnlatn,
-    Otolatn,
-    Otqlatn,
-    Otslatn,
-    Otwlatn,
-    Oymlatn,
-    Ozmlatn,
-    Pablatn,
-    Padlatn,
-    Paglatn,
-    Pahlatn,
-    Pamlatn,
-    Panguru,
-    Panlatn,
-    Paolatn,
-    Paplatn,
-    Paulatn,
-    Pbblatn,
-    Pbclatn,
-    Pbilatn,
-    Pbllatn,
-    Pbtarab,
-    Pcdlatn,
-    Pcklatn,
-    Pcmlatn,
-    Pdclatn,
-    Pdtlatn,
-    Pemlatn,
-    Pesarab,
-    Pezlatn,
-    Pfelatn,
-    Pfllatn,
-    Phmlatn,
-    Piblatn,
-    Pihlatn,
-    Piolatn,
-    Pirlatn,
-    Pislatn,
-    Pjtlatn,
-    Pkblatn,
-    Plglatn,
-    Plslatn,
-    Pltlatn,
-    Plulatn,
-    Plwlatn,
-    Pmalatn,
-    Pmflatn,
-    Pmqlatn,
-    Pmslatn,
-    Pmxlatn,
-    Pnbarab,
-    Pnelatn,
-    Pntgrek,
-    Pnylatn,
-    Poelatn,
-    Pohlatn,
-    Poilatn,
-    Pollatn,
-    Ponlatn,
-    Porlatn,
-    Poslatn,
-    Potlatn,
-    Povlatn,
-    Poylatn,
-    Ppklatn,
-    Ppolatn,
-    Ppslatn,
-    Prflatn,
-    Prglatn,
-    Prilatn,
-    Prklatn,
-    Prqlatn,
-    Prsarab,
-    Pselatn,
-    Psslatn,
-    Ptplatn,
-    Ptulatn,
-    Pualatn,
-    Puilatn,
-    Pusarab,
-    Pwglatn,
-    Pwnlatn,
-    Pwwthai,
-    Pxmlatn,
-    Qublatn,
-    Quclatn,
-    Quelatn,
-    Quflatn,
-    Quglatn,
-    Quhlatn,
-    Qullatn,
-    Quplatn,
-    Quslatn,
-    Quwlatn,
-    Quylatn,
-    Quzlatn,
-    Qvalatn,
-    Qvclatn,
-    Qvelatn,
-    Qvhlatn,
-    Qvilatn,
-    Qvmlatn,
-    Qvnlatn,
-    Qvolatn,
-    Qvslatn,
-    Qvwlatn,
-    Qvzlatn,
-    Qwhlatn,
-    Qxhlatn,
-    Qxllatn,
-    Qxnlatn,
-    Qxolatn,
-    Qxrlatn,
-    Radlatn,
-    Railatn,
-    Raplatn,
-    Rarlatn,
-    Ravdeva,
-    Rawlatn,
-    Rcflatn,
-    Rejlatn,
-    Rellatn,
-    Rgulatn,
-    Rhglatn,
-    Rialatn,
-    Rimlatn,
-    Rjsdeva,
-    Rkblatn,
-    Rmclatn,
-    Rmelatn,
-    Rmllatn,
-    Rmncyrl,
-    Rmngrek,
-    Rmnlatn,
-    Rmolatn,
-    Rmqlatn,
-    Rmycyrl,
-    Rmylatn,
-    Rndlatn,
-    Rnglatn,
-    Rnllatn,
-    Rohlatn,
-    Roncyrl,
-    Ronlatn,
-    Roolatn,
-    Roplatn,
-    Rowlatn,
-    Rrolatn,
-    Rtmlatn,
-    Rublatn,
-    Ruecyrl,
-    Ruflatn,
-    Ruglatn,
-    Runlatn,
-    Ruplatn,
-    Ruscyrl,
-    Rwolatn,
-    Sablatn,
-    Saglatn,
-    Sahcyrl,
-    Sajlatn,
-    Sandeva,
-    Sanlatn,
-    Saslatn,
-    Satlatn,
-    Satolck,
-    Saylatn,
-    Sbalatn,
-    Sbdlatn,
-    Sbelatn,
-    Sbllatn,
-    Sbslatn,
-    Sbylatn,
-    Sckdeva,
-    Scnlatn,
-    Scolatn,
-    Sdalatn,
-    Sdclatn,
-    Sdharab,
-    Sdolatn,
-    Sdqlatn,
-    Sehlatn,
-    Selcyrl,
-    Seslatn,
-    Seylatn,
-    Sfwlatn,
-    Sgblatn,
-    Sgclatn,
-    Sghcyrl,
-    Sgslatn,
-    Sgwethi,
-    Sgzlatn,
-    Shilatn,
-    Shklatn,
-    Shnmymr,
-    Shplatn,
-    Shrlatn,
-    Shuarab,
-    Sidlatn,
-    Siglatn,
-    Sillatn,
-    Simlatn,
-    Sinsinh,
-    Sjalatn,
-    Sjomong,
-    Sjulatn,
-    Skglatn,
-    Skrarab,
-    Sldlatn,
-    Slklatn,
-    Slllatn,
-    Slvlatn,
-    Smalatn,
-    Smelatn,
-    Smjlatn,
-    Smklatn,
-    Smllatn,
-    Smnlatn,
-    Smolatn,
-    Smslatn,
-    Smtlatn,
-    Snalatn,
-    Snclatn,
-    Sndarab,
-    Snddeva,
-    Sndlatn,
-    Snflatn,
-    Snnlatn,
-    Snplatn,
-    Snwlatn,
-    Snylatn,
-    Soelatn,
-    Somlatn,
-    Soplatn,
-    Soqlatn,
-    Sotlatn,
-    Soylatn,
-    Spalatn,
-    Spllatn,
-    Spmlatn,
-    Spplatn,
-    Spslatn,
-    Spylatn,
-    Sqilatn,
-    Srdlatn,
-    Srilatn,
-    Srmlatn,
-    Srnlatn,
-    Srpcyrl,
-    Srplatn,
-    Srqlatn,
-    Srrlatn,
-    Ssdlatn,
-    Ssglatn,
-    Sswlatn,
-    Ssxlatn,
-    Stnlatn,
-    Stplatn,
-    Stqlatn,
-    Sualatn,
-    Suclatn,
-    Suelatn,
-    Suklatn,
-    Sunlatn,
-    Surlatn,
-    Susarab,
-    Suslatn,
-    Suzdeva,
-    Swalatn,
-    Swblatn,
-    Swclatn,
-    Swelatn,
-    Swglatn,
-    Swhlatn,
-    Swklatn,
-    Swplatn,
-    Sxblatn,
-    Sxnlatn,
-    Syblatn,
-    Sycsyrc,
-    Sylbeng,
-    Syllatn,
-    Szblatn,
-    Szllatn,
-    Szylatn,
-    Tabcyrl,
-    Taclatn,
-    Tahlatn,
-    Tajdeva,
-    Tamlatn,
-    Tamtaml,
-    Taplatn,
-    Taqlatn,
-    Taqtfng,
-    Tarlatn,
-    Tatcyrl,
-    Tatlatn,
-    Tavlatn,
-    Tawlatn,
-    Taylatn,
-    Tbclatn,
-    Tbglatn,
-    Tbklatn,
-    Tbllatn,
-    Tbolatn,
-    Tbwlatn,
-    Tbylatn,
-    Tbzlatn,
-    Tcalatn,
-    Tcclatn,
-    Tcflatn,
-    Tcslatn,
-    Tcyknda,
-    Tczlatn,
-    Tdtlatn,
-    Tdxlatn,
-    Tedlatn,
-    Teelatn,
-    Tellatn,
-    Teltelu,
-    Temlatn,
-    Teolatn,
-    Terlatn,
-    Tetlatn,
-    Tewlatn,
-    Tfrlatn,
-    Tgkcyrl,
-    Tgllatn,
-    Tgolatn,
-    Tgplatn,
-    Thathai,
-    Thklatn,
-    Thldeva,
-    Thvlatn,
-    Tiflatn,
-    Tigethi,
-    Tihlatn,
-    Tiklatn,
-    Timlatn,
-    Tirethi,
-    Tivlatn,
-    Tiylatn,
-    Tkelatn,
-    Tkllatn,
-    Tkrcyrl,
-    Tkulatn,
-    Tlblatn,
-    Tlflatn,
-    Tlhlatn,
-    Tljlatn,
-    Tlllatn,
-    Tlylatn,
-    Tmclatn,
-    Tmdlatn,
-    Tnalatn,
-    Tnclatn,
-    Tnklatn,
-    Tnnlatn,
-    Tnplatn,
-    Tnrlatn,
-    Toblatn,
-    Toclatn,
-    Todlatn,
-    Toglatn,
-    Tohlatn,
-    Toilatn,
-    Tojlatn,
-    Toklatn,
-    Tonlatn,
-    Toolatn,
-    Toplatn,
-    Toslatn,
-    Tpalatn,
-    Tpilatn,
-    Tpmlatn,
-    Tpnlatn,
-    Tpplatn,
-    Tptlatn,
-    Tpwlatn,
-    Tpzlatn,
-    Tqolatn,
-    Trclatn,
-    Trnlatn,
-    Trolatn,
-    Trplatn,
-    Trqlatn,
-    Trslatn,
-    Trvlatn,
-    Tsclatn,
-    Tsglatn,
-    Tsnlatn,
-    Tsolatn,
-    Tswlatn,
-    Tszlatn,
-    Ttclatn,
-    Ttelatn,
-    Ttjlatn,
-    Ttqlatn,
-    Ttqtfng,
-    Tuclatn,
-    Tuelatn,
-    Tuflatn,
-    Tuilatn,
-    Tukarab,
-    Tukcyrl,
-    Tuklatn,
-    Tullatn,
-    Tumlatn,
-    Tuolatn,
-    Turlatn,
-    Tuvlatn,
-    Tvklatn,
-    Tvllatn,
-    Twblatn,
-    Twilatn,
-    Twulatn,
-    Twxlatn,
-    Txqlatn,
-    Txulatn,
-    Tyvcyrl,
-    Tzhlatn,
-    Tzjlatn,
-    Tzllatn,
-    Tzmtfng,
-    Tzolatn,
-    Ubrlatn,
-    Ubulatn,
-    Udmcyrl,
-    Udulatn,
-    Uigarab,
-    Uigcyrl,
-    Uiglatn,
-    Ukrcyrl,
-    Umblatn,
-    Undadlm,
-    Undaghb,
-    Undahom,
-    Undarab,
-    Undarmi,
-    Undarmn,
-    Undavst,
-    Undbali,
-    Undbamu,
-    Undbass,
-    Undbatk,
-    Undbeng,
-    Undbhks,
-    Undbopo,
-    Undbrah,
-    Undbrai,
-    Undbugi,
-    Undbuhd,
-    Undcakm,
-    Undcans,
-    Undcari,
-    Undcham,
-    Undcher,
-    Undchrs,
-    Undcopt,
-    Undcpmn,
-    Undcprt,
-    Undcyrl,
-    Unddeva,
-    Unddiak,
-    Unddogr,
-    Unddsrt,
-    Unddupl,
-    Undegyp,
-    Undelba,
-    Undelym,
-    Undethi,
-    Undgeor,
-    Undglag,
-    Undgong,
-    Undgonm,
-    Undgoth,
-    Undgran,
-    Undgrek,
-    Undgujr,
-    Undguru,
-    Undhang,
-    Undhani,
-    Undhano,
-    Undhatr,
-    Undhebr,
-    Undhira,
-    Undhluw,
-    Undhmng,
-    Undhmnp,
-    Undhung,
-    Undital,
-    Undjava,
-    Undkali,
-    Undkana,
-    Undkawi,
-    Undkhar,
-    Undkhmr,
-    Undkhoj,
-    Undkits,
-    Undknda,
-    Undkthi,
-    Undlana,
-    Undlaoo,
-    Undlatn,
-    Undlepc,
-    Undlimb,
-    Undlina,
-    Undlinb,
-    Undlisu,
-    Undlyci,
-    Undlydi,
-    Undmahj,
-    Undmaka,
-    Undmand,
-    Undmani,
-    Undmarc,
-    Undmedf,
-    Undmend,
-    Undmerc,
-    Undmero,
-    Undmlym,
-    Undmodi,
-    Undmong,
-    Undmroo,
-    Undmtei,
-    Undmult,
-    Undmymr,
-    Undnagm,
-    Undnand,
-    Undnarb,
-    Undnbat,
-    Undnewa,
-    Undnkoo,
-    Undnshu,
-    Undogam,
-    Undolck,
-    Undorkh,
-    Undorya,
-    Undosge,
-    Undosma,
-    Undougr,
-    Undpalm,
-    Undpauc,
-    Undperm,
-    Undphag,
-    Undphli,
-    Undphlp,
-    Undphnx,
-    Undplrd,
-    Undprti,
-    Undrjng,
-    Undrohg,
-    Undrunr,
-    Undsamr,
-    Undsarb,
-    Undsaur,
-    Undsgnw,
-    Undshaw,
-    Undshrd,
-    Undsidd,
-    Undsind,
-    Undsinh,
-    Undsogd,
-    Undsogo,
-    Undsora,
-    Undsoyo,
-    Undsund,
-    Undsylo,
-    Undsyrc,
-    Undtagb,
-    Undtakr,
-    Undtale,
-    Undtalu,
-    Undtaml,
-    Undtang,
-    Undtavt,
-    Undtelu,
-    Undtfng,
-    Undtglg,
-    Undthaa,
-    Undthai,
-    Undtibt,
-    Undtirh,
-    Undtnsa,
-    Undtoto,
-    Undugar,
-    Undvaii,
-    Undvith,
-    Undwara,
-    Undwcho,
-    Undxpeo,
-    Undxsux,
-    Undyezi,
-    Undyiii,
-    Undzanb,
-    Undzinh,
-    Undzyyy,
-    Undzzzz,
-    Upvlatn,
-    Uralatn,
-    Urblatn,
-    Urdarab,
-    Urdlatn,
-    Urhlatn,
-    Urilatn,
-    Urkthai,
-    Urtlatn,
-    Urwlatn,
-    Urylatn,
-    Usalatn,
-    Usplatn,
-    Uthlatn,
-    Uvhlatn,
-    Uvllatn,
-    Uzbcyrl,
-    Uzblatn,
-    Uzncyrl,
-    Uznlatn,
-    Uzsarab,
-    Vaglatn,
-    Vaplatn,
-    Varlatn,
-    Veclatn,
-    Venlatn,
-    Veplatn,
-    Vidlatn,
-    Vielatn,
-    Vivlatn,
-    Vlslatn,
-    Vmklatn,
-    Vmwlatn,
-    Vmylatn,
-    Vollatn,
-    Votlatn,
-    Vrolatn,
-    Vunlatn,
-    Vutlatn,
-    Wajlatn,
-    Walethi,
-    Wallatn,
-    Waplatn,
-    Warlatn,
-    Watlatn,
-    Waylatn,
-    Wbalatn,
-    Wbmlatn,
-    Wbplatn,
-    Wcalatn,
-    Wedlatn,
-    Werlatn,
-    Weslatn,
-    Wewlatn,
-    Whglatn,
-    Whklatn,
-    Wiblatn,
-    Wimlatn,
-    Wiulatn,
-    Wlnlatn,
-    Wlslatn,
-    Wlvlatn,
-    Wlxlatn,
-    Wmtlatn,
-    Wmwlatn,
-    Wnclatn,
-    Wnulatn,
-    Woblatn,
-    Wollatn,
-    Woslatn,
-    Wrklatn,
-    Wrslatn,
-    Wsgtelu,
-    Wsklatn,
-    Wuuhani,
-    Wuvlatn,
-    Wwalatn,
-    Xalcyrl,
-    Xavlatn,
-    Xbilatn,
-    Xbrlatn,
-    Xedlatn,
-    Xholatn,
-    Xlalatn,
-    Xmfgeor,
-    Xmmlatn,
-    Xmvlatn,
-    Xnnlatn,
-    Xoglatn,
-    Xonlatn,
-    Xrblatn,
-    Xsblatn,
-    Xsilatn,
-    Xsmlatn,
-    Xsrdeva,
-    Xsulatn,
-    Xtdlatn,
-    Xtmlatn,
-    Xtnlatn,
-    Xumlatn,
-    Xuolatn,
-    Yaalatn,
-    Yadlatn,
-    Yallatn,
-    Yamlatn,
-    Yanlatn,
-    Yaolatn,
-    Yaplatn,
-    Yaqlatn,
-    Yaslatn,
-    Yatlatn,
-    Yazlatn,
-    Ybblatn,
-    Ybylatn,
-    Ycnlatn,
-    Yddhebr,
-    Yidhebr,
-    Yimlatn,
-    Ykalatn,
-    Ylelatn,
-    Ylilatn,
-    Ymllatn,
-    Yomlatn,
-    Yonlatn,
-    Yorlatn,
-    Yrblatn,
-    Yrelatn,
-    Yrkcyrl,
-    Yrllatn,
-    Ysslatn,
-    Yualatn,
-    Yuehani,
-    Yujlatn,
-    Yuplatn,
-    Yutlatn,
-    Yuwlatn,
-    Yuzlatn,
-    Yvalatn,
-    Zaalatn,
-    Zablatn,
-    Zaclatn,
-    Zadlatn,
-    Zaelatn,
-    Zailatn,
-    Zamlatn,
-    Zaolatn,
-    Zarlatn,
-    Zaslatn,
-    Zatlatn,
-    Zavlatn,
-    Zawlatn,
-    Zcalatn,
-    Zdjlatn,
-    Zealatn,
-    Zghtfng,
-    Zhohani,
-    Zialatn,
-    Ziwlatn,
-    Zlmlatn,
-    Znelatn,
-    Zoclatn,
-    Zomlatn,
-    Zoslatn,
-    Zpalatn,
-    Zpclatn,
-    Zpglatn,
-    Zpilatn,
-    Zpjlatn,
-    Zpllatn,
-    Zpmlatn,
-    Zpolatn,
-    Zpqlatn,
-    Zptlatn,
-    Zpulatn,
-    Zpvlatn,
-    Zpzlatn,
-    Zsmarab,
-    Zsmlatn,
-    Zsrlatn,
-    Ztqlatn,
-    Ztylatn,
-    Zullatn,
-    Zxxarab,
-    Zxxlatn,
-    Zxxzzzz,
-    Zyblatn,
-    Zyplatn,
-    Zzalatn,
-    Aai,
-    Aak,
-    Aau,
-    Aaz,
-    Aba,
-    Abk,
-    Abn,
-    Abq,
-    Abt,
-    Abx,
-    Aby,
-    Abz,
-    Aca,
-    Acd,
-    Ace,
-    Acf,
-    Ach,
-    Acm,
-    Acn,
-    Acq,
-    Acr,
-    Acu,
-    Ada,
-    Ade,
-    Adh,
-    Adi,
-    Adj,
-    Adl,
-    Ady,
-    Adz,
-    Aeb,
-    Aer,
-    Aeu,
-    Aey,
-    Afb,
-    Afh,
-    Afr,
-    Agd,
-    Agg,
-    Agm,
-    Agn,
-    Agr,
-    Agt,
-    Agu,
-    Agw,
-    Agx,
-    Aha,
-    Ahk,
-    Aia,
-    Aii,
-    Aim,
-    Ain,
-    Ajg,
-    Aji,
-    Ajp,
-    Ajz,
-    Aka,
-    Akb,
-    Ake,
-    Akh,
-    Akl,
-    Akp,
-    Ald,
-    Alj,
-    Aln,
-    Alp,
-    Alq,
-    Als,
-    Alt,
-    Aly,
-    Alz,
-    Ame,
-    Amf,
-    Amh,
-    Ami,
-    Amk,
-    Amm,
-    Amn,
-    Amp,
-    Amr,
-    Amu,
-    Amx,
-    Ang,
-    Anm,
-    Ann,
-    Anv,
-    Any,
-    Aoc,
-    Aoi,
-    Aoj,
-    Aom,
-    Aon,
-    Aoz,
-    Apb,
-    Apc,
-    Ape,
-    Apn,
-    Apr,
-    Apt,
-    Apu,
-    Apw,
-    Apy,
-    Apz,
-    Ara,
-    Arb,
-    Are,
-    Arg,
-    Arh,
-    Arl,
-    Arn,
-    Arp,
-    Arq,
-    Ars,
-    Ary,
-    Arz,
-    Asg,
-    Asm,
-    Aso,
-    Ast,
-    Ata,
-    Atb,
-    Atd,
-    Atg,
-    Ati,
-    Att,
-    Auc,
-    Aui,
-    Auy,
-    Ava,
-    Avk,
-    Avt,
-    Avu,
-    Awa,
-    Awb,
-    Awi,
-    Awx,
-    Aym,
-    Ayo,
-    Ayr,
-    Azb,
-    Aze,
-    Azg,
-    Azj,
-    Azz,
-    Bak,
-    Bal,
-    Bam,
-    Ban,
-    Bao,
-    Bar,
-    Bas,
-    Bav,
-    Bba,
-    Bbb,
-    Bbc,
-    Bbj,
-    Bbr,
-    Bcc,
-    Bch,
-    Bci,
-    Bcl,
-    Bco,
-    Bcw,
-    Bdd,
-    Bdh,
-    Bea,
-    Bef,
-    Bel,
-    Bem,
-    Ben,
-    Beq,
-    Ber,
-    Bex,
-    Bfd,
-    Bfo,
-    Bfz,
-    Bgr,
-    Bgs,
-    Bgz,
-    Bhg,
-    Bhl,
-    Bho,
-    Bhp,
-    Bhw,
-    Bib,
-    Big,
-    Bih,
-    Bik,
-    Bim,
-    Bin,
-    Bis,
-    Biu,
-    Biv,
-    Bjn,
-    Bjp,
-    Bjr,
-    Bjv,
-    Bkd,
-    Bkq,
-    Bku,
-    Bkv,
-    Bla,
-    Blh,
-    Blw,
-    Blz,
-    Bmb,
-    Bmh,
-    Bmk,
-    Bmq,
-    Bmr,
-    Bmu,
-    Bnj,
-    Bnp,
-    Boa,
-    Bod,
-    Boj,
-    Bom,
-    Bon,
-    Bos,
-    Bov,
-    Box,
-    Bpr,
-    Bps,
-    Bpy,
-    Bqc,
-    Bqj,
-    Bqp,
-    Bre,
-    Bru,
-    Bsc,
-    Bsn,
-    Bsp,
-    Bsq,
-    Bss,
-    Btd,
-    Btg,
-    Bth,
-    Bts,
-    Btt,
-    Btx,
-    Bua,
-    Bud,
-    Bug,
-    Buk,
-    Bul,
-    Bum,
-    Bus,
-    Bvr,
-    Bvy,
-    Bvz,
-    Bwd,
-    Bwi,
-    Bwq,
-    Bwu,
-    Bxh,
-    Bxr,
-    Byr,
-    Byv,
-    Byx,
-    Bzd,
-    Bzh,
-    Bzi,
-    Bzj,
-    Bzt,
-    Caa,
-    Cab,
-    Cac,
-    Caf,
-    Cag,
-    Cak,
-    Cao,
-    Cap,
-    Caq,
-    Car,
-    Cas,
-    Cat,
-    Cav,
-    Cax,
-    Cbc,
-    Cbi,
-    Cbk,
-    Cbr,
-    Cbs,
-    Cbt,
-    Cbu,
-    Cbv,
-    Cce,
-    Cco,
-    Ceb,
-    Ceg,
-    Cek,
-    Ces,
-    Cfm,
-    Cgc,
-    Cgg,
-    Cha,
-    Chd,
-    Che,
-    Chf,
-    Chj,
-    Chk,
-    Chn,
-    Cho,
-    Chq,
-    Chr,
-    Chu,
-    Chv,
-    Chw,
-    Chz,
-    Cjk,
-    Cjo,
-    Cjp,
-    Cjs,
-    Cjv,
-    Ckb,
-    Cko,
-    Ckt,
-    Cle,
-    Clu,
-    Cly,
-    Cme,
-    Cmi,
-    Cmn,
-    Cmo,
-    Cnh,
-    Cni,
-    Cnl,
-    Cnt,
-    Cnw,
-    Coe,
-    Cof,
-    Cok,
-    Con,
-    Cop,
-    Cor,
-    Cos,
-    Cot,
-    Cpa,
-    Cpb,
-    Cpc,
-    Cpu,
-    Cpy,
-    Crh,
-    Cri,
-    Crk,
-    Crm,
-    Crn,
-    Crq,
-    Crs,
-    Crt,
-    Crx,
-    Csb,
-    Csk,
-    Cso,
-    Csw,
-    Csy,
-    Cta,
-    Ctd,
-    Cto,
-    Ctp,
-    Ctu,
-    Cub,
-    Cuc,
-    Cui,
-    Cuk,
-    Cul,
-    Cut,
-    Cux,
-    Cwd,
-    Cwe,
-    Cwt,
-    Cya,
-    Cym,
-    Czt,
-    Daa,
-    Dad,
-    Daf,
-    Dah,
-    Dak,
-    Dan,
-    Dar,
-    Ddg,
-    Ded,
-    Des,
-    Deu,
-    Dga,
-    Dgc,
-    Dgi,
-    Dgr,
-    Dgz,
-    Dhg,
-    Dhm,
-    Dhv,
-    Dig,
-    Dik,
-    Din,
-    Dip,
-    Diq,
-    Dis,
-    Diu,
-    Div,
-    Dje,
-    Djk,
-    Djr,
-    Dks,
-    Dng,
-    Dnj,
-    Dob,
-    Dop,
-    Dow,
-    Drg,
-    Drt,
-    Dru,
-    Dsb,
-    Dtp,
-    Dts,
-    Dua,
-    Due,
-    Dug,
-    Duo,
-    Dur,
-    Dwr,
-    Dws,
-    Dww,
-    Dyi,
-    Dyo,
-    Dyu,
-    Dzo,
-    Ebk,
-    Efi,
-    Egl,
-    Eka,
-    Ekk,
-    Eko,
-    Ell,
-    Emi,
-    Eml,
-    Emp,
-    Enb,
-    Eng,
-    Enl,
-    Enm,
-    Enx,
-    Epo,
-    Eri,
-    Ese,
-    Esi,
-    Esk,
-    Est,
-    Esu,
-    Eto,
-    Etr,
-    Etu,
-    Eus,
-    Eve,
-    Evn,
-    Ewe,
-    Ewo,
-    Ext,
-    Eza,
-    Faa,
-    Fad,
-    Fai,
-    Fal,
-    Fan,
-    Fao,
-    Fas,
-    Fat,
-    Ffm,
-    Fij,
-    Fil,
-    Fin,
-    Fkv,
-    Fmp,
-    Fon,
-    For,
-    Fra,
-    Frm,
-    Fro,
-    Frr,
-    Fry,
-    Fub,
-    Fud,
-    Fue,
-    Fuf,
-    Fuh,
-    Fuq,
-    Fur,
-    Fuv,
-    Gaa,
-    Gag,
-    Gah,
-    Gai,
-    Gam,
-    Gaw,
-    Gaz,
-    Gba,
-    Gbi,
-    Gbo,
-    Gbr,
-    Gcf,
-    Gcr,
-    Gde,
-    Gdg,
-    Gdn,
-    Gdr,
-    Geb,
-    Gej,
-    Gfk,
-    Ghe,
-    Ghs,
-    Gid,
-    Gil,
-    Giz,
-    Gjn,
-    Gkn,
-    Gkp,
-    Gla,
-    Gle,
-    Glg,
-    Glk,
-    Glv,
-    Gmv,
-    Gnb,
-    Gnd,
-    Gng,
-    Gnn,
-    Gnw,
-    Goa,
-    Gof,
-    Gog,
-    Gom,
-    Gor,
-    Gos,
-    Got,
-    Gqr,
-    Grc,
-    Grn,
-    Grt,
-    Gso,
-    Gsw,
-    Gub,
-    Guc,
-    Gud,
-    Gug,
-    Guh,
-    Gui,
-    Guj,
-    Guk,
-    Gul,
-    Gum,
-    Gun,
-    Guo,
-    Guq,
-    Gur,
-    Guw,
-    Gux,
-    Guz,
-    Gvc,
-    Gvf,
-    Gvl,
-    Gvn,
-    Gwi,
-    Gxx,
-    Gya,
-    Gym,
-    Gyr,
-    Hae,
-    Hag,
-    Hak,
-    Hat,
-    Hau,
-    Hav,
-    Haw,
-    Hay,
-    Hbo,
-    Hbs,
-    Hch,
-    Heb,
-    Heg,
-    Heh,
-    Her,
-    Hif,
-    Hig,
-    Hil,
-    Hin,
-    Hix,
-    Hla,
-    Hlt,
-    Hmn,
-    Hmo,
-    Hmr,
-    Hne,
-    Hnj,
-    Hnn,
-    Hns,
-    Hoc,
-    Hop,
-    Hot,
-    Hra,
-    Hrv,
-    Hrx,
-    Hsb,
-    Hto,
-    Hub,
-    Hui,
-    Hun,
-    Hus,
-    Huu,
-    Huv,
-    Hvn,
-    Hwc,
-    Hye,
-    Hyw,
-    Ian,
-    Iba,
-    Ibg,
-    Ibo,
-    Icr,
-    Ido,
-    Idu,
-    Ifa,
-    Ifb,
-    Ife,
-    Ifk,
-    Ifu,
-    Ify,
-    Ige,
-    Ign,
-    Igs,
-    Iii,
-    Ijc,
-    Ike,
-    Ikk,
-    Ikw,
-    Ilb,
-    Ile,
-    Ilo,
-    Imo,
-    Ina,
-    Inb,
-    Ind,
-    Ino,
-    Iou,
-    Ipi,
-    Iqw,
-    Iri,
-    Irk,
-    Iry,
-    Isd,
-    Ish,
-    Isl,
-    Iso,
-    Ita,
-    Its,
-    Itv,
-    Ium,
-    Ivb,
-    Ivv,
-    Iws,
-    Ixl,
-    Izh,
-    Izr,
-    Izz,
-    Jac,
-    Jae,
-    Jam,
-    Jav,
-    Jbo,
-    Jbu,
-    Jdt,
-    Jic,
-    Jiv,
-    Jmc,
-    Jmx,
-    Jpa,
-    Jpn,
-    Jra,
-    Jvn,
-    Kaa,
-    Kab,
-    Kac,
-    Kal,
-    Kam,
-    Kan,
-    Kao,
-    Kap,
-    Kaq,
-    Kas,
-    Kat,
-    Kaz,
-    Kbc,
-    Kbd,
-    Kbh,
-    Kbm,
-    Kbp,
-    Kbq,
-    Kbr,
-    Kck,
-    Kdc,
-    Kde,
-    Kdh,
-    Kdi,
-    Kdj,
-    Kdl,
-    Kea,
-    Kei,
-    Kek,
-    Ken,
-    Kew,
-    Kex,
-    Kez,
-    Kff,
-    Kgf,
-    Kgk,
-    Kgp,
-    Kha,
-    Khk,
-    Khm,
-    Khs,
-    Khy,
-    Khz,
-    Kia,
-    Kik,
-    Kin,
-    Kir,
-    Kiu,
-    Kix,
-    Kjb,
-    Kje,
-    Kjh,
-    Kjs,
-    Kkc,
-    Kki,
-    Kkj,
-    Kkl,
-    Kln,
-    Klt,
-    Klv,
-    Kma,
-    Kmb,
-    Kmg,
-    Kmh,
-    Kmk,
-    Kmm,
-    Kmo,
-    Kmr,
-    Kms,
-    Kmu,
-    Knc,
-    Kne,
-    Knf,
-    Kng,
-    Knj,
-    Knk,
-    Kno,
-    Knv,
-    Knx,
-    Kny,
-    Kog,
-    Koi,
-    Kom,
-    Kon,
-    Koo,
-    Kor,
-    Kos,
-    Kpf,
-    Kpg,
-    Kpj,
-    Kpr,
-    Kpv,
-    Kpw,
-    Kpx,
-    Kpz,
-    Kqc,
-    Kqe,
-    Kqf,
-    Kql,
-    Kqn,
-    Kqo,
-    Kqp,
-    Kqs,
-    Kqw,
-    Kqy,
-    Krc,
-    Kri,
-    Krj,
-    Krl,
-    Kru,
-    Ksb,
-    Ksc,
-    Ksd,
-    Ksf,
-    Ksh,
-    Ksj,
-    Ksp,
-    Ksr,
-    Kss,
-    Ksw,
-    Ktb,
-    Ktj,
-    Ktm,
-    Kto,
-    Ktu,
-    Kua,
-    Kub,
-    Kud,
-    Kue,
-    Kuj,
-    Kum,
-    Kup,
-    Kus,
-    Kvj,
-    Kvn,
-    Kwd,
-    Kwf,
-    Kwi,
-    Kwj,
-    Kwn,
-    Kwy,
-    Kxc,
-    Kxm,
-    Kxw,
-    Kyc,
-    Kyf,
-    Kyg,
-    Kyq,
-    Kyu,
-    Kyz,
-    Kze,
-    Kzf,
-    Kzj,
-    Kzn,
-    Lac,
-    Lad,
-    Lai,
-    Laj,
-    Lam,
-    Lao,
-    Las,
-    Lat,
-    Lav,
-    Lbb,
-    Lbe,
-    Lbj,
-    Lbk,
-    Lch,
-    Lcm,
-    Ldi,
-    Ldn,
-    Lea,
-    Led,
-    Lee,
-    Lef,
-    Leh,
-    Lem,
-    Leu,
-    Lew,
-    Lex,
-    Lez,
-    Lfn,
-    Lgm,
-    Lhi,
-    Lhm,
-    Lhu,
-    Lia,
-    Lid,
-    Lif,
-    Lij,
-    Lim,
-    Lin,
-    Lip,
-    Lir,
-    Lit,
-    Liv,
-    Ljp,
-    Llb,
-    Lld,
-    Lln,
-    Lmk,
-    Lmo,
-    Lmp,
-    Lob,
-    Loe,
-    Log,
-    Lol,
-    Lom,
-    Loq,
-    Loz,
-    Lsi,
-    Lsm,
-    Ltg,
-    Ltz,
-    Lua,
-    Lub,
-    Lue,
-    Lug,
-    Lun,
-    Luo,
-    Lus,
-    Lut,
-    Lvs,
-    Lwo,
-    Lww,
-    Lzh,
-    Lzz,
-    Maa,
-    Mad,
-    Maf,
-    Mag,
-    Mah,
-    Mai,
-    Maj,
-    Mak,
-    Mal,
-    Mam,
-    Maq,
-    Mar,
-    Mas,
-    Mau,
-    Mav,
-    Maw,
-    Max,
-    Maz,
-    Mbb,
-    Mbc,
-    Mbd,
-    Mbf,
-    Mbh,
-    Mbi,
-    Mbj,
-    Mbl,
-    Mbs,
-    Mbt,
-    Mca,
-    Mcb,
-    Mcd,
-    Mcf,
-    Mck,
-    Mcn,
-    Mco,
-    Mcp,
-    Mcq,
-    Mcu,
-    Mda,
-    Mdf,
-    Mdy,
-    Med,
-    Mee,
-    Meh,
-    Mej,
-    Mek,
-    Men,
-    Meq,
-    Mer,
-    Meu,
-    Mev,
-    Mfa,
-    Mfe,
-    Mfh,
-    Mfi,
-    Mfk,
-    Mfq,
-    Mfy,
-    Mfz,
-    Mgc,
-    Mgh,
-    Mgm,
-    Mgo,
-    Mgr,
-    Mgv,
-    Mhi,
-    Mhl,
-    Mhr,
-    Mhw,
-    Mhx,
-    Mhy,
-    Mib,
-    Mic,
-    Mie,
-    Mif,
-    Mig,
-    Mih,
-    Mik,
-    Mil,
-    Min,
-    Mio,
-    Miq,
-    Mir,
-    Mit,
-    Miy,
-    Miz,
-    Mjc,
-    Mjw,
-    Mkd,
-    Mkl,
-    Mkn,
-    Mks,
-    Mkz,
-    Mlg,
-    Mlh,
-    Mlp,
-    Mlt,
-    Mlu,
-    Mmn,
-    Mmo,
-    Mmx,
-    Mna,
-    Mnb,
-    Mnf,
-    Mni,
-    Mnk,
-    Mnx,
-    Mny,
-    Moa,
-    Moc,
-    Mog,
-    Moh,
-    Mon,
-    Mop,
-    Mor,
-    Mos,
-    Mox,
-    Mpg,
-    Mph,
-    Mpm,
-    Mpp,
-    Mps,
-    Mpt,
-    Mpx,
-    Mqb,
-    Mqj,
-    Mqy,
-    Mrg,
-    Mri,
-    Mrj,
-    Mrq,
-    Mrv,
-    Mrw,
-    Msa,
-    Msb,
-    Msc,
-    Mse,
-    Msk,
-    Msm,
-    Msy,
-    Mta,
-    Mtg,
-    Mti,
-    Mtj,
-    Mto,
-    Mtp,
-    Mua,
-    Mug,
-    Muh,
-    Mur,
-    Mus,
-    Mux,
-    Muy,
-    Mva,
-    Mvn,
-    Mvp,
-    Mwc,
-    Mwf,
-    Mwl,
-    Mwm,
-    Mwn,
-    Mwp,
-    Mwq,
-    Mwv,
-    Mww,
-    Mxb,
-    Mxp,
-    Mxq,
-    Mxt,
-    Mxv,
-    Mya,
-    Myb,
-    Myk,
-    Myu,
-    Myv,
-    Myw,
-    Myx,
-    Myy,
-    Mza,
-    Mzh,
-    Mzk,
-    Mzl,
-    Mzm,
-    Mzn,
-    Mzw,
-    Mzz,
-    Nab,
-    Naf,
-    Nah,
-    Nak,
-    Nan,
-    Nap,
-    Naq,
-    Nas,
-    Nav,
-    Naw,
-    Nba,
-    Nbc,
-    Nbe,
-    Nbl,
-    Nbq,
-    Nbu,
-    Nca,
-    Nch,
-    Ncj,
-    Ncl,
-    Nct,
-    Ncu,
-    Ncx,
-    Ndc,
-    Nde,
-    Ndh,
-    Ndi,
-    Ndj,
-    Ndo,
-    Ndp,
-    Nds,
-    Ndz,
-    Neb,
-    Nep,
-    New,
-    Nfa,
-    Nfr,
-    Ngb,
-    Ngc,
-    Ngl,
-    Ngp,
-    Ngu,
-    Nhd,
-    Nhe,
-    Nhg,
-    Nhi,
-    Nhk,
-    Nho,
-    Nhr,
-    Nhu,
-    Nhw,
-    Nhx,
-    Nhy,
-    Nia,
-    Nif,
-    Nii,
-    Nij,
-    Nim,
-    Nin,
-    Niq,
-    Niu,
-    Niy,
-    Njb,
-    Njm,
-    Njn,
-    Njo,
-    Njz,
-    Nka,
-    Nki,
-    Nko,
-    Nla,
-    Nlc,
-    Nld,
-    Nma,
-    Nmf,
-    Nmh,
-    Nmo,
-    Nmw,
-    Nmz,
-    Nnb,
-    Nng,
-    Nnh,
-    Nnl,
-    Nno,
-    Nnp,
-    Nnq,
-    Nnw,
-    Noa,
-    Nob,
-    Nog,
-    Nop,
-    Nor,
-    Not,
-    Nou,
-    Nov,
-    Nph,
-    Npi,
-    Npl,
-    Npo,
-    Npy,
-    Nre,
-    Nrf,
-    Nri,
-    Nsa,
-    Nse,
-    Nsm,
-    Nsn,
-    Nso,
-    Nss,
-    Nst,
-    Nsu,
-    Ntp,
-    Ntr,
-    Nus,
-    Nuy,
-    Nvm,
-    Nwb,
-    Nwi,
-    Nwx,
-    Nxd,
-    Nya,
-    Nyf,
-    Nyk,
-    Nyn,
-    Nyo,
-    Nyu,
-    Nyy,
-    Nzb,
-    Nzi,
-    Nzm,
-    Obo,
-    Oci,
-    Ogo,
-    Ojb,
-    Oji,
-    Ojs,
-    Oke,
-    Okv,
-    Old,
-    Omw,
-    Ong,
-    Ons,
-    Ood,
-    Opm,
-    Ori,
-    Orm,
-    Orv,
-    Ory,
-    Oss,
-    Ota,
-    Ote,
-    Otm,
-    Otn,
-    Otq,
-    Ots,
-    Otw,
-    Oym,
-    Ozm,
-    Pab,
-    Pad,
-    Pag,
-    Pah,
-    Pam,
-    Pan,
-    Pao,
-    Pap,
-    Pau,
-    Pbb,
-    Pbc,
-    Pbi,
-    Pbl,
-    Pbt,
-    Pcd,
-    Pck,
-    Pcm,
-    Pdc,
-    Pdt,
-    Pem,
-    Pes,
-    Pfe,
-    Pfl,
-    Phm,
-    Pib,
-    Pid,
-    Pio,
-    Pir,
-    Pis,
-    Pjt,
-    Pkb,
-    Plg,
-    Pls,
-    Plt,
-    Plu,
-    Plw,
-    Pma,
-    Pmf,
-    Pms,
-    Pmx,
-    Pnb,
-    Pne,
-    Poe,
-    Poh,
-    Poi,
-    Pol,
-    Pon,
-    Por,
-    Pot,
-    Pov,
-    Poy,
-    Ppk,
-    Ppl,
-    Ppo,
-    Pps,
-    Prf,
-    Prg,
-    Pri,
-    Prk,
-    Prs,
-    Pse,
-    Ptp,
-    Ptu,
-    Pua,
-    Pus,
-    Pwg,
-    Pww,
-    Qub,
-    Quc,
-    Que,
-    Quf,
-    Qug,
-    Quh,
-    Qul,
-    Qup,
-    Qus,
-    Quw,
-    Quy,
-    Quz,
-    Qva,
-    Qvc,
-    Qve,
-    Qvh,
-    Qvi,
-    Qvm,
-    Qvn,
-    Qvo,
-    Qvs,
-    Qvw,
-    Qvz,
-    Qwh,
-    Qxh,
-    Qxl,
-    Qxn,
-    Qxo,
-    Qxr,
-    Qya,
-    Rad,
-    Rai,
-    Rap,
-    Rar,
-    Rcf,
-    Rhg,
-    Ria,
-    Rif,
-    Rim,
-    Rkb,
-    Rmc,
-    Rme,
-    Rml,
-    Rmn,
-    Rmo,
-    Rmq,
-    Rmy,
-    Rnd,
-    Rng,
-    Rnl,
-    Roh,
-    Rom,
-    Ron,
-    Roo,
-    Rop,
-    Rro,
-    Rtm,
-    Rub,
-    Rue,
-    Ruf,
-    Run,
-    Rup,
-    Rus,
-    Rwo,
-    Sab,
-    Sag,
-    Sah,
-    San,
-    Sas,
-    Sat,
-    Sba,
-    Sbd,
-    Sbe,
-    Sbl,
-    Sbs,
-    Sby,
-    Sck,
-    Scn,
-    Sco,
-    Sda,
-    Sdh,
-    Sdo,
-    Seh,
-    Ses,
-    Sey,
-    Sfw,
-    Sgb,
-    Sgh,
-    Sgs,
-    Sgw,
-    Sgz,
-    Shi,
-    Shk,
-    Shn,
-    Shp,
-    Shr,
-    Shs,
-    Shu,
-    Shy,
-    Sid,
-    Sig,
-    Sil,
-    Sim,
-    Sin,
-    Sja,
-    Sjn,
-    Skg,
-    Skr,
-    Sld,
-    Slk,
-    Sll,
-    Slv,
-    Sma,
-    Sme,
-    Smk,
-    Sml,
-    Smo,
-    Smt,
-    Sna,
-    Snc,
-    Snd,
-    Snf,
-    Snn,
-    Snp,
-    Snw,
-    Sny,
-    Soe,
-    Som,
-    Sop,
-    Soq,
-    Sot,
-    Soy,
-    Spa,
-    Spl,
-    Spm,
-    Spp,
-    Sps,
-    Spy,
-    Sqi,
-    Srd,
-    Sri,
-    Srm,
-    Srn,
-    Srp,
-    Srq,
-    Srr,
-    Ssd,
-    Ssg,
-    Ssw,
-    Ssx,
-    Stn,
-    Stp,
-    Stq,
-    Sua,
-    Suc,
-    Sue,
-    Suk,
-    Sun,
-    Sur,
-    Sus,
-    Sux,
-    Suz,
-    Swa,
-    Swb,
-    Swc,
-    Swe,
-    Swg,
-    Swh,
-    Swk,
-    Swp,
-    Sxb,
-    Sxn,
-    Syb,
-    Syc,
-    Szb,
-    Szl,
-    Tab,
-    Tac,
-    Tah,
-    Taj,
-    Tam,
-    Tap,
-    Taq,
-    Tar,
-    Tat,
-    Tav,
-    Taw,
-    Tbc,
-    Tbg,
-    Tbk,
-    Tbl,
-    Tbo,
-    Tby,
-    Tbz,
-    Tca,
-    Tcc,
-    Tcf,
-    Tcs,
-    Tcy,
-    Tcz,
-    Tdt,
-    Tdx,
-    Ted,
-    Tee,
-    Tel,
-    Tem,
-    Teo,
-    Ter,
-    Tet,
-    Tew,
-    Tfr,
-    Tgk,
-    Tgl,
-    Tgo,
-    Tgp,
-    Tha,
-    Thk,
-    Thv,
-    Tif,
-    Tig,
-    Tih,
-    Tik,
-    Tim,
-    Tir,
-    Tiv,
-    Tiy,
-    Tke,
-    Tkl,
-    Tkr,
-    Tku,
-    Tlb,
-    Tlf,
-    Tlh,
-    Tlj,
-    Tll,
-    Tly,
-    Tmd,
-    Tmr,
-    Tna,
-    Tnc,
-    Tnk,
-    Tnn,
-    Tnp,
-    Tob,
-    Toc,
-    Tod,
-    Tog,
-    Toh,
-    Toi,
-    Toj,
-    Tok,
-    Ton,
-    Too,
-    Top,
-    Tos,
-    Tpa,
-    Tpi,
-    Tpm,
-    Tpp,
-    Tpt,
-    Tpw,
-    Tpz,
-    Tqb,
-    Trc,
-    Trn,
-    Tro,
-    Trp,
-    Trq,
-    Tsc,
-    Tsg,
-    Tsn,
-    Tso,
-    Tsw,
-    Tsz,
-    Ttc,
-    Tte,
-    Ttj,
-    Ttq,
-    Tts,
-    Tuc,
-    Tue,
-    Tuf,
-    Tui,
-    Tuk,
-    Tum,
-    Tuo,
-    Tur,
-    Tuv,
-    Tvk,
-    Tvl,
-    Twi,
-    Twu,
-    Twx,
-    Txq,
-    Txu,
-    Tyv,
-    Tzh,
-    Tzj,
-    Tzl,
-    Tzm,
-    Tzo,
-    Ubr,
-    Ubu,
-    Udm,
-    Udu,
-    Uig,
-    Ukr,
-    Umb,
-    Upv,
-    Ura,
-    Urb,
-    Urd,
-    Urh,
-    Uri,
-    Urk,
-    Urt,
-    Usa,
-    Usp,
-    Uvh,
-    Uvl,
-    Uzb,
-    Uzn,
-    Vag,
-    Vap,
-    Var,
-    Vec,
-    Ven,
-    Vgt,
-    Vid,
-    Vie,
-    Viv,
-    Vls,
-    Vmk,
-    Vmw,
-    Vmy,
-    Vol,
-    Vro,
-    Vun,
-    Vut,
-    Waj,
-    Wal,
-    Wap,
-    War,
-    Wat,
-    Way,
-    Wba,
-    Wbm,
-    Wbp,
-    Wca,
-    Wed,
-    Wer,
-    Wes,
-    Wew,
-    Whg,
-    Whk,
-    Wib,
-    Wim,
-    Wiu,
-    Wln,
-    Wls,
-    Wlv,
-    Wmt,
-    Wmw,
-    Wnc,
-    Wnu,
-    Wob,
-    Wol,
-    Wos,
-    Wrk,
-    Wrs,
-    Wsk,
-    Wuu,
-    Wuv,
-    Wwa,
-    Xal,
-    Xav,
-    Xbi,
-    Xbr,
-    Xed,
-    Xho,
-    Xla,
-    Xmf,
-    Xmv,
-    Xnn,
-    Xog,
-    Xon,
-    Xpe,
-    Xrb,
-    Xsb,
-    Xsi,
-    Xsm,
-    Xsr,
-    Xsu,
-    Xtd,
-    Xtm,
-    Xtn,
-    Xuo,
-    Yaa,
-    Yad,
-    Yal,
-    Yam,
-    Yan,
-    Yao,
-    Yap,
-    Yaq,
-    Ybb,
-    Yby,
-    Ycn,
-    Ydd,
-    Yid,
-    Yim,
-    Yka,
-    Yle,
-    Yli,
-    Yml,
-    Yom,
-    Yon,
-    Yor,
-    Yrb,
-    Yre,
-    Yrk,
-    Yrl,
-    Yss,
-    Yua,
-    Yue,
-    Yuj,
-    Yup,
-    Yut,
-    Yuw,
-    Yuz,
-    Yva,
-    Zaa,
-    Zab,
-    Zac,
-    Zad,
-    Zae,
-    Zai,
-    Zam,
-    Zao,
-    Zar,
-    Zas,
-    Zat,
-    Zav,
-    Zaw,
-    Zca,
-    Zdj,
-    Zea,
-    Zgh,
-    Zho,
-    Zia,
-    Ziw,
-    Zlm,
-    Zne,
-    Zom,
-    Zos,
-    Zpa,
-    Zpc,
-    Zpd,
-    Zpf,
-    Zpg,
-    Zpi,
-    Zpj,
-    Zpl,
-    Zpm,
-    Zpo,
-    Zpq,
-    Zpt,
-    Zpu,
-    Zpv,
-    Zpz,
-    Zsm,
-    Zsr,
-    Ztq,
-    Zty,
-    Zul,
-    Zyb,
-    Zyp,
-    Zza,
-    Multi,
+/// Language metadata, as found in `data/lang.tsv`.
+struct LangInfo {
+    tag: &'static str,
+    iso639_3: &'static str,
+    iso639_1: Option<&'static str>,
+    script: Option<&'static str>,
+    name: &'static str,
+    macrolanguage: Option<&'static str>,
 }
 
+include!(concat!(env!("OUT_DIR"), "/lang.rs"));
+
 impl Lang {
+    /// Iterate over all languages.
+    pub fn all() -> impl Iterator<Item = Lang> {
+        ALL.iter().copied()
+    }
+
+    fn info(self) -> &'static LangInfo {
+        &LANG_INFO[self as usize]
+    }
+
     /// Convert to a `'static str` for display/serialization
     pub fn to_static(self) -> &'static str {
-        let lang_str: &'static str = match self {
-            Self::Aahlatn => "aah-Latn",
-            Self::Aailatn => "aai-Latn",
-            Self::Aaklatn => "aak-Latn",
-            Self::Aaulatn => "aau-Latn",
-            Self::Aazlatn => "aaz-Latn",
-            Self::Abalatn => "aba-Latn",
-            Self::Abilatn => "abi-Latn",
-            Self::Abkcyrl => "abk-Cyrl",
-            Self::Abnlatn => "abn-Latn",
-            Self::Abqcyrl => "abq-Cyrl",
-            Self::Abslatn => "abs-Latn",
-            Self::Abtlatn => "abt-Latn",
-            Self::Abxlatn => "abx-Latn",
-            Self::Abylatn => "aby-Latn",
-            Self::Abzlatn => "abz-Latn",
-            Self::Acalatn => "aca-Latn",
-            Self::Acdlatn => "acd-Latn",
-            Self::Acearab => "ace-Arab",
-            Self::Acelatn => "ace-Latn",
-            Self::Acflatn => "acf-Latn",
-            Self::Achlatn => "ach-Latn",
-            Self::Acmarab => "acm-Arab",
-            Self::Acnlatn => "acn-Latn",
-            Self::Acqarab => "acq-Arab",
-            Self::Acrlatn => "acr-Latn",
-            Self::Aculatn => "acu-Latn",
-            Self::Adalatn => "ada-Latn",
-            Self::Adelatn => "ade-Latn",
-            Self::Adhlatn => "adh-Latn",
-            Self::Adilatn => "adi-Latn",
-            Self::Adjlatn => "adj-Latn",
-            Self::Adllatn => "adl-Latn",
-            Self::Adxtibt => "adx-Tibt",
-            Self::Adycyrl => "ady-Cyrl",
-            Self::Adzlatn => "adz-Latn",
-            Self::Aebarab => "aeb-Arab",
-            Self::Aerlatn => "aer-Latn",
-            Self::Aeulatn => "aeu-Latn",
-            Self::Aeylatn => "aey-Latn",
-            Self::Afrlatn => "afr-Latn",
-            Self::Agdlatn => "agd-Latn",
-            Self::Agglatn => "agg-Latn",
-            Self::Agmlatn => "agm-Latn",
-            Self::Agnlatn => "agn-Latn",
-            Self::Agrlatn => "agr-Latn",
-            Self::Agtlatn => "agt-Latn",
-            Self::Agulatn => "agu-Latn",
-            Self::Agwlatn => "agw-Latn",
-            Self::Agxcyrl => "agx-Cyrl",
-            Self::Ahalatn => "aha-Latn",
-            Self::Ahklatn => "ahk-Latn",
-            Self::Aialatn => "aia-Latn",
-            Self::Aiisyrc => "aii-Syrc",
-            Self::Aimlatn => "aim-Latn",
-            Self::Ainlatn => "ain-Latn",
-            Self::Ajglatn => "ajg-Latn",
-            Self::Ajilatn => "aji-Latn",
-            Self::Ajparab => "ajp-Arab",
-            Self::Ajzlatn => "ajz-Latn",
-            Self::Akalatn => "aka-Latn",
-            Self::Akblatn => "akb-Latn",
-            Self::Akelatn => "ake-Latn",
-            Self::Akhlatn => "akh-Latn",
-            Self::Akplatn => "akp-Latn",
-            Self::Aldlatn => "ald-Latn",
-            Self::Aljlatn => "alj-Latn",
-            Self::Alnlatn => "aln-Latn",
-            Self::Alplatn => "alp-Latn",
-            Self::Alqlatn => "alq-Latn",
-            Self::Alslatn => "als-Latn",
-            Self::Altcyrl => "alt-Cyrl",
-            Self::Alylatn => "aly-Latn",
-            Self::Alzlatn => "alz-Latn",
-            Self::Amelatn => "ame-Latn",
-            Self::Amflatn => "amf-Latn",
-            Self::Amhethi => "amh-Ethi",
-            Self::Amilatn => "ami-Latn",
-            Self::Amklatn => "amk-Latn",
-            Self::Ammlatn => "amm-Latn",
-            Self::Amnlatn => "amn-Latn",
-            Self::Amplatn => "amp-Latn",
-            Self::Amrlatn => "amr-Latn",
-            Self::Amulatn => "amu-Latn",
-            Self::Amxlatn => "amx-Latn",
-            Self::Anglatn => "ang-Latn",
-            Self::Anmlatn => "anm-Latn",
-            Self::Annlatn => "ann-Latn",
-            Self::Anpdeva => "anp-Deva",
-            Self::Anvlatn => "anv-Latn",
-            Self::Anylatn => "any-Latn",
-            Self::Aoilatn => "aoi-Latn",
-            Self::Aojlatn => "aoj-Latn",
-            Self::Aomlatn => "aom-Latn",
-            Self::Aonlatn => "aon-Latn",
-            Self::Aozlatn => "aoz-Latn",
-            Self::Apblatn => "apb-Latn",
-            Self::Apcarab => "apc-Arab",
-            Self::Apelatn => "ape-Latn",
-            Self::Apnlatn => "apn-Latn",
-            Self::Aprlatn => "apr-Latn",
-            Self::Aptlatn => "apt-Latn",
-            Self::Apulatn => "apu-Latn",
-            Self::Apwlatn => "apw-Latn",
-            Self::Apylatn => "apy-Latn",
-            Self::Apzlatn => "apz-Latn",
-            Self::Aqzlatn => "aqz-Latn",
-            Self::Araarab => "ara-Arab",
-            Self::Arbarab => "arb-Arab",
-            Self::Arblatn => "arb-Latn",
-            Self::Arelatn => "are-Latn",
-            Self::Arglatn => "arg-Latn",
-            Self::Arllatn => "arl-Latn",
-            Self::Arnlatn => "arn-Latn",
-            Self::Arplatn => "arp-Latn",
-            Self::Arqarab => "arq-Arab",
-            Self::Arrlatn => "arr-Latn",
-            Self::Arsarab => "ars-Arab",
-            Self::Aryarab => "ary-Arab",
-            Self::Arzarab => "arz-Arab",
-            Self::Asglatn => "asg-Latn",
-            Self::Asmbeng => "asm-Beng",
-            Self::Asmlatn => "asm-Latn",
-            Self::Asolatn => "aso-Latn",
-            Self::Astlatn => "ast-Latn",
-            Self::Atalatn => "ata-Latn",
-            Self::Atblatn => "atb-Latn",
-            Self::Atdlatn => "atd-Latn",
-            Self::Atglatn => "atg-Latn",
-            Self::Atilatn => "ati-Latn",
-            Self::Atjlatn => "atj-Latn",
-            Self::Atqlatn => "atq-Latn",
-            Self::Attlatn => "att-Latn",
-            Self::Auclatn => "auc-Latn",
-            Self::Auilatn => "aui-Latn",
-            Self::Auylatn => "auy-Latn",
-            Self::Avacyrl => "ava-Cyrl",
-            Self::Avklatn => "avk-Latn",
-            Self::Avnlatn => "avn-Latn",
-            Self::Avtlatn => "avt-Latn",
-            Self::Avulatn => "avu-Latn",
-            Self::Awadeva => "awa-Deva",
-            Self::Awblatn => "awb-Latn",
-            Self::Awilatn => "awi-Latn",
-            Self::Awxlatn => "awx-Latn",
-            Self::Aymlatn => "aym-Latn",
-            Self::Ayolatn => "ayo-Latn",
-            Self::Ayparab => "ayp-Arab",
-            Self::Ayrlatn => "ayr-Latn",
-            Self::Azbarab => "azb-Arab",
-            Self::Azecyrl => "aze-Cyrl",
-            Self::Azelatn => "aze-Latn",
-            Self::Azglatn => "azg-Latn",
-            Self::Azjcyrl => "azj-Cyrl",
-            Self::Azjlatn => "azj-Latn",
-            Self::Azzlatn => "azz-Latn",
-            Self::Bakcyrl => "bak-Cyrl",
-            Self::Balarab => "bal-Arab",
-            Self::Bamlatn => "bam-Latn",
-            Self::Banlatn => "ban-Latn",
-            Self::Baolatn => "bao-Latn",
-            Self::Barlatn => "bar-Latn",
-            Self::Baslatn => "bas-Latn",
-            Self::Bavlatn => "bav-Latn",
-            Self::Bbalatn => "bba-Latn",
-            Self::Bbblatn => "bbb-Latn",
-            Self::Bbclatn => "bbc-Latn",
-            Self::Bbjlatn => "bbj-Latn",
-            Self::Bbklatn => "bbk-Latn",
-            Self::Bbolatn => "bbo-Latn",
-            Self::Bbrlatn => "bbr-Latn",
-            Self::Bccarab => "bcc-Arab",
-            Self::Bchlatn => "bch-Latn",
-            Self::Bcilatn => "bci-Latn",
-            Self::Bcllatn => "bcl-Latn",
-            Self::Bcolatn => "bco-Latn",
-            Self::Bcwlatn => "bcw-Latn",
-            Self::Bddlatn => "bdd-Latn",
-            Self::Bdhlatn => "bdh-Latn",
-            Self::Bdqlatn => "bdq-Latn",
-            Self::Bealatn => "bea-Latn",
-            Self::Beflatn => "bef-Latn",
-            Self::Belcyrl => "bel-Cyrl",
-            Self::Bemlatn => "bem-Latn",
-            Self::Benbeng => "ben-Beng",
-            Self::Benlatn => "ben-Latn",
-            Self::Beqlatn => "beq-Latn",
-            Self::Berlatn => "ber-Latn",
-            Self::Bewlatn => "bew-Latn",
-            Self::Bexlatn => "bex-Latn",
-            Self::Bfdlatn => "bfd-Latn",
-            Self::Bfolatn => "bfo-Latn",
-            Self::Bgrlatn => "bgr-Latn",
-            Self::Bgslatn => "bgs-Latn",
-            Self::Bgtlatn => "bgt-Latn",
-            Self::Bgzlatn => "bgz-Latn",
-            Self::Bhglatn => "bhg-Latn",
-            Self::Bhllatn => "bhl-Latn",
-            Self::Bhodeva => "bho-Deva",
-            Self::Bhplatn => "bhp-Latn",
-            Self::Bhwlatn => "bhw-Latn",
-            Self::Bhzlatn => "bhz-Latn",
-            Self::Biblatn => "bib-Latn",
-            Self::Biglatn => "big-Latn",
-            Self::Biklatn => "bik-Latn",
-            Self::Bimlatn => "bim-Latn",
-            Self::Binlatn => "bin-Latn",
-            Self::Bislatn => "bis-Latn",
-            Self::Biulatn => "biu-Latn",
-            Self::Bivlatn => "biv-Latn",
-            Self::Bjnarab => "bjn-Arab",
-            Self::Bjnlatn => "bjn-Latn",
-            Self::Bjplatn => "bjp-Latn",
-            Self::Bjrlatn => "bjr-Latn",
-            Self::Bjvlatn => "bjv-Latn",
-            Self::Bkdlatn => "bkd-Latn",
-            Self::Bkllatn => "bkl-Latn",
-            Self::Bkqlatn => "bkq-Latn",
-            Self::Bkulatn => "bku-Latn",
-            Self::Bkvlatn => "bkv-Latn",
-            Self::Blalatn => "bla-Latn",
-            Self::Blhlatn => "blh-Latn",
-            Self::Blkmymr => "blk-Mymr",
-            Self::Bltlatn => "blt-Latn",
-            Self::Blwlatn => "blw-Latn",
-            Self::Blzlatn => "blz-Latn",
-            Self::Bmhlatn => "bmh-Latn",
-            Self::Bmklatn => "bmk-Latn",
-            Self::Bmqlatn => "bmq-Latn",
-            Self::Bmrlatn => "bmr-Latn",
-            Self::Bmulatn => "bmu-Latn",
-            Self::Bmvlatn => "bmv-Latn",
-            Self::Bnjlatn => "bnj-Latn",
-            Self::Bnolatn => "bno-Latn",
-            Self::Bnplatn => "bnp-Latn",
-            Self::Boalatn => "boa-Latn",
-            Self::Bodtibt => "bod-Tibt",
-            Self::Bojlatn => "boj-Latn",
-            Self::Bomlatn => "bom-Latn",
-            Self::Bonlatn => "bon-Latn",
-            Self::Borlatn => "bor-Latn",
-            Self::Boslatn => "bos-Latn",
-            Self::Bovlatn => "bov-Latn",
-            Self::Boxlatn => "box-Latn",
-            Self::Bprlatn => "bpr-Latn",
-            Self::Bpslatn => "bps-Latn",
-            Self::Bpybeng => "bpy-Beng",
-            Self::Bqclatn => "bqc-Latn",
-            Self::Bqjlatn => "bqj-Latn",
-            Self::Bqplatn => "bqp-Latn",
-            Self::Brelatn => "bre-Latn",
-            Self::Brharab => "brh-Arab",
-            Self::Brulatn => "bru-Latn",
-            Self::Brxdeva => "brx-Deva",
-            Self::Brxlatn => "brx-Latn",
-            Self::Bsclatn => "bsc-Latn",
-            Self::Bsnlatn => "bsn-Latn",
-            Self::Bsplatn => "bsp-Latn",
-            Self::Bsqlatn => "bsq-Latn",
-            Self::Bsslatn => "bss-Latn",
-            Self::Btdlatn => "btd-Latn",
-            Self::Bthlatn => "bth-Latn",
-            Self::Btslatn => "bts-Latn",
-            Self::Bttlatn => "btt-Latn",
-            Self::Btxlatn => "btx-Latn",
-            Self::Buacyrl => "bua-Cyrl",
-            Self::Budlatn => "bud-Latn",
-            Self::Buglatn => "bug-Latn",
-            Self::Buklatn => "buk-Latn",
-            Self::Bulcyrl => "bul-Cyrl",
-            Self::Bumlatn => "bum-Latn",
-            Self::Buslatn => "bus-Latn",
-            Self::Bvclatn => "bvc-Latn",
-            Self::Bvdlatn => "bvd-Latn",
-            Self::Bvrlatn => "bvr-Latn",
-            Self::Bvzlatn => "bvz-Latn",
-            Self::Bwdlatn => "bwd-Latn",
-            Self::Bwilatn => "bwi-Latn",
-            Self::Bwqlatn => "bwq-Latn",
-            Self::Bwulatn => "bwu-Latn",
-            Self::Bxhlatn => "bxh-Latn",
-            Self::Bxrcyrl => "bxr-Cyrl",
-            Self::Byrlatn => "byr-Latn",
-            Self::Byvlatn => "byv-Latn",
-            Self::Byxlatn => "byx-Latn",
-            Self::Bzdlatn => "bzd-Latn",
-            Self::Bzhlatn => "bzh-Latn",
-            Self::Bzithai => "bzi-Thai",
-            Self::Bzjlatn => "bzj-Latn",
-            Self::Caalatn => "caa-Latn",
-            Self::Cablatn => "cab-Latn",
-            Self::Caclatn => "cac-Latn",
-            Self::Caflatn => "caf-Latn",
-            Self::Caglatn => "cag-Latn",
-            Self::Caklatn => "cak-Latn",
-            Self::Caolatn => "cao-Latn",
-            Self::Caplatn => "cap-Latn",
-            Self::Caqlatn => "caq-Latn",
-            Self::Carlatn => "car-Latn",
-            Self::Caslatn => "cas-Latn",
-            Self::Catlatn => "cat-Latn",
-            Self::Cavlatn => "cav-Latn",
-            Self::Caxlatn => "cax-Latn",
-            Self::Cbclatn => "cbc-Latn",
-            Self::Cbilatn => "cbi-Latn",
-            Self::Cbklatn => "cbk-Latn",
-            Self::Cbrlatn => "cbr-Latn",
-            Self::Cbslatn => "cbs-Latn",
-            Self::Cbtlatn => "cbt-Latn",
-            Self::Cbulatn => "cbu-Latn",
-            Self::Cbvlatn => "cbv-Latn",
-            Self::Ccelatn => "cce-Latn",
-            Self::Ccolatn => "cco-Latn",
-            Self::Ccplatn => "ccp-Latn",
-            Self::Cdflatn => "cdf-Latn",
-            Self::Ceblatn => "ceb-Latn",
-            Self::Ceglatn => "ceg-Latn",
-            Self::Ceklatn => "cek-Latn",
-            Self::Ceslatn => "ces-Latn",
-            Self::Cfmlatn => "cfm-Latn",
-            Self::Cgclatn => "cgc-Latn",
-            Self::Cgglatn => "cgg-Latn",
-            Self::Chalatn => "cha-Latn",
-            Self::Chdlatn => "chd-Latn",
-            Self::Checyrl => "che-Cyrl",
-            Self::Chflatn => "chf-Latn",
-            Self::Chjlatn => "chj-Latn",
-            Self::Chklatn => "chk-Latn",
-            Self::Cholatn => "cho-Latn",
-            Self::Chqlatn => "chq-Latn",
-            Self::Chrcher => "chr-Cher",
-            Self::Chrlatn => "chr-Latn",
-            Self::Chucyrl => "chu-Cyrl",
-            Self::Chvcyrl => "chv-Cyrl",
-            Self::Chwlatn => "chw-Latn",
-            Self::Chzlatn => "chz-Latn",
-            Self::Cjklatn => "cjk-Latn",
-            Self::Cjolatn => "cjo-Latn",
-            Self::Cjplatn => "cjp-Latn",
-            Self::Cjscyrl => "cjs-Cyrl",
-            Self::Cjvlatn => "cjv-Latn",
-            Self::Ckbarab => "ckb-Arab",
-            Self::Ckmlatn => "ckm-Latn",
-            Self::Ckolatn => "cko-Latn",
-            Self::Cktcyrl => "ckt-Cyrl",
-            Self::Clelatn => "cle-Latn",
-            Self::Clulatn => "clu-Latn",
-            Self::Clylatn => "cly-Latn",
-            Self::Cmelatn => "cme-Latn",
-            Self::Cmnhani => "cmn-Hani",
-            Self::Cmokhmr => "cmo-Khmr",
-            Self::Cmolatn => "cmo-Latn",
-            Self::Cmrlatn => "cmr-Latn",
-            Self::Cnhlatn => "cnh-Latn",
-            Self::Cnilatn => "cni-Latn",
-            Self::Cnklatn => "cnk-Latn",
-            Self::Cnllatn => "cnl-Latn",
-            Self::Cnrlatn => "cnr-Latn",
-            Self::Cntlatn => "cnt-Latn",
-            Self::Cnwlatn => "cnw-Latn",
-            Self::Coelatn => "coe-Latn",
-            Self::Coflatn => "cof-Latn",
-            Self::Coklatn => "cok-Latn",
-            Self::Conlatn => "con-Latn",
-            Self::Copcopt => "cop-Copt",
-            Self::Corlatn => "cor-Latn",
-            Self::Coslatn => "cos-Latn",
-            Self::Cotlatn => "cot-Latn",
-            Self::Coulatn => "cou-Latn",
-            Self::Cpalatn => "cpa-Latn",
-            Self::Cpblatn => "cpb-Latn",
-            Self::Cpclatn => "cpc-Latn",
-            Self::Cpulatn => "cpu-Latn",
-            Self::Cpylatn => "cpy-Latn",
-            Self::Crhcyrl => "crh-Cyrl",
-            Self::Crhlatn => "crh-Latn",
-            Self::Crilatn => "cri-Latn",
-            Self::Crjcans => "crj-Cans",
-            Self::Crkcans => "crk-Cans",
-            Self::Crklatn => "crk-Latn",
-            Self::Crlcans => "crl-Cans",
-            Self::Crmcans => "crm-Cans",
-            Self::Crnlatn => "crn-Latn",
-            Self::Crslatn => "crs-Latn",
-            Self::Crtlatn => "crt-Latn",
-            Self::Crxlatn => "crx-Latn",
-            Self::Csblatn => "csb-Latn",
-            Self::Csklatn => "csk-Latn",
-            Self::Csolatn => "cso-Latn",
-            Self::Cswcans => "csw-Cans",
-            Self::Cswlatn => "csw-Latn",
-            Self::Csylatn => "csy-Latn",
-            Self::Ctalatn => "cta-Latn",
-            Self::Ctdlatn => "ctd-Latn",
-            Self::Ctolatn => "cto-Latn",
-            Self::Ctplatn => "ctp-Latn",
-            Self::Ctulatn => "ctu-Latn",
-            Self::Cublatn => "cub-Latn",
-            Self::Cuclatn => "cuc-Latn",
-            Self::Cuilatn => "cui-Latn",
-            Self::Cuklatn => "cuk-Latn",
-            Self::Cullatn => "cul-Latn",
-            Self::Cutlatn => "cut-Latn",
-            Self::Cuxlatn => "cux-Latn",
-            Self::Cwdcans => "cwd-Cans",
-            Self::Cwelatn => "cwe-Latn",
-            Self::Cwtlatn => "cwt-Latn",
-            Self::Cyalatn => "cya-Latn",
-            Self::Cymlatn => "cym-Latn",
-            Self::Cztlatn => "czt-Latn",
-            Self::Daalatn => "daa-Latn",
-            Self::Dadlatn => "dad-Latn",
-            Self::Daflatn => "daf-Latn",
-            Self::Daglatn => "dag-Latn",
-            Self::Dahlatn => "dah-Latn",
-            Self::Daklatn => "dak-Latn",
-            Self::Danlatn => "dan-Latn",
-            Self::Darcyrl => "dar-Cyrl",
-            Self::Dbqlatn => "dbq-Latn",
-            Self::Ddglatn => "ddg-Latn",
-            Self::Ddnlatn => "ddn-Latn",
-            Self::Dedlatn => "ded-Latn",
-            Self::Deslatn => "des-Latn",
-            Self::Deulatn => "deu-Latn",
-            Self::Dgalatn => "dga-Latn",
-            Self::Dgclatn => "dgc-Latn",
-            Self::Dgilatn => "dgi-Latn",
-            Self::Dgrlatn => "dgr-Latn",
-            Self::Dgzlatn => "dgz-Latn",
-            Self::Dhglatn => "dhg-Latn",
-            Self::Dhmlatn => "dhm-Latn",
-            Self::Dhvlatn => "dhv-Latn",
-            Self::Didlatn => "did-Latn",
-            Self::Diglatn => "dig-Latn",
-            Self::Diklatn => "dik-Latn",
-            Self::Diplatn => "dip-Latn",
-            Self::Diqlatn => "diq-Latn",
-            Self::Dislatn => "dis-Latn",
-            Self::Diulatn => "diu-Latn",
-            Self::Divthaa => "div-Thaa",
-            Self::Djelatn => "dje-Latn",
-            Self::Djklatn => "djk-Latn",
-            Self::Djrlatn => "djr-Latn",
-            Self::Dkslatn => "dks-Latn",
-            Self::Dlnlatn => "dln-Latn",
-            Self::Dngcyrl => "dng-Cyrl",
-            Self::Dnjlatn => "dnj-Latn",
-            Self::Dnwlatn => "dnw-Latn",
-            Self::Doblatn => "dob-Latn",
-            Self::Doideva => "doi-Deva",
-            Self::Doplatn => "dop-Latn",
-            Self::Doslatn => "dos-Latn",
-            Self::Dowlatn => "dow-Latn",
-            Self::Drglatn => "drg-Latn",
-            Self::Drulatn => "dru-Latn",
-            Self::Dsblatn => "dsb-Latn",
-            Self::Dshlatn => "dsh-Latn",
-            Self::Dtblatn => "dtb-Latn",
-            Self::Dtplatn => "dtp-Latn",
-            Self::Dtslatn => "dts-Latn",
-            Self::Dtydeva => "dty-Deva",
-            Self::Dualatn => "dua-Latn",
-            Self::Duelatn => "due-Latn",
-            Self::Duglatn => "dug-Latn",
-            Self::Duolatn => "duo-Latn",
-            Self::Durlatn => "dur-Latn",
-            Self::Dwrlatn => "dwr-Latn",
-            Self::Dwwlatn => "dww-Latn",
-            Self::Dyilatn => "dyi-Latn",
-            Self::Dyolatn => "dyo-Latn",
-            Self::Dyulatn => "dyu-Latn",
-            Self::Dzotibt => "dzo-Tibt",
-            Self::Ebklatn => "ebk-Latn",
-            Self::Efilatn => "efi-Latn",
-            Self::Ekalatn => "eka-Latn",
-            Self::Ekklatn => "ekk-Latn",
-            Self::Ekolatn => "eko-Latn",
-            Self::Ellgrek => "ell-Grek",
-            Self::Emelatn => "eme-Latn",
-            Self::Emilatn => "emi-Latn",
-            Self::Emllatn => "eml-Latn",
-            Self::Emplatn => "emp-Latn",
-            Self::Enblatn => "enb-Latn",
-            Self::Englatn => "eng-Latn",
-            Self::Enllatn => "enl-Latn",
-            Self::Enmlatn => "enm-Latn",
-            Self::Enqlatn => "enq-Latn",
-            Self::Enxlatn => "enx-Latn",
-            Self::Epolatn => "epo-Latn",
-            Self::Erilatn => "eri-Latn",
-            Self::Eselatn => "ese-Latn",
-            Self::Esilatn => "esi-Latn",
-            Self::Esklatn => "esk-Latn",
-            Self::Esslatn => "ess-Latn",
-            Self::Estlatn => "est-Latn",
-            Self::Esulatn => "esu-Latn",
-            Self::Etolatn => "eto-Latn",
-            Self::Etrlatn => "etr-Latn",
-            Self::Etulatn => "etu-Latn",
-            Self::Euslatn => "eus-Latn",
-            Self::Evecyrl => "eve-Cyrl",
-            Self::Ewelatn => "ewe-Latn",
-            Self::Ewolatn => "ewo-Latn",
-            Self::Extlatn => "ext-Latn",
-            Self::Ezalatn => "eza-Latn",
-            Self::Faalatn => "faa-Latn",
-            Self::Fadlatn => "fad-Latn",
-            Self::Failatn => "fai-Latn",
-            Self::Fallatn => "fal-Latn",
-            Self::Fanlatn => "fan-Latn",
-            Self::Faolatn => "fao-Latn",
-            Self::Farlatn => "far-Latn",
-            Self::Fasarab => "fas-Arab",
-            Self::Fatlatn => "fat-Latn",
-            Self::Ffmlatn => "ffm-Latn",
-            Self::Fijlatn => "fij-Latn",
-            Self::Fillatn => "fil-Latn",
-            Self::Finlatn => "fin-Latn",
-            Self::Fitlatn => "fit-Latn",
-            Self::Fkvlatn => "fkv-Latn",
-            Self::Fmudeva => "fmu-Deva",
-            Self::Fonlatn => "fon-Latn",
-            Self::Forlatn => "for-Latn",
-            Self::Fralatn => "fra-Latn",
-            Self::Frdlatn => "frd-Latn",
-            Self::Frmlatn => "frm-Latn",
-            Self::Frolatn => "fro-Latn",
-            Self::Frplatn => "frp-Latn",
-            Self::Frrlatn => "frr-Latn",
-            Self::Frylatn => "fry-Latn",
-            Self::Fublatn => "fub-Latn",
-            Self::Fudlatn => "fud-Latn",
-            Self::Fuelatn => "fue-Latn",
-            Self::Fuflatn => "fuf-Latn",
-            Self::Fuhlatn => "fuh-Latn",
-            Self::Fuqlatn => "fuq-Latn",
-            Self::Furlatn => "fur-Latn",
-            Self::Fuvarab => "fuv-Arab",
-            Self::Fuvlatn => "fuv-Latn",
-            Self::Gaalatn => "gaa-Latn",
-            Self::Gagcyrl => "gag-Cyrl",
-            Self::Gaglatn => "gag-Latn",
-            Self::Gahlatn => "gah-Latn",
-            Self::Gailatn => "gai-Latn",
-            Self::Gamlatn => "gam-Latn",
-            Self::Gawlatn => "gaw-Latn",
-            Self::Gazlatn => "gaz-Latn",
-            Self::Gbilatn => "gbi-Latn",
-            Self::Gbolatn => "gbo-Latn",
-            Self::Gbrlatn => "gbr-Latn",
-            Self::Gcflatn => "gcf-Latn",
-            Self::Gcrlatn => "gcr-Latn",
-            Self::Gdelatn => "gde-Latn",
-            Self::Gdglatn => "gdg-Latn",
-            Self::Gdnlatn => "gdn-Latn",
-            Self::Gdrlatn => "gdr-Latn",
-            Self::Geblatn => "geb-Latn",
-            Self::Gejlatn => "gej-Latn",
-            Self::Gfklatn => "gfk-Latn",
-            Self::Ghedeva => "ghe-Deva",
-            Self::Ghslatn => "ghs-Latn",
-            Self::Gidlatn => "gid-Latn",
-            Self::Gillatn => "gil-Latn",
-            Self::Gizlatn => "giz-Latn",
-            Self::Gjnlatn => "gjn-Latn",
-            Self::Gknlatn => "gkn-Latn",
-            Self::Glalatn => "gla-Latn",
-            Self::Glelatn => "gle-Latn",
-            Self::Glglatn => "glg-Latn",
-            Self::Glkarab => "glk-Arab",
-            Self::Glvlatn => "glv-Latn",
-            Self::Gmhlatn => "gmh-Latn",
-            Self::Gmvethi => "gmv-Ethi",
-            Self::Gmvlatn => "gmv-Latn",
-            Self::Gnalatn => "gna-Latn",
-            Self::Gnblatn => "gnb-Latn",
-            Self::Gndlatn => "gnd-Latn",
-            Self::Gnglatn => "gng-Latn",
-            Self::Gnnlatn => "gnn-Latn",
-            Self::Gnwlatn => "gnw-Latn",
-            Self::Goalatn => "goa-Latn",
-            Self::Gofethi => "gof-Ethi",
-            Self::Goflatn => "gof-Latn",
-            Self::Goglatn => "gog-Latn",
-            Self::Gohlatn => "goh-Latn",
-            Self::Gomdeva => "gom-Deva",
-            Self::Gomlatn => "gom-Latn",
-            Self::Gorlatn => "gor-Latn",
-            Self::Goslatn => "gos-Latn",
-            Self::Gotgoth => "got-Goth",
-            Self::Gotlatn => "got-Latn",
-            Self::Gqrlatn => "gqr-Latn",
-            Self::Grcgrek => "grc-Grek",
-            Self::Grnlatn => "grn-Latn",
-            Self::Grtbeng => "grt-Beng",
-            Self::Gsolatn => "gso-Latn",
-            Self::Gswlatn => "gsw-Latn",
-            Self::Gublatn => "gub-Latn",
-            Self::Guclatn => "guc-Latn",
-            Self::Gudlatn => "gud-Latn",
-            Self::Guglatn => "gug-Latn",
-            Self::Guhlatn => "guh-Latn",
-            Self::Guilatn => "gui-Latn",
-            Self::Gujgujr => "guj-Gujr",
-            Self::Gujlatn => "guj-Latn",
-            Self::Gukethi => "guk-Ethi",
-            Self::Gullatn => "gul-Latn",
-            Self::Gumlatn => "gum-Latn",
-            Self::Gunlatn => "gun-Latn",
-            Self::Guolatn => "guo-Latn",
-            Self::Guqlatn => "guq-Latn",
-            Self::Gurlatn => "gur-Latn",
-            Self::Guulatn => "guu-Latn",
-            Self::Guwlatn => "guw-Latn",
-            Self::Guxlatn => "gux-Latn",
-            Self::Guzlatn => "guz-Latn",
-            Self::Gvclatn => "gvc-Latn",
-            Self::Gvflatn => "gvf-Latn",
-            Self::Gvllatn => "gvl-Latn",
-            Self::Gvnlatn => "gvn-Latn",
-            Self::Gwilatn => "gwi-Latn",
-            Self::Gwrlatn => "gwr-Latn",
-            Self::Gyalatn => "gya-Latn",
-            Self::Gymlatn => "gym-Latn",
-            Self::Gyrlatn => "gyr-Latn",
-            Self::Hacarab => "hac-Arab",
-            Self::Haelatn => "hae-Latn",
-            Self::Haglatn => "hag-Latn",
-            Self::Hakhani => "hak-Hani",
-            Self::Haklatn => "hak-Latn",
-            Self::Hatlatn => "hat-Latn",
-            Self::Haulatn => "hau-Latn",
-            Self::Havlatn => "hav-Latn",
-            Self::Hawlatn => "haw-Latn",
-            Self::Haylatn => "hay-Latn",
-            Self::Hbohebr => "hbo-Hebr",
-            Self::Hbscyrl => "hbs-Cyrl",
-            Self::Hbslatn => "hbs-Latn",
-            Self::Hchlatn => "hch-Latn",
-            Self::Hebhebr => "heb-Hebr",
-            Self::Heglatn => "heg-Latn",
-            Self::Hehlatn => "heh-Latn",
-            Self::Herlatn => "her-Latn",
-            Self::Hiflatn => "hif-Latn",
-            Self::Higlatn => "hig-Latn",
-            Self::Hillatn => "hil-Latn",
-            Self::Hindeva => "hin-Deva",
-            Self::Hinlatn => "hin-Latn",
-            Self::Hixlatn => "hix-Latn",
-            Self::Hlalatn => "hla-Latn",
-            Self::Hltlatn => "hlt-Latn",
-            Self::Hmnlatn => "hmn-Latn",
-            Self::Hmolatn => "hmo-Latn",
-            Self::Hmrlatn => "hmr-Latn",
-            Self::Hnedeva => "hne-Deva",
-            Self::Hnjlatn => "hnj-Latn",
-            Self::Hnnlatn => "hnn-Latn",
-            Self::Hnslatn => "hns-Latn",
-            Self::Hoclatn => "hoc-Latn",
-            Self::Hocwara => "hoc-Wara",
-            Self::Hoplatn => "hop-Latn",
-            Self::Hotlatn => "hot-Latn",
-            Self::Hralatn => "hra-Latn",
-            Self::Hrvlatn => "hrv-Latn",
-            Self::Hrxlatn => "hrx-Latn",
-            Self::Hsblatn => "hsb-Latn",
-            Self::Htolatn => "hto-Latn",
-            Self::Hublatn => "hub-Latn",
-            Self::Huilatn => "hui-Latn",
-            Self::Hunlatn => "hun-Latn",
-            Self::Huslatn => "hus-Latn",
-            Self::Huulatn => "huu-Latn",
-            Self::Huvlatn => "huv-Latn",
-            Self::Hvnlatn => "hvn-Latn",
-            Self::Hwclatn => "hwc-Latn",
-            Self::Hyearmn => "hye-Armn",
-            Self::Hywarmn => "hyw-Armn",
-            Self::Ianlatn => "ian-Latn",
-            Self::Ibalatn => "iba-Latn",
-            Self::Ibglatn => "ibg-Latn",
-            Self::Ibolatn => "ibo-Latn",
-            Self::Icrlatn => "icr-Latn",
-            Self::Idolatn => "ido-Latn",
-            Self::Idulatn => "idu-Latn",
-            Self::Ifalatn => "ifa-Latn",
-            Self::Ifblatn => "ifb-Latn",
-            Self::Ifelatn => "ife-Latn",
-            Self::Ifklatn => "ifk-Latn",
-            Self::Ifulatn => "ifu-Latn",
-            Self::Ifylatn => "ify-Latn",
-            Self::Igelatn => "ige-Latn",
-            Self::Ignlatn => "ign-Latn",
-            Self::Ikecans => "ike-Cans",
-            Self::Ikklatn => "ikk-Latn",
-            Self::Iktlatn => "ikt-Latn",
-            Self::Ikwlatn => "ikw-Latn",
-            Self::Ilblatn => "ilb-Latn",
-            Self::Ilelatn => "ile-Latn",
-            Self::Ilolatn => "ilo-Latn",
-            Self::Imolatn => "imo-Latn",
-            Self::Inalatn => "ina-Latn",
-            Self::Inblatn => "inb-Latn",
-            Self::Indlatn => "ind-Latn",
-            Self::Inhcyrl => "inh-Cyrl",
-            Self::Inolatn => "ino-Latn",
-            Self::Ioulatn => "iou-Latn",
-            Self::Ipilatn => "ipi-Latn",
-            Self::Iqwlatn => "iqw-Latn",
-            Self::Irilatn => "iri-Latn",
-            Self::Irklatn => "irk-Latn",
-            Self::Irylatn => "iry-Latn",
-            Self::Isdlatn => "isd-Latn",
-            Self::Ishlatn => "ish-Latn",
-            Self::Isllatn => "isl-Latn",
-            Self::Isolatn => "iso-Latn",
-            Self::Italatn => "ita-Latn",
-            Self::Itlcyrl => "itl-Cyrl",
-            Self::Itvlatn => "itv-Latn",
-            Self::Iumlatn => "ium-Latn",
-            Self::Ivblatn => "ivb-Latn",
-            Self::Ivvlatn => "ivv-Latn",
-            Self::Iwslatn => "iws-Latn",
-            Self::Ixllatn => "ixl-Latn",
-            Self::Izrlatn => "izr-Latn",
-            Self::Izzlatn => "izz-Latn",
-            Self::Jaalatn => "jaa-Latn",
-            Self::Jaclatn => "jac-Latn",
-            Self::Jaelatn => "jae-Latn",
-            Self::Jamlatn => "jam-Latn",
-            Self::Javjava => "jav-Java",
-            Self::Javlatn => "jav-Latn",
-            Self::Jbolatn => "jbo-Latn",
-            Self::Jbulatn => "jbu-Latn",
-            Self::Jiclatn => "jic-Latn",
-            Self::Jivlatn => "jiv-Latn",
-            Self::Jmclatn => "jmc-Latn",
-            Self::Jpnjpan => "jpn-Jpan",
-            Self::Jralatn => "jra-Latn",
-            Self::Junorya => "jun-Orya",
-            Self::Jvnlatn => "jvn-Latn",
-            Self::Kaacyrl => "kaa-Cyrl",
-            Self::Kaalatn => "kaa-Latn",
-            Self::Kablatn => "kab-Latn",
-            Self::Kaclatn => "kac-Latn",
-            Self::Kaklatn => "kak-Latn",
-            Self::Kallatn => "kal-Latn",
-            Self::Kamlatn => "kam-Latn",
-            Self::Kanknda => "kan-Knda",
-            Self::Kanlatn => "kan-Latn",
-            Self::Kaolatn => "kao-Latn",
-            Self::Kapcyrl => "kap-Cyrl",
-            Self::Kaqlatn => "kaq-Latn",
-            Self::Kasarab => "kas-Arab",
-            Self::Kasdeva => "kas-Deva",
-            Self::Kaslatn => "kas-Latn",
-            Self::Katgeor => "kat-Geor",
-            Self::Kazcyrl => "kaz-Cyrl",
-            Self::Kbclatn => "kbc-Latn",
-            Self::Kbdcyrl => "kbd-Cyrl",
-            Self::Kbhlatn => "kbh-Latn",
-            Self::Kbmlatn => "kbm-Latn",
-            Self::Kbolatn => "kbo-Latn",
-            Self::Kbplatn => "kbp-Latn",
-            Self::Kbqlatn => "kbq-Latn",
-            Self::Kbrlatn => "kbr-Latn",
-            Self::Kbylatn => "kby-Latn",
-            Self::Kcacyrl => "kca-Cyrl",
-            Self::Kcglatn => "kcg-Latn",
-            Self::Kcklatn => "kck-Latn",
-            Self::Kdclatn => "kdc-Latn",
-            Self::Kdelatn => "kde-Latn",
-            Self::Kdhlatn => "kdh-Latn",
-            Self::Kdilatn => "kdi-Latn",
-            Self::Kdjlatn => "kdj-Latn",
-            Self::Kdllatn => "kdl-Latn",
-            Self::Kdplatn => "kdp-Latn",
-            Self::Kdrlatn => "kdr-Latn",
-            Self::Kealatn => "kea-Latn",
-            Self::Keilatn => "kei-Latn",
-            Self::Keklatn => "kek-Latn",
-            Self::Kenlatn => "ken-Latn",
-            Self::Keolatn => "keo-Latn",
-            Self::Kerlatn => "ker-Latn",
-            Self::Kewlatn => "kew-Latn",
-            Self::Kexdeva => "kex-Deva",
-            Self::Kezlatn => "kez-Latn",
-            Self::Kfftelu => "kff-Telu",
-            Self::Kgflatn => "kgf-Latn",
-            Self::Kgklatn => "kgk-Latn",
-            Self::Kgplatn => "kgp-Latn",
-            Self::Kgrlatn => "kgr-Latn",
-            Self::Khalatn => "kha-Latn",
-            Self::Khgtibt => "khg-Tibt",
-            Self::Khkcyrl => "khk-Cyrl",
-            Self::Khmkhmr => "khm-Khmr",
-            Self::Khqlatn => "khq-Latn",
-            Self::Khslatn => "khs-Latn",
-            Self::Khylatn => "khy-Latn",
-            Self::Khzlatn => "khz-Latn",
-            Self::Kialatn => "kia-Latn",
-            Self::Kijlatn => "kij-Latn",
-            Self::Kiklatn => "kik-Latn",
-            Self::Kinlatn => "kin-Latn",
-            Self::Kircyrl => "kir-Cyrl",
-            Self::Kirlatn => "kir-Latn",
-            Self::Kiulatn => "kiu-Latn",
-            Self::Kixlatn => "kix-Latn",
-            Self::Kjblatn => "kjb-Latn",
-            Self::Kjelatn => "kje-Latn",
-            Self::Kjhcyrl => "kjh-Cyrl",
-            Self::Kjslatn => "kjs-Latn",
-            Self::Kkclatn => "kkc-Latn",
-            Self::Kkilatn => "kki-Latn",
-            Self::Kkjlatn => "kkj-Latn",
-            Self::Kkllatn => "kkl-Latn",
-            Self::Kledeva => "kle-Deva",
-            Self::Klnlatn => "kln-Latn",
-            Self::Kltlatn => "klt-Latn",
-            Self::Klvlatn => "klv-Latn",
-            Self::Kmalatn => "kma-Latn",
-            Self::Kmblatn => "kmb-Latn",
-            Self::Kmdlatn => "kmd-Latn",
-            Self::Kmglatn => "kmg-Latn",
-            Self::Kmhlatn => "kmh-Latn",
-            Self::Kmklatn => "kmk-Latn",
-            Self::Kmmlatn => "kmm-Latn",
-            Self::Kmolatn => "kmo-Latn",
-            Self::Kmrcyrl => "kmr-Cyrl",
-            Self::Kmrlatn => "kmr-Latn",
-            Self::Kmslatn => "kms-Latn",
-            Self::Kmulatn => "kmu-Latn",
-            Self::Kmylatn => "kmy-Latn",
-            Self::Kncarab => "knc-Arab",
-            Self::Knclatn => "knc-Latn",
-            Self::Knelatn => "kne-Latn",
-            Self::Knflatn => "knf-Latn",
-            Self::Knglatn => "kng-Latn",
-            Self::Knjlatn => "knj-Latn",
-            Self::Knklatn => "knk-Latn",
-            Self::Knolatn => "kno-Latn",
-            Self::Knvlatn => "knv-Latn",
-            Self::Knxlatn => "knx-Latn",
-            Self::Knylatn => "kny-Latn",
-            Self::Koglatn => "kog-Latn",
-            Self::Koicyrl => "koi-Cyrl",
-            Self::Komcyrl => "kom-Cyrl",
-            Self::Konlatn => "kon-Latn",
-            Self::Koolatn => "koo-Latn",
-            Self::Korhang => "kor-Hang",
-            Self::Koslatn => "kos-Latn",
-            Self::Kpelatn => "kpe-Latn",
-            Self::Kpflatn => "kpf-Latn",
-            Self::Kpglatn => "kpg-Latn",
-            Self::Kpjlatn => "kpj-Latn",
-            Self::Kpqlatn => "kpq-Latn",
-            Self::Kprlatn => "kpr-Latn",
-            Self::Kpvcyrl => "kpv-Cyrl",
-            Self::Kpwlatn => "kpw-Latn",
-            Self::Kpxlatn => "kpx-Latn",
-            Self::Kpzlatn => "kpz-Latn",
-            Self::Kqalatn => "kqa-Latn",
-            Self::Kqclatn => "kqc-Latn",
-            Self::Kqelatn => "kqe-Latn",
-            Self::Kqflatn => "kqf-Latn",
-            Self::Kqllatn => "kql-Latn",
-            Self::Kqnlatn => "kqn-Latn",
-            Self::Kqolatn => "kqo-Latn",
-            Self::Kqplatn => "kqp-Latn",
-            Self::Kqslatn => "kqs-Latn",
-            Self::Kqwlatn => "kqw-Latn",
-            Self::Kqyethi => "kqy-Ethi",
-            Self::Krccyrl => "krc-Cyrl",
-            Self::Krilatn => "kri-Latn",
-            Self::Krjlatn => "krj-Latn",
-            Self::Krllatn => "krl-Latn",
-            Self::Krudeva => "kru-Deva",
-            Self::Krxlatn => "krx-Latn",
-            Self::Ksblatn => "ksb-Latn",
-            Self::Ksclatn => "ksc-Latn",
-            Self::Ksdlatn => "ksd-Latn",
-            Self::Ksflatn => "ksf-Latn",
-            Self::Kshlatn => "ksh-Latn",
-            Self::Ksjlatn => "ksj-Latn",
-            Self::Ksplatn => "ksp-Latn",
-            Self::Ksrlatn => "ksr-Latn",
-            Self::Ksslatn => "kss-Latn",
-            Self::Kswmymr => "ksw-Mymr",
-            Self::Ktbethi => "ktb-Ethi",
-            Self::Ktjlatn => "ktj-Latn",
-            Self::Ktmlatn => "ktm-Latn",
-            Self::Ktolatn => "kto-Latn",
-            Self::Ktulatn => "ktu-Latn",
-            Self::Ktzlatn => "ktz-Latn",
-            Self::Kualatn => "kua-Latn",
-            Self::Kublatn => "kub-Latn",
-            Self::Kudlatn => "kud-Latn",
-            Self::Kuelatn => "kue-Latn",
-            Self::Kujlatn => "kuj-Latn",
-            Self::Kumcyrl => "kum-Cyrl",
-            Self::Kuplatn => "kup-Latn",
-            Self::Kuslatn => "kus-Latn",
-            Self::Kvglatn => "kvg-Latn",
-            Self::Kvjlatn => "kvj-Latn",
-            Self::Kvnlatn => "kvn-Latn",
-            Self::Kwdlatn => "kwd-Latn",
-            Self::Kwflatn => "kwf-Latn",
-            Self::Kwilatn => "kwi-Latn",
-            Self::Kwjlatn => "kwj-Latn",
-            Self::Kwnlatn => "kwn-Latn",
-            Self::Kwylatn => "kwy-Latn",
-            Self::Kxcethi => "kxc-Ethi",
-            Self::Kxmthai => "kxm-Thai",
-            Self::Kxwlatn => "kxw-Latn",
-            Self::Kyclatn => "kyc-Latn",
-            Self::Kyflatn => "kyf-Latn",
-            Self::Kyglatn => "kyg-Latn",
-            Self::Kyqlatn => "kyq-Latn",
-            Self::Kyukali => "kyu-Kali",
-            Self::Kyulatn => "kyu-Latn",
-            Self::Kyumymr => "kyu-Mymr",
-            Self::Kyzlatn => "kyz-Latn",
-            Self::Kzelatn => "kze-Latn",
-            Self::Kzflatn => "kzf-Latn",
-            Self::Kzjlatn => "kzj-Latn",
-            Self::Kznlatn => "kzn-Latn",
-            Self::Laclatn => "lac-Latn",
-            Self::Ladhebr => "lad-Hebr",
-            Self::Ladlatn => "lad-Latn",
-            Self::Lailatn => "lai-Latn",
-            Self::Lajlatn => "laj-Latn",
-            Self::Lamlatn => "lam-Latn",
-            Self::Laolaoo => "lao-Laoo",
-            Self::Laplatn => "lap-Latn",
-            Self::Laslatn => "las-Latn",
-            Self::Latlatn => "lat-Latn",
-            Self::Lavlatn => "lav-Latn",
-            Self::Lawlatn => "law-Latn",
-            Self::Lbblatn => "lbb-Latn",
-            Self::Lbecyrl => "lbe-Cyrl",
-            Self::Lbjtibt => "lbj-Tibt",
-            Self::Lbklatn => "lbk-Latn",
-            Self::Lcmlatn => "lcm-Latn",
-            Self::Lcpthai => "lcp-Thai",
-            Self::Ldilatn => "ldi-Latn",
-            Self::Ldnlatn => "ldn-Latn",
-            Self::Lealatn => "lea-Latn",
-            Self::Ledlatn => "led-Latn",
-            Self::Leelatn => "lee-Latn",
-            Self::Leflatn => "lef-Latn",
-            Self::Lehlatn => "leh-Latn",
-            Self::Lemlatn => "lem-Latn",
-            Self::Leulatn => "leu-Latn",
-            Self::Lewlatn => "lew-Latn",
-            Self::Lexlatn => "lex-Latn",
-            Self::Lezcyrl => "lez-Cyrl",
-            Self::Lfncyrl => "lfn-Cyrl",
-            Self::Lfnlatn => "lfn-Latn",
-            Self::Lgglatn => "lgg-Latn",
-            Self::Lgllatn => "lgl-Latn",
-            Self::Lgmlatn => "lgm-Latn",
-            Self::Lhilatn => "lhi-Latn",
-            Self::Lhulatn => "lhu-Latn",
-            Self::Lialatn => "lia-Latn",
-            Self::Lidlatn => "lid-Latn",
-            Self::Lifdeva => "lif-Deva",
-            Self::Liflimb => "lif-Limb",
-            Self::Lijlatn => "lij-Latn",
-            Self::Limlatn => "lim-Latn",
-            Self::Linlatn => "lin-Latn",
-            Self::Liplatn => "lip-Latn",
-            Self::Lislisu => "lis-Lisu",
-            Self::Litlatn => "lit-Latn",
-            Self::Livlatn => "liv-Latn",
-            Self::Ljplatn => "ljp-Latn",
-            Self::Lkiarab => "lki-Arab",
-            Self::Llblatn => "llb-Latn",
-            Self::Lldlatn => "lld-Latn",
-            Self::Llglatn => "llg-Latn",
-            Self::Llnlatn => "lln-Latn",
-            Self::Lmklatn => "lmk-Latn",
-            Self::Lmolatn => "lmo-Latn",
-            Self::Lmplatn => "lmp-Latn",
-            Self::Lndlatn => "lnd-Latn",
-            Self::Loblatn => "lob-Latn",
-            Self::Loelatn => "loe-Latn",
-            Self::Loglatn => "log-Latn",
-            Self::Loklatn => "lok-Latn",
-            Self::Lollatn => "lol-Latn",
-            Self::Lomlatn => "lom-Latn",
-            Self::Loqlatn => "loq-Latn",
-            Self::Lozlatn => "loz-Latn",
-            Self::Lrcarab => "lrc-Arab",
-            Self::Lsilatn => "lsi-Latn",
-            Self::Lsmlatn => "lsm-Latn",
-            Self::Ltglatn => "ltg-Latn",
-            Self::Ltzlatn => "ltz-Latn",
-            Self::Lualatn => "lua-Latn",
-            Self::Lublatn => "lub-Latn",
-            Self::Luclatn => "luc-Latn",
-            Self::Ludlatn => "lud-Latn",
-            Self::Luelatn => "lue-Latn",
-            Self::Luglatn => "lug-Latn",
-            Self::Lunlatn => "lun-Latn",
-            Self::Luolatn => "luo-Latn",
-            Self::Luslatn => "lus-Latn",
-            Self::Lvslatn => "lvs-Latn",
-            Self::Lwglatn => "lwg-Latn",
-            Self::Lwolatn => "lwo-Latn",
-            Self::Lwwlatn => "lww-Latn",
-            Self::Lzhhani => "lzh-Hani",
-            Self::Maalatn => "maa-Latn",
-            Self::Madlatn => "mad-Latn",
-            Self::Maflatn => "maf-Latn",
-            Self::Magdeva => "mag-Deva",
-            Self::Mahlatn => "mah-Latn",
-            Self::Maideva => "mai-Deva",
-            Self::Majlatn => "maj-Latn",
-            Self::Maklatn => "mak-Latn",
-            Self::Mallatn => "mal-Latn",
-            Self::Malmlym => "mal-Mlym",
-            Self::Mamlatn => "mam-Latn",
-            Self::Maqlatn => "maq-Latn",
-            Self::Mardeva => "mar-Deva",
-            Self::Marlatn => "mar-Latn",
-            Self::Maslatn => "mas-Latn",
-            Self::Maulatn => "mau-Latn",
-            Self::Mavlatn => "mav-Latn",
-            Self::Mawlatn => "maw-Latn",
-            Self::Maxlatn => "max-Latn",
-            Self::Mazlatn => "maz-Latn",
-            Self::Mbblatn => "mbb-Latn",
-            Self::Mbclatn => "mbc-Latn",
-            Self::Mbdlatn => "mbd-Latn",
-            Self::Mbflatn => "mbf-Latn",
-            Self::Mbhlatn => "mbh-Latn",
-            Self::Mbilatn => "mbi-Latn",
-            Self::Mbjlatn => "mbj-Latn",
-            Self::Mbllatn => "mbl-Latn",
-            Self::Mbslatn => "mbs-Latn",
-            Self::Mbtlatn => "mbt-Latn",
-            Self::Mcalatn => "mca-Latn",
-            Self::Mcblatn => "mcb-Latn",
-            Self::Mcdlatn => "mcd-Latn",
-            Self::Mcflatn => "mcf-Latn",
-            Self::Mcklatn => "mck-Latn",
-            Self::Mcnlatn => "mcn-Latn",
-            Self::Mcolatn => "mco-Latn",
-            Self::Mcplatn => "mcp-Latn",
-            Self::Mcqlatn => "mcq-Latn",
-            Self::Mculatn => "mcu-Latn",
-            Self::Mdalatn => "mda-Latn",
-            Self::Mdfcyrl => "mdf-Cyrl",
-            Self::Mdyethi => "mdy-Ethi",
-            Self::Medlatn => "med-Latn",
-            Self::Meelatn => "mee-Latn",
-            Self::Mejlatn => "mej-Latn",
-            Self::Meklatn => "mek-Latn",
-            Self::Menlatn => "men-Latn",
-            Self::Meqlatn => "meq-Latn",
-            Self::Merlatn => "mer-Latn",
-            Self::Metlatn => "met-Latn",
-            Self::Meulatn => "meu-Latn",
-            Self::Mevlatn => "mev-Latn",
-            Self::Mfelatn => "mfe-Latn",
-            Self::Mfglatn => "mfg-Latn",
-            Self::Mfhlatn => "mfh-Latn",
-            Self::Mfilatn => "mfi-Latn",
-            Self::Mfklatn => "mfk-Latn",
-            Self::Mfqlatn => "mfq-Latn",
-            Self::Mfylatn => "mfy-Latn",
-            Self::Mfzlatn => "mfz-Latn",
-            Self::Mgclatn => "mgc-Latn",
-            Self::Mghlatn => "mgh-Latn",
-            Self::Mgmlatn => "mgm-Latn",
-            Self::Mgolatn => "mgo-Latn",
-            Self::Mgrlatn => "mgr-Latn",
-            Self::Mhilatn => "mhi-Latn",
-            Self::Mhllatn => "mhl-Latn",
-            Self::Mhrcyrl => "mhr-Cyrl",
-            Self::Mhwlatn => "mhw-Latn",
-            Self::Mhxlatn => "mhx-Latn",
-            Self::Mhylatn => "mhy-Latn",
-            Self::Miblatn => "mib-Latn",
-            Self::Miclatn => "mic-Latn",
-            Self::Mielatn => "mie-Latn",
-            Self::Miflatn => "mif-Latn",
-            Self::Miglatn => "mig-Latn",
-            Self::Mihlatn => "mih-Latn",
-            Self::Millatn => "mil-Latn",
-            Self::Mimlatn => "mim-Latn",
-            Self::Minarab => "min-Arab",
-            Self::Minlatn => "min-Latn",
-            Self::Miolatn => "mio-Latn",
-            Self::Miplatn => "mip-Latn",
-            Self::Miqlatn => "miq-Latn",
-            Self::Mirlatn => "mir-Latn",
-            Self::Mitlatn => "mit-Latn",
-            Self::Miylatn => "miy-Latn",
-            Self::Mizlatn => "miz-Latn",
-            Self::Mjclatn => "mjc-Latn",
-            Self::Mjwlatn => "mjw-Latn",
-            Self::Mkdcyrl => "mkd-Cyrl",
-            Self::Mkllatn => "mkl-Latn",
-            Self::Mknlatn => "mkn-Latn",
-            Self::Mkslatn => "mks-Latn",
-            Self::Mkzlatn => "mkz-Latn",
-            Self::Mlglatn => "mlg-Latn",
-            Self::Mlhlatn => "mlh-Latn",
-            Self::Mlplatn => "mlp-Latn",
-            Self::Mltlatn => "mlt-Latn",
-            Self::Mlulatn => "mlu-Latn",
-            Self::Mmnlatn => "mmn-Latn",
-            Self::Mmolatn => "mmo-Latn",
-            Self::Mmxlatn => "mmx-Latn",
-            Self::Mnalatn => "mna-Latn",
-            Self::Mnblatn => "mnb-Latn",
-            Self::Mnflatn => "mnf-Latn",
-            Self::Mnibeng => "mni-Beng",
-            Self::Mnilatn => "mni-Latn",
-            Self::Mnimtei => "mni-Mtei",
-            Self::Mnklatn => "mnk-Latn",
-            Self::Mnscyrl => "mns-Cyrl",
-            Self::Mnwmymr => "mnw-Mymr",
-            Self::Mnxlatn => "mnx-Latn",
-            Self::Mnylatn => "mny-Latn",
-            Self::Moalatn => "moa-Latn",
-            Self::Moclatn => "moc-Latn",
-            Self::Moglatn => "mog-Latn",
-            Self::Mohlatn => "moh-Latn",
-            Self::Moncyrl => "mon-Cyrl",
-            Self::Moplatn => "mop-Latn",
-            Self::Morlatn => "mor-Latn",
-            Self::Moslatn => "mos-Latn",
-            Self::Moxlatn => "mox-Latn",
-            Self::Mpglatn => "mpg-Latn",
-            Self::Mphlatn => "mph-Latn",
-            Self::Mpmlatn => "mpm-Latn",
-            Self::Mpplatn => "mpp-Latn",
-            Self::Mpslatn => "mps-Latn",
-            Self::Mptlatn => "mpt-Latn",
-            Self::Mpxlatn => "mpx-Latn",
-            Self::Mqblatn => "mqb-Latn",
-            Self::Mqjlatn => "mqj-Latn",
-            Self::Mqylatn => "mqy-Latn",
-            Self::Mrglatn => "mrg-Latn",
-            Self::Mrilatn => "mri-Latn",
-            Self::Mrjcyrl => "mrj-Cyrl",
-            Self::Mrqlatn => "mrq-Latn",
-            Self::Mrvlatn => "mrv-Latn",
-            Self::Mrwlatn => "mrw-Latn",
-            Self::Msalatn => "msa-Latn",
-            Self::Msblatn => "msb-Latn",
-            Self::Msclatn => "msc-Latn",
-            Self::Mselatn => "mse-Latn",
-            Self::Msklatn => "msk-Latn",
-            Self::Msmlatn => "msm-Latn",
-            Self::Msylatn => "msy-Latn",
-            Self::Mtalatn => "mta-Latn",
-            Self::Mtglatn => "mtg-Latn",
-            Self::Mtilatn => "mti-Latn",
-            Self::Mtjlatn => "mtj-Latn",
-            Self::Mtolatn => "mto-Latn",
-            Self::Mtplatn => "mtp-Latn",
-            Self::Mualatn => "mua-Latn",
-            Self::Muglatn => "mug-Latn",
-            Self::Muhlatn => "muh-Latn",
-            Self::Muilatn => "mui-Latn",
-            Self::Mupdeva => "mup-Deva",
-            Self::Murlatn => "mur-Latn",
-            Self::Muslatn => "mus-Latn",
-            Self::Muxlatn => "mux-Latn",
-            Self::Muylatn => "muy-Latn",
-            Self::Mvalatn => "mva-Latn",
-            Self::Mvnlatn => "mvn-Latn",
-            Self::Mvplatn => "mvp-Latn",
-            Self::Mwclatn => "mwc-Latn",
-            Self::Mwflatn => "mwf-Latn",
-            Self::Mwllatn => "mwl-Latn",
-            Self::Mwmlatn => "mwm-Latn",
-            Self::Mwnlatn => "mwn-Latn",
-            Self::Mwplatn => "mwp-Latn",
-            Self::Mwqlatn => "mwq-Latn",
-            Self::Mwvlatn => "mwv-Latn",
-            Self::Mwwlatn => "mww-Latn",
-            Self::Mxblatn => "mxb-Latn",
-            Self::Mxplatn => "mxp-Latn",
-            Self::Mxqlatn => "mxq-Latn",
-            Self::Mxtlatn => "mxt-Latn",
-            Self::Mxvlatn => "mxv-Latn",
-            Self::Myamymr => "mya-Mymr",
-            Self::Myblatn => "myb-Latn",
-            Self::Myklatn => "myk-Latn",
-            Self::Myulatn => "myu-Latn",
-            Self::Myvcyrl => "myv-Cyrl",
-            Self::Mywlatn => "myw-Latn",
-            Self::Myxlatn => "myx-Latn",
-            Self::Myylatn => "myy-Latn",
-            Self::Mzalatn => "mza-Latn",
-            Self::Mzhlatn => "mzh-Latn",
-            Self::Mzklatn => "mzk-Latn",
-            Self::Mzllatn => "mzl-Latn",
-            Self::Mzmlatn => "mzm-Latn",
-            Self::Mznarab => "mzn-Arab",
-            Self::Mzwlatn => "mzw-Latn",
-            Self::Mzzlatn => "mzz-Latn",
-            Self::Nablatn => "nab-Latn",
-            Self::Naflatn => "naf-Latn",
-            Self::Nahlatn => "nah-Latn",
-            Self::Naklatn => "nak-Latn",
-            Self::Nanhani => "nan-Hani",
-            Self::Nanlatn => "nan-Latn",
-            Self::Naplatn => "nap-Latn",
-            Self::Naqlatn => "naq-Latn",
-            Self::Naslatn => "nas-Latn",
-            Self::Navlatn => "nav-Latn",
-            Self::Nawlatn => "naw-Latn",
-            Self::Nbalatn => "nba-Latn",
-            Self::Nbclatn => "nbc-Latn",
-            Self::Nbelatn => "nbe-Latn",
-            Self::Nbllatn => "nbl-Latn",
-            Self::Nbqlatn => "nbq-Latn",
-            Self::Nbulatn => "nbu-Latn",
-            Self::Ncalatn => "nca-Latn",
-            Self::Nchlatn => "nch-Latn",
-            Self::Ncjlatn => "ncj-Latn",
-            Self::Ncllatn => "ncl-Latn",
-            Self::Ncqlaoo => "ncq-Laoo",
-            Self::Nctlatn => "nct-Latn",
-            Self::Nculatn => "ncu-Latn",
-            Self::Ncxlatn => "ncx-Latn",
-            Self::Ndclatn => "ndc-Latn",
-            Self::Ndelatn => "nde-Latn",
-            Self::Ndhlatn => "ndh-Latn",
-            Self::Ndilatn => "ndi-Latn",
-            Self::Ndjlatn => "ndj-Latn",
-            Self::Ndolatn => "ndo-Latn",
-            Self::Ndplatn => "ndp-Latn",
-            Self::Ndslatn => "nds-Latn",
-            Self::Ndylatn => "ndy-Latn",
-            Self::Ndzlatn => "ndz-Latn",
-            Self::Neblatn => "neb-Latn",
-            Self::Nepdeva => "nep-Deva",
-            Self::Newdeva => "new-Deva",
-            Self::Nfalatn => "nfa-Latn",
-            Self::Nfrlatn => "nfr-Latn",
-            Self::Ngblatn => "ngb-Latn",
-            Self::Ngclatn => "ngc-Latn",
-            Self::Ngllatn => "ngl-Latn",
-            Self::Ngplatn => "ngp-Latn",
-            Self::Ngulatn => "ngu-Latn",
-            Self::Nhdlatn => "nhd-Latn",
-            Self::Nhelatn => "nhe-Latn",
-            Self::Nhglatn => "nhg-Latn",
-            Self::Nhilatn => "nhi-Latn",
-            Self::Nhklatn => "nhk-Latn",
-            Self::Nholatn => "nho-Latn",
-            Self::Nhrlatn => "nhr-Latn",
-            Self::Nhulatn => "nhu-Latn",
-            Self::Nhwlatn => "nhw-Latn",
-            Self::Nhxlatn => "nhx-Latn",
-            Self::Nhylatn => "nhy-Latn",
-            Self::Nialatn => "nia-Latn",
-            Self::Niflatn => "nif-Latn",
-            Self::Niilatn => "nii-Latn",
-            Self::Nijlatn => "nij-Latn",
-            Self::Nimlatn => "nim-Latn",
-            Self::Ninlatn => "nin-Latn",
-            Self::Niocyrl => "nio-Cyrl",
-            Self::Niqlatn => "niq-Latn",
-            Self::Niulatn => "niu-Latn",
-            Self::Niylatn => "niy-Latn",
-            Self::Njblatn => "njb-Latn",
-            Self::Njmlatn => "njm-Latn",
-            Self::Njnlatn => "njn-Latn",
-            Self::Njolatn => "njo-Latn",
-            Self::Njzlatn => "njz-Latn",
-            Self::Nkflatn => "nkf-Latn",
-            Self::Nkilatn => "nki-Latn",
-            Self::Nkolatn => "nko-Latn",
-            Self::Nlalatn => "nla-Latn",
-            Self::Nlclatn => "nlc-Latn",
-            Self::Nldlatn => "nld-Latn",
-            Self::Nlglatn => "nlg-Latn",
-            Self::Nmalatn => "nma-Latn",
-            Self::Nmflatn => "nmf-Latn",
-            Self::Nmhlatn => "nmh-Latn",
-            Self::Nmolatn => "nmo-Latn",
-            Self::Nmwlatn => "nmw-Latn",
-            Self::Nmzlatn => "nmz-Latn",
-            Self::Nnblatn => "nnb-Latn",
-            Self::Nnglatn => "nng-Latn",
-            Self::Nnhlatn => "nnh-Latn",
-            Self::Nnllatn => "nnl-Latn",
-            Self::Nnolatn => "nno-Latn",
-            Self::Nnplatn => "nnp-Latn",
-            Self::Nnqlatn => "nnq-Latn",
-            Self::Nnwlatn => "nnw-Latn",
-            Self::Noalatn => "noa-Latn",
-            Self::Noblatn => "nob-Latn",
-            Self::Nodthai => "nod-Thai",
-            Self::Nogcyrl => "nog-Cyrl",
-            Self::Nonlatn => "non-Latn",
-            Self::Noplatn => "nop-Latn",
-            Self::Norlatn => "nor-Latn",
-            Self::Notlatn => "not-Latn",
-            Self::Noulatn => "nou-Latn",
-            Self::Novlatn => "nov-Latn",
-            Self::Nphlatn => "nph-Latn",
-            Self::Npideva => "npi-Deva",
-            Self::Npilatn => "npi-Latn",
-            Self::Npllatn => "npl-Latn",
-            Self::Npolatn => "npo-Latn",
-            Self::Npylatn => "npy-Latn",
-            Self::Nqonkoo => "nqo-Nkoo",
-            Self::Nrelatn => "nre-Latn",
-            Self::Nrflatn => "nrf-Latn",
-            Self::Nrilatn => "nri-Latn",
-            Self::Nrmlatn => "nrm-Latn",
-            Self::Nsalatn => "nsa-Latn",
-            Self::Nselatn => "nse-Latn",
-            Self::Nsmlatn => "nsm-Latn",
-            Self::Nsnlatn => "nsn-Latn",
-            Self::Nsolatn => "nso-Latn",
-            Self::Nsslatn => "nss-Latn",
-            Self::Nstlatn => "nst-Latn",
-            Self::Nsulatn => "nsu-Latn",
-            Self::Ntplatn => "ntp-Latn",
-            Self::Ntrlatn => "ntr-Latn",
-            Self::Ntulatn => "ntu-Latn",
-            Self::Nujlatn => "nuj-Latn",
-            Self::Nuslatn => "nus-Latn",
-            Self::Nuylatn => "nuy-Latn",
-            Self::Nuzlatn => "nuz-Latn",
-            Self::Nvmlatn => "nvm-Latn",
-            Self::Nwblatn => "nwb-Latn",
-            Self::Nwilatn => "nwi-Latn",
-            Self::Nwxdeva => "nwx-Deva",
-            Self::Nxdlatn => "nxd-Latn",
-            Self::Nyalatn => "nya-Latn",
-            Self::Nyflatn => "nyf-Latn",
-            Self::Nyklatn => "nyk-Latn",
-            Self::Nynlatn => "nyn-Latn",
-            Self::Nyolatn => "nyo-Latn",
-            Self::Nyulatn => "nyu-Latn",
-            Self::Nyylatn => "nyy-Latn",
-            Self::Nzalatn => "nza-Latn",
-            Self::Nzilatn => "nzi-Latn",
-            Self::Nzmlatn => "nzm-Latn",
-            Self::Obolatn => "obo-Latn",
-            Self::Ocilatn => "oci-Latn",
-            Self::Ogolatn => "ogo-Latn",
-            Self::Ojbcans => "ojb-Cans",
-            Self::Ojblatn => "ojb-Latn",
-            Self::Ojilatn => "oji-Latn",
-            Self::Okelatn => "oke-Latn",
-            Self::Okulatn => "oku-Latn",
-            Self::Okvlatn => "okv-Latn",
-            Self::Oldlatn => "old-Latn",
-            Self::Ololatn => "olo-Latn",
-            Self::Omblatn => "omb-Latn",
-            Self::Omwlatn => "omw-Latn",
-            Self::Onglatn => "ong-Latn",
-            Self::Onslatn => "ons-Latn",
-            Self::Oodlatn => "ood-Latn",
-            Self::Opmlatn => "opm-Latn",
-            Self::Oriorya => "ori-Orya",
-            Self::Ormlatn => "orm-Latn",
-            Self::Orvcyrl => "orv-Cyrl",
-            Self::Orylatn => "ory-Latn",
-            Self::Oryorya => "ory-Orya",
-            Self::Osscyrl => "oss-Cyrl",
-            Self::Otaarab => "ota-Arab",
-            Self::Otdlatn => "otd-Latn",
-            Self::Otelatn => "ote-Latn",
-            Self::Otmlatn => "otm-Latn",
-            Self::Otnlatn => "otn-Latn",
-            Self::Otolatn => "oto-Latn",
-            Self::Otqlatn => "otq-Latn",
-            Self::Otslatn => "ots-Latn",
-            Self::Otwlatn => "otw-Latn",
-            Self::Oymlatn => "oym-Latn",
-            Self::Ozmlatn => "ozm-Latn",
-            Self::Pablatn => "pab-Latn",
-            Self::Padlatn => "pad-Latn",
-            Self::Paglatn => "pag-Latn",
-            Self::Pahlatn => "pah-Latn",
-            Self::Pamlatn => "pam-Latn",
-            Self::Panguru => "pan-Guru",
-            Self::Panlatn => "pan-Latn",
-            Self::Paolatn => "pao-Latn",
-            Self::Paplatn => "pap-Latn",
-            Self::Paulatn => "pau-Latn",
-            Self::Pbblatn => "pbb-Latn",
-            Self::Pbclatn => "pbc-Latn",
-            Self::Pbilatn => "pbi-Latn",
-            Self::Pbllatn => "pbl-Latn",
-            Self::Pbtarab => "pbt-Arab",
-            Self::Pcdlatn => "pcd-Latn",
-            Self::Pcklatn => "pck-Latn",
-            Self::Pcmlatn => "pcm-Latn",
-            Self::Pdclatn => "pdc-Latn",
-            Self::Pdtlatn => "pdt-Latn",
-            Self::Pemlatn => "pem-Latn",
-            Self::Pesarab => "pes-Arab",
-            Self::Pezlatn => "pez-Latn",
-            Self::Pfelatn => "pfe-Latn",
-            Self::Pfllatn => "pfl-Latn",
-            Self::Phmlatn => "phm-Latn",
-            Self::Piblatn => "pib-Latn",
-            Self::Pihlatn => "pih-Latn",
-            Self::Piolatn => "pio-Latn",
-            Self::Pirlatn => "pir-Latn",
-            Self::Pislatn => "pis-Latn",
-            Self::Pjtlatn => "pjt-Latn",
-            Self::Pkblatn => "pkb-Latn",
-            Self::Plglatn => "plg-Latn",
-            Self::Plslatn => "pls-Latn",
-            Self::Pltlatn => "plt-Latn",
-            Self::Plulatn => "plu-Latn",
-            Self::Plwlatn => "plw-Latn",
-            Self::Pmalatn => "pma-Latn",
-            Self::Pmflatn => "pmf-Latn",
-            Self::Pmqlatn => "pmq-Latn",
-            Self::Pmslatn => "pms-Latn",
-            Self::Pmxlatn => "pmx-Latn",
-            Self::Pnbarab => "pnb-Arab",
-            Self::Pnelatn => "pne-Latn",
-            Self::Pntgrek => "pnt-Grek",
-            Self::Pnylatn => "pny-Latn",
-            Self::Poelatn => "poe-Latn",
-            Self::Pohlatn => "poh-Latn",
-            Self::Poilatn => "poi-Latn",
-            Self::Pollatn => "pol-Latn",
-            Self::Ponlatn => "pon-Latn",
-            Self::Porlatn => "por-Latn",
-            Self::Poslatn => "pos-Latn",
-            Self::Potlatn => "pot-Latn",
-            Self::Povlatn => "pov-Latn",
-            Self::Poylatn => "poy-Latn",
-            Self::Ppklatn => "ppk-Latn",
-            Self::Ppolatn => "ppo-Latn",
-            Self::Ppslatn => "pps-Latn",
-            Self::Prflatn => "prf-Latn",
-            Self::Prglatn => "prg-Latn",
-            Self::Prilatn => "pri-Latn",
-            Self::Prklatn => "prk-Latn",
-            Self::Prqlatn => "prq-Latn",
-            Self::Prsarab => "prs-Arab",
-            Self::Pselatn => "pse-Latn",
-            Self::Psslatn => "pss-Latn",
-            Self::Ptplatn => "ptp-Latn",
-            Self::Ptulatn => "ptu-Latn",
-            Self::Pualatn => "pua-Latn",
-            Self::Puilatn => "pui-Latn",
-            Self::Pusarab => "pus-Arab",
-            Self::Pwglatn => "pwg-Latn",
-            Self::Pwnlatn => "pwn-Latn",
-            Self::Pwwthai => "pww-Thai",
-            Self::Pxmlatn => "pxm-Latn",
-            Self::Qublatn => "qub-Latn",
-            Self::Quclatn => "quc-Latn",
-            Self::Quelatn => "que-Latn",
-            Self::Quflatn => "quf-Latn",
-            Self::Quglatn => "qug-Latn",
-            Self::Quhlatn => "quh-Latn",
-            Self::Qullatn => "qul-Latn",
-            Self::Quplatn => "qup-Latn",
-            Self::Quslatn => "qus-Latn",
-            Self::Quwlatn => "quw-Latn",
-            Self::Quylatn => "quy-Latn",
-            Self::Quzlatn => "quz-Latn",
-            Self::Qvalatn => "qva-Latn",
-            Self::Qvclatn => "qvc-Latn",
-            Self::Qvelatn => "qve-Latn",
-            Self::Qvhlatn => "qvh-Latn",
-            Self::Qvilatn => "qvi-Latn",
-            Self::Qvmlatn => "qvm-Latn",
-            Self::Qvnlatn => "qvn-Latn",
-            Self::Qvolatn => "qvo-Latn",
-            Self::Qvslatn => "qvs-Latn",
-            Self::Qvwlatn => "qvw-Latn",
-            Self::Qvzlatn => "qvz-Latn",
-            Self::Qwhlatn => "qwh-Latn",
-            Self::Qxhlatn => "qxh-Latn",
-            Self::Qxllatn => "qxl-Latn",
-            Self::Qxnlatn => "qxn-Latn",
-            Self::Qxolatn => "qxo-Latn",
-            Self::Qxrlatn => "qxr-Latn",
-            Self::Radlatn => "rad-Latn",
-            Self::Railatn => "rai-Latn",
-            Self::Raplatn => "rap-Latn",
-            Self::Rarlatn => "rar-Latn",
-            Self::Ravdeva => "rav-Deva",
-            Self::Rawlatn => "raw-Latn",
-            Self::Rcflatn => "rcf-Latn",
-            Self::Rejlatn => "rej-Latn",
-            Self::Rellatn => "rel-Latn",
-            Self::Rgulatn => "rgu-Latn",
-            Self::Rhglatn => "rhg-Latn",
-            Self::Rialatn => "ria-Latn",
-            Self::Rimlatn => "rim-Latn",
-            Self::Rjsdeva => "rjs-Deva",
-            Self::Rkblatn => "rkb-Latn",
-            Self::Rmclatn => "rmc-Latn",
-            Self::Rmelatn => "rme-Latn",
-            Self::Rmllatn => "rml-Latn",
-            Self::Rmncyrl => "rmn-Cyrl",
-            Self::Rmngrek => "rmn-Grek",
-            Self::Rmnlatn => "rmn-Latn",
-            Self::Rmolatn => "rmo-Latn",
-            Self::Rmqlatn => "rmq-Latn",
-            Self::Rmycyrl => "rmy-Cyrl",
-            Self::Rmylatn => "rmy-Latn",
-            Self::Rndlatn => "rnd-Latn",
-            Self::Rnglatn => "rng-Latn",
-            Self::Rnllatn => "rnl-Latn",
-            Self::Rohlatn => "roh-Latn",
-            Self::Roncyrl => "ron-Cyrl",
-            Self::Ronlatn => "ron-Latn",
-            Self::Roolatn => "roo-Latn",
-            Self::Roplatn => "rop-Latn",
-            Self::Rowlatn => "row-Latn",
-            Self::Rrolatn => "rro-Latn",
-            Self::Rtmlatn => "rtm-Latn",
-            Self::Rublatn => "rub-Latn",
-            Self::Ruecyrl => "rue-Cyrl",
-            Self::Ruflatn => "ruf-Latn",
-            Self::Ruglatn => "rug-Latn",
-            Self::Runlatn => "run-Latn",
-            Self::Ruplatn => "rup-Latn",
-            Self::Ruscyrl => "rus-Cyrl",
-            Self::Rwolatn => "rwo-Latn",
-            Self::Sablatn => "sab-Latn",
-            Self::Saglatn => "sag-Latn",
-            Self::Sahcyrl => "sah-Cyrl",
-            Self::Sajlatn => "saj-Latn",
-            Self::Sandeva => "san-Deva",
-            Self::Sanlatn => "san-Latn",
-            Self::Saslatn => "sas-Latn",
-            Self::Satlatn => "sat-Latn",
-            Self::Satolck => "sat-Olck",
-            Self::Saylatn => "say-Latn",
-            Self::Sbalatn => "sba-Latn",
-            Self::Sbdlatn => "sbd-Latn",
-            Self::Sbelatn => "sbe-Latn",
-            Self::Sbllatn => "sbl-Latn",
-            Self::Sbslatn => "sbs-Latn",
-            Self::Sbylatn => "sby-Latn",
-            Self::Sckdeva => "sck-Deva",
-            Self::Scnlatn => "scn-Latn",
-            Self::Scolatn => "sco-Latn",
-            Self::Sdalatn => "sda-Latn",
-            Self::Sdclatn => "sdc-Latn",
-            Self::Sdharab => "sdh-Arab",
-            Self::Sdolatn => "sdo-Latn",
-            Self::Sdqlatn => "sdq-Latn",
-            Self::Sehlatn => "seh-Latn",
-            Self::Selcyrl => "sel-Cyrl",
-            Self::Seslatn => "ses-Latn",
-            Self::Seylatn => "sey-Latn",
-            Self::Sfwlatn => "sfw-Latn",
-            Self::Sgblatn => "sgb-Latn",
-            Self::Sgclatn => "sgc-Latn",
-            Self::Sghcyrl => "sgh-Cyrl",
-            Self::Sgslatn => "sgs-Latn",
-            Self::Sgwethi => "sgw-Ethi",
-            Self::Sgzlatn => "sgz-Latn",
-            Self::Shilatn => "shi-Latn",
-            Self::Shklatn => "shk-Latn",
-            Self::Shnmymr => "shn-Mymr",
-            Self::Shplatn => "shp-Latn",
-            Self::Shrlatn => "shr-Latn",
-            Self::Shuarab => "shu-Arab",
-            Self::Sidlatn => "sid-Latn",
-            Self::Siglatn => "sig-Latn",
-            Self::Sillatn => "sil-Latn",
-            Self::Simlatn => "sim-Latn",
-            Self::Sinsinh => "sin-Sinh",
-            Self::Sjalatn => "sja-Latn",
-            Self::Sjomong => "sjo-Mong",
-            Self::Sjulatn => "sju-Latn",
-            Self::Skglatn => "skg-Latn",
-            Self::Skrarab => "skr-Arab",
-            Self::Sldlatn => "sld-Latn",
-            Self::Slklatn => "slk-Latn",
-            Self::Slllatn => "sll-Latn",
-            Self::Slvlatn => "slv-Latn",
-            Self::Smalatn => "sma-Latn",
-            Self::Smelatn => "sme-Latn",
-            Self::Smjlatn => "smj-Latn",
-            Self::Smklatn => "smk-Latn",
-            Self::Smllatn => "sml-Latn",
-            Self::Smnlatn => "smn-Latn",
-            Self::Smolatn => "smo-Latn",
-            Self::Smslatn => "sms-Latn",
-            Self::Smtlatn => "smt-Latn",
-            Self::Snalatn => "sna-Latn",
-            Self::Snclatn => "snc-Latn",
-            Self::Sndarab => "snd-Arab",
-            Self::Snddeva => "snd-Deva",
-            Self::Sndlatn => "snd-Latn",
-            Self::Snflatn => "snf-Latn",
-            Self::Snnlatn => "snn-Latn",
-            Self::Snplatn => "snp-Latn",
-            Self::Snwlatn => "snw-Latn",
-            Self::Snylatn => "sny-Latn",
-            Self::Soelatn => "soe-Latn",
-            Self::Somlatn => "som-Latn",
-            Self::Soplatn => "sop-Latn",
-            Self::Soqlatn => "soq-Latn",
-            Self::Sotlatn => "sot-Latn",
-            Self::Soylatn => "soy-Latn",
-            Self::Spalatn => "spa-Latn",
-            Self::Spllatn => "spl-Latn",
-            Self::Spmlatn => "spm-Latn",
-            Self::Spplatn => "spp-Latn",
-            Self::Spslatn => "sps-Latn",
-            Self::Spylatn => "spy-Latn",
-            Self::Sqilatn => "sqi-Latn",
-            Self::Srdlatn => "srd-Latn",
-            Self::Srilatn => "sri-Latn",
-            Self::Srmlatn => "srm-Latn",
-            Self::Srnlatn => "srn-Latn",
-            Self::Srpcyrl => "srp-Cyrl",
-            Self::Srplatn => "srp-Latn",
-            Self::Srqlatn => "srq-Latn",
-            Self::Srrlatn => "srr-Latn",
-            Self::Ssdlatn => "ssd-Latn",
-            Self::Ssglatn => "ssg-Latn",
-            Self::Sswlatn => "ssw-Latn",
-            Self::Ssxlatn => "ssx-Latn",
-            Self::Stnlatn => "stn-Latn",
-            Self::Stplatn => "stp-Latn",
-            Self::Stqlatn => "stq-Latn",
-            Self::Sualatn => "sua-Latn",
-            Self::Suclatn => "suc-Latn",
-            Self::Suelatn => "sue-Latn",
-            Self::Suklatn => "suk-Latn",
-            Self::Sunlatn => "sun-Latn",
-            Self::Surlatn => "sur-Latn",
-            Self::Susarab => "sus-Arab",
-            Self::Suslatn => "sus-Latn",
-            Self::Suzdeva => "suz-Deva",
-            Self::Swalatn => "swa-Latn",
-            Self::Swblatn => "swb-Latn",
-            Self::Swclatn => "swc-Latn",
-            Self::Swelatn => "swe-Latn",
-            Self::Swglatn => "swg-Latn",
-            Self::Swhlatn => "swh-Latn",
-            Self::Swklatn => "swk-Latn",
-            Self::Swplatn => "swp-Latn",
-            Self::Sxblatn => "sxb-Latn",
-            Self::Sxnlatn => "sxn-Latn",
-            Self::Syblatn => "syb-Latn",
-            Self::Sycsyrc => "syc-Syrc",
-            Self::Sylbeng => "syl-Beng",
-            Self::Syllatn => "syl-Latn",
-            Self::Szblatn => "szb-Latn",
-            Self::Szllatn => "szl-Latn",
-            Self::Szylatn => "szy-Latn",
-            Self::Tabcyrl => "tab-Cyrl",
-            Self::Taclatn => "tac-Latn",
-            Self::Tahlatn => "tah-Latn",
-            Self::Tajdeva => "taj-Deva",
-            Self::Tamlatn => "tam-Latn",
-            Self::Tamtaml => "tam-Taml",
-            Self::Taplatn => "tap-Latn",
-            Self::Taqlatn => "taq-Latn",
-            Self::Taqtfng => "taq-Tfng",
-            Self::Tarlatn => "tar-Latn",
-            Self::Tatcyrl => "tat-Cyrl",
-            Self::Tatlatn => "tat-Latn",
-            Self::Tavlatn => "tav-Latn",
-            Self::Tawlatn => "taw-Latn",
-            Self::Taylatn => "tay-Latn",
-            Self::Tbclatn => "tbc-Latn",
-            Self::Tbglatn => "tbg-Latn",
-            Self::Tbklatn => "tbk-Latn",
-            Self::Tbllatn => "tbl-Latn",
-            Self::Tbolatn => "tbo-Latn",
-            Self::Tbwlatn => "tbw-Latn",
-            Self::Tbylatn => "tby-Latn",
-            Self::Tbzlatn => "tbz-Latn",
-            Self::Tcalatn => "tca-Latn",
-            Self::Tcclatn => "tcc-Latn",
-            Self::Tcflatn => "tcf-Latn",
-            Self::Tcslatn => "tcs-Latn",
-            Self::Tcyknda => "tcy-Knda",
-            Self::Tczlatn => "tcz-Latn",
-            Self::Tdtlatn => "tdt-Latn",
-            Self::Tdxlatn => "tdx-Latn",
-            Self::Tedlatn => "ted-Latn",
-            Self::Teelatn => "tee-Latn",
-            Self::Tellatn => "tel-Latn",
-            Self::Teltelu => "tel-Telu",
-            Self::Temlatn => "tem-Latn",
-            Self::Teolatn => "teo-Latn",
-            Self::Terlatn => "ter-Latn",
-            Self::Tetlatn => "tet-Latn",
-            Self::Tewlatn => "tew-Latn",
-            Self::Tfrlatn => "tfr-Latn",
-            Self::Tgkcyrl => "tgk-Cyrl",
-            Self::Tgllatn => "tgl-Latn",
-            Self::Tgolatn => "tgo-Latn",
-            Self::Tgplatn => "tgp-Latn",
-            Self::Thathai => "tha-Thai",
-            Self::Thklatn => "thk-Latn",
-            Self::Thldeva => "thl-Deva",
-            Self::Thvlatn => "thv-Latn",
-            Self::Tiflatn => "tif-Latn",
-            Self::Tigethi => "tig-Ethi",
-            Self::Tihlatn => "tih-Latn",
-            Self::Tiklatn => "tik-Latn",
-            Self::Timlatn => "tim-Latn",
-            Self::Tirethi => "tir-Ethi",
-            Self::Tivlatn => "tiv-Latn",
-            Self::Tiylatn => "tiy-Latn",
-            Self::Tkelatn => "tke-Latn",
-            Self::Tkllatn => "tkl-Latn",
-            Self::Tkrcyrl => "tkr-Cyrl",
-            Self::Tkulatn => "tku-Latn",
-            Self::Tlblatn => "tlb-Latn",
-            Self::Tlflatn => "tlf-Latn",
-            Self::Tlhlatn => "tlh-Latn",
-            Self::Tljlatn => "tlj-Latn",
-            Self::Tlllatn => "tll-Latn",
-            Self::Tlylatn => "tly-Latn",
-            Self::Tmclatn => "tmc-Latn",
-            Self::Tmdlatn => "tmd-Latn",
-            Self::Tnalatn => "tna-Latn",
-            Self::Tnclatn => "tnc-Latn",
-            Self::Tnklatn => "tnk-Latn",
-            Self::Tnnlatn => "tnn-Latn",
-            Self::Tnplatn => "tnp-Latn",
-            Self::Tnrlatn => "tnr-Latn",
-            Self::Toblatn => "tob-Latn",
-            Self::Toclatn => "toc-Latn",
-            Self::Todlatn => "tod-Latn",
-            Self::Toglatn => "tog-Latn",
-            Self::Tohlatn => "toh-Latn",
-            Self::Toilatn => "toi-Latn",
-            Self::Tojlatn => "toj-Latn",
-            Self::Toklatn => "tok-Latn",
-            Self::Tonlatn => "ton-Latn",
-            Self::Toolatn => "too-Latn",
-            Self::Toplatn => "top-Latn",
-            Self::Toslatn => "tos-Latn",
-            Self::Tpalatn => "tpa-Latn",
-            Self::Tpilatn => "tpi-Latn",
-            Self::Tpmlatn => "tpm-Latn",
-            Self::Tpnlatn => "tpn-Latn",
-            Self::Tpplatn => "tpp-Latn",
-            Self::Tptlatn => "tpt-Latn",
-            Self::Tpwlatn => "tpw-Latn",
-            Self::Tpzlatn => "tpz-Latn",
-            Self::Tqolatn => "tqo-Latn",
-            Self::Trclatn => "trc-Latn",
-            Self::Trnlatn => "trn-Latn",
-            Self::Trolatn => "tro-Latn",
-            Self::Trplatn => "trp-Latn",
-            Self::Trqlatn => "trq-Latn",
-            Self::Trslatn => "trs-Latn",
-            Self::Trvlatn => "trv-Latn",
-            Self::Tsclatn => "tsc-Latn",
-            Self::Tsglatn => "tsg-Latn",
-            Self::Tsnlatn => "tsn-Latn",
-            Self::Tsolatn => "tso-Latn",
-            Self::Tswlatn => "tsw-Latn",
-            Self::Tszlatn => "tsz-Latn",
-            Self::Ttclatn => "ttc-Latn",
-            Self::Ttelatn => "tte-Latn",
-            Self::Ttjlatn => "ttj-Latn",
-            Self::Ttqlatn => "ttq-Latn",
-            Self::Ttqtfng => "ttq-Tfng",
-            Self::Tuclatn => "tuc-Latn",
-            Self::Tuelatn => "tue-Latn",
-            Self::Tuflatn => "tuf-Latn",
-            Self::Tuilatn => "tui-Latn",
-            Self::Tukarab => "tuk-Arab",
-            Self::Tukcyrl => "tuk-Cyrl",
-            Self::Tuklatn => "tuk-Latn",
-            Self::Tullatn => "tul-Latn",
-            Self::Tumlatn => "tum-Latn",
-            Self::Tuolatn => "tuo-Latn",
-            Self::Turlatn => "tur-Latn",
-            Self::Tuvlatn => "tuv-Latn",
-            Self::Tvklatn => "tvk-Latn",
-            Self::Tvllatn => "tvl-Latn",
-            Self::Twblatn => "twb-Latn",
-            Self::Twilatn => "twi-Latn",
-            Self::Twulatn => "twu-Latn",
-            Self::Twxlatn => "twx-Latn",
-            Self::Txqlatn => "txq-Latn",
-            Self::Txulatn => "txu-Latn",
-            Self::Tyvcyrl => "tyv-Cyrl",
-            Self::Tzhlatn => "tzh-Latn",
-            Self::Tzjlatn => "tzj-Latn",
-            Self::Tzllatn => "tzl-Latn",
-            Self::Tzmtfng => "tzm-Tfng",
-            Self::Tzolatn => "tzo-Latn",
-            Self::Ubrlatn => "ubr-Latn",
-            Self::Ubulatn => "ubu-Latn",
-            Self::Udmcyrl => "udm-Cyrl",
-            Self::Udulatn => "udu-Latn",
-            Self::Uigarab => "uig-Arab",
-            Self::Uigcyrl => "uig-Cyrl",
-            Self::Uiglatn => "uig-Latn",
-            Self::Ukrcyrl => "ukr-Cyrl",
-            Self::Umblatn => "umb-Latn",
-            Self::Undadlm => "und-Adlm",
-            Self::Undaghb => "und-Aghb",
-            Self::Undahom => "und-Ahom",
-            Self::Undarab => "und-Arab",
-            Self::Undarmi => "und-Armi",
-            Self::Undarmn => "und-Armn",
-            Self::Undavst => "und-Avst",
-            Self::Undbali => "und-Bali",
-            Self::Undbamu => "und-Bamu",
-            Self::Undbass => "und-Bass",
-            Self::Undbatk => "und-Batk",
-            Self::Undbeng => "und-Beng",
-            Self::Undbhks => "und-Bhks",
-            Self::Undbopo => "und-Bopo",
-            Self::Undbrah => "und-Brah",
-            Self::Undbrai => "und-Brai",
-            Self::Undbugi => "und-Bugi",
-            Self::Undbuhd => "und-Buhd",
-            Self::Undcakm => "und-Cakm",
-            Self::Undcans => "und-Cans",
-            Self::Undcari => "und-Cari",
-            Self::Undcham => "und-Cham",
-            Self::Undcher => "und-Cher",
-            Self::Undchrs => "und-Chrs",
-            Self::Undcopt => "und-Copt",
-            Self::Undcpmn => "und-Cpmn",
-            Self::Undcprt => "und-Cprt",
-            Self::Undcyrl => "und-Cyrl",
-            Self::Unddeva => "und-Deva",
-            Self::Unddiak => "und-Diak",
-            Self::Unddogr => "und-Dogr",
-            Self::Unddsrt => "und-Dsrt",
-            Self::Unddupl => "und-Dupl",
-            Self::Undegyp => "und-Egyp",
-            Self::Undelba => "und-Elba",
-            Self::Undelym => "und-Elym",
-            Self::Undethi => "und-Ethi",
-            Self::Undgeor => "und-Geor",
-            Self::Undglag => "und-Glag",
-            Self::Undgong => "und-Gong",
-            Self::Undgonm => "und-Gonm",
-            Self::Undgoth => "und-Goth",
-            Self::Undgran => "und-Gran",
-            Self::Undgrek => "und-Grek",
-            Self::Undgujr => "und-Gujr",
-            Self::Undguru => "und-Guru",
-            Self::Undhang => "und-Hang",
-            Self::Undhani => "und-Hani",
-            Self::Undhano => "und-Hano",
-            Self::Undhatr => "und-Hatr",
-            Self::Undhebr => "und-Hebr",
-            Self::Undhira => "und-Hira",
-            Self::Undhluw => "und-Hluw",
-            Self::Undhmng => "und-Hmng",
-            Self::Undhmnp => "und-Hmnp",
-            Self::Undhung => "und-Hung",
-            Self::Undital => "und-Ital",
-            Self::Undjava => "und-Java",
-            Self::Undkali => "und-Kali",
-            Self::Undkana => "und-Kana",
-            Self::Undkawi => "und-Kawi",
-            Self::Undkhar => "und-Khar",
-            Self::Undkhmr => "und-Khmr",
-            Self::Undkhoj => "und-Khoj",
-            Self::Undkits => "und-Kits",
-            Self::Undknda => "und-Knda",
-            Self::Undkthi => "und-Kthi",
-            Self::Undlana => "und-Lana",
-            Self::Undlaoo => "und-Laoo",
-            Self::Undlatn => "und-Latn",
-            Self::Undlepc => "und-Lepc",
-            Self::Undlimb => "und-Limb",
-            Self::Undlina => "und-Lina",
-            Self::Undlinb => "und-Linb",
-            Self::Undlisu => "und-Lisu",
-            Self::Undlyci => "und-Lyci",
-            Self::Undlydi => "und-Lydi",
-            Self::Undmahj => "und-Mahj",
-            Self::Undmaka => "und-Maka",
-            Self::Undmand => "und-Mand",
-            Self::Undmani => "und-Mani",
-            Self::Undmarc => "und-Marc",
-            Self::Undmedf => "und-Medf",
-            Self::Undmend => "und-Mend",
-            Self::Undmerc => "und-Merc",
-            Self::Undmero => "und-Mero",
-            Self::Undmlym => "und-Mlym",
-            Self::Undmodi => "und-Modi",
-            Self::Undmong => "und-Mong",
-            Self::Undmroo => "und-Mroo",
-            Self::Undmtei => "und-Mtei",
-            Self::Undmult => "und-Mult",
-            Self::Undmymr => "und-Mymr",
-            Self::Undnagm => "und-Nagm",
-            Self::Undnand => "und-Nand",
-            Self::Undnarb => "und-Narb",
-            Self::Undnbat => "und-Nbat",
-            Self::Undnewa => "und-Newa",
-            Self::Undnkoo => "und-Nkoo",
-            Self::Undnshu => "und-Nshu",
-            Self::Undogam => "und-Ogam",
-            Self::Undolck => "und-Olck",
-            Self::Undorkh => "und-Orkh",
-            Self::Undorya => "und-Orya",
-            Self::Undosge => "und-Osge",
-            Self::Undosma => "und-Osma",
-            Self::Undougr => "und-Ougr",
-            Self::Undpalm => "und-Palm",
-            Self::Undpauc => "und-Pauc",
-            Self::Undperm => "und-Perm",
-            Self::Undphag => "und-Phag",
-            Self::Undphli => "und-Phli",
-            Self::Undphlp => "und-Phlp",
-            Self::Undphnx => "und-Phnx",
-            Self::Undplrd => "und-Plrd",
-            Self::Undprti => "und-Prti",
-            Self::Undrjng => "und-Rjng",
-            Self::Undrohg => "und-Rohg",
-            Self::Undrunr => "und-Runr",
-            Self::Undsamr => "und-Samr",
-            Self::Undsarb => "und-Sarb",
-            Self::Undsaur => "und-Saur",
-            Self::Undsgnw => "und-Sgnw",
-            Self::Undshaw => "und-Shaw",
-            Self::Undshrd => "und-Shrd",
-            Self::Undsidd => "und-Sidd",
-            Self::Undsind => "und-Sind",
-            Self::Undsinh => "und-Sinh",
-            Self::Undsogd => "und-Sogd",
-            Self::Undsogo => "und-Sogo",
-            Self::Undsora => "und-Sora",
-            Self::Undsoyo => "und-Soyo",
-            Self::Undsund => "und-Sund",
-            Self::Undsylo => "und-Sylo",
-            Self::Undsyrc => "und-Syrc",
-            Self::Undtagb => "und-Tagb",
-            Self::Undtakr => "und-Takr",
-            Self::Undtale => "und-Tale",
-            Self::Undtalu => "und-Talu",
-            Self::Undtaml => "und-Taml",
-            Self::Undtang => "und-Tang",
-            Self::Undtavt => "und-Tavt",
-            Self::Undtelu => "und-Telu",
-            Self::Undtfng => "und-Tfng",
-            Self::Undtglg => "und-Tglg",
-            Self::Undthaa => "und-Thaa",
-            Self::Undthai => "und-Thai",
-            Self::Undtibt => "und-Tibt",
-            Self::Undtirh => "und-Tirh",
-            Self::Undtnsa => "und-Tnsa",
-            Self::Undtoto => "und-Toto",
-            Self::Undugar => "und-Ugar",
-            Self::Undvaii => "und-Vaii",
-            Self::Undvith => "und-Vith",
-            Self::Undwara => "und-Wara",
-            Self::Undwcho => "und-Wcho",
-            Self::Undxpeo => "und-Xpeo",
-            Self::Undxsux => "und-Xsux",
-            Self::Undyezi => "und-Yezi",
-            Self::Undyiii => "und-Yiii",
-            Self::Undzanb => "und-Zanb",
-            Self::Undzinh => "und-Zinh",
-            Self::Undzyyy => "und-Zyyy",
-            Self::Undzzzz => "und-Zzzz",
-            Self::Upvlatn => "upv-Latn",
-            Self::Uralatn => "ura-Latn",
-            Self::Urblatn => "urb-Latn",
-            Self::Urdarab => "urd-Arab",
-            Self::Urdlatn => "urd-Latn",
-            Self::Urhlatn => "urh-Latn",
-            Self::Urilatn => "uri-Latn",
-            Self::Urkthai => "urk-Thai",
-            Self::Urtlatn => "urt-Latn",
-            Self::Urwlatn => "urw-Latn",
-            Self::Urylatn => "ury-Latn",
-            Self::Usalatn => "usa-Latn",
-            Self::Usplatn => "usp-Latn",
-            Self::Uthlatn => "uth-Latn",
-            Self::Uvhlatn => "uvh-Latn",
-            Self::Uvllatn => "uvl-Latn",
-            Self::Uzbcyrl => "uzb-Cyrl",
-            Self::Uzblatn => "uzb-Latn",
-            Self::Uzncyrl => "uzn-Cyrl",
-            Self::Uznlatn => "uzn-Latn",
-            Self::Uzsarab => "uzs-Arab",
-            Self::Vaglatn => "vag-Latn",
-            Self::Vaplatn => "vap-Latn",
-            Self::Varlatn => "var-Latn",
-            Self::Veclatn => "vec-Latn",
-            Self::Venlatn => "ven-Latn",
-            Self::Veplatn => "vep-Latn",
-            Self::Vidlatn => "vid-Latn",
-            Self::Vielatn => "vie-Latn",
-            Self::Vivlatn => "viv-Latn",
-            Self::Vlslatn => "vls-Latn",
-            Self::Vmklatn => "vmk-Latn",
-            Self::Vmwlatn => "vmw-Latn",
-            Self::Vmylatn => "vmy-Latn",
-            Self::Vollatn => "vol-Latn",
-            Self::Votlatn => "vot-Latn",
-            Self::Vrolatn => "vro-Latn",
-            Self::Vunlatn => "vun-Latn",
-            Self::Vutlatn => "vut-Latn",
-            Self::Wajlatn => "waj-Latn",
-            Self::Walethi => "wal-Ethi",
-            Self::Wallatn => "wal-Latn",
-            Self::Waplatn => "wap-Latn",
-            Self::Warlatn => "war-Latn",
-            Self::Watlatn => "wat-Latn",
-            Self::Waylatn => "way-Latn",
-            Self::Wbalatn => "wba-Latn",
-            Self::Wbmlatn => "wbm-Latn",
-            Self::Wbplatn => "wbp-Latn",
-            Self::Wcalatn => "wca-Latn",
-            Self::Wedlatn => "wed-Latn",
-            Self::Werlatn => "wer-Latn",
-            Self::Weslatn => "wes-Latn",
-            Self::Wewlatn => "wew-Latn",
-            Self::Whglatn => "whg-Latn",
-            Self::Whklatn => "whk-Latn",
-            Self::Wiblatn => "wib-Latn",
-            Self::Wimlatn => "wim-Latn",
-            Self::Wiulatn => "wiu-Latn",
-            Self::Wlnlatn => "wln-Latn",
-            Self::Wlslatn => "wls-Latn",
-            Self::Wlvlatn => "wlv-Latn",
-            Self::Wlxlatn => "wlx-Latn",
-            Self::Wmtlatn => "wmt-Latn",
-            Self::Wmwlatn => "wmw-Latn",
-            Self::Wnclatn => "wnc-Latn",
-            Self::Wnulatn => "wnu-Latn",
-            Self::Woblatn => "wob-Latn",
-            Self::Wollatn => "wol-Latn",
-            Self::Woslatn => "wos-Latn",
-            Self::Wrklatn => "wrk-Latn",
-            Self::Wrslatn => "wrs-Latn",
-            Self::Wsgtelu => "wsg-Telu",
-            Self::Wsklatn => "wsk-Latn",
-            Self::Wuuhani => "wuu-Hani",
-            Self::Wuvlatn => "wuv-Latn",
-            Self::Wwalatn => "wwa-Latn",
-            Self::Xalcyrl => "xal-Cyrl",
-            Self::Xavlatn => "xav-Latn",
-            Self::Xbilatn => "xbi-Latn",
-            Self::Xbrlatn => "xbr-Latn",
-            Self::Xedlatn => "xed-Latn",
-            Self::Xholatn => "xho-Latn",
-            Self::Xlalatn => "xla-Latn",
-            Self::Xmfgeor => "xmf-Geor",
-            Self::Xmmlatn => "xmm-Latn",
-            Self::Xmvlatn => "xmv-Latn",
-            Self::Xnnlatn => "xnn-Latn",
-            Self::Xoglatn => "xog-Latn",
-            Self::Xonlatn => "xon-Latn",
-            Self::Xrblatn => "xrb-Latn",
-            Self::Xsblatn => "xsb-Latn",
-            Self::Xsilatn => "xsi-Latn",
-            Self::Xsmlatn => "xsm-Latn",
-            Self::Xsrdeva => "xsr-Deva",
-            Self::Xsulatn => "xsu-Latn",
-            Self::Xtdlatn => "xtd-Latn",
-            Self::Xtmlatn => "xtm-Latn",
-            Self::Xtnlatn => "xtn-Latn",
-            Self::Xumlatn => "xum-Latn",
-            Self::Xuolatn => "xuo-Latn",
-            Self::Yaalatn => "yaa-Latn",
-            Self::Yadlatn => "yad-Latn",
-            Self::Yallatn => "yal-Latn",
-            Self::Yamlatn => "yam-Latn",
-            Self::Yanlatn => "yan-Latn",
-            Self::Yaolatn => "yao-Latn",
-            Self::Yaplatn => "yap-Latn",
-            Self::Yaqlatn => "yaq-Latn",
-            Self::Yaslatn => "yas-Latn",
-            Self::Yatlatn => "yat-Latn",
-            Self::Yazlatn => "yaz-Latn",
-            Self::Ybblatn => "ybb-Latn",
-            Self::Ybylatn => "yby-Latn",
-            Self::Ycnlatn => "ycn-Latn",
-            Self::Yddhebr => "ydd-Hebr",
-            Self::Yidhebr => "yid-Hebr",
-            Self::Yimlatn => "yim-Latn",
-            Self::Ykalatn => "yka-Latn",
-            Self::Ylelatn => "yle-Latn",
-            Self::Ylilatn => "yli-Latn",
-            Self::Ymllatn => "yml-Latn",
-            Self::Yomlatn => "yom-Latn",
-            Self::Yonlatn => "yon-Latn",
-            Self::Yorlatn => "yor-Latn",
-            Self::Yrblatn => "yrb-Latn",
-            Self::Yrelatn => "yre-Latn",
-            Self::Yrkcyrl => "yrk-Cyrl",
-            Self::Yrllatn => "yrl-Latn",
-            Self::Ysslatn => "yss-Latn",
-            Self::Yualatn => "yua-Latn",
-            Self::Yuehani => "yue-Hani",
-            Self::Yujlatn => "yuj-Latn",
-            Self::Yuplatn => "yup-Latn",
-            Self::Yutlatn => "yut-Latn",
-            Self::Yuwlatn => "yuw-Latn",
-            Self::Yuzlatn => "yuz-Latn",
-            Self::Yvalatn => "yva-Latn",
-            Self::Zaalatn => "zaa-Latn",
-            Self::Zablatn => "zab-Latn",
-            Self::Zaclatn => "zac-Latn",
-            Self::Zadlatn => "zad-Latn",
-            Self::Zaelatn => "zae-Latn",
-            Self::Zailatn => "zai-Latn",
-            Self::Zamlatn => "zam-Latn",
-            Self::Zaolatn => "zao-Latn",
-            Self::Zarlatn => "zar-Latn",
-            Self::Zaslatn => "zas-Latn",
-            Self::Zatlatn => "zat-Latn",
-            Self::Zavlatn => "zav-Latn",
-            Self::Zawlatn => "zaw-Latn",
-            Self::Zcalatn => "zca-Latn",
-            Self::Zdjlatn => "zdj-Latn",
-            Self::Zealatn => "zea-Latn",
-            Self::Zghtfng => "zgh-Tfng",
-            Self::Zhohani => "zho-Hani",
-            Self::Zialatn => "zia-Latn",
-            Self::Ziwlatn => "ziw-Latn",
-            Self::Zlmlatn => "zlm-Latn",
-            Self::Znelatn => "zne-Latn",
-            Self::Zoclatn => "zoc-Latn",
-            Self::Zomlatn => "zom-Latn",
-            Self::Zoslatn => "zos-Latn",
-            Self::Zpalatn => "zpa-Latn",
-            Self::Zpclatn => "zpc-Latn",
-            Self::Zpglatn => "zpg-Latn",
-            Self::Zpilatn => "zpi-Latn",
-            Self::Zpjlatn => "zpj-Latn",
-            Self::Zpllatn => "zpl-Latn",
-            Self::Zpmlatn => "zpm-Latn",
-            Self::Zpolatn => "zpo-Latn",
-            Self::Zpqlatn => "zpq-Latn",
-            Self::Zptlatn => "zpt-Latn",
-            Self::Zpulatn => "zpu-Latn",
-            Self::Zpvlatn => "zpv-Latn",
-            Self::Zpzlatn => "zpz-Latn",
-            Self::Zsmarab => "zsm-Arab",
-            Self::Zsmlatn => "zsm-Latn",
-            Self::Zsrlatn => "zsr-Latn",
-            Self::Ztqlatn => "ztq-Latn",
-            Self::Ztylatn => "zty-Latn",
-            Self::Zullatn => "zul-Latn",
-            Self::Zxxarab => "zxx-Arab",
-            Self::Zxxlatn => "zxx-Latn",
-            Self::Zxxzzzz => "zxx-Zzzz",
-            Self::Zyblatn => "zyb-Latn",
-            Self::Zyplatn => "zyp-Latn",
-            Self::Zzalatn => "zza-Latn",
-            Self::Aai => "aai",
-            Self::Aak => "aak",
-            Self::Aau => "aau",
-            Self::Aaz => "aaz",
-            Self::Aba => "aba",
-            Self::Abk => "abk",
-            Self::Abn => "abn",
-            Self::Abq => "abq",
-            Self::Abt => "abt",
-            Self::Abx => "abx",
-            Self::Aby => "aby",
-            Self::Abz => "abz",
-            Self::Aca => "aca",
-            Self::Acd => "acd",
-            Self::Ace => "ace",
-            Self::Acf => "acf",
-            Self::Ach => "ach",
-            Self::Acm => "acm",
-            Self::Acn => "acn",
-            Self::Acq => "acq",
-            Self::Acr => "acr",
-            Self::Acu => "acu",
-            Self::Ada => "ada",
-            Self::Ade => "ade",
-            Self::Adh => "adh",
-            Self::Adi => "adi",
-            Self::Adj => "adj",
-            Self::Adl => "adl",
-            Self::Ady => "ady",
-            Self::Adz => "adz",
-            Self::Aeb => "aeb",
-            Self::Aer => "aer",
-            Self::Aeu => "aeu",
-            Self::Aey => "aey",
-            Self::Afb => "afb",
-            Self::Afh => "afh",
-            Self::Afr => "afr",
-            Self::Agd => "agd",
-            Self::Agg => "agg",
-            Self::Agm => "agm",
-            Self::Agn => "agn",
-            Self::Agr => "agr",
-            Self::Agt => "agt",
-            Self::Agu => "agu",
-            Self::Agw => "agw",
-            Self::Agx => "agx",
-            Self::Aha => "aha",
-            Self::Ahk => "ahk",
-            Self::Aia => "aia",
-            Self::Aii => "aii",
-            Self::Aim => "aim",
-            Self::Ain => "ain",
-            Self::Ajg => "ajg",
-            Self::Aji => "aji",
-            Self::Ajp => "ajp",
-            Self::Ajz => "ajz",
-            Self::Aka => "aka",
-            Self::Akb => "akb",
-            Self::Ake => "ake",
-            Self::Akh => "akh",
-            Self::Akl => "akl",
-            Self::Akp => "akp",
-            Self::Ald => "ald",
-            Self::Alj => "alj",
-            Self::Aln => "aln",
-            Self::Alp => "alp",
-            Self::Alq => "alq",
-            Self::Als => "als",
-            Self::Alt => "alt",
-            Self::Aly => "aly",
-            Self::Alz => "alz",
-            Self::Ame => "ame",
-            Self::Amf => "amf",
-            Self::Amh => "amh",
-            Self::Ami => "ami",
-            Self::Amk => "amk",
-            Self::Amm => "amm",
-            Self::Amn => "amn",
-            Self::Amp => "amp",
-            Self::Amr => "amr",
-            Self::Amu => "amu",
-            Self::Amx => "amx",
-            Self::Ang => "ang",
-            Self::Anm => "anm",
-            Self::Ann => "ann",
-            Self::Anv => "anv",
-            Self::Any => "any",
-            Self::Aoc => "aoc",
-            Self::Aoi => "aoi",
-            Self::Aoj => "aoj",
-            Self::Aom => "aom",
-            Self::Aon => "aon",
-            Self::Aoz => "aoz",
-            Self::Apb => "apb",
-            Self::Apc => "apc",
-            Self::Ape => "ape",
-            Self::Apn => "apn",
-            Self::Apr => "apr",
-            Self::Apt => "apt",
-            Self::Apu => "apu",
-            Self::Apw => "apw",
-            Self::Apy => "apy",
-            Self::Apz => "apz",
-            Self::Ara => "ara",
-            Self::Arb => "arb",
-            Self::Are => "are",
-            Self::Arg => "arg",
-            Self::Arh => "arh",
-            Self::Arl => "arl",
-            Self::Arn => "arn",
-            Self::Arp => "arp",
-            Self::Arq => "arq",
-            Self::Ars => "ars",
-            Self::Ary => "ary",
-            Self::Arz => "arz",
-            Self::Asg => "asg",
-            Self::Asm => "asm",
-            Self::Aso => "aso",
-            Self::Ast => "ast",
-            Self::Ata => "ata",
-            Self::Atb => "atb",
-            Self::Atd => "atd",
-            Self::Atg => "atg",
-            Self::Ati => "ati",
-            Self::Att => "att",
-            Self::Auc => "auc",
-            Self::Aui => "aui",
-            Self::Auy => "auy",
-            Self::Ava => "ava",
-            Self::Avk => "avk",
-            Self::Avt => "avt",
-            Self::Avu => "avu",
-            Self::Awa => "awa",
-            Self::Awb => "awb",
-            Self::Awi => "awi",
-            Self::Awx => "awx",
-            Self::Aym => "aym",
-            Self::Ayo => "ayo",
-            Self::Ayr => "ayr",
-            Self::Azb => "azb",
-            Self::Aze => "aze",
-            Self::Azg => "azg",
-            Self::Azj => "azj",
-            Self::Azz => "azz",
-            Self::Bak => "bak",
-            Self::Bal => "bal",
-            Self::Bam => "bam",
-            Self::Ban => "ban",
-            Self::Bao => "bao",
-            Self::Bar => "bar",
-            Self::Bas => "bas",
-            Self::Bav => "bav",
-            Self::Bba => "bba",
-            Self::Bbb => "bbb",
-            Self::Bbc => "bbc",
-            Self::Bbj => "bbj",
-            Self::Bbr => "bbr",
-            Self::Bcc => "bcc",
-            Self::Bch => "bch",
-            Self::Bci => "bci",
-            Self::Bcl => "bcl",
-            Self::Bco => "bco",
-            Self::Bcw => "bcw",
-            Self::Bdd => "bdd",
-            Self::Bdh => "bdh",
-            Self::Bea => "bea",
-            Self::Bef => "bef",
-            Self::Bel => "bel",
-            Self::Bem => "bem",
-            Self::Ben => "ben",
-            Self::Beq => "beq",
-            Self::Ber => "ber",
-            Self::Bex => "bex",
-            Self::Bfd => "bfd",
-            Self::Bfo => "bfo",
-            Self::Bfz => "bfz",
-            Self::Bgr => "bgr",
-            Self::Bgs => "bgs",
-            Self::Bgz => "bgz",
-            Self::Bhg => "bhg",
-            Self::Bhl => "bhl",
-            Self::Bho => "bho",
-            Self::Bhp => "bhp",
-            Self::Bhw => "bhw",
-            Self::Bib => "bib",
-            Self::Big => "big",
-            Self::Bih => "bih",
-            Self::Bik => "bik",
-            Self::Bim => "bim",
-            Self::Bin => "bin",
-            Self::Bis => "bis",
-            Self::Biu => "biu",
-            Self::Biv => "biv",
-            Self::Bjn => "bjn",
-            Self::Bjp => "bjp",
-            Self::Bjr => "bjr",
-            Self::Bjv => "bjv",
-            Self::Bkd => "bkd",
-            Self::Bkq => "bkq",
-            Self::Bku => "bku",
-            Self::Bkv => "bkv",
-            Self::Bla => "bla",
-            Self::Blh => "blh",
-            Self::Blw => "blw",
-            Self::Blz => "blz",
-            Self::Bmb => "bmb",
-            Self::Bmh => "bmh",
-            Self::Bmk => "bmk",
-            Self::Bmq => "bmq",
-            Self::Bmr => "bmr",
-            Self::Bmu => "bmu",
-            Self::Bnj => "bnj",
-            Self::Bnp => "bnp",
-            Self::Boa => "boa",
-            Self::Bod => "bod",
-            Self::Boj => "boj",
-            Self::Bom => "bom",
-            Self::Bon => "bon",
-            Self::Bos => "bos",
-            Self::Bov => "bov",
-            Self::Box => "box",
-            Self::Bpr => "bpr",
-            Self::Bps => "bps",
-            Self::Bpy => "bpy",
-            Self::Bqc => "bqc",
-            Self::Bqj => "bqj",
-            Self::Bqp => "bqp",
-            Self::Bre => "bre",
-            Self::Bru => "bru",
-            Self::Bsc => "bsc",
-            Self::Bsn => "bsn",
-            Self::Bsp => "bsp",
-            Self::Bsq => "bsq",
-            Self::Bss => "bss",
-            Self::Btd => "btd",
-            Self::Btg => "btg",
-            Self::Bth => "bth",
-            Self::Bts => "bts",
-            Self::Btt => "btt",
-            Self::Btx => "btx",
-            Self::Bua => "bua",
-            Self::Bud => "bud",
-            Self::Bug => "bug",
-            Self::Buk => "buk",
-            Self::Bul => "bul",
-            Self::Bum => "bum",
-            Self::Bus => "bus",
-            Self::Bvr => "bvr",
-            Self::Bvy => "bvy",
-            Self::Bvz => "bvz",
-            Self::Bwd => "bwd",
-            Self::Bwi => "bwi",
-            Self::Bwq => "bwq",
-            Self::Bwu => "bwu",
-            Self::Bxh => "bxh",
-            Self::Bxr => "bxr",
-            Self::Byr => "byr",
-            Self::Byv => "byv",
-            Self::Byx => "byx",
-            Self::Bzd => "bzd",
-            Self::Bzh => "bzh",
-            Self::Bzi => "bzi",
-            Self::Bzj => "bzj",
-            Self::Bzt => "bzt",
-            Self::Caa => "caa",
-            Self::Cab => "cab",
-            Self::Cac => "cac",
-            Self::Caf => "caf",
-            Self::Cag => "cag",
-            Self::Cak => "cak",
-            Self::Cao => "cao",
-            Self::Cap => "cap",
-            Self::Caq => "caq",
-            Self::Car => "car",
-            Self::Cas => "cas",
-            Self::Cat => "cat",
-            Self::Cav => "cav",
-            Self::Cax => "cax",
-            Self::Cbc => "cbc",
-            Self::Cbi => "cbi",
-            Self::Cbk => "cbk",
-            Self::Cbr => "cbr",
-            Self::Cbs => "cbs",
-            Self::Cbt => "cbt",
-            Self::Cbu => "cbu",
-            Self::Cbv => "cbv",
-            Self::Cce => "cce",
-            Self::Cco => "cco",
-            Self::Ceb => "ceb",
-            Self::Ceg => "ceg",
-            Self::Cek => "cek",
-            Self::Ces => "ces",
-            Self::Cfm => "cfm",
-            Self::Cgc => "cgc",
-            Self::Cgg => "cgg",
-            Self::Cha => "cha",
-            Self::Chd => "chd",
-            Self::Che => "che",
-            Self::Chf => "chf",
-            Self::Chj => "chj",
-            Self::Chk => "chk",
-            Self::Chn => "chn",
-            Self::Cho => "cho",
-            Self::Chq => "chq",
-            Self::Chr => "chr",
-            Self::Chu => "chu",
-            Self::Chv => "chv",
-            Self::Chw => "chw",
-            Self::Chz => "chz",
-            Self::Cjk => "cjk",
-            Self::Cjo => "cjo",
-            Self::Cjp => "cjp",
-            Self::Cjs => "cjs",
-            Self::Cjv => "cjv",
-            Self::Ckb => "ckb",
-            Self::Cko => "cko",
-            Self::Ckt => "ckt",
-            Self::Cle => "cle",
-            Self::Clu => "clu",
-            Self::Cly => "cly",
-            Self::Cme => "cme",
-            Self::Cmi => "cmi",
-            Self::Cmn => "cmn",
-            Self::Cmo => "cmo",
-            Self::Cnh => "cnh",
-            Self::Cni => "cni",
-            Self::Cnl => "cnl",
-            Self::Cnt => "cnt",
-            Self::Cnw => "cnw",
-            Self::Coe => "coe",
-            Self::Cof => "cof",
-            Self::Cok => "cok",
-            Self::Con => "con",
-            Self::Cop => "cop",
-            Self::Cor => "cor",
-            Self::Cos => "cos",
-            Self::Cot => "cot",
-            Self::Cpa => "cpa",
-            Self::Cpb => "cpb",
-            Self::Cpc => "cpc",
-            Self::Cpu => "cpu",
-            Self::Cpy => "cpy",
-            Self::Crh => "crh",
-            Self::Cri => "cri",
-            Self::Crk => "crk",
-            Self::Crm => "crm",
-            Self::Crn => "crn",
-            Self::Crq => "crq",
-            Self::Crs => "crs",
-            Self::Crt => "crt",
-            Self::Crx => "crx",
-            Self::Csb => "csb",
-            Self::Csk => "csk",
-            Self::Cso => "cso",
-            Self::Csw => "csw",
-            Self::Csy => "csy",
-            Self::Cta => "cta",
-            Self::Ctd => "ctd",
-            Self::Cto => "cto",
-            Self::Ctp => "ctp",
-            Self::Ctu => "ctu",
-            Self::Cub => "cub",
-            Self::Cuc => "cuc",
-            Self::Cui => "cui",
-            Self::Cuk => "cuk",
-            Self::Cul => "cul",
-            Self::Cut => "cut",
-            Self::Cux => "cux",
-            Self::Cwd => "cwd",
-            Self::Cwe => "cwe",
-            Self::Cwt => "cwt",
-            Self::Cya => "cya",
-            Self::Cym => "cym",
-            Self::Czt => "czt",
-            Self::Daa => "daa",
-            Self::Dad => "dad",
-            Self::Daf => "daf",
-            Self::Dah => "dah",
-            Self::Dak => "dak",
-            Self::Dan => "dan",
-            Self::Dar => "dar",
-            Self::Ddg => "ddg",
-            Self::Ded => "ded",
-            Self::Des => "des",
-            Self::Deu => "deu",
-            Self::Dga => "dga",
-            Self::Dgc => "dgc",
-            Self::Dgi => "dgi",
-            Self::Dgr => "dgr",
-            Self::Dgz => "dgz",
-            Self::Dhg => "dhg",
-            Self::Dhm => "dhm",
-            Self::Dhv => "dhv",
-            Self::Dig => "dig",
-            Self::Dik => "dik",
-            Self::Din => "din",
-            Self::Dip => "dip",
-            Self::Diq => "diq",
-            Self::Dis => "dis",
-            Self::Diu => "diu",
-            Self::Div => "div",
-            Self::Dje => "dje",
-            Self::Djk => "djk",
-            Self::Djr => "djr",
-            Self::Dks => "dks",
-            Self::Dng => "dng",
-            Self::Dnj => "dnj",
-            Self::Dob => "dob",
-            Self::Dop => "dop",
-            Self::Dow => "dow",
-            Self::Drg => "drg",
-            Self::Drt => "drt",
-            Self::Dru => "dru",
-            Self::Dsb => "dsb",
-            Self::Dtp => "dtp",
-            Self::Dts => "dts",
-            Self::Dua => "dua",
-            Self::Due => "due",
-            Self::Dug => "dug",
-            Self::Duo => "duo",
-            Self::Dur => "dur",
-            Self::Dwr => "dwr",
-            Self::Dws => "dws",
-            Self::Dww => "dww",
-            Self::Dyi => "dyi",
-            Self::Dyo => "dyo",
-            Self::Dyu => "dyu",
-            Self::Dzo => "dzo",
-            Self::Ebk => "ebk",
-            Self::Efi => "efi",
-            Self::Egl => "egl",
-            Self::Eka => "eka",
-            Self::Ekk => "ekk",
-            Self::Eko => "eko",
-            Self::Ell => "ell",
-            Self::Emi => "emi",
-            Self::Eml => "eml",
-            Self::Emp => "emp",
-            Self::Enb => "enb",
-            Self::Eng => "eng",
-            Self::Enl => "enl",
-            Self::Enm => "enm",
-            Self::Enx => "enx",
-            Self::Epo => "epo",
-            Self::Eri => "eri",
-            Self::Ese => "ese",
-            Self::Esi => "esi",
-            Self::Esk => "esk",
-            Self::Est => "est",
-            Self::Esu => "esu",
-            Self::Eto => "eto",
-            Self::Etr => "etr",
-            Self::Etu => "etu",
-            Self::Eus => "eus",
-            Self::Eve => "eve",
-            Self::Evn => "evn",
-            Self::Ewe => "ewe",
-            Self::Ewo => "ewo",
-            Self::Ext => "ext",
-            Self::Eza => "eza",
-            Self::Faa => "faa",
-            Self::Fad => "fad",
-            Self::Fai => "fai",
-            Self::Fal => "fal",
-            Self::Fan => "fan",
-            Self::Fao => "fao",
-            Self::Fas => "fas",
-            Self::Fat => "fat",
-            Self::Ffm => "ffm",
-            Self::Fij => "fij",
-            Self::Fil => "fil",
-            Self::Fin => "fin",
-            Self::Fkv => "fkv",
-            Self::Fmp => "fmp",
-            Self::Fon => "fon",
-            Self::For => "for",
-            Self::Fra => "fra",
-            Self::Frm => "frm",
-            Self::Fro => "fro",
-            Self::Frr => "frr",
-            Self::Fry => "fry",
-            Self::Fub => "fub",
-            Self::Fud => "fud",
-            Self::Fue => "fue",
-            Self::Fuf => "fuf",
-            Self::Fuh => "fuh",
-            Self::Fuq => "fuq",
-            Self::Fur => "fur",
-            Self::Fuv => "fuv",
-            Self::Gaa => "gaa",
-            Self::Gag => "gag",
-            Self::Gah => "gah",
-            Self::Gai => "gai",
-            Self::Gam => "gam",
-            Self::Gaw => "gaw",
-            Self::Gaz => "gaz",
-            Self::Gba => "gba",
-            Self::Gbi => "gbi",
-            Self::Gbo => "gbo",
-            Self::Gbr => "gbr",
-            Self::Gcf => "gcf",
-            Self::Gcr => "gcr",
-            Self::Gde => "gde",
-            Self::Gdg => "gdg",
-            Self::Gdn => "gdn",
-            Self::Gdr => "gdr",
-            Self::Geb => "geb",
-            Self::Gej => "gej",
-            Self::Gfk => "gfk",
-            Self::Ghe => "ghe",
-            Self::Ghs => "ghs",
-            Self::Gid => "gid",
-            Self::Gil => "gil",
-            Self::Giz => "giz",
-            Self::Gjn => "gjn",
-            Self::Gkn => "gkn",
-            Self::Gkp => "gkp",
-            Self::Gla => "gla",
-            Self::Gle => "gle",
-            Self::Glg => "glg",
-            Self::Glk => "glk",
-            Self::Glv => "glv",
-            Self::Gmv => "gmv",
-            Self::Gnb => "gnb",
-            Self::Gnd => "gnd",
-            Self::Gng => "gng",
-            Self::Gnn => "gnn",
-            Self::Gnw => "gnw",
-            Self::Goa => "goa",
-            Self::Gof => "gof",
-            Self::Gog => "gog",
-            Self::Gom => "gom",
-            Self::Gor => "gor",
-            Self::Gos => "gos",
-            Self::Got => "got",
-            Self::Gqr => "gqr",
-            Self::Grc => "grc",
-            Self::Grn => "grn",
-            Self::Grt => "grt",
-            Self::Gso => "gso",
-            Self::Gsw => "gsw",
-            Self::Gub => "gub",
-            Self::Guc => "guc",
-            Self::Gud => "gud",
-            Self::Gug => "gug",
-            Self::Guh => "guh",
-            Self::Gui => "gui",
-            Self::Guj => "guj",
-            Self::Guk => "guk",
-            Self::Gul => "gul",
-            Self::Gum => "gum",
-            Self::Gun => "gun",
-            Self::Guo => "guo",
-            Self::Guq => "guq",
-            Self::Gur => "gur",
-            Self::Guw => "guw",
-            Self::Gux => "gux",
-            Self::Guz => "guz",
-            Self::Gvc => "gvc",
-            Self::Gvf => "gvf",
-            Self::Gvl => "gvl",
-            Self::Gvn => "gvn",
-            Self::Gwi => "gwi",
-            Self::Gxx => "gxx",
-            Self::Gya => "gya",
-            Self::Gym => "gym",
-            Self::Gyr => "gyr",
-            Self::Hae => "hae",
-            Self::Hag => "hag",
-            Self::Hak => "hak",
-            Self::Hat => "hat",
-            Self::Hau => "hau",
-            Self::Hav => "hav",
-            Self::Haw => "haw",
-            Self::Hay => "hay",
-            Self::Hbo => "hbo",
-            Self::Hbs => "hbs",
-            Self::Hch => "hch",
-            Self::Heb => "heb",
-            Self::Heg => "heg",
-            Self::Heh => "heh",
-            Self::Her => "her",
-            Self::Hif => "hif",
-            Self::Hig => "hig",
-            Self::Hil => "hil",
-            Self::Hin => "hin",
-            Self::Hix => "hix",
-            Self::Hla => "hla",
-            Self::Hlt => "hlt",
-            Self::Hmn => "hmn",
-            Self::Hmo => "hmo",
-            Self::Hmr => "hmr",
-            Self::Hne => "hne",
-            Self::Hnj => "hnj",
-            Self::Hnn => "hnn",
-            Self::Hns => "hns",
-            Self::Hoc => "hoc",
-            Self::Hop => "hop",
-            Self::Hot => "hot",
-            Self::Hra => "hra",
-            Self::Hrv => "hrv",
-            Self::Hrx => "hrx",
-            Self::Hsb => "hsb",
-            Self::Hto => "hto",
-            Self::Hub => "hub",
-            Self::Hui => "hui",
-            Self::Hun => "hun",
-            Self::Hus => "hus",
-            Self::Huu => "huu",
-            Self::Huv => "huv",
-            Self::Hvn => "hvn",
-            Self::Hwc => "hwc",
-            Self::Hye => "hye",
-            Self::Hyw => "hyw",
-            Self::Ian => "ian",
-            Self::Iba => "iba",
-            Self::Ibg => "ibg",
-            Self::Ibo => "ibo",
-            Self::Icr => "icr",
-            Self::Ido => "ido",
-            Self::Idu => "idu",
-            Self::Ifa => "ifa",
-            Self::Ifb => "ifb",
-            Self::Ife => "ife",
-            Self::Ifk => "ifk",
-            Self::Ifu => "ifu",
-            Self::Ify => "ify",
-            Self::Ige => "ige",
-            Self::Ign => "ign",
-            Self::Igs => "igs",
-            Self::Iii => "iii",
-            Self::Ijc => "ijc",
-            Self::Ike => "ike",
-            Self::Ikk => "ikk",
-            Self::Ikw => "ikw",
-            Self::Ilb => "ilb",
-            Self::Ile => "ile",
-            Self::Ilo => "ilo",
-            Self::Imo => "imo",
-            Self::Ina => "ina",
-            Self::Inb => "inb",
-            Self::Ind => "ind",
-            Self::Ino => "ino",
-            Self::Iou => "iou",
-            Self::Ipi => "ipi",
-            Self::Iqw => "iqw",
-            Self::Iri => "iri",
-            Self::Irk => "irk",
-            Self::Iry => "iry",
-            Self::Isd => "isd",
-            Self::Ish => "ish",
-            Self::Isl => "isl",
-            Self::Iso => "iso",
-            Self::Ita => "ita",
-            Self::Its => "its",
-            Self::Itv => "itv",
-            Self::Ium => "ium",
-            Self::Ivb => "ivb",
-            Self::Ivv => "ivv",
-            Self::Iws => "iws",
-            Self::Ixl => "ixl",
-            Self::Izh => "izh",
-            Self::Izr => "izr",
-            Self::Izz => "izz",
-            Self::Jac => "jac",
-            Self::Jae => "jae",
-            Self::Jam => "jam",
-            Self::Jav => "jav",
-            Self::Jbo => "jbo",
-            Self::Jbu => "jbu",
-            Self::Jdt => "jdt",
-            Self::Jic => "jic",
-            Self::Jiv => "jiv",
-            Self::Jmc => "jmc",
-            Self::Jmx => "jmx",
-            Self::Jpa => "jpa",
-            Self::Jpn => "jpn",
-            Self::Jra => "jra",
-            Self::Jvn => "jvn",
-            Self::Kaa => "kaa",
-            Self::Kab => "kab",
-            Self::Kac => "kac",
-            Self::Kal => "kal",
-            Self::Kam => "kam",
-            Self::Kan => "kan",
-            Self::Kao => "kao",
-            Self::Kap => "kap",
-            Self::Kaq => "kaq",
-            Self::Kas => "kas",
-            Self::Kat => "kat",
-            Self::Kaz => "kaz",
-            Self::Kbc => "kbc",
-            Self::Kbd => "kbd",
-            Self::Kbh => "kbh",
-            Self::Kbm => "kbm",
-            Self::Kbp => "kbp",
-            Self::Kbq => "kbq",
-            Self::Kbr => "kbr",
-            Self::Kck => "kck",
-            Self::Kdc => "kdc",
-            Self::Kde => "kde",
-            Self::Kdh => "kdh",
-            Self::Kdi => "kdi",
-            Self::Kdj => "kdj",
-            Self::Kdl => "kdl",
-            Self::Kea => "kea",
-            Self::Kei => "kei",
-            Self::Kek => "kek",
-            Self::Ken => "ken",
-            Self::Kew => "kew",
-            Self::Kex => "kex",
-            Self::Kez => "kez",
-            Self::Kff => "kff",
-            Self::Kgf => "kgf",
-            Self::Kgk => "kgk",
-            Self::Kgp => "kgp",
-            Self::Kha => "kha",
-            Self::Khk => "khk",
-            Self::Khm => "khm",
-            Self::Khs => "khs",
-            Self::Khy => "khy",
-            Self::Khz => "khz",
-            Self::Kia => "kia",
-            Self::Kik => "kik",
-            Self::Kin => "kin",
-            Self::Kir => "kir",
-            Self::Kiu => "kiu",
-            Self::Kix => "kix",
-            Self::Kjb => "kjb",
-            Self::Kje => "kje",
-            Self::Kjh => "kjh",
-            Self::Kjs => "kjs",
-            Self::Kkc => "kkc",
-            Self::Kki => "kki",
-            Self::Kkj => "kkj",
-            Self::Kkl => "kkl",
-            Self::Kln => "kln",
-            Self::Klt => "klt",
-            Self::Klv => "klv",
-            Self::Kma => "kma",
-            Self::Kmb => "kmb",
-            Self::Kmg => "kmg",
-            Self::Kmh => "kmh",
-            Self::Kmk => "kmk",
-            Self::Kmm => "kmm",
-            Self::Kmo => "kmo",
-            Self::Kmr => "kmr",
-            Self::Kms => "kms",
-            Self::Kmu => "kmu",
-            Self::Knc => "knc",
-            Self::Kne => "kne",
-            Self::Knf => "knf",
-            Self::Kng => "kng",
-            Self::Knj => "knj",
-            Self::Knk => "knk",
-            Self::Kno => "kno",
-            Self::Knv => "knv",
-            Self::Knx => "knx",
-            Self::Kny => "kny",
-            Self::Kog => "kog",
-            Self::Koi => "koi",
-            Self::Kom => "kom",
-            Self::Kon => "kon",
-            Self::Koo => "koo",
-            Self::Kor => "kor",
-            Self::Kos => "kos",
-            Self::Kpf => "kpf",
-            Self::Kpg => "kpg",
-            Self::Kpj => "kpj",
-            Self::Kpr => "kpr",
-            Self::Kpv => "kpv",
-            Self::Kpw => "kpw",
-            Self::Kpx => "kpx",
-            Self::Kpz => "kpz",
-            Self::Kqc => "kqc",
-            Self::Kqe => "kqe",
-            Self::Kqf => "kqf",
-            Self::Kql => "kql",
-            Self::Kqn => "kqn",
-            Self::Kqo => "kqo",
-            Self::Kqp => "kqp",
-            Self::Kqs => "kqs",
-            Self::Kqw => "kqw",
-            Self::Kqy => "kqy",
-            Self::Krc => "krc",
-            Self::Kri => "kri",
-            Self::Krj => "krj",
-            Self::Krl => "krl",
-            Self::Kru => "kru",
-            Self::Ksb => "ksb",
-            Self::Ksc => "ksc",
-            Self::Ksd => "ksd",
-            Self::Ksf => "ksf",
-            Self::Ksh => "ksh",
-            Self::Ksj => "ksj",
-            Self::Ksp => "ksp",
-            Self::Ksr => "ksr",
-            Self::Kss => "kss",
-            Self::Ksw => "ksw",
-            Self::Ktb => "ktb",
-            Self::Ktj => "ktj",
-            Self::Ktm => "ktm",
-            Self::Kto => "kto",
-            Self::Ktu => "ktu",
-            Self::Kua => "kua",
-            Self::Kub => "kub",
-            Self::Kud => "kud",
-            Self::Kue => "kue",
-            Self::Kuj => "kuj",
-            Self::Kum => "kum",
-            Self::Kup => "kup",
-            Self::Kus => "kus",
-            Self::Kvj => "kvj",
-            Self::Kvn => "kvn",
-            Self::Kwd => "kwd",
-            Self::Kwf => "kwf",
-            Self::Kwi => "kwi",
-            Self::Kwj => "kwj",
-            Self::Kwn => "kwn",
-            Self::Kwy => "kwy",
-            Self::Kxc => "kxc",
-            Self::Kxm => "kxm",
-            Self::Kxw => "kxw",
-            Self::Kyc => "kyc",
-            Self::Kyf => "kyf",
-            Self::Kyg => "kyg",
-            Self::Kyq => "kyq",
-            Self::Kyu => "kyu",
-            Self::Kyz => "kyz",
-            Self::Kze => "kze",
-            Self::Kzf => "kzf",
-            Self::Kzj => "kzj",
-            Self::Kzn => "kzn",
-            Self::Lac => "lac",
-            Self::Lad => "lad",
-            Self::Lai => "lai",
-            Self::Laj => "laj",
-            Self::Lam => "lam",
-            Self::Lao => "lao",
-            Self::Las => "las",
-            Self::Lat => "lat",
-            Self::Lav => "lav",
-            Self::Lbb => "lbb",
-            Self::Lbe => "lbe",
-            Self::Lbj => "lbj",
-            Self::Lbk => "lbk",
-            Self::Lch => "lch",
-            Self::Lcm => "lcm",
-            Self::Ldi => "ldi",
-            Self::Ldn => "ldn",
-            Self::Lea => "lea",
-            Self::Led => "led",
-            Self::Lee => "lee",
-            Self::Lef => "lef",
-            Self::Leh => "leh",
-            Self::Lem => "lem",
-            Self::Leu => "leu",
-            Self::Lew => "lew",
-            Self::Lex => "lex",
-            Self::Lez => "lez",
-            Self::Lfn => "lfn",
-            Self::Lgm => "lgm",
-            Self::Lhi => "lhi",
-            Self::Lhm => "lhm",
-            Self::Lhu => "lhu",
-            Self::Lia => "lia",
-            Self::Lid => "lid",
-            Self::Lif => "lif",
-            Self::Lij => "lij",
-            Self::Lim => "lim",
-            Self::Lin => "lin",
-            Self::Lip => "lip",
-            Self::Lir => "lir",
-            Self::Lit => "lit",
-            Self::Liv => "liv",
-            Self::Ljp => "ljp",
-            Self::Llb => "llb",
-            Self::Lld => "lld",
-            Self::Lln => "lln",
-            Self::Lmk => "lmk",
-            Self::Lmo => "lmo",
-            Self::Lmp => "lmp",
-            Self::Lob => "lob",
-            Self::Loe => "loe",
-            Self::Log => "log",
-            Self::Lol => "lol",
-            Self::Lom => "lom",
-            Self::Loq => "loq",
-            Self::Loz => "loz",
-            Self::Lsi => "lsi",
-            Self::Lsm => "lsm",
-            Self::Ltg => "ltg",
-            Self::Ltz => "ltz",
-            Self::Lua => "lua",
-            Self::Lub => "lub",
-            Self::Lue => "lue",
-            Self::Lug => "lug",
-            Self::Lun => "lun",
-            Self::Luo => "luo",
-            Self::Lus => "lus",
-            Self::Lut => "lut",
-            Self::Lvs => "lvs",
-            Self::Lwo => "lwo",
-            Self::Lww => "lww",
-            Self::Lzh => "lzh",
-            Self::Lzz => "lzz",
-            Self::Maa => "maa",
-            Self::Mad => "mad",
-            Self::Maf => "maf",
-            Self::Mag => "mag",
-            Self::Mah => "mah",
-            Self::Mai => "mai",
-            Self::Maj => "maj",
-            Self::Mak => "mak",
-            Self::Mal => "mal",
-            Self::Mam => "mam",
-            Self::Maq => "maq",
-            Self::Mar => "mar",
-            Self::Mas => "mas",
-            Self::Mau => "mau",
-            Self::Mav => "mav",
-            Self::Maw => "maw",
-            Self::Max => "max",
-            Self::Maz => "maz",
-            Self::Mbb => "mbb",
-            Self::Mbc => "mbc",
-            Self::Mbd => "mbd",
-            Self::Mbf => "mbf",
-            Self::Mbh => "mbh",
-            Self::Mbi => "mbi",
-            Self::Mbj => "mbj",
-            Self::Mbl => "mbl",
-            Self::Mbs => "mbs",
-            Self::Mbt => "mbt",
-            Self::Mca => "mca",
-            Self::Mcb => "mcb",
-            Self::Mcd => "mcd",
-            Self::Mcf => "mcf",
-            Self::Mck => "mck",
-            Self::Mcn => "mcn",
-            Self::Mco => "mco",
-            Self::Mcp => "mcp",
-            Self::Mcq => "mcq",
-            Self::Mcu => "mcu",
-            Self::Mda => "mda",
-            Self::Mdf => "mdf",
-            Self::Mdy => "mdy",
-            Self::Med => "med",
-            Self::Mee => "mee",
-            Self::Meh => "meh",
-            Self::Mej => "mej",
-            Self::Mek => "mek",
-            Self::Men => "men",
-            Self::Meq => "meq",
-            Self::Mer => "mer",
-            Self::Meu => "meu",
-            Self::Mev => "mev",
-            Self::Mfa => "mfa",
-            Self::Mfe => "mfe",
-            Self::Mfh => "mfh",
-            Self::Mfi => "mfi",
-            Self::Mfk => "mfk",
-            Self::Mfq => "mfq",
-            Self::Mfy => "mfy",
-            Self::Mfz => "mfz",
-            Self::Mgc => "mgc",
-            Self::Mgh => "mgh",
-            Self::Mgm => "mgm",
-            Self::Mgo => "mgo",
-            Self::Mgr => "mgr",
-            Self::Mgv => "mgv",
-            Self::Mhi => "mhi",
-            Self::Mhl => "mhl",
-            Self::Mhr => "mhr",
-            Self::Mhw => "mhw",
-            Self::Mhx => "mhx",
-            Self::Mhy => "mhy",
-            Self::Mib => "mib",
-            Self::Mic => "mic",
-            Self::Mie => "mie",
-            Self::Mif => "mif",
-            Self::Mig => "mig",
-            Self::Mih => "mih",
-            Self::Mik => "mik",
-            Self::Mil => "mil",
-            Self::Min => "min",
-            Self::Mio => "mio",
-            Self::Miq => "miq",
-            Self::Mir => "mir",
-            Self::Mit => "mit",
-            Self::Miy => "miy",
-            Self::Miz => "miz",
-            Self::Mjc => "mjc",
-            Self::Mjw => "mjw",
-            Self::Mkd => "mkd",
-            Self::Mkl => "mkl",
-            Self::Mkn => "mkn",
-            Self::Mks => "mks",
-            Self::Mkz => "mkz",
-            Self::Mlg => "mlg",
-            Self::Mlh => "mlh",
-            Self::Mlp => "mlp",
-            Self::Mlt => "mlt",
-            Self::Mlu => "mlu",
-            Self::Mmn => "mmn",
-            Self::Mmo => "mmo",
-            Self::Mmx => "mmx",
-            Self::Mna => "mna",
-            Self::Mnb => "mnb",
-            Self::Mnf => "mnf",
-            Self::Mni => "mni",
-            Self::Mnk => "mnk",
-            Self::Mnx => "mnx",
-            Self::Mny => "mny",
-            Self::Moa => "moa",
-            Self::Moc => "moc",
-            Self::Mog => "mog",
-            Self::Moh => "moh",
-            Self::Mon => "mon",
-            Self::Mop => "mop",
-            Self::Mor => "mor",
-            Self::Mos => "mos",
-            Self::Mox => "mox",
-            Self::Mpg => "mpg",
-            Self::Mph => "mph",
-            Self::Mpm => "mpm",
-            Self::Mpp => "mpp",
-            Self::Mps => "mps",
-            Self::Mpt => "mpt",
-            Self::Mpx => "mpx",
-            Self::Mqb => "mqb",
-            Self::Mqj => "mqj",
-            Self::Mqy => "mqy",
-            Self::Mrg => "mrg",
-            Self::Mri => "mri",
-            Self::Mrj => "mrj",
-            Self::Mrq => "mrq",
-            Self::Mrv => "mrv",
-            Self::Mrw => "mrw",
-            Self::Msa => "msa",
-            Self::Msb => "msb",
-            Self::Msc => "msc",
-            Self::Mse => "mse",
-            Self::Msk => "msk",
-            Self::Msm => "msm",
-            Self::Msy => "msy",
-            Self::Mta => "mta",
-            Self::Mtg => "mtg",
-            Self::Mti => "mti",
-            Self::Mtj => "mtj",
-            Self::Mto => "mto",
-            Self::Mtp => "mtp",
-            Self::Mua => "mua",
-            Self::Mug => "mug",
-            Self::Muh => "muh",
-            Self::Mur => "mur",
-            Self::Mus => "mus",
-            Self::Mux => "mux",
-            Self::Muy => "muy",
-            Self::Mva => "mva",
-            Self::Mvn => "mvn",
-            Self::Mvp => "mvp",
-            Self::Mwc => "mwc",
-            Self::Mwf => "mwf",
-            Self::Mwl => "mwl",
-            Self::Mwm => "mwm",
-            Self::Mwn => "mwn",
-            Self::Mwp => "mwp",
-            Self::Mwq => "mwq",
-            Self::Mwv => "mwv",
-            Self::Mww => "mww",
-            Self::Mxb => "mxb",
-            Self::Mxp => "mxp",
-            Self::Mxq => "mxq",
-            Self::Mxt => "mxt",
-            Self::Mxv => "mxv",
-            Self::Mya => "mya",
-            Self::Myb => "myb",
-            Self::Myk => "myk",
-            Self::Myu => "myu",
-            Self::Myv => "myv",
-            Self::Myw => "myw",
-            Self::Myx => "myx",
-            Self::Myy => "myy",
-            Self::Mza => "mza",
-            Self::Mzh => "mzh",
-            Self::Mzk => "mzk",
-            Self::Mzl => "mzl",
-            Self::Mzm => "mzm",
-            Self::Mzn => "mzn",
-            Self::Mzw => "mzw",
-            Self::Mzz => "mzz",
-            Self::Nab => "nab",
-            Self::Naf => "naf",
-            Self::Nah => "nah",
-            Self::Nak => "nak",
-            Self::Nan => "nan",
-            Self::Nap => "nap",
-            Self::Naq => "naq",
-            Self::Nas => "nas",
-            Self::Nav => "nav",
-            Self::Naw => "naw",
-            Self::Nba => "nba",
-            Self::Nbc => "nbc",
-            Self::Nbe => "nbe",
-            Self::Nbl => "nbl",
-            Self::Nbq => "nbq",
-            Self::Nbu => "nbu",
-            Self::Nca => "nca",
-            Self::Nch => "nch",
-            Self::Ncj => "ncj",
-            Self::Ncl => "ncl",
-            Self::Nct => "nct",
-            Self::Ncu => "ncu",
-            Self::Ncx => "ncx",
-            Self::Ndc => "ndc",
-            Self::Nde => "nde",
-            Self::Ndh => "ndh",
-            Self::Ndi => "ndi",
-            Self::Ndj => "ndj",
-            Self::Ndo => "ndo",
-            Self::Ndp => "ndp",
-            Self::Nds => "nds",
-            Self::Ndz => "ndz",
-            Self::Neb => "neb",
-            Self::Nep => "nep",
-            Self::New => "new",
-            Self::Nfa => "nfa",
-            Self::Nfr => "nfr",
-            Self::Ngb => "ngb",
-            Self::Ngc => "ngc",
-            Self::Ngl => "ngl",
-            Self::Ngp => "ngp",
-            Self::Ngu => "ngu",
-            Self::Nhd => "nhd",
-            Self::Nhe => "nhe",
-            Self::Nhg => "nhg",
-            Self::Nhi => "nhi",
-            Self::Nhk => "nhk",
-            Self::Nho => "nho",
-            Self::Nhr => "nhr",
-            Self::Nhu => "nhu",
-            Self::Nhw => "nhw",
-            Self::Nhx => "nhx",
-            Self::Nhy => "nhy",
-            Self::Nia => "nia",
-            Self::Nif => "nif",
-            Self::Nii => "nii",
-            Self::Nij => "nij",
-            Self::Nim => "nim",
-            Self::Nin => "nin",
-            Self::Niq => "niq",
-            Self::Niu => "niu",
-            Self::Niy => "niy",
-            Self::Njb => "njb",
-            Self::Njm => "njm",
-            Self::Njn => "njn",
-            Self::Njo => "njo",
-            Self::Njz => "njz",
-            Self::Nka => "nka",
-            Self::Nki => "nki",
-            Self::Nko => "nko",
-            Self::Nla => "nla",
-            Self::Nlc => "nlc",
-            Self::Nld => "nld",
-            Self::Nma => "nma",
-            Self::Nmf => "nmf",
-            Self::Nmh => "nmh",
-            Self::Nmo => "nmo",
-            Self::Nmw => "nmw",
-            Self::Nmz => "nmz",
-            Self::Nnb => "nnb",
-            Self::Nng => "nng",
-            Self::Nnh => "nnh",
-            Self::Nnl => "nnl",
-            Self::Nno => "nno",
-            Self::Nnp => "nnp",
-            Self::Nnq => "nnq",
-            Self::Nnw => "nnw",
-            Self::Noa => "noa",
-            Self::Nob => "nob",
-            Self::Nog => "nog",
-            Self::Nop => "nop",
-            Self::Nor => "nor",
-            Self::Not => "not",
-            Self::Nou => "nou",
-            Self::Nov => "nov",
-            Self::Nph => "nph",
-            Self::Npi => "npi",
-            Self::Npl => "npl",
-            Self::Npo => "npo",
-            Self::Npy => "npy",
-            Self::Nre => "nre",
-            Self::Nrf => "nrf",
-            Self::Nri => "nri",
-            Self::Nsa => "nsa",
-            Self::Nse => "nse",
-            Self::Nsm => "nsm",
-            Self::Nsn => "nsn",
-            Self::Nso => "nso",
-            Self::Nss => "nss",
-            Self::Nst => "nst",
-            Self::Nsu => "nsu",
-            Self::Ntp => "ntp",
-            Self::Ntr => "ntr",
-            Self::Nus => "nus",
-            Self::Nuy => "nuy",
-            Self::Nvm => "nvm",
-            Self::Nwb => "nwb",
-            Self::Nwi => "nwi",
-            Self::Nwx => "nwx",
-            Self::Nxd => "nxd",
-            Self::Nya => "nya",
-            Self::Nyf => "nyf",
-            Self::Nyk => "nyk",
-            Self::Nyn => "nyn",
-            Self::Nyo => "nyo",
-            Self::Nyu => "nyu",
-            Self::Nyy => "nyy",
-            Self::Nzb => "nzb",
-            Self::Nzi => "nzi",
-            Self::Nzm => "nzm",
-            Self::Obo => "obo",
-            Self::Oci => "oci",
-            Self::Ogo => "ogo",
-            Self::Ojb => "ojb",
-            Self::Oji => "oji",
-            Self::Ojs => "ojs",
-            Self::Oke => "oke",
-            Self::Okv => "okv",
-            Self::Old => "old",
-            Self::Omw => "omw",
-            Self::Ong => "ong",
-            Self::Ons => "ons",
-            Self::Ood => "ood",
-            Self::Opm => "opm",
-            Self::Ori => "ori",
-            Self::Orm => "orm",
-            Self::Orv => "orv",
-            Self::Ory => "ory",
-            Self::Oss => "oss",
-            Self::Ota => "ota",
-            Self::Ote => "ote",
-            Self::Otm => "otm",
-            Self::Otn => "otn",
-            Self::Otq => "otq",
-            Self::Ots => "ots",
-            Self::Otw => "otw",
-            Self::Oym => "oym",
-            Self::Ozm => "ozm",
-            Self::Pab => "pab",
-            Self::Pad => "pad",
-            Self::Pag => "pag",
-            Self::Pah => "pah",
-            Self::Pam => "pam",
-            Self::Pan => "pan",
-            Self::Pao => "pao",
-            Self::Pap => "pap",
-            Self::Pau => "pau",
-            Self::Pbb => "pbb",
-            Self::Pbc => "pbc",
-            Self::Pbi => "pbi",
-            Self::Pbl => "pbl",
-            Self::Pbt => "pbt",
-            Self::Pcd => "pcd",
-            Self::Pck => "pck",
-            Self::Pcm => "pcm",
-            Self::Pdc => "pdc",
-            Self::Pdt => "pdt",
-            Self::Pem => "pem",
-            Self::Pes => "pes",
-            Self::Pfe => "pfe",
-            Self::Pfl => "pfl",
-            Self::Phm => "phm",
-            Self::Pib => "pib",
-            Self::Pid => "pid",
-            Self::Pio => "pio",
-            Self::Pir => "pir",
-            Self::Pis => "pis",
-            Self::Pjt => "pjt",
-            Self::Pkb => "pkb",
-            Self::Plg => "plg",
-            Self::Pls => "pls",
-            Self::Plt => "plt",
-            Self::Plu => "plu",
-            Self::Plw => "plw",
-            Self::Pma => "pma",
-            Self::Pmf => "pmf",
-            Self::Pms => "pms",
-            Self::Pmx => "pmx",
-            Self::Pnb => "pnb",
-            Self::Pne => "pne",
-            Self::Poe => "poe",
-            Self::Poh => "poh",
-            Self::Poi => "poi",
-            Self::Pol => "pol",
-            Self::Pon => "pon",
-            Self::Por => "por",
-            Self::Pot => "pot",
-            Self::Pov => "pov",
-            Self::Poy => "poy",
-            Self::Ppk => "ppk",
-            Self::Ppl => "ppl",
-            Self::Ppo => "ppo",
-            Self::Pps => "pps",
-            Self::Prf => "prf",
-            Self::Prg => "prg",
-            Self::Pri => "pri",
-            Self::Prk => "prk",
-            Self::Prs => "prs",
-            Self::Pse => "pse",
-            Self::Ptp => "ptp",
-            Self::Ptu => "ptu",
-            Self::Pua => "pua",
-            Self::Pus => "pus",
-            Self::Pwg => "pwg",
-            Self::Pww => "pww",
-            Self::Qub => "qub",
-            Self::Quc => "quc",
-            Self::Que => "que",
-            Self::Quf => "quf",
-            Self::Qug => "qug",
-            Self::Quh => "quh",
-            Self::Qul => "qul",
-            Self::Qup => "qup",
-            Self::Qus => "qus",
-            Self::Quw => "quw",
-            Self::Quy => "quy",
-            Self::Quz => "quz",
-            Self::Qva => "qva",
-            Self::Qvc => "qvc",
-            Self::Qve => "qve",
-            Self::Qvh => "qvh",
-            Self::Qvi => "qvi",
-            Self::Qvm => "qvm",
-            Self::Qvn => "qvn",
-            Self::Qvo => "qvo",
-            Self::Qvs => "qvs",
-            Self::Qvw => "qvw",
-            Self::Qvz => "qvz",
-            Self::Qwh => "qwh",
-            Self::Qxh => "qxh",
-            Self::Qxl => "qxl",
-            Self::Qxn => "qxn",
-            Self::Qxo => "qxo",
-            Self::Qxr => "qxr",
-            Self::Qya => "qya",
-            Self::Rad => "rad",
-            Self::Rai => "rai",
-            Self::Rap => "rap",
-            Self::Rar => "rar",
-            Self::Rcf => "rcf",
-            Self::Rhg => "rhg",
-            Self::Ria => "ria",
-            Self::Rif => "rif",
-            Self::Rim => "rim",
-            Self::Rkb => "rkb",
-            Self::Rmc => "rmc",
-            Self::Rme => "rme",
-            Self::Rml => "rml",
-            Self::Rmn => "rmn",
-            Self::Rmo => "rmo",
-            Self::Rmq => "rmq",
-            Self::Rmy => "rmy",
-            Self::Rnd => "rnd",
-            Self::Rng => "rng",
-            Self::Rnl => "rnl",
-            Self::Roh => "roh",
-            Self::Rom => "rom",
-            Self::Ron => "ron",
-            Self::Roo => "roo",
-            Self::Rop => "rop",
-            Self::Rro => "rro",
-            Self::Rtm => "rtm",
-            Self::Rub => "rub",
-            Self::Rue => "rue",
-            Self::Ruf => "ruf",
-            Self::Run => "run",
-            Self::Rup => "rup",
-            Self::Rus => "rus",
-            Self::Rwo => "rwo",
-            Self::Sab => "sab",
-            Self::Sag => "sag",
-            Self::Sah => "sah",
-            Self::San => "san",
-            Self::Sas => "sas",
-            Self::Sat => "sat",
-            Self::Sba => "sba",
-            Self::Sbd => "sbd",
-            Self::Sbe => "sbe",
-            Self::Sbl => "sbl",
-            Self::Sbs => "sbs",
-            Self::Sby => "sby",
-            Self::Sck => "sck",
-            Self::Scn => "scn",
-            Self::Sco => "sco",
-            Self::Sda => "sda",
-            Self::Sdh => "sdh",
-            Self::Sdo => "sdo",
-            Self::Seh => "seh",
-            Self::Ses => "ses",
-            Self::Sey => "sey",
-            Self::Sfw => "sfw",
-            Self::Sgb => "sgb",
-            Self::Sgh => "sgh",
-            Self::Sgs => "sgs",
-            Self::Sgw => "sgw",
-            Self::Sgz => "sgz",
-            Self::Shi => "shi",
-            Self::Shk => "shk",
-            Self::Shn => "shn",
-            Self::Shp => "shp",
-            Self::Shr => "shr",
-            Self::Shs => "shs",
-            Self::Shu => "shu",
-            Self::Shy => "shy",
-            Self::Sid => "sid",
-            Self::Sig => "sig",
-            Self::Sil => "sil",
-            Self::Sim => "sim",
-            Self::Sin => "sin",
-            Self::Sja => "sja",
-            Self::Sjn => "sjn",
-            Self::Skg => "skg",
-            Self::Skr => "skr",
-            Self::Sld => "sld",
-            Self::Slk => "slk",
-            Self::Sll => "sll",
-            Self::Slv => "slv",
-            Self::Sma => "sma",
-            Self::Sme => "sme",
-            Self::Smk => "smk",
-            Self::Sml => "sml",
-            Self::Smo => "smo",
-            Self::Smt => "smt",
-            Self::Sna => "sna",
-            Self::Snc => "snc",
-            Self::Snd => "snd",
-            Self::Snf => "snf",
-            Self::Snn => "snn",
-            Self::Snp => "snp",
-            Self::Snw => "snw",
-            Self::Sny => "sny",
-            Self::Soe => "soe",
-            Self::Som => "som",
-            Self::Sop => "sop",
-            Self::Soq => "soq",
-            Self::Sot => "sot",
-            Self::Soy => "soy",
-            Self::Spa => "spa",
-            Self::Spl => "spl",
-            Self::Spm => "spm",
-            Self::Spp => "spp",
-            Self::Sps => "sps",
-            Self::Spy => "spy",
-            Self::Sqi => "sqi",
-            Self::Srd => "srd",
-            Self::Sri => "sri",
-            Self::Srm => "srm",
-            Self::Srn => "srn",
-            Self::Srp => "srp",
-            Self::Srq => "srq",
-            Self::Srr => "srr",
-            Self::Ssd => "ssd",
-            Self::Ssg => "ssg",
-            Self::Ssw => "ssw",
-            Self::Ssx => "ssx",
-            Self::Stn => "stn",
-            Self::Stp => "stp",
-            Self::Stq => "stq",
-            Self::Sua => "sua",
-            Self::Suc => "suc",
-            Self::Sue => "sue",
-            Self::Suk => "suk",
-            Self::Sun => "sun",
-            Self::Sur => "sur",
-            Self::Sus => "sus",
-            Self::Sux => "sux",
-            Self::Suz => "suz",
-            Self::Swa => "swa",
-            Self::Swb => "swb",
-            Self::Swc => "swc",
-            Self::Swe => "swe",
-            Self::Swg => "swg",
-            Self::Swh => "swh",
-            Self::Swk => "swk",
-            Self::Swp => "swp",
-            Self::Sxb => "sxb",
-            Self::Sxn => "sxn",
-            Self::Syb => "syb",
-            Self::Syc => "syc",
-            Self::Szb => "szb",
-            Self::Szl => "szl",
-            Self::Tab => "tab",
-            Self::Tac => "tac",
-            Self::Tah => "tah",
-            Self::Taj => "taj",
-            Self::Tam => "tam",
-            Self::Tap => "tap",
-            Self::Taq => "taq",
-            Self::Tar => "tar",
-            Self::Tat => "tat",
-            Self::Tav => "tav",
-            Self::Taw => "taw",
-            Self::Tbc => "tbc",
-            Self::Tbg => "tbg",
-            Self::Tbk => "tbk",
-            Self::Tbl => "tbl",
-            Self::Tbo => "tbo",
-            Self::Tby => "tby",
-            Self::Tbz => "tbz",
-            Self::Tca => "tca",
-            Self::Tcc => "tcc",
-            Self::Tcf => "tcf",
-            Self::Tcs => "tcs",
-            Self::Tcy => "tcy",
-            Self::Tcz => "tcz",
-            Self::Tdt => "tdt",
-            Self::Tdx => "tdx",
-            Self::Ted => "ted",
-            Self::Tee => "tee",
-            Self::Tel => "tel",
-            Self::Tem => "tem",
-            Self::Teo => "teo",
-            Self::Ter => "ter",
-            Self::Tet => "tet",
-            Self::Tew => "tew",
-            Self::Tfr => "tfr",
-            Self::Tgk => "tgk",
-            Self::Tgl => "tgl",
-            Self::Tgo => "tgo",
-            Self::Tgp => "tgp",
-            Self::Tha => "tha",
-            Self::Thk => "thk",
-            Self::Thv => "thv",
-            Self::Tif => "tif",
-            Self::Tig => "tig",
-            Self::Tih => "tih",
-            Self::Tik => "tik",
-            Self::Tim => "tim",
-            Self::Tir => "tir",
-            Self::Tiv => "tiv",
-            Self::Tiy => "tiy",
-            Self::Tke => "tke",
-            Self::Tkl => "tkl",
-            Self::Tkr => "tkr",
-            Self::Tku => "tku",
-            Self::Tlb => "tlb",
-            Self::Tlf => "tlf",
-            Self::Tlh => "tlh",
-            Self::Tlj => "tlj",
-            Self::Tll => "tll",
-            Self::Tly => "tly",
-            Self::Tmd => "tmd",
-            Self::Tmr => "tmr",
-            Self::Tna => "tna",
-            Self::Tnc => "tnc",
-            Self::Tnk => "tnk",
-            Self::Tnn => "tnn",
-            Self::Tnp => "tnp",
-            Self::Tob => "tob",
-            Self::Toc => "toc",
-            Self::Tod => "tod",
-            Self::Tog => "tog",
-            Self::Toh => "toh",
-            Self::Toi => "toi",
-            Self::Toj => "toj",
-            Self::Tok => "tok",
-            Self::Ton => "ton",
-            Self::Too => "too",
-            Self::Top => "top",
-            Self::Tos => "tos",
-            Self::Tpa => "tpa",
-            Self::Tpi => "tpi",
-            Self::Tpm => "tpm",
-            Self::Tpp => "tpp",
-            Self::Tpt => "tpt",
-            Self::Tpw => "tpw",
-            Self::Tpz => "tpz",
-            Self::Tqb => "tqb",
-            Self::Trc => "trc",
-            Self::Trn => "trn",
-            Self::Tro => "tro",
-            Self::Trp => "trp",
-            Self::Trq => "trq",
-            Self::Tsc => "tsc",
-            Self::Tsg => "tsg",
-            Self::Tsn => "tsn",
-            Self::Tso => "tso",
-            Self::Tsw => "tsw",
-            Self::Tsz => "tsz",
-            Self::Ttc => "ttc",
-            Self::Tte => "tte",
-            Self::Ttj => "ttj",
-            Self::Ttq => "ttq",
-            Self::Tts => "tts",
-            Self::Tuc => "tuc",
-            Self::Tue => "tue",
-            Self::Tuf => "tuf",
-            Self::Tui => "tui",
-            Self::Tuk => "tuk",
-            Self::Tum => "tum",
-            Self::Tuo => "tuo",
-            Self::Tur => "tur",
-            Self::Tuv => "tuv",
-            Self::Tvk => "tvk",
-            Self::Tvl => "tvl",
-            Self::Twi => "twi",
-            Self::Twu => "twu",
-            Self::Twx => "twx",
-            Self::Txq => "txq",
-            Self::Txu => "txu",
-            Self::Tyv => "tyv",
-            Self::Tzh => "tzh",
-            Self::Tzj => "tzj",
-            Self::Tzl => "tzl",
-            Self::Tzm => "tzm",
-            Self::Tzo => "tzo",
-            Self::Ubr => "ubr",
-            Self::Ubu => "ubu",
-            Self::Udm => "udm",
-            Self::Udu => "udu",
-            Self::Uig => "uig",
-            Self::Ukr => "ukr",
-            Self::Umb => "umb",
-            Self::Upv => "upv",
-            Self::Ura => "ura",
-            Self::Urb => "urb",
-            Self::Urd => "urd",
-            Self::Urh => "urh",
-            Self::Uri => "uri",
-            Self::Urk => "urk",
-            Self::Urt => "urt",
-            Self::Usa => "usa",
-            Self::Usp => "usp",
-            Self::Uvh => "uvh",
-            Self::Uvl => "uvl",
-            Self::Uzb => "uzb",
-            Self::Uzn => "uzn",
-            Self::Vag => "vag",
-            Self::Vap => "vap",
-            Self::Var => "var",
-            Self::Vec => "vec",
-            Self::Ven => "ven",
-            Self::Vgt => "vgt",
-            Self::Vid => "vid",
-            Self::Vie => "vie",
-            Self::Viv => "viv",
-            Self::Vls => "vls",
-            Self::Vmk => "vmk",
-            Self::Vmw => "vmw",
-            Self::Vmy => "vmy",
-            Self::Vol => "vol",
-            Self::Vro => "vro",
-            Self::Vun => "vun",
-            Self::Vut => "vut",
-            Self::Waj => "waj",
-            Self::Wal => "wal",
-            Self::Wap => "wap",
-            Self::War => "war",
-            Self::Wat => "wat",
-            Self::Way => "way",
-            Self::Wba => "wba",
-            Self::Wbm => "wbm",
-            Self::Wbp => "wbp",
-            Self::Wca => "wca",
-            Self::Wed => "wed",
-            Self::Wer => "wer",
-            Self::Wes => "wes",
-            Self::Wew => "wew",
-            Self::Whg => "whg",
-            Self::Whk => "whk",
-            Self::Wib => "wib",
-            Self::Wim => "wim",
-            Self::Wiu => "wiu",
-            Self::Wln => "wln",
-            Self::Wls => "wls",
-            Self::Wlv => "wlv",
-            Self::Wmt => "wmt",
-            Self::Wmw => "wmw",
-            Self::Wnc => "wnc",
-            Self::Wnu => "wnu",
-            Self::Wob => "wob",
-            Self::Wol => "wol",
-            Self::Wos => "wos",
-            Self::Wrk => "wrk",
-            Self::Wrs => "wrs",
-            Self::Wsk => "wsk",
-            Self::Wuu => "wuu",
-            Self::Wuv => "wuv",
-            Self::Wwa => "wwa",
-            Self::Xal => "xal",
-            Self::Xav => "xav",
-            Self::Xbi => "xbi",
-            Self::Xbr => "xbr",
-            Self::Xed => "xed",
-            Self::Xho => "xho",
-            Self::Xla => "xla",
-            Self::Xmf => "xmf",
-            Self::Xmv => "xmv",
-            Self::Xnn => "xnn",
-            Self::Xog => "xog",
-            Self::Xon => "xon",
-            Self::Xpe => "xpe",
-            Self::Xrb => "xrb",
-            Self::Xsb => "xsb",
-            Self::Xsi => "xsi",
-            Self::Xsm => "xsm",
-            Self::Xsr => "xsr",
-            Self::Xsu => "xsu",
-            Self::Xtd => "xtd",
-            Self::Xtm => "xtm",
-            Self::Xtn => "xtn",
-            Self::Xuo => "xuo",
-            Self::Yaa => "yaa",
-            Self::Yad => "yad",
-            Self::Yal => "yal",
-            Self::Yam => "yam",
-            Self::Yan => "yan",
-            Self::Yao => "yao",
-            Self::Yap => "yap",
-            Self::Yaq => "yaq",
-            Self::Ybb => "ybb",
-            Self::Yby => "yby",
-            Self::Ycn => "ycn",
-            Self::Ydd => "ydd",
-            Self::Yid => "yid",
-            Self::Yim => "yim",
-            Self::Yka => "yka",
-            Self::Yle => "yle",
-            Self::Yli => "yli",
-            Self::Yml => "yml",
-            Self::Yom => "yom",
-            Self::Yon => "yon",
-            Self::Yor => "yor",
-            Self::Yrb => "yrb",
-            Self::Yre => "yre",
-            Self::Yrk => "yrk",
-            Self::Yrl => "yrl",
-            Self::Yss => "yss",
-            Self::Yua => "yua",
-            Self::Yue => "yue",
-            Self::Yuj => "yuj",
-            Self::Yup => "yup",
-            Self::Yut => "yut",
-            Self::Yuw => "yuw",
-            Self::Yuz => "yuz",
-            Self::Yva => "yva",
-            Self::Zaa => "zaa",
-            Self::Zab => "zab",
-            Self::Zac => "zac",
-            Self::Zad => "zad",
-            Self::Zae => "zae",
-            Self::Zai => "zai",
-            Self::Zam => "zam",
-            Self::Zao => "zao",
-            Self::Zar => "zar",
-            Self::Zas => "zas",
-            Self::Zat => "zat",
-            Self::Zav => "zav",
-            Self::Zaw => "zaw",
-            Self::Zca => "zca",
-            Self::Zdj => "zdj",
-            Self::Zea => "zea",
-            Self::Zgh => "zgh",
-            Self::Zho => "zho",
-            Self::Zia => "zia",
-            Self::Ziw => "ziw",
-            Self::Zlm => "zlm",
-            Self::Zne => "zne",
-            Self::Zom => "zom",
-            Self::Zos => "zos",
-            Self::Zpa => "zpa",
-            Self::Zpc => "zpc",
-            Self::Zpd => "zpd",
-            Self::Zpf => "zpf",
-            Self::Zpg => "zpg",
-            Self::Zpi => "zpi",
-            Self::Zpj => "zpj",
-            Self::Zpl => "zpl",
-            Self::Zpm => "zpm",
-            Self::Zpo => "zpo",
-            Self::Zpq => "zpq",
-            Self::Zpt => "zpt",
-            Self::Zpu => "zpu",
-            Self::Zpv => "zpv",
-            Self::Zpz => "zpz",
-            Self::Zsm => "zsm",
-            Self::Zsr => "zsr",
-            Self::Ztq => "ztq",
-            Self::Zty => "zty",
-            Self::Zul => "zul",
-            Self::Zyb => "zyb",
-            Self::Zyp => "zyp",
-            Self::Zza => "zza",
-            Self::Multi => "multi",
-        };
+        self.info().tag
+    }
 
-        lang_str
+    /// Get the ISO 639-3 code (`mul` for [Lang::Multi]).
+    pub fn iso639_3(self) -> &'static str {
+        self.info().iso639_3
     }
-}
-impl FromStr for Lang {
-    type Err = Error;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "aah-Latn" => Ok(Self::Aahlatn),
-            "aai-Latn" => Ok(Self::Aailatn),
-            "aak-Latn" => Ok(Self::Aaklatn),
-            "aau-Latn" => Ok(Self::Aaulatn),
-            "aaz-Latn" => Ok(Self::Aazlatn),
-            "aba-Latn" => Ok(Self::Abalatn),
-            "abi-Latn" => Ok(Self::Abilatn),
-            "abk-Cyrl" => Ok(Self::Abkcyrl),
-            "abn-Latn" => Ok(Self::Abnlatn),
-            "abq-Cyrl" => Ok(Self::Abqcyrl),
-            "abs-Latn" => Ok(Self::Abslatn),
-            "abt-Latn" => Ok(Self::Abtlatn),
-            "abx-Latn" => Ok(Self::Abxlatn),
-            "aby-Latn" => Ok(Self::Abylatn),
-            "abz-Latn" => Ok(Self::Abzlatn),
-            "aca-Latn" => Ok(Self::Acalatn),
-            "acd-Latn" => Ok(Self::Acdlatn),
-            "ace-Arab" => Ok(Self::Acearab),
-            "ace-Latn" => Ok(Self::Acelatn),
-            "acf-Latn" => Ok(Self::Acflatn),
-            "ach-Latn" => Ok(Self::Achlatn),
-            "acm-Arab" => Ok(Self::Acmarab),
-            "acn-Latn" => Ok(Self::Acnlatn),
-            "acq-Arab" => Ok(Self::Acqarab),
-            "acr-Latn" => Ok(Self::Acrlatn),
-            "acu-Latn" => Ok(Self::Aculatn),
-            "ada-Latn" => Ok(Self::Adalatn),
-            "ade-Latn" => Ok(Self::Adelatn),
-            "adh-Latn" => Ok(Self::Adhlatn),
-            "adi-Latn" => Ok(Self::Adilatn),
-            "adj-Latn" => Ok(Self::Adjlatn),
-            "adl-Latn" => Ok(Self::Adllatn),
-            "adx-Tibt" => Ok(Self::Adxtibt),
-            "ady-Cyrl" => Ok(Self::Adycyrl),
-            "adz-Latn" => Ok(Self::Adzlatn),
-            "aeb-Arab" => Ok(Self::Aebarab),
-            "aer-Latn" => Ok(Self::Aerlatn),
-            "aeu-Latn" => Ok(Self::Aeulatn),
-            "aey-Latn" => Ok(Self::Aeylatn),
-            "afr-Latn" => Ok(Self::Afrlatn),
-            "agd-Latn" => Ok(Self::Agdlatn),
-            "agg-Latn" => Ok(Self::Agglatn),
-            "agm-Latn" => Ok(Self::Agmlatn),
-            "agn-Latn" => Ok(Self::Agnlatn),
-            "agr-Latn" => Ok(Self::Agrlatn),
-            "agt-Latn" => Ok(Self::Agtlatn),
-            "agu-Latn" => Ok(Self::Agulatn),
-            "agw-Latn" => Ok(Self::Agwlatn),
-            "agx-Cyrl" => Ok(Self::Agxcyrl),
-            "aha-Latn" => Ok(Self::Ahalatn),
-            "ahk-Latn" => Ok(Self::Ahklatn),
-            "aia-Latn" => Ok(Self::Aialatn),
-            "aii-Syrc" => Ok(Self::Aiisyrc),
-            "aim-Latn" => Ok(Self::Aimlatn),
-            "ain-Latn" => Ok(Self::Ainlatn),
-            "ajg-Latn" => Ok(Self::Ajglatn),
-            "aji-Latn" => Ok(Self::Ajilatn),
-            "ajp-Arab" => Ok(Self::Ajparab),
-            "ajz-Latn" => Ok(Self::Ajzlatn),
-            "aka-Latn" => Ok(Self::Akalatn),
-            "akb-Latn" => Ok(Self::Akblatn),
-            "ake-Latn" => Ok(Self::Akelatn),
-            "akh-Latn" => Ok(Self::Akhlatn),
-            "akp-Latn" => Ok(Self::Akplatn),
-            "ald-Latn" => Ok(Self::Aldlatn),
-            "alj-Latn" => Ok(Self::Aljlatn),
-            "aln-Latn" => Ok(Self::Alnlatn),
-            "alp-Latn" => Ok(Self::Alplatn),
-            "alq-Latn" => Ok(Self::Alqlatn),
-            "als-Latn" => Ok(Self::Alslatn),
-            "alt-Cyrl" => Ok(Self::Altcyrl),
-            "aly-Latn" => Ok(Self::Alylatn),
-            "alz-Latn" => Ok(Self::Alzlatn),
-            "ame-Latn" => Ok(Self::Amelatn),
-            "amf-Latn" => Ok(Self::Amflatn),
-            "amh-Ethi" => Ok(Self::Amhethi),
-            "ami-Latn" => Ok(Self::Amilatn),
-            "amk-Latn" => Ok(Self::Amklatn),
-            "amm-Latn" => Ok(Self::Ammlatn),
-            "amn-Latn" => Ok(Self::Amnlatn),
-            "amp-Latn" => Ok(Self::Amplatn),
-            "amr-Latn" => Ok(Self::Amrlatn),
-            "amu-Latn" => Ok(Self::Amulatn),
-            "amx-Latn" => Ok(Self::Amxlatn),
-            "ang-Latn" => Ok(Self::Anglatn),
-            "anm-Latn" => Ok(Self::Anmlatn),
-            "ann-Latn" => Ok(Self::Annlatn),
-            "anp-Deva" => Ok(Self::Anpdeva),
-            "anv-Latn" => Ok(Self::Anvlatn),
-            "any-Latn" => Ok(Self::Anylatn),
-            "aoi-Latn" => Ok(Self::Aoilatn),
-            "aoj-Latn" => Ok(Self::Aojlatn),
-            "aom-Latn" => Ok(Self::Aomlatn),
-            "aon-Latn" => Ok(Self::Aonlatn),
-            "aoz-Latn" => Ok(Self::Aozlatn),
-            "apb-Latn" => Ok(Self::Apblatn),
-            "apc-Arab" => Ok(Self::Apcarab),
-            "ape-Latn" => Ok(Self::Apelatn),
-            "apn-Latn" => Ok(Self::Apnlatn),
-            "apr-Latn" => Ok(Self::Aprlatn),
-            "apt-Latn" => Ok(Self::Aptlatn),
-            "apu-Latn" => Ok(Self::Apulatn),
-            "apw-Latn" => Ok(Self::Apwlatn),
-            "apy-Latn" => Ok(Self::Apylatn),
-            "apz-Latn" => Ok(Self::Apzlatn),
-            "aqz-Latn" => Ok(Self::Aqzlatn),
-            "ara-Arab" => Ok(Self::Araarab),
-            "arb-Arab" => Ok(Self::Arbarab),
-            "arb-Latn" => Ok(Self::Arblatn),
-            "are-Latn" => Ok(Self::Arelatn),
-            "arg-Latn" => Ok(Self::Arglatn),
-            "arl-Latn" => Ok(Self::Arllatn),
-            "arn-Latn" => Ok(Self::Arnlatn),
-            "arp-Latn" => Ok(Self::Arplatn),
-            "arq-Arab" => Ok(Self::Arqarab),
-            "arr-Latn" => Ok(Self::Arrlatn),
-            "ars-Arab" => Ok(Self::Arsarab),
-            "ary-Arab" => Ok(Self::Aryarab),
-            "arz-Arab" => Ok(Self::Arzarab),
-            "asg-Latn" => Ok(Self::Asglatn),
-            "asm-Beng" => Ok(Self::Asmbeng),
-            "asm-Latn" => Ok(Self::Asmlatn),
-            "aso-Latn" => Ok(Self::Asolatn),
-            "ast-Latn" => Ok(Self::Astlatn),
-            "ata-Latn" => Ok(Self::Atalatn),
-            "atb-Latn" => Ok(Self::Atblatn),
-            "atd-Latn" => Ok(Self::Atdlatn),
-            "atg-Latn" => Ok(Self::Atglatn),
-            "ati-Latn" => Ok(Self::Atilatn),
-            "atj-Latn" => Ok(Self::Atjlatn),
-            "atq-Latn" => Ok(Self::Atqlatn),
-            "att-Latn" => Ok(Self::Attlatn),
-            "auc-Latn" => Ok(Self::Auclatn),
-            "aui-Latn" => Ok(Self::Auilatn),
-            "auy-Latn" => Ok(Self::Auylatn),
-            "ava-Cyrl" => Ok(Self::Avacyrl),
-            "avk-Latn" => Ok(Self::Avklatn),
-            "avn-Latn" => Ok(Self::Avnlatn),
-            "avt-Latn" => Ok(Self::Avtlatn),
-            "avu-Latn" => Ok(Self::Avulatn),
-            "awa-Deva" => Ok(Self::Awadeva),
-            "awb-Latn" => Ok(Self::Awblatn),
-            "awi-Latn" => Ok(Self::Awilatn),
-            "awx-Latn" => Ok(Self::Awxlatn),
-            "aym-Latn" => Ok(Self::Aymlatn),
-            "ayo-Latn" => Ok(Self::Ayolatn),
-            "ayp-Arab" => Ok(Self::Ayparab),
-            "ayr-Latn" => Ok(Self::Ayrlatn),
-            "azb-Arab" => Ok(Self::Azbarab),
-            "aze-Cyrl" => Ok(Self::Azecyrl),
-            "aze-Latn" => Ok(Self::Azelatn),
-            "azg-Latn" => Ok(Self::Azglatn),
-            "azj-Cyrl" => Ok(Self::Azjcyrl),
-            "azj-Latn" => Ok(Self::Azjlatn),
-            "azz-Latn" => Ok(Self::Azzlatn),
-            "bak-Cyrl" => Ok(Self::Bakcyrl),
-            "bal-Arab" => Ok(Self::Balarab),
-            "bam-Latn" => Ok(Self::Bamlatn),
-            "ban-Latn" => Ok(Self::Banlatn),
-            "bao-Latn" => Ok(Self::Baolatn),
-            "bar-Latn" => Ok(Self::Barlatn),
-            "bas-Latn" => Ok(Self::Baslatn),
-            "bav-Latn" => Ok(Self::Bavlatn),
-            "bba-Latn" => Ok(Self::Bbalatn),
-            "bbb-Latn" => Ok(Self::Bbblatn),
-            "bbc-Latn" => Ok(Self::Bbclatn),
-            "bbj-Latn" => Ok(Self::Bbjlatn),
-            "bbk-Latn" => Ok(Self::Bbklatn),
-            "bbo-Latn" => Ok(Self::Bbolatn),
-            "bbr-Latn" => Ok(Self::Bbrlatn),
-            "bcc-Arab" => Ok(Self::Bccarab),
-            "bch-Latn" => Ok(Self::Bchlatn),
-            "bci-Latn" => Ok(Self::Bcilatn),
-            "bcl-Latn" => Ok(Self::Bcllatn),
-            "bco-Latn" => Ok(Self::Bcolatn),
-            "bcw-Latn" => Ok(Self::Bcwlatn),
-            "bdd-Latn" => Ok(Self::Bddlatn),
-            "bdh-Latn" => Ok(Self::Bdhlatn),
-            "bdq-Latn" => Ok(Self::Bdqlatn),
-            "bea-Latn" => Ok(Self::Bealatn),
-            "bef-Latn" => Ok(Self::Beflatn),
-            "bel-Cyrl" => Ok(Self::Belcyrl),
-            "bem-Latn" => Ok(Self::Bemlatn),
-            "ben-Beng" => Ok(Self::Benbeng),
-            "ben-Latn" => Ok(Self::Benlatn),
-            "beq-Latn" => Ok(Self::Beqlatn),
-            "ber-Latn" => Ok(Self::Berlatn),
-            "bew-Latn" => Ok(Self::Bewlatn),
-            "bex-Latn" => Ok(Self::Bexlatn),
-            "bfd-Latn" => Ok(Self::Bfdlatn),
-            "bfo-Latn" => Ok(Self::Bfolatn),
-            "bgr-Latn" => Ok(Self::Bgrlatn),
-            "bgs-Latn" => Ok(Self::Bgslatn),
-            "bgt-Latn" => Ok(Self::Bgtlatn),
-            "bgz-Latn" => Ok(Self::Bgzlatn),
-            "bhg-Latn" => Ok(Self::Bhglatn),
-            "bhl-Latn" => Ok(Self::Bhllatn),
-            "bho-Deva" => Ok(Self::Bhodeva),
-            "bhp-Latn" => Ok(Self::Bhplatn),
-            "bhw-Latn" => Ok(Self::Bhwlatn),
-            "bhz-Latn" => Ok(Self::Bhzlatn),
-            "bib-Latn" => Ok(Self::Biblatn),
-            "big-Latn" => Ok(Self::Biglatn),
-            "bik-Latn" => Ok(Self::Biklatn),
-            "bim-Latn" => Ok(Self::Bimlatn),
-            "bin-Latn" => Ok(Self::Binlatn),
-            "bis-Latn" => Ok(Self::Bislatn),
-            "biu-Latn" => Ok(Self::Biulatn),
-            "biv-Latn" => Ok(Self::Bivlatn),
-            "bjn-Arab" => Ok(Self::Bjnarab),
-            "bjn-Latn" => Ok(Self::Bjnlatn),
-            "bjp-Latn" => Ok(Self::Bjplatn),
-            "bjr-Latn" => Ok(Self::Bjrlatn),
-            "bjv-Latn" => Ok(Self::Bjvlatn),
-            "bkd-Latn" => Ok(Self::Bkdlatn),
-            "bkl-Latn" => Ok(Self::Bkllatn),
-            "bkq-Latn" => Ok(Self::Bkqlatn),
-            "bku-Latn" => Ok(Self::Bkulatn),
-            "bkv-Latn" => Ok(Self::Bkvlatn),
-            "bla-Latn" => Ok(Self::Blalatn),
-            "blh-Latn" => Ok(Self::Blhlatn),
-            "blk-Mymr" => Ok(Self::Blkmymr),
-            "blt-Latn" => Ok(Self::Bltlatn),
-            "blw-Latn" => Ok(Self::Blwlatn),
-            "blz-Latn" => Ok(Self::Blzlatn),
-            "bmh-Latn" => Ok(Self::Bmhlatn),
-            "bmk-Latn" => Ok(Self::Bmklatn),
-            "bmq-Latn" => Ok(Self::Bmqlatn),
-            "bmr-Latn" => Ok(Self::Bmrlatn),
-            "bmu-Latn" => Ok(Self::Bmulatn),
-            "bmv-Latn" => Ok(Self::Bmvlatn),
-            "bnj-Latn" => Ok(Self::Bnjlatn),
-            "bno-Latn" => Ok(Self::Bnolatn),
-            "bnp-Latn" => Ok(Self::Bnplatn),
-            "boa-Latn" => Ok(Self::Boalatn),
-            "bod-Tibt" => Ok(Self::Bodtibt),
-            "boj-Latn" => Ok(Self::Bojlatn),
-            "bom-Latn" => Ok(Self::Bomlatn),
-            "bon-Latn" => Ok(Self::Bonlatn),
-            "bor-Latn" => Ok(Self::Borlatn),
-            "bos-Latn" => Ok(Self::Boslatn),
-            "bov-Latn" => Ok(Self::Bovlatn),
-            "box-Latn" => Ok(Self::Boxlatn),
-            "bpr-Latn" => Ok(Self::Bprlatn),
-            "bps-Latn" => Ok(Self::Bpslatn),
-            "bpy-Beng" => Ok(Self::Bpybeng),
-            "bqc-Latn" => Ok(Self::Bqclatn),
-            "bqj-Latn" => Ok(Self::Bqjlatn),
-            "bqp-Latn" => Ok(Self::Bqplatn),
-            "bre-Latn" => Ok(Self::Brelatn),
-            "brh-Arab" => Ok(Self::Brharab),
-            "bru-Latn" => Ok(Self::Brulatn),
-            "brx-Deva" => Ok(Self::Brxdeva),
-            "brx-Latn" => Ok(Self::Brxlatn),
-            "bsc-Latn" => Ok(Self::Bsclatn),
-            "bsn-Latn" => Ok(Self::Bsnlatn),
-            "bsp-Latn" => Ok(Self::Bsplatn),
-            "bsq-Latn" => Ok(Self::Bsqlatn),
-            "bss-Latn" => Ok(Self::Bsslatn),
-            "btd-Latn" => Ok(Self::Btdlatn),
-            "bth-Latn" => Ok(Self::Bthlatn),
-            "bts-Latn" => Ok(Self::Btslatn),
-            "btt-Latn" => Ok(Self::Bttlatn),
-            "btx-Latn" => Ok(Self::Btxlatn),
-            "bua-Cyrl" => Ok(Self::Buacyrl),
-            "bud-Latn" => Ok(Self::Budlatn),
-            "bug-Latn" => Ok(Self::Buglatn),
-            "buk-Latn" => Ok(Self::Buklatn),
-            "bul-Cyrl" => Ok(Self::Bulcyrl),
-            "bum-Latn" => Ok(Self::Bumlatn),
-            "bus-Latn" => Ok(Self::Buslatn),
-            "bvc-Latn" => Ok(Self::Bvclatn),
-            "bvd-Latn" => Ok(Self::Bvdlatn),
-            "bvr-Latn" => Ok(Self::Bvrlatn),
-            "bvz-Latn" => Ok(Self::Bvzlatn),
-            "bwd-Latn" => Ok(Self::Bwdlatn),
-            "bwi-Latn" => Ok(Self::Bwilatn),
-            "bwq-Latn" => Ok(Self::Bwqlatn),
-            "bwu-Latn" => Ok(Self::Bwulatn),
-            "bxh-Latn" => Ok(Self::Bxhlatn),
-            "bxr-Cyrl" => Ok(Self::Bxrcyrl),
-            "byr-Latn" => Ok(Self::Byrlatn),
-            "byv-Latn" => Ok(Self::Byvlatn),
-            "byx-Latn" => Ok(Self::Byxlatn),
-            "bzd-Latn" => Ok(Self::Bzdlatn),
-            "bzh-Latn" => Ok(Self::Bzhlatn),
-            "bzi-Thai" => Ok(Self::Bzithai),
-            "bzj-Latn" => Ok(Self::Bzjlatn),
-            "caa-Latn" => Ok(Self::Caalatn),
-            "cab-Latn" => Ok(Self::Cablatn),
-            "cac-Latn" => Ok(Self::Caclatn),
-            "caf-Latn" => Ok(Self::Caflatn),
-            "cag-Latn" => Ok(Self::Caglatn),
-            "cak-Latn" => Ok(Self::Caklatn),
-            "cao-Latn" => Ok(Self::Caolatn),
-            "cap-Latn" => Ok(Self::Caplatn),
-            "caq-Latn" => Ok(Self::Caqlatn),
-            "car-Latn" => Ok(Self::Carlatn),
-            "cas-Latn" => Ok(Self::Caslatn),
-            "cat-Latn" => Ok(Self::Catlatn),
-            "cav-Latn" => Ok(Self::Cavlatn),
-            "cax-Latn" => Ok(Self::Caxlatn),
-            "cbc-Latn" => Ok(Self::Cbclatn),
-            "cbi-Latn" => Ok(Self::Cbilatn),
-            "cbk-Latn" => Ok(Self::Cbklatn),
-            "cbr-Latn" => Ok(Self::Cbrlatn),
-            "cbs-Latn" => Ok(Self::Cbslatn),
-            "cbt-Latn" => Ok(Self::Cbtlatn),
-            "cbu-Latn" => Ok(Self::Cbulatn),
-            "cbv-Latn" => Ok(Self::Cbvlatn),
-            "cce-Latn" => Ok(Self::Ccelatn),
-            "cco-Latn" => Ok(Self::Ccolatn),
-            "ccp-Latn" => Ok(Self::Ccplatn),
-            "cdf-Latn" => Ok(Self::Cdflatn),
-            "ceb-Latn" => Ok(Self::Ceblatn),
-            "ceg-Latn" => Ok(Self::Ceglatn),
-            "cek-Latn" => Ok(Self::Ceklatn),
-            "ces-Latn" => Ok(Self::Ceslatn),
-            "cfm-Latn" => Ok(Self::Cfmlatn),
-            "cgc-Latn" => Ok(Self::Cgclatn),
-            "cgg-Latn" => Ok(Self::Cgglatn),
-            "cha-Latn" => Ok(Self::Chalatn),
-            "chd-Latn" => Ok(Self::Chdlatn),
-            "che-Cyrl" => Ok(Self::Checyrl),
-            "chf-Latn" => Ok(Self::Chflatn),
-            "chj-Latn" => Ok(Self::Chjlatn),
-            "chk-Latn" => Ok(Self::Chklatn),
-            "cho-Latn" => Ok(Self::Cholatn),
-            "chq-Latn" => Ok(Self::Chqlatn),
-            "chr-Cher" => Ok(Self::Chrcher),
-            "chr-Latn" => Ok(Self::Chrlatn),
-            "chu-Cyrl" => Ok(Self::Chucyrl),
-            "chv-Cyrl" => Ok(Self::Chvcyrl),
-            "chw-Latn" => Ok(Self::Chwlatn),
-            "chz-Latn" => Ok(Self::Chzlatn),
-            "cjk-Latn" => Ok(Self::Cjklatn),
-            "cjo-Latn" => Ok(Self::Cjolatn),
-            "cjp-Latn" => Ok(Self::Cjplatn),
-            "cjs-Cyrl" => Ok(Self::Cjscyrl),
-            "cjv-Latn" => Ok(Self::Cjvlatn),
-            "ckb-Arab" => Ok(Self::Ckbarab),
-            "ckm-Latn" => Ok(Self::Ckmlatn),
-            "cko-Latn" => Ok(Self::Ckolatn),
-            "ckt-Cyrl" => Ok(Self::Cktcyrl),
-            "cle-Latn" => Ok(Self::Clelatn),
-            "clu-Latn" => Ok(Self::Clulatn),
-            "cly-Latn" => Ok(Self::Clylatn),
-            "cme-Latn" => Ok(Self::Cmelatn),
-            "cmn-Hani" => Ok(Self::Cmnhani),
-            "cmo-Khmr" => Ok(Self::Cmokhmr),
-            "cmo-Latn" => Ok(Self::Cmolatn),
-            "cmr-Latn" => Ok(Self::Cmrlatn),
-            "cnh-Latn" => Ok(Self::Cnhlatn),
-            "cni-Latn" => Ok(Self::Cnilatn),
-            "cnk-Latn" => Ok(Self::Cnklatn),
-            "cnl-Latn" => Ok(Self::Cnllatn),
-            "cnr-Latn" => Ok(Self::Cnrlatn),
-            "cnt-Latn" => Ok(Self::Cntlatn),
-            "cnw-Latn" => Ok(Self::Cnwlatn),
-            "coe-Latn" => Ok(Self::Coelatn),
-            "cof-Latn" => Ok(Self::Coflatn),
-            "cok-Latn" => Ok(Self::Coklatn),
-            "con-Latn" => Ok(Self::Conlatn),
-            "cop-Copt" => Ok(Self::Copcopt),
-            "cor-Latn" => Ok(Self::Corlatn),
-            "cos-Latn" => Ok(Self::Coslatn),
-            "cot-Latn" => Ok(Self::Cotlatn),
-            "cou-Latn" => Ok(Self::Coulatn),
-            "cpa-Latn" => Ok(Self::Cpalatn),
-            "cpb-Latn" => Ok(Self::Cpblatn),
-            "cpc-Latn" => Ok(Self::Cpclatn),
-            "cpu-Latn" => Ok(Self::Cpulatn),
-            "cpy-Latn" => Ok(Self::Cpylatn),
-            "crh-Cyrl" => Ok(Self::Crhcyrl),
-            "crh-Latn" => Ok(Self::Crhlatn),
-            "cri-Latn" => Ok(Self::Crilatn),
-            "crj-Cans" => Ok(Self::Crjcans),
-            "crk-Cans" => Ok(Self::Crkcans),
-            "crk-Latn" => Ok(Self::Crklatn),
-            "crl-Cans" => Ok(Self::Crlcans),
-            "crm-Cans" => Ok(Self::Crmcans),
-            "crn-Latn" => Ok(Self::Crnlatn),
-            "crs-Latn" => Ok(Self::Crslatn),
-            "crt-Latn" => Ok(Self::Crtlatn),
-            "crx-Latn" => Ok(Self::Crxlatn),
-            "csb-Latn" => Ok(Self::Csblatn),
-            "csk-Latn" => Ok(Self::Csklatn),
-            "cso-Latn" => Ok(Self::Csolatn),
-            "csw-Cans" => Ok(Self::Cswcans),
-            "csw-Latn" => Ok(Self::Cswlatn),
-            "csy-Latn" => Ok(Self::Csylatn),
-            "cta-Latn" => Ok(Self::Ctalatn),
-            "ctd-Latn" => Ok(Self::Ctdlatn),
-            "cto-Latn" => Ok(Self::Ctolatn),
-            "ctp-Latn" => Ok(Self::Ctplatn),
-            "ctu-Latn" => Ok(Self::Ctulatn),
-            "cub-Latn" => Ok(Self::Cublatn),
-            "cuc-Latn" => Ok(Self::Cuclatn),
-            "cui-Latn" => Ok(Self::Cuilatn),
-            "cuk-Latn" => Ok(Self::Cuklatn),
-            "cul-Latn" => Ok(Self::Cullatn),
-            "cut-Latn" => Ok(Self::Cutlatn),
-            "cux-Latn" => Ok(Self::Cuxlatn),
-            "cwd-Cans" => Ok(Self::Cwdcans),
-            "cwe-Latn" => Ok(Self::Cwelatn),
-            "cwt-Latn" => Ok(Self::Cwtlatn),
-            "cya-Latn" => Ok(Self::Cyalatn),
-            "cym-Latn" => Ok(Self::Cymlatn),
-            "czt-Latn" => Ok(Self::Cztlatn),
-            "daa-Latn" => Ok(Self::Daalatn),
-            "dad-Latn" => Ok(Self::Dadlatn),
-            "daf-Latn" => Ok(Self::Daflatn),
-            "dag-Latn" => Ok(Self::Daglatn),
-            "dah-Latn" => Ok(Self::Dahlatn),
-            "dak-Latn" => Ok(Self::Daklatn),
-            "dan-Latn" => Ok(Self::Danlatn),
-            "dar-Cyrl" => Ok(Self::Darcyrl),
-            "dbq-Latn" => Ok(Self::Dbqlatn),
-            "ddg-Latn" => Ok(Self::Ddglatn),
-            "ddn-Latn" => Ok(Self::Ddnlatn),
-            "ded-Latn" => Ok(Self::Dedlatn),
-            "des-Latn" => Ok(Self::Deslatn),
-            "deu-Latn" => Ok(Self::Deulatn),
-            "dga-Latn" => Ok(Self::Dgalatn),
-            "dgc-Latn" => Ok(Self::Dgclatn),
-            "dgi-Latn" => Ok(Self::Dgilatn),
-            "dgr-Latn" => Ok(Self::Dgrlatn),
-            "dgz-Latn" => Ok(Self::Dgzlatn),
-            "dhg-Latn" => Ok(Self::Dhglatn),
-            "dhm-Latn" => Ok(Self::Dhmlatn),
-            "dhv-Latn" => Ok(Self::Dhvlatn),
-            "did-Latn" => Ok(Self::Didlatn),
-            "dig-Latn" => Ok(Self::Diglatn),
-            "dik-Latn" => Ok(Self::Diklatn),
-            "dip-Latn" => Ok(Self::Diplatn),
-            "diq-Latn" => Ok(Self::Diqlatn),
-            "dis-Latn" => Ok(Self::Dislatn),
-            "diu-Latn" => Ok(Self::Diulatn),
-            "div-Thaa" => Ok(Self::Divthaa),
-            "dje-Latn" => Ok(Self::Djelatn),
-            "djk-Latn" => Ok(Self::Djklatn),
-            "djr-Latn" => Ok(Self::Djrlatn),
-            "dks-Latn" => Ok(Self::Dkslatn),
-            "dln-Latn" => Ok(Self::Dlnlatn),
-            "dng-Cyrl" => Ok(Self::Dngcyrl),
-            "dnj-Latn" => Ok(Self::Dnjlatn),
-            "dnw-Latn" => Ok(Self::Dnwlatn),
-            "dob-Latn" => Ok(Self::Doblatn),
-            "doi-Deva" => Ok(Self::Doideva),
-            "dop-Latn" => Ok(Self::Doplatn),
-            "dos-Latn" => Ok(Self::Doslatn),
-            "dow-Latn" => Ok(Self::Dowlatn),
-            "drg-Latn" => Ok(Self::Drglatn),
-            "dru-Latn" => Ok(Self::Drulatn),
-            "dsb-Latn" => Ok(Self::Dsblatn),
-            "dsh-Latn" => Ok(Self::Dshlatn),
-            "dtb-Latn" => Ok(Self::Dtblatn),
-            "dtp-Latn" => Ok(Self::Dtplatn),
-            "dts-Latn" => Ok(Self::Dtslatn),
-            "dty-Deva" => Ok(Self::Dtydeva),
-            "dua-Latn" => Ok(Self::Dualatn),
-            "due-Latn" => Ok(Self::Duelatn),
-            "dug-Latn" => Ok(Self::Duglatn),
-            "duo-Latn" => Ok(Self::Duolatn),
-            "dur-Latn" => Ok(Self::Durlatn),
-            "dwr-Latn" => Ok(Self::Dwrlatn),
-            "dww-Latn" => Ok(Self::Dwwlatn),
-            "dyi-Latn" => Ok(Self::Dyilatn),
-            "dyo-Latn" => Ok(Self::Dyolatn),
-            "dyu-Latn" => Ok(Self::Dyulatn),
-            "dzo-Tibt" => Ok(Self::Dzotibt),
-            "ebk-Latn" => Ok(Self::Ebklatn),
-            "efi-Latn" => Ok(Self::Efilatn),
-            "eka-Latn" => Ok(Self::Ekalatn),
-            "ekk-Latn" => Ok(Self::Ekklatn),
-            "eko-Latn" => Ok(Self::Ekolatn),
-            "ell-Grek" => Ok(Self::Ellgrek),
-            "eme-Latn" => Ok(Self::Emelatn),
-            "emi-Latn" => Ok(Self::Emilatn),
-            "eml-Latn" => Ok(Self::Emllatn),
-            "emp-Latn" => Ok(Self::Emplatn),
-            "enb-Latn" => Ok(Self::Enblatn),
-            "eng-Latn" => Ok(Self::Englatn),
-            "enl-Latn" => Ok(Self::Enllatn),
-            "enm-Latn" => Ok(Self::Enmlatn),
-            "enq-Latn" => Ok(Self::Enqlatn),
-            "enx-Latn" => Ok(Self::Enxlatn),
-            "epo-Latn" => Ok(Self::Epolatn),
-            "eri-Latn" => Ok(Self::Erilatn),
-            "ese-Latn" => Ok(Self::Eselatn),
-            "esi-Latn" => Ok(Self::Esilatn),
-            "esk-Latn" => Ok(Self::Esklatn),
-            "ess-Latn" => Ok(Self::Esslatn),
-            "est-Latn" => Ok(Self::Estlatn),
-            "esu-Latn" => Ok(Self::Esulatn),
-            "eto-Latn" => Ok(Self::Etolatn),
-            "etr-Latn" => Ok(Self::Etrlatn),
-            "etu-Latn" => Ok(Self::Etulatn),
-            "eus-Latn" => Ok(Self::Euslatn),
-            "eve-Cyrl" => Ok(Self::Evecyrl),
-            "ewe-Latn" => Ok(Self::Ewelatn),
-            "ewo-Latn" => Ok(Self::Ewolatn),
-            "ext-Latn" => Ok(Self::Extlatn),
-            "eza-Latn" => Ok(Self::Ezalatn),
-            "faa-Latn" => Ok(Self::Faalatn),
-            "fad-Latn" => Ok(Self::Fadlatn),
-            "fai-Latn" => Ok(Self::Failatn),
-            "fal-Latn" => Ok(Self::Fallatn),
-            "fan-Latn" => Ok(Self::Fanlatn),
-            "fao-Latn" => Ok(Self::Faolatn),
-            "far-Latn" => Ok(Self::Farlatn),
-            "fas-Arab" => Ok(Self::Fasarab),
-            "fat-Latn" => Ok(Self::Fatlatn),
-            "ffm-Latn" => Ok(Self::Ffmlatn),
-            "fij-Latn" => Ok(Self::Fijlatn),
-            "fil-Latn" => Ok(Self::Fillatn),
-            "fin-Latn" => Ok(Self::Finlatn),
-            "fit-Latn" => Ok(Self::Fitlatn),
-            "fkv-Latn" => Ok(Self::Fkvlatn),
-            "fmu-Deva" => Ok(Self::Fmudeva),
-            "fon-Latn" => Ok(Self::Fonlatn),
-            "for-Latn" => Ok(Self::Forlatn),
-            "fra-Latn" => Ok(Self::Fralatn),
-            "frd-Latn" => Ok(Self::Frdlatn),
-            "frm-Latn" => Ok(Self::Frmlatn),
-            "fro-Latn" => Ok(Self::Frolatn),
-            "frp-Latn" => Ok(Self::Frplatn),
-            "frr-Latn" => Ok(Self::Frrlatn),
-            "fry-Latn" => Ok(Self::Frylatn),
-            "fub-Latn" => Ok(Self::Fublatn),
-            "fud-Latn" => Ok(Self::Fudlatn),
-            "fue-Latn" => Ok(Self::Fuelatn),
-            "fuf-Latn" => Ok(Self::Fuflatn),
-            "fuh-Latn" => Ok(Self::Fuhlatn),
-            "fuq-Latn" => Ok(Self::Fuqlatn),
-            "fur-Latn" => Ok(Self::Furlatn),
-            "fuv-Arab" => Ok(Self::Fuvarab),
-            "fuv-Latn" => Ok(Self::Fuvlatn),
-            "gaa-Latn" => Ok(Self::Gaalatn),
-            "gag-Cyrl" => Ok(Self::Gagcyrl),
-            "gag-Latn" => Ok(Self::Gaglatn),
-            "gah-Latn" => Ok(Self::Gahlatn),
-            "gai-Latn" => Ok(Self::Gailatn),
-            "gam-Latn" => Ok(Self::Gamlatn),
-            "gaw-Latn" => Ok(Self::Gawlatn),
-            "gaz-Latn" => Ok(Self::Gazlatn),
-            "gbi-Latn" => Ok(Self::Gbilatn),
-            "gbo-Latn" => Ok(Self::Gbolatn),
-            "gbr-Latn" => Ok(Self::Gbrlatn),
-            "gcf-Latn" => Ok(Self::Gcflatn),
-            "gcr-Latn" => Ok(Self::Gcrlatn),
-            "gde-Latn" => Ok(Self::Gdelatn),
-            "gdg-Latn" => Ok(Self::Gdglatn),
-            "gdn-Latn" => Ok(Self::Gdnlatn),
-            "gdr-Latn" => Ok(Self::Gdrlatn),
-            "geb-Latn" => Ok(Self::Geblatn),
-            "gej-Latn" => Ok(Self::Gejlatn),
-            "gfk-Latn" => Ok(Self::Gfklatn),
-            "ghe-Deva" => Ok(Self::Ghedeva),
-            "ghs-Latn" => Ok(Self::Ghslatn),
-            "gid-Latn" => Ok(Self::Gidlatn),
-            "gil-Latn" => Ok(Self::Gillatn),
-            "giz-Latn" => Ok(Self::Gizlatn),
-            "gjn-Latn" => Ok(Self::Gjnlatn),
-            "gkn-Latn" => Ok(Self::Gknlatn),
-            "gla-Latn" => Ok(Self::Glalatn),
-            "gle-Latn" => Ok(Self::Glelatn),
-            "glg-Latn" => Ok(Self::Glglatn),
-            "glk-Arab" => Ok(Self::Glkarab),
-            "glv-Latn" => Ok(Self::Glvlatn),
-            "gmh-Latn" => Ok(Self::Gmhlatn),
-            "gmv-Ethi" => Ok(Self::Gmvethi),
-            "gmv-Latn" => Ok(Self::Gmvlatn),
-            "gna-Latn" => Ok(Self::Gnalatn),
-            "gnb-Latn" => Ok(Self::Gnblatn),
-            "gnd-Latn" => Ok(Self::Gndlatn),
-            "gng-Latn" => Ok(Self::Gnglatn),
-            "gnn-Latn" => Ok(Self::Gnnlatn),
-            "gnw-Latn" => Ok(Self::Gnwlatn),
-            "goa-Latn" => Ok(Self::Goalatn),
-            "gof-Ethi" => Ok(Self::Gofethi),
-            "gof-Latn" => Ok(Self::Goflatn),
-            "gog-Latn" => Ok(Self::Goglatn),
-            "goh-Latn" => Ok(Self::Gohlatn),
-            "gom-Deva" => Ok(Self::Gomdeva),
-            "gom-Latn" => Ok(Self::Gomlatn),
-            "gor-Latn" => Ok(Self::Gorlatn),
-            "gos-Latn" => Ok(Self::Goslatn),
-            "got-Goth" => Ok(Self::Gotgoth),
-            "got-Latn" => Ok(Self::Gotlatn),
-            "gqr-Latn" => Ok(Self::Gqrlatn),
-            "grc-Grek" => Ok(Self::Grcgrek),
-            "grn-Latn" => Ok(Self::Grnlatn),
-            "grt-Beng" => Ok(Self::Grtbeng),
-            "gso-Latn" => Ok(Self::Gsolatn),
-            "gsw-Latn" => Ok(Self::Gswlatn),
-            "gub-Latn" => Ok(Self::Gublatn),
-            "guc-Latn" => Ok(Self::Guclatn),
-            "gud-Latn" => Ok(Self::Gudlatn),
-            "gug-Latn" => Ok(Self::Guglatn),
-            "guh-Latn" => Ok(Self::Guhlatn),
-            "gui-Latn" => Ok(Self::Guilatn),
-            "guj-Gujr" => Ok(Self::Gujgujr),
-            "guj-Latn" => Ok(Self::Gujlatn),
-            "guk-Ethi" => Ok(Self::Gukethi),
-            "gul-Latn" => Ok(Self::Gullatn),
-            "gum-Latn" => Ok(Self::Gumlatn),
-            "gun-Latn" => Ok(Self::Gunlatn),
-            "guo-Latn" => Ok(Self::Guolatn),
-            "guq-Latn" => Ok(Self::Guqlatn),
-            "gur-Latn" => Ok(Self::Gurlatn),
-            "guu-Latn" => Ok(Self::Guulatn),
-            "guw-Latn" => Ok(Self::Guwlatn),
-            "gux-Latn" => Ok(Self::Guxlatn),
-            "guz-Latn" => Ok(Self::Guzlatn),
-            "gvc-Latn" => Ok(Self::Gvclatn),
-            "gvf-Latn" => Ok(Self::Gvflatn),
-            "gvl-Latn" => Ok(Self::Gvllatn),
-            "gvn-Latn" => Ok(Self::Gvnlatn),
-            "gwi-Latn" => Ok(Self::Gwilatn),
-            "gwr-Latn" => Ok(Self::Gwrlatn),
-            "gya-Latn" => Ok(Self::Gyalatn),
-            "gym-Latn" => Ok(Self::Gymlatn),
-            "gyr-Latn" => Ok(Self::Gyrlatn),
-            "hac-Arab" => Ok(Self::Hacarab),
-            "hae-Latn" => Ok(Self::Haelatn),
-            "hag-Latn" => Ok(Self::Haglatn),
-            "hak-Hani" => Ok(Self::Hakhani),
-            "hak-Latn" => Ok(Self::Haklatn),
-            "hat-Latn" => Ok(Self::Hatlatn),
-            "hau-Latn" => Ok(Self::Haulatn),
-            "hav-Latn" => Ok(Self::Havlatn),
-            "haw-Latn" => Ok(Self::Hawlatn),
-            "hay-Latn" => Ok(Self::Haylatn),
-            "hbo-Hebr" => Ok(Self::Hbohebr),
-            "hbs-Cyrl" => Ok(Self::Hbscyrl),
-            "hbs-Latn" => Ok(Self::Hbslatn),
-            "hch-Latn" => Ok(Self::Hchlatn),
-            "heb-Hebr" => Ok(Self::Hebhebr),
-            "heg-Latn" => Ok(Self::Heglatn),
-            "heh-Latn" => Ok(Self::Hehlatn),
-            "her-Latn" => Ok(Self::Herlatn),
-            "hif-Latn" => Ok(Self::Hiflatn),
-            "hig-Latn" => Ok(Self::Higlatn),
-            "hil-Latn" => Ok(Self::Hillatn),
-            "hin-Deva" => Ok(Self::Hindeva),
-            "hin-Latn" => Ok(Self::Hinlatn),
-            "hix-Latn" => Ok(Self::Hixlatn),
-            "hla-Latn" => Ok(Self::Hlalatn),
-            "hlt-Latn" => Ok(Self::Hltlatn),
-            "hmn-Latn" => Ok(Self::Hmnlatn),
-            "hmo-Latn" => Ok(Self::Hmolatn),
-            "hmr-Latn" => Ok(Self::Hmrlatn),
-            "hne-Deva" => Ok(Self::Hnedeva),
-            "hnj-Latn" => Ok(Self::Hnjlatn),
-            "hnn-Latn" => Ok(Self::Hnnlatn),
-            "hns-Latn" => Ok(Self::Hnslatn),
-            "hoc-Latn" => Ok(Self::Hoclatn),
-            "hoc-Wara" => Ok(Self::Hocwara),
-            "hop-Latn" => Ok(Self::Hoplatn),
-            "hot-Latn" => Ok(Self::Hotlatn),
-            "hra-Latn" => Ok(Self::Hralatn),
-            "hrv-Latn" => Ok(Self::Hrvlatn),
-            "hrx-Latn" => Ok(Self::Hrxlatn),
-            "hsb-Latn" => Ok(Self::Hsblatn),
-            "hto-Latn" => Ok(Self::Htolatn),
-            "hub-Latn" => Ok(Self::Hublatn),
-            "hui-Latn" => Ok(Self::Huilatn),
-            "hun-Latn" => Ok(Self::Hunlatn),
-            "hus-Latn" => Ok(Self::Huslatn),
-            "huu-Latn" => Ok(Self::Huulatn),
-            "huv-Latn" => Ok(Self::Huvlatn),
-            "hvn-Latn" => Ok(Self::Hvnlatn),
-            "hwc-Latn" => Ok(Self::Hwclatn),
-            "hye-Armn" => Ok(Self::Hyearmn),
-            "hyw-Armn" => Ok(Self::Hywarmn),
-            "ian-Latn" => Ok(Self::Ianlatn),
-            "iba-Latn" => Ok(Self::Ibalatn),
-            "ibg-Latn" => Ok(Self::Ibglatn),
-            "ibo-Latn" => Ok(Self::Ibolatn),
-            "icr-Latn" => Ok(Self::Icrlatn),
-            "ido-Latn" => Ok(Self::Idolatn),
-            "idu-Latn" => Ok(Self::Idulatn),
-            "ifa-Latn" => Ok(Self::Ifalatn),
-            "ifb-Latn" => Ok(Self::Ifblatn),
-            "ife-Latn" => Ok(Self::Ifelatn),
-            "ifk-Latn" => Ok(Self::Ifklatn),
-            "ifu-Latn" => Ok(Self::Ifulatn),
-            "ify-Latn" => Ok(Self::Ifylatn),
-            "ige-Latn" => Ok(Self::Igelatn),
-            "ign-Latn" => Ok(Self::Ignlatn),
-            "ike-Cans" => Ok(Self::Ikecans),
-            "ikk-Latn" => Ok(Self::Ikklatn),
-            "ikt-Latn" => Ok(Self::Iktlatn),
-            "ikw-Latn" => Ok(Self::Ikwlatn),
-            "ilb-Latn" => Ok(Self::Ilblatn),
-            "ile-Latn" => Ok(Self::Ilelatn),
-            "ilo-Latn" => Ok(Self::Ilolatn),
-            "imo-Latn" => Ok(Self::Imolatn),
-            "ina-Latn" => Ok(Self::Inalatn),
-            "inb-Latn" => Ok(Self::Inblatn),
-            "ind-Latn" => Ok(Self::Indlatn),
-            "inh-Cyrl" => Ok(Self::Inhcyrl),
-            "ino-Latn" => Ok(Self::Inolatn),
-            "iou-Latn" => Ok(Self::Ioulatn),
-            "ipi-Latn" => Ok(Self::Ipilatn),
-            "iqw-Latn" => Ok(Self::Iqwlatn),
-            "iri-Latn" => Ok(Self::Irilatn),
-            "irk-Latn" => Ok(Self::Irklatn),
-            "iry-Latn" => Ok(Self::Irylatn),
-            "isd-Latn" => Ok(Self::Isdlatn),
-            "ish-Latn" => Ok(Self::Ishlatn),
-            "isl-Latn" => Ok(Self::Isllatn),
-            "iso-Latn" => Ok(Self::Isolatn),
-            "ita-Latn" => Ok(Self::Italatn),
-            "itl-Cyrl" => Ok(Self::Itlcyrl),
-            "itv-Latn" => Ok(Self::Itvlatn),
-            "ium-Latn" => Ok(Self::Iumlatn),
-            "ivb-Latn" => Ok(Self::Ivblatn),
-            "ivv-Latn" => Ok(Self::Ivvlatn),
-            "iws-Latn" => Ok(Self::Iwslatn),
-            "ixl-Latn" => Ok(Self::Ixllatn),
-            "izr-Latn" => Ok(Self::Izrlatn),
-            "izz-Latn" => Ok(Self::Izzlatn),
-            "jaa-Latn" => Ok(Self::Jaalatn),
-            "jac-Latn" => Ok(Self::Jaclatn),
-            "jae-Latn" => Ok(Self::Jaelatn),
-            "jam-Latn" => Ok(Self::Jamlatn),
-            "jav-Java" => Ok(Self::Javjava),
-            "jav-Latn" => Ok(Self::Javlatn),
-            "jbo-Latn" => Ok(Self::Jbolatn),
-            "jbu-Latn" => Ok(Self::Jbulatn),
-            "jic-Latn" => Ok(Self::Jiclatn),
-            "jiv-Latn" => Ok(Self::Jivlatn),
-            "jmc-Latn" => Ok(Self::Jmclatn),
-            "jpn-Jpan" => Ok(Self::Jpnjpan),
-            "jra-Latn" => Ok(Self::Jralatn),
-            "jun-Orya" => Ok(Self::Junorya),
-            "jvn-Latn" => Ok(Self::Jvnlatn),
-            "kaa-Cyrl" => Ok(Self::Kaacyrl),
-            "kaa-Latn" => Ok(Self::Kaalatn),
-            "kab-Latn" => Ok(Self::Kablatn),
-            "kac-Latn" => Ok(Self::Kaclatn),
-            "kak-Latn" => Ok(Self::Kaklatn),
-            "kal-Latn" => Ok(Self::Kallatn),
-            "kam-Latn" => Ok(Self::Kamlatn),
-            "kan-Knda" => Ok(Self::Kanknda),
-            "kan-Latn" => Ok(Self::Kanlatn),
-            "kao-Latn" => Ok(Self::Kaolatn),
-            "kap-Cyrl" => Ok(Self::Kapcyrl),
-            "kaq-Latn" => Ok(Self::Kaqlatn),
-            "kas-Arab" => Ok(Self::Kasarab),
-            "kas-Deva" => Ok(Self::Kasdeva),
-            "kas-Latn" => Ok(Self::Kaslatn),
-            "kat-Geor" => Ok(Self::Katgeor),
-            "kaz-Cyrl" => Ok(Self::Kazcyrl),
-            "kbc-Latn" => Ok(Self::Kbclatn),
-            "kbd-Cyrl" => Ok(Self::Kbdcyrl),
-            "kbh-Latn" => Ok(Self::Kbhlatn),
-            "kbm-Latn" => Ok(Self::Kbmlatn),
-            "kbo-Latn" => Ok(Self::Kbolatn),
-            "kbp-Latn" => Ok(Self::Kbplatn),
-            "kbq-Latn" => Ok(Self::Kbqlatn),
-            "kbr-Latn" => Ok(Self::Kbrlatn),
-            "kby-Latn" => Ok(Self::Kbylatn),
-            "kca-Cyrl" => Ok(Self::Kcacyrl),
-            "kcg-Latn" => Ok(Self::Kcglatn),
-            "kck-Latn" => Ok(Self::Kcklatn),
-            "kdc-Latn" => Ok(Self::Kdclatn),
-            "kde-Latn" => Ok(Self::Kdelatn),
-            "kdh-Latn" => Ok(Self::Kdhlatn),
-            "kdi-Latn" => Ok(Self::Kdilatn),
-            "kdj-Latn" => Ok(Self::Kdjlatn),
-            "kdl-Latn" => Ok(Self::Kdllatn),
-            "kdp-Latn" => Ok(Self::Kdplatn),
-            "kdr-Latn" => Ok(Self::Kdrlatn),
-            "kea-Latn" => Ok(Self::Kealatn),
-            "kei-Latn" => Ok(Self::Keilatn),
-            "kek-Latn" => Ok(Self::Keklatn),
-            "ken-Latn" => Ok(Self::Kenlatn),
-            "keo-Latn" => Ok(Self::Keolatn),
-            "ker-Latn" => Ok(Self::Kerlatn),
-            "kew-Latn" => Ok(Self::Kewlatn),
-            "kex-Deva" => Ok(Self::Kexdeva),
-            "kez-Latn" => Ok(Self::Kezlatn),
-            "kff-Telu" => Ok(Self::Kfftelu),
-            "kgf-Latn" => Ok(Self::Kgflatn),
-            "kgk-Latn" => Ok(Self::Kgklatn),
-            "kgp-Latn" => Ok(Self::Kgplatn),
-            "kgr-Latn" => Ok(Self::Kgrlatn),
-            "kha-Latn" => Ok(Self::Khalatn),
-            "khg-Tibt" => Ok(Self::Khgtibt),
-            "khk-Cyrl" => Ok(Self::Khkcyrl),
-            "khm-Khmr" => Ok(Self::Khmkhmr),
-            "khq-Latn" => Ok(Self::Khqlatn),
-            "khs-Latn" => Ok(Self::Khslatn),
-            "khy-Latn" => Ok(Self::Khylatn),
-            "khz-Latn" => Ok(Self::Khzlatn),
-            "kia-Latn" => Ok(Self::Kialatn),
-            "kij-Latn" => Ok(Self::Kijlatn),
-            "kik-Latn" => Ok(Self::Kiklatn),
-            "kin-Latn" => Ok(Self::Kinlatn),
-            "kir-Cyrl" => Ok(Self::Kircyrl),
-            "kir-Latn" => Ok(Self::Kirlatn),
-            "kiu-Latn" => Ok(Self::Kiulatn),
-            "kix-Latn" => Ok(Self::Kixlatn),
-            "kjb-Latn" => Ok(Self::Kjblatn),
-            "kje-Latn" => Ok(Self::Kjelatn),
-            "kjh-Cyrl" => Ok(Self::Kjhcyrl),
-            "kjs-Latn" => Ok(Self::Kjslatn),
-            "kkc-Latn" => Ok(Self::Kkclatn),
-            "kki-Latn" => Ok(Self::Kkilatn),
-            "kkj-Latn" => Ok(Self::Kkjlatn),
-            "kkl-Latn" => Ok(Self::Kkllatn),
-            "kle-Deva" => Ok(Self::Kledeva),
-            "kln-Latn" => Ok(Self::Klnlatn),
-            "klt-Latn" => Ok(Self::Kltlatn),
-            "klv-Latn" => Ok(Self::Klvlatn),
-            "kma-Latn" => Ok(Self::Kmalatn),
-            "kmb-Latn" => Ok(Self::Kmblatn),
-            "kmd-Latn" => Ok(Self::Kmdlatn),
-            "kmg-Latn" => Ok(Self::Kmglatn),
-            "kmh-Latn" => Ok(Self::Kmhlatn),
-            "kmk-Latn" => Ok(Self::Kmklatn),
-            "kmm-Latn" => Ok(Self::Kmmlatn),
-            "kmo-Latn" => Ok(Self::Kmolatn),
-            "kmr-Cyrl" => Ok(Self::Kmrcyrl),
-            "kmr-Latn" => Ok(Self::Kmrlatn),
-            "kms-Latn" => Ok(Self::Kmslatn),
-            "kmu-Latn" => Ok(Self::Kmulatn),
-            "kmy-Latn" => Ok(Self::Kmylatn),
-            "knc-Arab" => Ok(Self::Kncarab),
-            "knc-Latn" => Ok(Self::Knclatn),
-            "kne-Latn" => Ok(Self::Knelatn),
-            "knf-Latn" => Ok(Self::Knflatn),
-            "kng-Latn" => Ok(Self::Knglatn),
-            "knj-Latn" => Ok(Self::Knjlatn),
-            "knk-Latn" => Ok(Self::Knklatn),
-            "kno-Latn" => Ok(Self::Knolatn),
-            "knv-Latn" => Ok(Self::Knvlatn),
-            "knx-Latn" => Ok(Self::Knxlatn),
-            "kny-Latn" => Ok(Self::Knylatn),
-            "kog-Latn" => Ok(Self::Koglatn),
-            "koi-Cyrl" => Ok(Self::Koicyrl),
-            "kom-Cyrl" => Ok(Self::Komcyrl),
-            "kon-Latn" => Ok(Self::Konlatn),
-            "koo-Latn" => Ok(Self::Koolatn),
-            "kor-Hang" => Ok(Self::Korhang),
-            "kos-Latn" => Ok(Self::Koslatn),
-            "kpe-Latn" => Ok(Self::Kpelatn),
-            "kpf-Latn" => Ok(Self::Kpflatn),
-            "kpg-Latn" => Ok(Self::Kpglatn),
-            "kpj-Latn" => Ok(Self::Kpjlatn),
-            "kpq-Latn" => Ok(Self::Kpqlatn),
-            "kpr-Latn" => Ok(Self::Kprlatn),
-            "kpv-Cyrl" => Ok(Self::Kpvcyrl),
-            "kpw-Latn" => Ok(Self::Kpwlatn),
-            "kpx-Latn" => Ok(Self::Kpxlatn),
-            "kpz-Latn" => Ok(Self::Kpzlatn),
-            "kqa-Latn" => Ok(Self::Kqalatn),
-            "kqc-Latn" => Ok(Self::Kqclatn),
-            "kqe-Latn" => Ok(Self::Kqelatn),
-            "kqf-Latn" => Ok(Self::Kqflatn),
-            "kql-Latn" => Ok(Self::Kqllatn),
-            "kqn-Latn" => Ok(Self::Kqnlatn),
-            "kqo-Latn" => Ok(Self::Kqolatn),
-            "kqp-Latn" => Ok(Self::Kqplatn),
-            "kqs-Latn" => Ok(Self::Kqslatn),
-            "kqw-Latn" => Ok(Self::Kqwlatn),
-            "kqy-Ethi" => Ok(Self::Kqyethi),
-            "krc-Cyrl" => Ok(Self::Krccyrl),
-            "kri-Latn" => Ok(Self::Krilatn),
-            "krj-Latn" => Ok(Self::Krjlatn),
-            "krl-Latn" => Ok(Self::Krllatn),
-            "kru-Deva" => Ok(Self::Krudeva),
-            "krx-Latn" => Ok(Self::Krxlatn),
-            "ksb-Latn" => Ok(Self::Ksblatn),
-            "ksc-Latn" => Ok(Self::Ksclatn),
-            "ksd-Latn" => Ok(Self::Ksdlatn),
-            "ksf-Latn" => Ok(Self::Ksflatn),
-            "ksh-Latn" => Ok(Self::Kshlatn),
-            "ksj-Latn" => Ok(Self::Ksjlatn),
-            "ksp-Latn" => Ok(Self::Ksplatn),
-            "ksr-Latn" => Ok(Self::Ksrlatn),
-            "kss-Latn" => Ok(Self::Ksslatn),
-            "ksw-Mymr" => Ok(Self::Kswmymr),
-            "ktb-Ethi" => Ok(Self::Ktbethi),
-            "ktj-Latn" => Ok(Self::Ktjlatn),
-            "ktm-Latn" => Ok(Self::Ktmlatn),
-            "kto-Latn" => Ok(Self::Ktolatn),
-            "ktu-Latn" => Ok(Self::Ktulatn),
-            "ktz-Latn" => Ok(Self::Ktzlatn),
-            "kua-Latn" => Ok(Self::Kualatn),
-            "kub-Latn" => Ok(Self::Kublatn),
-            "kud-Latn" => Ok(Self::Kudlatn),
-            "kue-Latn" => Ok(Self::Kuelatn),
-            "kuj-Latn" => Ok(Self::Kujlatn),
-            "kum-Cyrl" => Ok(Self::Kumcyrl),
-            "kup-Latn" => Ok(Self::Kuplatn),
-            "kus-Latn" => Ok(Self::Kuslatn),
-            "kvg-Latn" => Ok(Self::Kvglatn),
-            "kvj-Latn" => Ok(Self::Kvjlatn),
-            "kvn-Latn" => Ok(Self::Kvnlatn),
-            "kwd-Latn" => Ok(Self::Kwdlatn),
-            "kwf-Latn" => Ok(Self::Kwflatn),
-            "kwi-Latn" => Ok(Self::Kwilatn),
-            "kwj-Latn" => Ok(Self::Kwjlatn),
-            "kwn-Latn" => Ok(Self::Kwnlatn),
-            "kwy-Latn" => Ok(Self::Kwylatn),
-            "kxc-Ethi" => Ok(Self::Kxcethi),
-            "kxm-Thai" => Ok(Self::Kxmthai),
-            "kxw-Latn" => Ok(Self::Kxwlatn),
-            "kyc-Latn" => Ok(Self::Kyclatn),
-            "kyf-Latn" => Ok(Self::Kyflatn),
-            "kyg-Latn" => Ok(Self::Kyglatn),
-            "kyq-Latn" => Ok(Self::Kyqlatn),
-            "kyu-Kali" => Ok(Self::Kyukali),
-            "kyu-Latn" => Ok(Self::Kyulatn),
-            "kyu-Mymr" => Ok(Self::Kyumymr),
-            "kyz-Latn" => Ok(Self::Kyzlatn),
-            "kze-Latn" => Ok(Self::Kzelatn),
-            "kzf-Latn" => Ok(Self::Kzflatn),
-            "kzj-Latn" => Ok(Self::Kzjlatn),
-            "kzn-Latn" => Ok(Self::Kznlatn),
-            "lac-Latn" => Ok(Self::Laclatn),
-            "lad-Hebr" => Ok(Self::Ladhebr),
-            "lad-Latn" => Ok(Self::Ladlatn),
-            "lai-Latn" => Ok(Self::Lailatn),
-            "laj-Latn" => Ok(Self::Lajlatn),
-            "lam-Latn" => Ok(Self::Lamlatn),
-            "lao-Laoo" => Ok(Self::Laolaoo),
-            "lap-Latn" => Ok(Self::Laplatn),
-            "las-Latn" => Ok(Self::Laslatn),
-            "lat-Latn" => Ok(Self::Latlatn),
-            "lav-Latn" => Ok(Self::Lavlatn),
-            "law-Latn" => Ok(Self::Lawlatn),
-            "lbb-Latn" => Ok(Self::Lbblatn),
-            "lbe-Cyrl" => Ok(Self::Lbecyrl),
-            "lbj-Tibt" => Ok(Self::Lbjtibt),
-            "lbk-Latn" => Ok(Self::Lbklatn),
-            "lcm-Latn" => Ok(Self::Lcmlatn),
-            "lcp-Thai" => Ok(Self::Lcpthai),
-            "ldi-Latn" => Ok(Self::Ldilatn),
-            "ldn-Latn" => Ok(Self::Ldnlatn),
-            "lea-Latn" => Ok(Self::Lealatn),
-            "led-Latn" => Ok(Self::Ledlatn),
-            "lee-Latn" => Ok(Self::Leelatn),
-            "lef-Latn" => Ok(Self::Leflatn),
-            "leh-Latn" => Ok(Self::Lehlatn),
-            "lem-Latn" => Ok(Self::Lemlatn),
-            "leu-Latn" => Ok(Self::Leulatn),
-            "lew-Latn" => Ok(Self::Lewlatn),
-            "lex-Latn" => Ok(Self::Lexlatn),
-            "lez-Cyrl" => Ok(Self::Lezcyrl),
-            "lfn-Cyrl" => Ok(Self::Lfncyrl),
-            "lfn-Latn" => Ok(Self::Lfnlatn),
-            "lgg-Latn" => Ok(Self::Lgglatn),
-            "lgl-Latn" => Ok(Self::Lgllatn),
-            "lgm-Latn" => Ok(Self::Lgmlatn),
-            "lhi-Latn" => Ok(Self::Lhilatn),
-            "lhu-Latn" => Ok(Self::Lhulatn),
-            "lia-Latn" => Ok(Self::Lialatn),
-            "lid-Latn" => Ok(Self::Lidlatn),
-            "lif-Deva" => Ok(Self::Lifdeva),
-            "lif-Limb" => Ok(Self::Liflimb),
-            "lij-Latn" => Ok(Self::Lijlatn),
-            "lim-Latn" => Ok(Self::Limlatn),
-            "lin-Latn" => Ok(Self::Linlatn),
-            "lip-Latn" => Ok(Self::Liplatn),
-            "lis-Lisu" => Ok(Self::Lislisu),
-            "lit-Latn" => Ok(Self::Litlatn),
-            "liv-Latn" => Ok(Self::Livlatn),
-            "ljp-Latn" => Ok(Self::Ljplatn),
-            "lki-Arab" => Ok(Self::Lkiarab),
-            "llb-Latn" => Ok(Self::Llblatn),
-            "lld-Latn" => Ok(Self::Lldlatn),
-            "llg-Latn" => Ok(Self::Llglatn),
-            "lln-Latn" => Ok(Self::Llnlatn),
-            "lmk-Latn" => Ok(Self::Lmklatn),
-            "lmo-Latn" => Ok(Self::Lmolatn),
-            "lmp-Latn" => Ok(Self::Lmplatn),
-            "lnd-Latn" => Ok(Self::Lndlatn),
-            "lob-Latn" => Ok(Self::Loblatn),
-            "loe-Latn" => Ok(Self::Loelatn),
-            "log-Latn" => Ok(Self::Loglatn),
-            "lok-Latn" => Ok(Self::Loklatn),
-            "lol-Latn" => Ok(Self::Lollatn),
-            "lom-Latn" => Ok(Self::Lomlatn),
-            "loq-Latn" => Ok(Self::Loqlatn),
-            "loz-Latn" => Ok(Self::Lozlatn),
-            "lrc-Arab" => Ok(Self::Lrcarab),
-            "lsi-Latn" => Ok(Self::Lsilatn),
-            "lsm-Latn" => Ok(Self::Lsmlatn),
-            "ltg-Latn" => Ok(Self::Ltglatn),
-            "ltz-Latn" => Ok(Self::Ltzlatn),
-            "lua-Latn" => Ok(Self::Lualatn),
-            "lub-Latn" => Ok(Self::Lublatn),
-            "luc-Latn" => Ok(Self::Luclatn),
-            "lud-Latn" => Ok(Self::Ludlatn),
-            "lue-Latn" => Ok(Self::Luelatn),
-            "lug-Latn" => Ok(Self::Luglatn),
-            "lun-Latn" => Ok(Self::Lunlatn),
-            "luo-Latn" => Ok(Self::Luolatn),
-            "lus-Latn" => Ok(Self::Luslatn),
-            "lvs-Latn" => Ok(Self::Lvslatn),
-            "lwg-Latn" => Ok(Self::Lwglatn),
-            "lwo-Latn" => Ok(Self::Lwolatn),
-            "lww-Latn" => Ok(Self::Lwwlatn),
-            "lzh-Hani" => Ok(Self::Lzhhani),
-            "maa-Latn" => Ok(Self::Maalatn),
-            "mad-Latn" => Ok(Self::Madlatn),
-            "maf-Latn" => Ok(Self::Maflatn),
-            "mag-Deva" => Ok(Self::Magdeva),
-            "mah-Latn" => Ok(Self::Mahlatn),
-            "mai-Deva" => Ok(Self::Maideva),
-            "maj-Latn" => Ok(Self::Majlatn),
-            "mak-Latn" => Ok(Self::Maklatn),
-            "mal-Latn" => Ok(Self::Mallatn),
-            "mal-Mlym" => Ok(Self::Malmlym),
-            "mam-Latn" => Ok(Self::Mamlatn),
-            "maq-Latn" => Ok(Self::Maqlatn),
-            "mar-Deva" => Ok(Self::Mardeva),
-            "mar-Latn" => Ok(Self::Marlatn),
-            "mas-Latn" => Ok(Self::Maslatn),
-            "mau-Latn" => Ok(Self::Maulatn),
-            "mav-Latn" => Ok(Self::Mavlatn),
-            "maw-Latn" => Ok(Self::Mawlatn),
-            "max-Latn" => Ok(Self::Maxlatn),
-            "maz-Latn" => Ok(Self::Mazlatn),
-            "mbb-Latn" => Ok(Self::Mbblatn),
-            "mbc-Latn" => Ok(Self::Mbclatn),
-            "mbd-Latn" => Ok(Self::Mbdlatn),
-            "mbf-Latn" => Ok(Self::Mbflatn),
-            "mbh-Latn" => Ok(Self::Mbhlatn),
-            "mbi-Latn" => Ok(Self::Mbilatn),
-            "mbj-Latn" => Ok(Self::Mbjlatn),
-            "mbl-Latn" => Ok(Self::Mbllatn),
-            "mbs-Latn" => Ok(Self::Mbslatn),
-            "mbt-Latn" => Ok(Self::Mbtlatn),
-            "mca-Latn" => Ok(Self::Mcalatn),
-            "mcb-Latn" => Ok(Self::Mcblatn),
-            "mcd-Latn" => Ok(Self::Mcdlatn),
-            "mcf-Latn" => Ok(Self::Mcflatn),
-            "mck-Latn" => Ok(Self::Mcklatn),
-            "mcn-Latn" => Ok(Self::Mcnlatn),
-            "mco-Latn" => Ok(Self::Mcolatn),
-            "mcp-Latn" => Ok(Self::Mcplatn),
-            "mcq-Latn" => Ok(Self::Mcqlatn),
-            "mcu-Latn" => Ok(Self::Mculatn),
-            "mda-Latn" => Ok(Self::Mdalatn),
-            "mdf-Cyrl" => Ok(Self::Mdfcyrl),
-            "mdy-Ethi" => Ok(Self::Mdyethi),
-            "med-Latn" => Ok(Self::Medlatn),
-            "mee-Latn" => Ok(Self::Meelatn),
-            "mej-Latn" => Ok(Self::Mejlatn),
-            "mek-Latn" => Ok(Self::Meklatn),
-            "men-Latn" => Ok(Self::Menlatn),
-            "meq-Latn" => Ok(Self::Meqlatn),
-            "mer-Latn" => Ok(Self::Merlatn),
-            "met-Latn" => Ok(Self::Metlatn),
-            "meu-Latn" => Ok(Self::Meulatn),
-            "mev-Latn" => Ok(Self::Mevlatn),
-            "mfe-Latn" => Ok(Self::Mfelatn),
-            "mfg-Latn" => Ok(Self::Mfglatn),
-            "mfh-Latn" => Ok(Self::Mfhlatn),
-            "mfi-Latn" => Ok(Self::Mfilatn),
-            "mfk-Latn" => Ok(Self::Mfklatn),
-            "mfq-Latn" => Ok(Self::Mfqlatn),
-            "mfy-Latn" => Ok(Self::Mfylatn),
-            "mfz-Latn" => Ok(Self::Mfzlatn),
-            "mgc-Latn" => Ok(Self::Mgclatn),
-            "mgh-Latn" => Ok(Self::Mghlatn),
-            "mgm-Latn" => Ok(Self::Mgmlatn),
-            "mgo-Latn" => Ok(Self::Mgolatn),
-            "mgr-Latn" => Ok(Self::Mgrlatn),
-            "mhi-Latn" => Ok(Self::Mhilatn),
-            "mhl-Latn" => Ok(Self::Mhllatn),
-            "mhr-Cyrl" => Ok(Self::Mhrcyrl),
-            "mhw-Latn" => Ok(Self::Mhwlatn),
-            "mhx-Latn" => Ok(Self::Mhxlatn),
-            "mhy-Latn" => Ok(Self::Mhylatn),
-            "mib-Latn" => Ok(Self::Miblatn),
-            "mic-Latn" => Ok(Self::Miclatn),
-            "mie-Latn" => Ok(Self::Mielatn),
-            "mif-Latn" => Ok(Self::Miflatn),
-            "mig-Latn" => Ok(Self::Miglatn),
-            "mih-Latn" => Ok(Self::Mihlatn),
-            "mil-Latn" => Ok(Self::Millatn),
-            "mim-Latn" => Ok(Self::Mimlatn),
-            "min-Arab" => Ok(Self::Minarab),
-            "min-Latn" => Ok(Self::Minlatn),
-            "mio-Latn" => Ok(Self::Miolatn),
-            "mip-Latn" => Ok(Self::Miplatn),
-            "miq-Latn" => Ok(Self::Miqlatn),
-            "mir-Latn" => Ok(Self::Mirlatn),
-            "mit-Latn" => Ok(Self::Mitlatn),
-            "miy-Latn" => Ok(Self::Miylatn),
-            "miz-Latn" => Ok(Self::Mizlatn),
-            "mjc-Latn" => Ok(Self::Mjclatn),
-            "mjw-Latn" => Ok(Self::Mjwlatn),
-            "mkd-Cyrl" => Ok(Self::Mkdcyrl),
-            "mkl-Latn" => Ok(Self::Mkllatn),
-            "mkn-Latn" => Ok(Self::Mknlatn),
-            "mks-Latn" => Ok(Self::Mkslatn),
-            "mkz-Latn" => Ok(Self::Mkzlatn),
-            "mlg-Latn" => Ok(Self::Mlglatn),
-            "mlh-Latn" => Ok(Self::Mlhlatn),
-            "mlp-Latn" => Ok(Self::Mlplatn),
-            "mlt-Latn" => Ok(Self::Mltlatn),
-            "mlu-Latn" => Ok(Self::Mlulatn),
-            "mmn-Latn" => Ok(Self::Mmnlatn),
-            "mmo-Latn" => Ok(Self::Mmolatn),
-            "mmx-Latn" => Ok(Self::Mmxlatn),
-            "mna-Latn" => Ok(Self::Mnalatn),
-            "mnb-Latn" => Ok(Self::Mnblatn),
-            "mnf-Latn" => Ok(Self::Mnflatn),
-            "mni-Beng" => Ok(Self::Mnibeng),
-            "mni-Latn" => Ok(Self::Mnilatn),
-            "mni-Mtei" => Ok(Self::Mnimtei),
-            "mnk-Latn" => Ok(Self::Mnklatn),
-            "mns-Cyrl" => Ok(Self::Mnscyrl),
-            "mnw-Mymr" => Ok(Self::Mnwmymr),
-            "mnx-Latn" => Ok(Self::Mnxlatn),
-            "mny-Latn" => Ok(Self::Mnylatn),
-            "moa-Latn" => Ok(Self::Moalatn),
-            "moc-Latn" => Ok(Self::Moclatn),
-            "mog-Latn" => Ok(Self::Moglatn),
-            "moh-Latn" => Ok(Self::Mohlatn),
-            "mon-Cyrl" => Ok(Self::Moncyrl),
-            "mop-Latn" => Ok(Self::Moplatn),
-            "mor-Latn" => Ok(Self::Morlatn),
-            "mos-Latn" => Ok(Self::Moslatn),
-            "mox-Latn" => Ok(Self::Moxlatn),
-            "mpg-Latn" => Ok(Self::Mpglatn),
-            "mph-Latn" => Ok(Self::Mphlatn),
-            "mpm-Latn" => Ok(Self::Mpmlatn),
-            "mpp-Latn" => Ok(Self::Mpplatn),
-            "mps-Latn" => Ok(Self::Mpslatn),
-            "mpt-Latn" => Ok(Self::Mptlatn),
-            "mpx-Latn" => Ok(Self::Mpxlatn),
-            "mqb-Latn" => Ok(Self::Mqblatn),
-            "mqj-Latn" => Ok(Self::Mqjlatn),
-            "mqy-Latn" => Ok(Self::Mqylatn),
-            "mrg-Latn" => Ok(Self::Mrglatn),
-            "mri-Latn" => Ok(Self::Mrilatn),
-            "mrj-Cyrl" => Ok(Self::Mrjcyrl),
-            "mrq-Latn" => Ok(Self::Mrqlatn),
-            "mrv-Latn" => Ok(Self::Mrvlatn),
-            "mrw-Latn" => Ok(Self::Mrwlatn),
-            "msa-Latn" => Ok(Self::Msalatn),
-            "msb-Latn" => Ok(Self::Msblatn),
-            "msc-Latn" => Ok(Self::Msclatn),
-            "mse-Latn" => Ok(Self::Mselatn),
-            "msk-Latn" => Ok(Self::Msklatn),
-            "msm-Latn" => Ok(Self::Msmlatn),
-            "msy-Latn" => Ok(Self::Msylatn),
-            "mta-Latn" => Ok(Self::Mtalatn),
-            "mtg-Latn" => Ok(Self::Mtglatn),
-            "mti-Latn" => Ok(Self::Mtilatn),
-            "mtj-Latn" => Ok(Self::Mtjlatn),
-            "mto-Latn" => Ok(Self::Mtolatn),
-            "mtp-Latn" => Ok(Self::Mtplatn),
-            "mua-Latn" => Ok(Self::Mualatn),
-            "mug-Latn" => Ok(Self::Muglatn),
-            "muh-Latn" => Ok(Self::Muhlatn),
-            "mui-Latn" => Ok(Self::Muilatn),
-            "mup-Deva" => Ok(Self::Mupdeva),
-            "mur-Latn" => Ok(Self::Murlatn),
-            "mus-Latn" => Ok(Self::Muslatn),
-            "mux-Latn" => Ok(Self::Muxlatn),
-            "muy-Latn" => Ok(Self::Muylatn),
-            "mva-Latn" => Ok(Self::Mvalatn),
-            "mvn-Latn" => Ok(Self::Mvnlatn),
-            "mvp-Latn" => Ok(Self::Mvplatn),
-            "mwc-Latn" => Ok(Self::Mwclatn),
-            "mwf-Latn" => Ok(Self::Mwflatn),
-            "mwl-Latn" => Ok(Self::Mwllatn),
-            "mwm-Latn" => Ok(Self::Mwmlatn),
-            "mwn-Latn" => Ok(Self::Mwnlatn),
-            "mwp-Latn" => Ok(Self::Mwplatn),
-            "mwq-Latn" => Ok(Self::Mwqlatn),
-            "mwv-Latn" => Ok(Self::Mwvlatn),
-            "mww-Latn" => Ok(Self::Mwwlatn),
-            "mxb-Latn" => Ok(Self::Mxblatn),
-            "mxp-Latn" => Ok(Self::Mxplatn),
-            "mxq-Latn" => Ok(Self::Mxqlatn),
-            "mxt-Latn" => Ok(Self::Mxtlatn),
-            "mxv-Latn" => Ok(Self::Mxvlatn),
-            "mya-Mymr" => Ok(Self::Myamymr),
-            "myb-Latn" => Ok(Self::Myblatn),
-            "myk-Latn" => Ok(Self::Myklatn),
-            "myu-Latn" => Ok(Self::Myulatn),
-            "myv-Cyrl" => Ok(Self::Myvcyrl),
-            "myw-Latn" => Ok(Self::Mywlatn),
-            "myx-Latn" => Ok(Self::Myxlatn),
-            "myy-Latn" => Ok(Self::Myylatn),
-            "mza-Latn" => Ok(Self::Mzalatn),
-            "mzh-Latn" => Ok(Self::Mzhlatn),
-            "mzk-Latn" => Ok(Self::Mzklatn),
-            "mzl-Latn" => Ok(Self::Mzllatn),
-            "mzm-Latn" => Ok(Self::Mzmlatn),
-            "mzn-Arab" => Ok(Self::Mznarab),
-            "mzw-Latn" => Ok(Self::Mzwlatn),
-            "mzz-Latn" => Ok(Self::Mzzlatn),
-            "nab-Latn" => Ok(Self::Nablatn),
-            "naf-Latn" => Ok(Self::Naflatn),
-            "nah-Latn" => Ok(Self::Nahlatn),
-            "nak-Latn" => Ok(Self::Naklatn),
-            "nan-Hani" => Ok(Self::Nanhani),
-            "nan-Latn" => Ok(Self::Nanlatn),
-            "nap-Latn" => Ok(Self::Naplatn),
-            "naq-Latn" => Ok(Self::Naqlatn),
-            "nas-Latn" => Ok(Self::Naslatn),
-            "nav-Latn" => Ok(Self::Navlatn),
-            "naw-Latn" => Ok(Self::Nawlatn),
-            "nba-Latn" => Ok(Self::Nbalatn),
-            "nbc-Latn" => Ok(Self::Nbclatn),
-            "nbe-Latn" => Ok(Self::Nbelatn),
-            "nbl-Latn" => Ok(Self::Nbllatn),
-            "nbq-Latn" => Ok(Self::Nbqlatn),
-            "nbu-Latn" => Ok(Self::Nbulatn),
-            "nca-Latn" => Ok(Self::Ncalatn),
-            "nch-Latn" => Ok(Self::Nchlatn),
-            "ncj-Latn" => Ok(Self::Ncjlatn),
-            "ncl-Latn" => Ok(Self::Ncllatn),
-            "ncq-Laoo" => Ok(Self::Ncqlaoo),
-            "nct-Latn" => Ok(Self::Nctlatn),
-            "ncu-Latn" => Ok(Self::Nculatn),
-            "ncx-Latn" => Ok(Self::Ncxlatn),
-            "ndc-Latn" => Ok(Self::Ndclatn),
-            "nde-Latn" => Ok(Self::Ndelatn),
-            "ndh-Latn" => Ok(Self::Ndhlatn),
-            "ndi-Latn" => Ok(Self::Ndilatn),
-            "ndj-Latn" => Ok(Self::Ndjlatn),
-            "ndo-Latn" => Ok(Self::Ndolatn),
-            "ndp-Latn" => Ok(Self::Ndplatn),
-            "nds-Latn" => Ok(Self::Ndslatn),
-            "ndy-Latn" => Ok(Self::Ndylatn),
-            "ndz-Latn" => Ok(Self::Ndzlatn),
-            "neb-Latn" => Ok(Self::Neblatn),
-            "nep-Deva" => Ok(Self::Nepdeva),
-            "new-Deva" => Ok(Self::Newdeva),
-            "nfa-Latn" => Ok(Self::Nfalatn),
-            "nfr-Latn" => Ok(Self::Nfrlatn),
-            "ngb-Latn" => Ok(Self::Ngblatn),
-            "ngc-Latn" => Ok(Self::Ngclatn),
-            "ngl-Latn" => Ok(Self::Ngllatn),
-            "ngp-Latn" => Ok(Self::Ngplatn),
-            "ngu-Latn" => Ok(Self::Ngulatn),
-            "nhd-Latn" => Ok(Self::Nhdlatn),
-            "nhe-Latn" => Ok(Self::Nhelatn),
-            "nhg-Latn" => Ok(Self::Nhglatn),
-            "nhi-Latn" => Ok(Self::Nhilatn),
-            "nhk-Latn" => Ok(Self::Nhklatn),
-            "nho-Latn" => Ok(Self::Nholatn),
-            "nhr-Latn" => Ok(Self::Nhrlatn),
-            "nhu-Latn" => Ok(Self::Nhulatn),
-            "nhw-Latn" => Ok(Self::Nhwlatn),
-            "nhx-Latn" => Ok(Self::Nhxlatn),
-            "nhy-Latn" => Ok(Self::Nhylatn),
-            "nia-Latn" => Ok(Self::Nialatn),
-            "nif-Latn" => Ok(Self::Niflatn),
-            "nii-Latn" => Ok(Self::Niilatn),
-            "nij-Latn" => Ok(Self::Nijlatn),
-            "nim-Latn" => Ok(Self::Nimlatn),
-            "nin-Latn" => Ok(Self::Ninlatn),
-            "nio-Cyrl" => Ok(Self::Niocyrl),
-            "niq-Latn" => Ok(Self::Niqlatn),
-            "niu-Latn" => Ok(Self::Niulatn),
-            "niy-Latn" => Ok(Self::Niylatn),
-            "njb-Latn" => Ok(Self::Njblatn),
-            "njm-Latn" => Ok(Self::Njmlatn),
-            "njn-Latn" => Ok(Self::Njnlatn),
-            "njo-Latn" => Ok(Self::Njolatn),
-            "njz-Latn" => Ok(Self::Njzlatn),
-            "nkf-Latn" => Ok(Self::Nkflatn),
-            "nki-Latn" => Ok(Self::Nkilatn),
-            "nko-Latn" => Ok(Self::Nkolatn),
-            "nla-Latn" => Ok(Self::Nlalatn),
-            "nlc-Latn" => Ok(Self::Nlclatn),
-            "nld-Latn" => Ok(Self::Nldlatn),
-            "nlg-Latn" => Ok(Self::Nlglatn),
-            "nma-Latn" => Ok(Self::Nmalatn),
-            "nmf-Latn" => Ok(Self::Nmflatn),
-            "nmh-Latn" => Ok(Self::Nmhlatn),
-            "nmo-Latn" => Ok(Self::Nmolatn),
-            "nmw-Latn" => Ok(Self::Nmwlatn),
-            "nmz-Latn" => Ok(Self::Nmzlatn),
-            "nnb-Latn" => Ok(Self::Nnblatn),
-            "nng-Latn" => Ok(Self::Nnglatn),
-            "nnh-Latn" => Ok(Self::Nnhlatn),
-            "nnl-Latn" => Ok(Self::Nnllatn),
-            "nno-Latn" => Ok(Self::Nnolatn),
-            "nnp-Latn" => Ok(Self::Nnplatn),
-            "nnq-Latn" => Ok(Self::Nnqlatn),
-            "nnw-Latn" => Ok(Self::Nnwlatn),
-            "noa-Latn" => Ok(Self::Noalatn),
-            "nob-Latn" => Ok(Self::Noblatn),
-            "nod-Thai" => Ok(Self::Nodthai),
-            "nog-Cyrl" => Ok(Self::Nogcyrl),
-            "non-Latn" => Ok(Self::Nonlatn),
-            "nop-Latn" => Ok(Self::Noplatn),
-            "nor-Latn" => Ok(Self::Norlatn),
-            "not-Latn" => Ok(Self::Notlatn),
-            "nou-Latn" => Ok(Self::Noulatn),
-            "nov-Latn" => Ok(Self::Novlatn),
-            "nph-Latn" => Ok(Self::Nphlatn),
-            "npi-Deva" => Ok(Self::Npideva),
-            "npi-Latn" => Ok(Self::Npilatn),
-            "npl-Latn" => Ok(Self::Npllatn),
-            "npo-Latn" => Ok(Self::Npolatn),
-            "npy-Latn" => Ok(Self::Npylatn),
-            "nqo-Nkoo" => Ok(Self::Nqonkoo),
-            "nre-Latn" => Ok(Self::Nrelatn),
-            "nrf-Latn" => Ok(Self::Nrflatn),
-            "nri-Latn" => Ok(Self::Nrilatn),
-            "nrm-Latn" => Ok(Self::Nrmlatn),
-            "nsa-Latn" => Ok(Self::Nsalatn),
-            "nse-Latn" => Ok(Self::Nselatn),
-            "nsm-Latn" => Ok(Self::Nsmlatn),
-            "nsn-Latn" => Ok(Self::Nsnlatn),
-            "nso-Latn" => Ok(Self::Nsolatn),
-            "nss-Latn" => Ok(Self::Nsslatn),
-            "nst-Latn" => Ok(Self::Nstlatn),
-            "nsu-Latn" => Ok(Self::Nsulatn),
-            "ntp-Latn" => Ok(Self::Ntplatn),
-            "ntr-Latn" => Ok(Self::Ntrlatn),
-            "ntu-Latn" => Ok(Self::Ntulatn),
-            "nuj-Latn" => Ok(Self::Nujlatn),
-            "nus-Latn" => Ok(Self::Nuslatn),
-            "nuy-Latn" => Ok(Self::Nuylatn),
-            "nuz-Latn" => Ok(Self::Nuzlatn),
-            "nvm-Latn" => Ok(Self::Nvmlatn),
-            "nwb-Latn" => Ok(Self::Nwblatn),
-            "nwi-Latn" => Ok(Self::Nwilatn),
-            "nwx-Deva" => Ok(Self::Nwxdeva),
-            "nxd-Latn" => Ok(Self::Nxdlatn),
-            "nya-Latn" => Ok(Self::Nyalatn),
-            "nyf-Latn" => Ok(Self::Nyflatn),
-            "nyk-Latn" => Ok(Self::Nyklatn),
-            "nyn-Latn" => Ok(Self::Nynlatn),
-            "nyo-Latn" => Ok(Self::Nyolatn),
-            "nyu-Latn" => Ok(Self::Nyulatn),
-            "nyy-Latn" => Ok(Self::Nyylatn),
-            "nza-Latn" => Ok(Self::Nzalatn),
-            "nzi-Latn" => Ok(Self::Nzilatn),
-            "nzm-Latn" => Ok(Self::Nzmlatn),
-            "obo-Latn" => Ok(Self::Obolatn),
-            "oci-Latn" => Ok(Self::Ocilatn),
-            "ogo-Latn" => Ok(Self::Ogolatn),
-            "ojb-Cans" => Ok(Self::Ojbcans),
-            "ojb-Latn" => Ok(Self::Ojblatn),
-            "oji-Latn" => Ok(Self::Ojilatn),
-            "oke-Latn" => Ok(Self::Okelatn),
-            "oku-Latn" => Ok(Self::Okulatn),
-            "okv-Latn" => Ok(Self::Okvlatn),
-            "old-Latn" => Ok(Self::Oldlatn),
-            "olo-Latn" => Ok(Self::Ololatn),
-            "omb-Latn" => Ok(Self::Omblatn),
-            "omw-Latn" => Ok(Self::Omwlatn),
-            "ong-Latn" => Ok(Self::Onglatn),
-            "ons-Latn" => Ok(Self::Onslatn),
-            "ood-Latn" => Ok(Self::Oodlatn),
-            "opm-Latn" => Ok(Self::Opmlatn),
-            "ori-Orya" => Ok(Self::Oriorya),
-            "orm-Latn" => Ok(Self::Ormlatn),
-            "orv-Cyrl" => Ok(Self::Orvcyrl),
-            "ory-Latn" => Ok(Self::Orylatn),
-            "ory-Orya" => Ok(Self::Oryorya),
-            "oss-Cyrl" => Ok(Self::Osscyrl),
-            "ota-Arab" => Ok(Self::Otaarab),
-            "otd-Latn" => Ok(Self::Otdlatn),
-            "ote-Latn" => Ok(Self::Otelatn),
-            "otm-Latn" => Ok(Self::Otmlatn),
-            "otn-Latn" => Ok(Self::Otnlatn),
-            "oto-Latn" => Ok(Self::Otolatn),
-            "otq-Latn" => Ok(Self::Otqlatn),
-            "ots-Latn" => Ok(Self::Otslatn),
-            "otw-Latn" => Ok(Self::Otwlatn),
-            "oym-Latn" => Ok(Self::Oymlatn),
-            "ozm-Latn" => Ok(Self::Ozmlatn),
-            "pab-Latn" => Ok(Self::Pablatn),
-            "pad-Latn" => Ok(Self::Padlatn),
-            "pag-Latn" => Ok(Self::Paglatn),
-            "pah-Latn" => Ok(Self::Pahlatn),
-            "pam-Latn" => Ok(Self::Pamlatn),
-            "pan-Guru" => Ok(Self::Panguru),
-            "pan-Latn" => Ok(Self::Panlatn),
-            "pao-Latn" => Ok(Self::Paolatn),
-            "pap-Latn" => Ok(Self::Paplatn),
-            "pau-Latn" => Ok(Self::Paulatn),
-            "pbb-Latn" => Ok(Self::Pbblatn),
-            "pbc-Latn" => Ok(Self::Pbclatn),
-            "pbi-Latn" => Ok(Self::Pbilatn),
-            "pbl-Latn" => Ok(Self::Pbllatn),
-            "pbt-Arab" => Ok(Self::Pbtarab),
-            "pcd-Latn" => Ok(Self::Pcdlatn),
-            "pck-Latn" => Ok(Self::Pcklatn),
-            "pcm-Latn" => Ok(Self::Pcmlatn),
-            "pdc-Latn" => Ok(Self::Pdclatn),
-            "pdt-Latn" => Ok(Self::Pdtlatn),
-            "pem-Latn" => Ok(Self::Pemlatn),
-            "pes-Arab" => Ok(Self::Pesarab),
-            "pez-Latn" => Ok(Self::Pezlatn),
-            "pfe-Latn" => Ok(Self::Pfelatn),
-            "pfl-Latn" => Ok(Self::Pfllatn),
-            "phm-Latn" => Ok(Self::Phmlatn),
-            "pib-Latn" => Ok(Self::Piblatn),
-            "pih-Latn" => Ok(Self::Pihlatn),
-            "pio-Latn" => Ok(Self::Piolatn),
-            "pir-Latn" => Ok(Self::Pirlatn),
-            "pis-Latn" => Ok(Self::Pislatn),
-            "pjt-Latn" => Ok(Self::Pjtlatn),
-            "pkb-Latn" => Ok(Self::Pkblatn),
-            "plg-Latn" => Ok(Self::Plglatn),
-            "pls-Latn" => Ok(Self::Plslatn),
-            "plt-Latn" => Ok(Self::Pltlatn),
-            "plu-Latn" => Ok(Self::Plulatn),
-            "plw-Latn" => Ok(Self::Plwlatn),
-            "pma-Latn" => Ok(Self::Pmalatn),
-            "pmf-Latn" => Ok(Self::Pmflatn),
-            "pmq-Latn" => Ok(Self::Pmqlatn),
-            "pms-Latn" => Ok(Self::Pmslatn),
-            "pmx-Latn" => Ok(Self::Pmxlatn),
-            "pnb-Arab" => Ok(Self::Pnbarab),
-            "pne-Latn" => Ok(Self::Pnelatn),
-            "pnt-Grek" => Ok(Self::Pntgrek),
-            "pny-Latn" => Ok(Self::Pnylatn),
-            "poe-Latn" => Ok(Self::Poelatn),
-            "poh-Latn" => Ok(Self::Pohlatn),
-            "poi-Latn" => Ok(Self::Poilatn),
-            "pol-Latn" => Ok(Self::Pollatn),
-            "pon-Latn" => Ok(Self::Ponlatn),
-            "por-Latn" => Ok(Self::Porlatn),
-            "pos-Latn" => Ok(Self::Poslatn),
-            "pot-Latn" => Ok(Self::Potlatn),
-            "pov-Latn" => Ok(Self::Povlatn),
-            "poy-Latn" => Ok(Self::Poylatn),
-            "ppk-Latn" => Ok(Self::Ppklatn),
-            "ppo-Latn" => Ok(Self::Ppolatn),
-            "pps-Latn" => Ok(Self::Ppslatn),
-            "prf-Latn" => Ok(Self::Prflatn),
-            "prg-Latn" => Ok(Self::Prglatn),
-            "pri-Latn" => Ok(Self::Prilatn),
-            "prk-Latn" => Ok(Self::Prklatn),
-            "prq-Latn" => Ok(Self::Prqlatn),
-            "prs-Arab" => Ok(Self::Prsarab),
-            "pse-Latn" => Ok(Self::Pselatn),
-            "pss-Latn" => Ok(Self::Psslatn),
-            "ptp-Latn" => Ok(Self::Ptplatn),
-            "ptu-Latn" => Ok(Self::Ptulatn),
-            "pua-Latn" => Ok(Self::Pualatn),
-            "pui-Latn" => Ok(Self::Puilatn),
-            "pus-Arab" => Ok(Self::Pusarab),
-            "pwg-Latn" => Ok(Self::Pwglatn),
-            "pwn-Latn" => Ok(Self::Pwnlatn),
-            "pww-Thai" => Ok(Self::Pwwthai),
-            "pxm-Latn" => Ok(Self::Pxmlatn),
-            "qub-Latn" => Ok(Self::Qublatn),
-            "quc-Latn" => Ok(Self::Quclatn),
-            "que-Latn" => Ok(Self::Quelatn),
-            "quf-Latn" => Ok(Self::Quflatn),
-            "qug-Latn" => Ok(Self::Quglatn),
-            "quh-Latn" => Ok(Self::Quhlatn),
-            "qul-Latn" => Ok(Self::Qullatn),
-            "qup-Latn" => Ok(Self::Quplatn),
-            "qus-Latn" => Ok(Self::Quslatn),
-            "quw-Latn" => Ok(Self::Quwlatn),
-            "quy-Latn" => Ok(Self::Quylatn),
-            "quz-Latn" => Ok(Self::Quzlatn),
-            "qva-Latn" => Ok(Self::Qvalatn),
-            "qvc-Latn" => Ok(Self::Qvclatn),
-            "qve-Latn" => Ok(Self::Qvelatn),
-            "qvh-Latn" => Ok(Self::Qvhlatn),
-            "qvi-Latn" => Ok(Self::Qvilatn),
-            "qvm-Latn" => Ok(Self::Qvmlatn),
-            "qvn-Latn" => Ok(Self::Qvnlatn),
-            "qvo-Latn" => Ok(Self::Qvolatn),
-            "qvs-Latn" => Ok(Self::Qvslatn),
-            "qvw-Latn" => Ok(Self::Qvwlatn),
-            "qvz-Latn" => Ok(Self::Qvzlatn),
-            "qwh-Latn" => Ok(Self::Qwhlatn),
-            "qxh-Latn" => Ok(Self::Qxhlatn),
-            "qxl-Latn" => Ok(Self::Qxllatn),
-            "qxn-Latn" => Ok(Self::Qxnlatn),
-            "qxo-Latn" => Ok(Self::Qxolatn),
-            "qxr-Latn" => Ok(Self::Qxrlatn),
-            "rad-Latn" => Ok(Self::Radlatn),
-            "rai-Latn" => Ok(Self::Railatn),
-            "rap-Latn" => Ok(Self::Raplatn),
-            "rar-Latn" => Ok(Self::Rarlatn),
-            "rav-Deva" => Ok(Self::Ravdeva),
-            "raw-Latn" => Ok(Self::Rawlatn),
-            "rcf-Latn" => Ok(Self::Rcflatn),
-            "rej-Latn" => Ok(Self::Rejlatn),
-            "rel-Latn" => Ok(Self::Rellatn),
-            "rgu-Latn" => Ok(Self::Rgulatn),
-            "rhg-Latn" => Ok(Self::Rhglatn),
-            "ria-Latn" => Ok(Self::Rialatn),
-            "rim-Latn" => Ok(Self::Rimlatn),
-            "rjs-Deva" => Ok(Self::Rjsdeva),
-            "rkb-Latn" => Ok(Self::Rkblatn),
-            "rmc-Latn" => Ok(Self::Rmclatn),
-            "rme-Latn" => Ok(Self::Rmelatn),
-            "rml-Latn" => Ok(Self::Rmllatn),
-            "rmn-Cyrl" => Ok(Self::Rmncyrl),
-            "rmn-Grek" => Ok(Self::Rmngrek),
-            "rmn-Latn" => Ok(Self::Rmnlatn),
-            "rmo-Latn" => Ok(Self::Rmolatn),
-            "rmq-Latn" => Ok(Self::Rmqlatn),
-            "rmy-Cyrl" => Ok(Self::Rmycyrl),
-            "rmy-Latn" => Ok(Self::Rmylatn),
-            "rnd-Latn" => Ok(Self::Rndlatn),
-            "rng-Latn" => Ok(Self::Rnglatn),
-            "rnl-Latn" => Ok(Self::Rnllatn),
-            "roh-Latn" => Ok(Self::Rohlatn),
-            "ron-Cyrl" => Ok(Self::Roncyrl),
-            "ron-Latn" => Ok(Self::Ronlatn),
-            "roo-Latn" => Ok(Self::Roolatn),
-            "rop-Latn" => Ok(Self::Roplatn),
-            "row-Latn" => Ok(Self::Rowlatn),
-            "rro-Latn" => Ok(Self::Rrolatn),
-            "rtm-Latn" => Ok(Self::Rtmlatn),
-            "rub-Latn" => Ok(Self::Rublatn),
-            "rue-Cyrl" => Ok(Self::Ruecyrl),
-            "ruf-Latn" => Ok(Self::Ruflatn),
-            "rug-Latn" => Ok(Self::Ruglatn),
-            "run-Latn" => Ok(Self::Runlatn),
-            "rup-Latn" => Ok(Self::Ruplatn),
-            "rus-Cyrl" => Ok(Self::Ruscyrl),
-            "rwo-Latn" => Ok(Self::Rwolatn),
-            "sab-Latn" => Ok(Self::Sablatn),
-            "sag-Latn" => Ok(Self::Saglatn),
-            "sah-Cyrl" => Ok(Self::Sahcyrl),
-            "saj-Latn" => Ok(Self::Sajlatn),
-            "san-Deva" => Ok(Self::Sandeva),
-            "san-Latn" => Ok(Self::Sanlatn),
-            "sas-Latn" => Ok(Self::Saslatn),
-            "sat-Latn" => Ok(Self::Satlatn),
-            "sat-Olck" => Ok(Self::Satolck),
-            "say-Latn" => Ok(Self::Saylatn),
-            "sba-Latn" => Ok(Self::Sbalatn),
-            "sbd-Latn" => Ok(Self::Sbdlatn),
-            "sbe-Latn" => Ok(Self::Sbelatn),
-            "sbl-Latn" => Ok(Self::Sbllatn),
-            "sbs-Latn" => Ok(Self::Sbslatn),
-            "sby-Latn" => Ok(Self::Sbylatn),
-            "sck-Deva" => Ok(Self::Sckdeva),
-            "scn-Latn" => Ok(Self::Scnlatn),
-            "sco-Latn" => Ok(Self::Scolatn),
-            "sda-Latn" => Ok(Self::Sdalatn),
-            "sdc-Latn" => Ok(Self::Sdclatn),
-            "sdh-Arab" => Ok(Self::Sdharab),
-            "sdo-Latn" => Ok(Self::Sdolatn),
-            "sdq-Latn" => Ok(Self::Sdqlatn),
-            "seh-Latn" => Ok(Self::Sehlatn),
-            "sel-Cyrl" => Ok(Self::Selcyrl),
-            "ses-Latn" => Ok(Self::Seslatn),
-            "sey-Latn" => Ok(Self::Seylatn),
-            "sfw-Latn" => Ok(Self::Sfwlatn),
-            "sgb-Latn" => Ok(Self::Sgblatn),
-            "sgc-Latn" => Ok(Self::Sgclatn),
-            "sgh-Cyrl" => Ok(Self::Sghcyrl),
-            "sgs-Latn" => Ok(Self::Sgslatn),
-            "sgw-Ethi" => Ok(Self::Sgwethi),
-            "sgz-Latn" => Ok(Self::Sgzlatn),
-            "shi-Latn" => Ok(Self::Shilatn),
-            "shk-Latn" => Ok(Self::Shklatn),
-            "shn-Mymr" => Ok(Self::Shnmymr),
-            "shp-Latn" => Ok(Self::Shplatn),
-            "shr-Latn" => Ok(Self::Shrlatn),
-            "shu-Arab" => Ok(Self::Shuarab),
-            "sid-Latn" => Ok(Self::Sidlatn),
-            "sig-Latn" => Ok(Self::Siglatn),
-            "sil-Latn" => Ok(Self::Sillatn),
-            "sim-Latn" => Ok(Self::Simlatn),
-            "sin-Sinh" => Ok(Self::Sinsinh),
-            "sja-Latn" => Ok(Self::Sjalatn),
-            "sjo-Mong" => Ok(Self::Sjomong),
-            "sju-Latn" => Ok(Self::Sjulatn),
-            "skg-Latn" => Ok(Self::Skglatn),
-            "skr-Arab" => Ok(Self::Skrarab),
-            "sld-Latn" => Ok(Self::Sldlatn),
-            "slk-Latn" => Ok(Self::Slklatn),
-            "sll-Latn" => Ok(Self::Slllatn),
-            "slv-Latn" => Ok(Self::Slvlatn),
-            "sma-Latn" => Ok(Self::Smalatn),
-            "sme-Latn" => Ok(Self::Smelatn),
-            "smj-Latn" => Ok(Self::Smjlatn),
-            "smk-Latn" => Ok(Self::Smklatn),
-            "sml-Latn" => Ok(Self::Smllatn),
-            "smn-Latn" => Ok(Self::Smnlatn),
-            "smo-Latn" => Ok(Self::Smolatn),
-            "sms-Latn" => Ok(Self::Smslatn),
-            "smt-Latn" => Ok(Self::Smtlatn),
-            "sna-Latn" => Ok(Self::Snalatn),
-            "snc-Latn" => Ok(Self::Snclatn),
-            "snd-Arab" => Ok(Self::Sndarab),
-            "snd-Deva" => Ok(Self::Snddeva),
-            "snd-Latn" => Ok(Self::Sndlatn),
-            "snf-Latn" => Ok(Self::Snflatn),
-            "snn-Latn" => Ok(Self::Snnlatn),
-            "snp-Latn" => Ok(Self::Snplatn),
-            "snw-Latn" => Ok(Self::Snwlatn),
-            "sny-Latn" => Ok(Self::Snylatn),
-            "soe-Latn" => Ok(Self::Soelatn),
-            "som-Latn" => Ok(Self::Somlatn),
-            "sop-Latn" => Ok(Self::Soplatn),
-            "soq-Latn" => Ok(Self::Soqlatn),
-            "sot-Latn" => Ok(Self::Sotlatn),
-            "soy-Latn" => Ok(Self::Soylatn),
-            "spa-Latn" => Ok(Self::Spalatn),
-            "spl-Latn" => Ok(Self::Spllatn),
-            "spm-Latn" => Ok(Self::Spmlatn),
-            "spp-Latn" => Ok(Self::Spplatn),
-            "sps-Latn" => Ok(Self::Spslatn),
-            "spy-Latn" => Ok(Self::Spylatn),
-            "sqi-Latn" => Ok(Self::Sqilatn),
-            "srd-Latn" => Ok(Self::Srdlatn),
-            "sri-Latn" => Ok(Self::Srilatn),
-            "srm-Latn" => Ok(Self::Srmlatn),
-            "srn-Latn" => Ok(Self::Srnlatn),
-            "srp-Cyrl" => Ok(Self::Srpcyrl),
-            "srp-Latn" => Ok(Self::Srplatn),
-            "srq-Latn" => Ok(Self::Srqlatn),
-            "srr-Latn" => Ok(Self::Srrlatn),
-            "ssd-Latn" => Ok(Self::Ssdlatn),
-            "ssg-Latn" => Ok(Self::Ssglatn),
-            "ssw-Latn" => Ok(Self::Sswlatn),
-            "ssx-Latn" => Ok(Self::Ssxlatn),
-            "stn-Latn" => Ok(Self::Stnlatn),
-            "stp-Latn" => Ok(Self::Stplatn),
-            "stq-Latn" => Ok(Self::Stqlatn),
-            "sua-Latn" => Ok(Self::Sualatn),
-            "suc-Latn" => Ok(Self::Suclatn),
-            "sue-Latn" => Ok(Self::Suelatn),
-            "suk-Latn" => Ok(Self::Suklatn),
-            "sun-Latn" => Ok(Self::Sunlatn),
-            "sur-Latn" => Ok(Self::Surlatn),
-            "sus-Arab" => Ok(Self::Susarab),
-            "sus-Latn" => Ok(Self::Suslatn),
-            "suz-Deva" => Ok(Self::Suzdeva),
-            "swa-Latn" => Ok(Self::Swalatn),
-            "swb-Latn" => Ok(Self::Swblatn),
-            "swc-Latn" => Ok(Self::Swclatn),
-            "swe-Latn" => Ok(Self::Swelatn),
-            "swg-Latn" => Ok(Self::Swglatn),
-            "swh-Latn" => Ok(Self::Swhlatn),
-            "swk-Latn" => Ok(Self::Swklatn),
-            "swp-Latn" => Ok(Self::Swplatn),
-            "sxb-Latn" => Ok(Self::Sxblatn),
-            "sxn-Latn" => Ok(Self::Sxnlatn),
-            "syb-Latn" => Ok(Self::Syblatn),
-            "syc-Syrc" => Ok(Self::Sycsyrc),
-            "syl-Beng" => Ok(Self::Sylbeng),
-            "syl-Latn" => Ok(Self::Syllatn),
-            "szb-Latn" => Ok(Self::Szblatn),
-            "szl-Latn" => Ok(Self::Szllatn),
-            "szy-Latn" => Ok(Self::Szylatn),
-            "tab-Cyrl" => Ok(Self::Tabcyrl),
-            "tac-Latn" => Ok(Self::Taclatn),
-            "tah-Latn" => Ok(Self::Tahlatn),
-            "taj-Deva" => Ok(Self::Tajdeva),
-            "tam-Latn" => Ok(Self::Tamlatn),
-            "tam-Taml" => Ok(Self::Tamtaml),
-            "tap-Latn" => Ok(Self::Taplatn),
-            "taq-Latn" => Ok(Self::Taqlatn),
-            "taq-Tfng" => Ok(Self::Taqtfng),
-            "tar-Latn" => Ok(Self::Tarlatn),
-            "tat-Cyrl" => Ok(Self::Tatcyrl),
-            "tat-Latn" => Ok(Self::Tatlatn),
-            "tav-Latn" => Ok(Self::Tavlatn),
-            "taw-Latn" => Ok(Self::Tawlatn),
-            "tay-Latn" => Ok(Self::Taylatn),
-            "tbc-Latn" => Ok(Self::Tbclatn),
-            "tbg-Latn" => Ok(Self::Tbglatn),
-            "tbk-Latn" => Ok(Self::Tbklatn),
-            "tbl-Latn" => Ok(Self::Tbllatn),
-            "tbo-Latn" => Ok(Self::Tbolatn),
-            "tbw-Latn" => Ok(Self::Tbwlatn),
-            "tby-Latn" => Ok(Self::Tbylatn),
-            "tbz-Latn" => Ok(Self::Tbzlatn),
-            "tca-Latn" => Ok(Self::Tcalatn),
-            "tcc-Latn" => Ok(Self::Tcclatn),
-            "tcf-Latn" => Ok(Self::Tcflatn),
-            "tcs-Latn" => Ok(Self::Tcslatn),
-            "tcy-Knda" => Ok(Self::Tcyknda),
-            "tcz-Latn" => Ok(Self::Tczlatn),
-            "tdt-Latn" => Ok(Self::Tdtlatn),
-            "tdx-Latn" => Ok(Self::Tdxlatn),
-            "ted-Latn" => Ok(Self::Tedlatn),
-            "tee-Latn" => Ok(Self::Teelatn),
-            "tel-Latn" => Ok(Self::Tellatn),
-            "tel-Telu" => Ok(Self::Teltelu),
-            "tem-Latn" => Ok(Self::Temlatn),
-            "teo-Latn" => Ok(Self::Teolatn),
-            "ter-Latn" => Ok(Self::Terlatn),
-            "tet-Latn" => Ok(Self::Tetlatn),
-            "tew-Latn" => Ok(Self::Tewlatn),
-            "tfr-Latn" => Ok(Self::Tfrlatn),
-            "tgk-Cyrl" => Ok(Self::Tgkcyrl),
-            "tgl-Latn" => Ok(Self::Tgllatn),
-            "tgo-Latn" => Ok(Self::Tgolatn),
-            "tgp-Latn" => Ok(Self::Tgplatn),
-            "tha-Thai" => Ok(Self::Thathai),
-            "thk-Latn" => Ok(Self::Thklatn),
-            "thl-Deva" => Ok(Self::Thldeva),
-            "thv-Latn" => Ok(Self::Thvlatn),
-            "tif-Latn" => Ok(Self::Tiflatn),
-            "tig-Ethi" => Ok(Self::Tigethi),
-            "tih-Latn" => Ok(Self::Tihlatn),
-            "tik-Latn" => Ok(Self::Tiklatn),
-            "tim-Latn" => Ok(Self::Timlatn),
-            "tir-Ethi" => Ok(Self::Tirethi),
-            "tiv-Latn" => Ok(Self::Tivlatn),
-            "tiy-Latn" => Ok(Self::Tiylatn),
-            "tke-Latn" => Ok(Self::Tkelatn),
-            "tkl-Latn" => Ok(Self::Tkllatn),
-            "tkr-Cyrl" => Ok(Self::Tkrcyrl),
-            "tku-Latn" => Ok(Self::Tkulatn),
-            "tlb-Latn" => Ok(Self::Tlblatn),
-            "tlf-Latn" => Ok(Self::Tlflatn),
-            "tlh-Latn" => Ok(Self::Tlhlatn),
-            "tlj-Latn" => Ok(Self::Tljlatn),
-            "tll-Latn" => Ok(Self::Tlllatn),
-            "tly-Latn" => Ok(Self::Tlylatn),
-            "tmc-Latn" => Ok(Self::Tmclatn),
-            "tmd-Latn" => Ok(Self::Tmdlatn),
-            "tna-Latn" => Ok(Self::Tnalatn),
-            "tnc-Latn" => Ok(Self::Tnclatn),
-            "tnk-Latn" => Ok(Self::Tnklatn),
-            "tnn-Latn" => Ok(Self::Tnnlatn),
-            "tnp-Latn" => Ok(Self::Tnplatn),
-            "tnr-Latn" => Ok(Self::Tnrlatn),
-            "tob-Latn" => Ok(Self::Toblatn),
-            "toc-Latn" => Ok(Self::Toclatn),
-            "tod-Latn" => Ok(Self::Todlatn),
-            "tog-Latn" => Ok(Self::Toglatn),
-            "toh-Latn" => Ok(Self::Tohlatn),
-            "toi-Latn" => Ok(Self::Toilatn),
-            "toj-Latn" => Ok(Self::Tojlatn),
-            "tok-Latn" => Ok(Self::Toklatn),
-            "ton-Latn" => Ok(Self::Tonlatn),
-            "too-Latn" => Ok(Self::Toolatn),
-            "top-Latn" => Ok(Self::Toplatn),
-            "tos-Latn" => Ok(Self::Toslatn),
-            "tpa-Latn" => Ok(Self::Tpalatn),
-            "tpi-Latn" => Ok(Self::Tpilatn),
-            "tpm-Latn" => Ok(Self::Tpmlatn),
-            "tpn-Latn" => Ok(Self::Tpnlatn),
-            "tpp-Latn" => Ok(Self::Tpplatn),
-            "tpt-Latn" => Ok(Self::Tptlatn),
-            "tpw-Latn" => Ok(Self::Tpwlatn),
-            "tpz-Latn" => Ok(Self::Tpzlatn),
-            "tqo-Latn" => Ok(Self::Tqolatn),
-            "trc-Latn" => Ok(Self::Trclatn),
-            "trn-Latn" => Ok(Self::Trnlatn),
-            "tro-Latn" => Ok(Self::Trolatn),
-            "trp-Latn" => Ok(Self::Trplatn),
-            "trq-Latn" => Ok(Self::Trqlatn),
-            "trs-Latn" => Ok(Self::Trslatn),
-            "trv-Latn" => Ok(Self::Trvlatn),
-            "tsc-Latn" => Ok(Self::Tsclatn),
-            "tsg-Latn" => Ok(Self::Tsglatn),
-            "tsn-Latn" => Ok(Self::Tsnlatn),
-            "tso-Latn" => Ok(Self::Tsolatn),
-            "tsw-Latn" => Ok(Self::Tswlatn),
-            "tsz-Latn" => Ok(Self::Tszlatn),
-            "ttc-Latn" => Ok(Self::Ttclatn),
-            "tte-Latn" => Ok(Self::Ttelatn),
-            "ttj-Latn" => Ok(Self::Ttjlatn),
-            "ttq-Latn" => Ok(Self::Ttqlatn),
-            "ttq-Tfng" => Ok(Self::Ttqtfng),
-            "tuc-Latn" => Ok(Self::Tuclatn),
-            "tue-Latn" => Ok(Self::Tuelatn),
-            "tuf-Latn" => Ok(Self::Tuflatn),
-            "tui-Latn" => Ok(Self::Tuilatn),
-            "tuk-Arab" => Ok(Self::Tukarab),
-            "tuk-Cyrl" => Ok(Self::Tukcyrl),
-            "tuk-Latn" => Ok(Self::Tuklatn),
-            "tul-Latn" => Ok(Self::Tullatn),
-            "tum-Latn" => Ok(Self::Tumlatn),
-            "tuo-Latn" => Ok(Self::Tuolatn),
-            "tur-Latn" => Ok(Self::Turlatn),
-            "tuv-Latn" => Ok(Self::Tuvlatn),
-            "tvk-Latn" => Ok(Self::Tvklatn),
-            "tvl-Latn" => Ok(Self::Tvllatn),
-            "twb-Latn" => Ok(Self::Twblatn),
-            "twi-Latn" => Ok(Self::Twilatn),
-            "twu-Latn" => Ok(Self::Twulatn),
-            "twx-Latn" => Ok(Self::Twxlatn),
-            "txq-Latn" => Ok(Self::Txqlatn),
-            "txu-Latn" => Ok(Self::Txulatn),
-            "tyv-Cyrl" => Ok(Self::Tyvcyrl),
-            "tzh-Latn" => Ok(Self::Tzhlatn),
-            "tzj-Latn" => Ok(Self::Tzjlatn),
-            "tzl-Latn" => Ok(Self::Tzllatn),
-            "tzm-Tfng" => Ok(Self::Tzmtfng),
-            "tzo-Latn" => Ok(Self::Tzolatn),
-            "ubr-Latn" => Ok(Self::Ubrlatn),
-            "ubu-Latn" => Ok(Self::Ubulatn),
-            "udm-Cyrl" => Ok(Self::Udmcyrl),
-            "udu-Latn" => Ok(Self::Udulatn),
-            "uig-Arab" => Ok(Self::Uigarab),
-            "uig-Cyrl" => Ok(Self::Uigcyrl),
-            "uig-Latn" => Ok(Self::Uiglatn),
-            "ukr-Cyrl" => Ok(Self::Ukrcyrl),
-            "umb-Latn" => Ok(Self::Umblatn),
-            "und-Adlm" => Ok(Self::Undadlm),
-            "und-Aghb" => Ok(Self::Undaghb),
-            "und-Ahom" => Ok(Self::Undahom),
-            "und-Arab" => Ok(Self::Undarab),
-            "und-Armi" => Ok(Self::Undarmi),
-            "und-Armn" => Ok(Self::Undarmn),
-            "und-Avst" => Ok(Self::Undavst),
-            "und-Bali" => Ok(Self::Undbali),
-            "und-Bamu" => Ok(Self::Undbamu),
-            "und-Bass" => Ok(Self::Undbass),
-            "und-Batk" => Ok(Self::Undbatk),
-            "und-Beng" => Ok(Self::Undbeng),
-            "und-Bhks" => Ok(Self::Undbhks),
-            "und-Bopo" => Ok(Self::Undbopo),
-            "und-Brah" => Ok(Self::Undbrah),
-            "und-Brai" => Ok(Self::Undbrai),
-            "und-Bugi" => Ok(Self::Undbugi),
-            "und-Buhd" => Ok(Self::Undbuhd),
-            "und-Cakm" => Ok(Self::Undcakm),
-            "und-Cans" => Ok(Self::Undcans),
-            "und-Cari" => Ok(Self::Undcari),
-            "und-Cham" => Ok(Self::Undcham),
-            "und-Cher" => Ok(Self::Undcher),
-            "und-Chrs" => Ok(Self::Undchrs),
-            "und-Copt" => Ok(Self::Undcopt),
-            "und-Cpmn" => Ok(Self::Undcpmn),
-            "und-Cprt" => Ok(Self::Undcprt),
-            "und-Cyrl" => Ok(Self::Undcyrl),
-            "und-Deva" => Ok(Self::Unddeva),
-            "und-Diak" => Ok(Self::Unddiak),
-            "und-Dogr" => Ok(Self::Unddogr),
-            "und-Dsrt" => Ok(Self::Unddsrt),
-            "und-Dupl" => Ok(Self::Unddupl),
-            "und-Egyp" => Ok(Self::Undegyp),
-            "und-Elba" => Ok(Self::Undelba),
-            "und-Elym" => Ok(Self::Undelym),
-            "und-Ethi" => Ok(Self::Undethi),
-            "und-Geor" => Ok(Self::Undgeor),
-            "und-Glag" => Ok(Self::Undglag),
-            "und-Gong" => Ok(Self::Undgong),
-            "und-Gonm" => Ok(Self::Undgonm),
-            "und-Goth" => Ok(Self::Undgoth),
-            "und-Gran" => Ok(Self::Undgran),
-            "und-Grek" => Ok(Self::Undgrek),
-            "und-Gujr" => Ok(Self::Undgujr),
-            "und-Guru" => Ok(Self::Undguru),
-            "und-Hang" => Ok(Self::Undhang),
-            "und-Hani" => Ok(Self::Undhani),
-            "und-Hano" => Ok(Self::Undhano),
-            "und-Hatr" => Ok(Self::Undhatr),
-            "und-Hebr" => Ok(Self::Undhebr),
-            "und-Hira" => Ok(Self::Undhira),
-            "und-Hluw" => Ok(Self::Undhluw),
-            "und-Hmng" => Ok(Self::Undhmng),
-            "und-Hmnp" => Ok(Self::Undhmnp),
-            "und-Hung" => Ok(Self::Undhung),
-            "und-Ital" => Ok(Self::Undital),
-            "und-Java" => Ok(Self::Undjava),
-            "und-Kali" => Ok(Self::Undkali),
-            "und-Kana" => Ok(Self::Undkana),
-            "und-Kawi" => Ok(Self::Undkawi),
-            "und-Khar" => Ok(Self::Undkhar),
-            "und-Khmr" => Ok(Self::Undkhmr),
-            "und-Khoj" => Ok(Self::Undkhoj),
-            "und-Kits" => Ok(Self::Undkits),
-            "und-Knda" => Ok(Self::Undknda),
-            "und-Kthi" => Ok(Self::Undkthi),
-            "und-Lana" => Ok(Self::Undlana),
-            "und-Laoo" => Ok(Self::Undlaoo),
-            "und-Latn" => Ok(Self::Undlatn),
-            "und-Lepc" => Ok(Self::Undlepc),
-            "und-Limb" => Ok(Self::Undlimb),
-            "und-Lina" => Ok(Self::Undlina),
-            "und-Linb" => Ok(Self::Undlinb),
-            "und-Lisu" => Ok(Self::Undlisu),
-            "und-Lyci" => Ok(Self::Undlyci),
-            "und-Lydi" => Ok(Self::Undlydi),
-            "und-Mahj" => Ok(Self::Undmahj),
-            "und-Maka" => Ok(Self::Undmaka),
-            "und-Mand" => Ok(Self::Undmand),
-            "und-Mani" => Ok(Self::Undmani),
-            "und-Marc" => Ok(Self::Undmarc),
-            "und-Medf" => Ok(Self::Undmedf),
-            "und-Mend" => Ok(Self::Undmend),
-            "und-Merc" => Ok(Self::Undmerc),
-            "und-Mero" => Ok(Self::Undmero),
-            "und-Mlym" => Ok(Self::Undmlym),
-            "und-Modi" => Ok(Self::Undmodi),
-            "und-Mong" => Ok(Self::Undmong),
-            "und-Mroo" => Ok(Self::Undmroo),
-            "und-Mtei" => Ok(Self::Undmtei),
-            "und-Mult" => Ok(Self::Undmult),
-            "und-Mymr" => Ok(Self::Undmymr),
-            "und-Nagm" => Ok(Self::Undnagm),
-            "und-Nand" => Ok(Self::Undnand),
-            "und-Narb" => Ok(Self::Undnarb),
-            "und-Nbat" => Ok(Self::Undnbat),
-            "und-Newa" => Ok(Self::Undnewa),
-            "und-Nkoo" => Ok(Self::Undnkoo),
-            "und-Nshu" => Ok(Self::Undnshu),
-            "und-Ogam" => Ok(Self::Undogam),
-            "und-Olck" => Ok(Self::Undolck),
-            "und-Orkh" => Ok(Self::Undorkh),
-            "und-Orya" => Ok(Self::Undorya),
-            "und-Osge" => Ok(Self::Undosge),
-            "und-Osma" => Ok(Self::Undosma),
-            "und-Ougr" => Ok(Self::Undougr),
-            "und-Palm" => Ok(Self::Undpalm),
-            "und-Pauc" => Ok(Self::Undpauc),
-            "und-Perm" => Ok(Self::Undperm),
-            "und-Phag" => Ok(Self::Undphag),
-            "und-Phli" => Ok(Self::Undphli),
-            "und-Phlp" => Ok(Self::Undphlp),
-            "und-Phnx" => Ok(Self::Undphnx),
-            "und-Plrd" => Ok(Self::Undplrd),
-            "und-Prti" => Ok(Self::Undprti),
-            "und-Rjng" => Ok(Self::Undrjng),
-            "und-Rohg" => Ok(Self::Undrohg),
-            "und-Runr" => Ok(Self::Undrunr),
-            "und-Samr" => Ok(Self::Undsamr),
-            "und-Sarb" => Ok(Self::Undsarb),
-            "und-Saur" => Ok(Self::Undsaur),
-            "und-Sgnw" => Ok(Self::Undsgnw),
-            "und-Shaw" => Ok(Self::Undshaw),
-            "und-Shrd" => Ok(Self::Undshrd),
-            "und-Sidd" => Ok(Self::Undsidd),
-            "und-Sind" => Ok(Self::Undsind),
-            "und-Sinh" => Ok(Self::Undsinh),
-            "und-Sogd" => Ok(Self::Undsogd),
-            "und-Sogo" => Ok(Self::Undsogo),
-            "und-Sora" => Ok(Self::Undsora),
-            "und-Soyo" => Ok(Self::Undsoyo),
-            "und-Sund" => Ok(Self::Undsund),
-            "und-Sylo" => Ok(Self::Undsylo),
-            "und-Syrc" => Ok(Self::Undsyrc),
-            "und-Tagb" => Ok(Self::Undtagb),
-            "und-Takr" => Ok(Self::Undtakr),
-            "und-Tale" => Ok(Self::Undtale),
-            "und-Talu" => Ok(Self::Undtalu),
-            "und-Taml" => Ok(Self::Undtaml),
-            "und-Tang" => Ok(Self::Undtang),
-            "und-Tavt" => Ok(Self::Undtavt),
-            "und-Telu" => Ok(Self::Undtelu),
-            "und-Tfng" => Ok(Self::Undtfng),
-            "und-Tglg" => Ok(Self::Undtglg),
-            "und-Thaa" => Ok(Self::Undthaa),
-            "und-Thai" => Ok(Self::Undthai),
-            "und-Tibt" => Ok(Self::Undtibt),
-            "und-Tirh" => Ok(Self::Undtirh),
-            "und-Tnsa" => Ok(Self::Undtnsa),
-            "und-Toto" => Ok(Self::Undtoto),
-            "und-Ugar" => Ok(Self::Undugar),
-            "und-Vaii" => Ok(Self::Undvaii),
-            "und-Vith" => Ok(Self::Undvith),
-            "und-Wara" => Ok(Self::Undwara),
-            "und-Wcho" => Ok(Self::Undwcho),
-            "und-Xpeo" => Ok(Self::Undxpeo),
-            "und-Xsux" => Ok(Self::Undxsux),
-            "und-Yezi" => Ok(Self::Undyezi),
-            "und-Yiii" => Ok(Self::Undyiii),
-            "und-Zanb" => Ok(Self::Undzanb),
-            "und-Zinh" => Ok(Self::Undzinh),
-            "und-Zyyy" => Ok(Self::Undzyyy),
-            "und-Zzzz" => Ok(Self::Undzzzz),
-            "upv-Latn" => Ok(Self::Upvlatn),
-            "ura-Latn" => Ok(Self::Uralatn),
-            "urb-Latn" => Ok(Self::Urblatn),
-            "urd-Arab" => Ok(Self::Urdarab),
-            "urd-Latn" => Ok(Self::Urdlatn),
-            "urh-Latn" => Ok(Self::Urhlatn),
-            "uri-Latn" => Ok(Self::Urilatn),
-            "urk-Thai" => Ok(Self::Urkthai),
-            "urt-Latn" => Ok(Self::Urtlatn),
-            "urw-Latn" => Ok(Self::Urwlatn),
-            "ury-Latn" => Ok(Self::Urylatn),
-            "usa-Latn" => Ok(Self::Usalatn),
-            "usp-Latn" => Ok(Self::Usplatn),
-            "uth-Latn" => Ok(Self::Uthlatn),
-            "uvh-Latn" => Ok(Self::Uvhlatn),
-            "uvl-Latn" => Ok(Self::Uvllatn),
-            "uzb-Cyrl" => Ok(Self::Uzbcyrl),
-            "uzb-Latn" => Ok(Self::Uzblatn),
-            "uzn-Cyrl" => Ok(Self::Uzncyrl),
-            "uzn-Latn" => Ok(Self::Uznlatn),
-            "uzs-Arab" => Ok(Self::Uzsarab),
-            "vag-Latn" => Ok(Self::Vaglatn),
-            "vap-Latn" => Ok(Self::Vaplatn),
-            "var-Latn" => Ok(Self::Varlatn),
-            "vec-Latn" => Ok(Self::Veclatn),
-            "ven-Latn" => Ok(Self::Venlatn),
-            "vep-Latn" => Ok(Self::Veplatn),
-            "vid-Latn" => Ok(Self::Vidlatn),
-            "vie-Latn" => Ok(Self::Vielatn),
-            "viv-Latn" => Ok(Self::Vivlatn),
-            "vls-Latn" => Ok(Self::Vlslatn),
-            "vmk-Latn" => Ok(Self::Vmklatn),
-            "vmw-Latn" => Ok(Self::Vmwlatn),
-            "vmy-Latn" => Ok(Self::Vmylatn),
-            "vol-Latn" => Ok(Self::Vollatn),
-            "vot-Latn" => Ok(Self::Votlatn),
-            "vro-Latn" => Ok(Self::Vrolatn),
-            "vun-Latn" => Ok(Self::Vunlatn),
-            "vut-Latn" => Ok(Self::Vutlatn),
-            "waj-Latn" => Ok(Self::Wajlatn),
-            "wal-Ethi" => Ok(Self::Walethi),
-            "wal-Latn" => Ok(Self::Wallatn),
-            "wap-Latn" => Ok(Self::Waplatn),
-            "war-Latn" => Ok(Self::Warlatn),
-            "wat-Latn" => Ok(Self::Watlatn),
-            "way-Latn" => Ok(Self::Waylatn),
-            "wba-Latn" => Ok(Self::Wbalatn),
-            "wbm-Latn" => Ok(Self::Wbmlatn),
-            "wbp-Latn" => Ok(Self::Wbplatn),
-            "wca-Latn" => Ok(Self::Wcalatn),
-            "wed-Latn" => Ok(Self::Wedlatn),
-            "wer-Latn" => Ok(Self::Werlatn),
-            "wes-Latn" => Ok(Self::Weslatn),
-            "wew-Latn" => Ok(Self::Wewlatn),
-            "whg-Latn" => Ok(Self::Whglatn),
-            "whk-Latn" => Ok(Self::Whklatn),
-            "wib-Latn" => Ok(Self::Wiblatn),
-            "wim-Latn" => Ok(Self::Wimlatn),
-            "wiu-Latn" => Ok(Self::Wiulatn),
-            "wln-Latn" => Ok(Self::Wlnlatn),
-            "wls-Latn" => Ok(Self::Wlslatn),
-            "wlv-Latn" => Ok(Self::Wlvlatn),
-            "wlx-Latn" => Ok(Self::Wlxlatn),
-            "wmt-Latn" => Ok(Self::Wmtlatn),
-            "wmw-Latn" => Ok(Self::Wmwlatn),
-            "wnc-Latn" => Ok(Self::Wnclatn),
-            "wnu-Latn" => Ok(Self::Wnulatn),
-            "wob-Latn" => Ok(Self::Woblatn),
-            "wol-Latn" => Ok(Self::Wollatn),
-            "wos-Latn" => Ok(Self::Woslatn),
-            "wrk-Latn" => Ok(Self::Wrklatn),
-            "wrs-Latn" => Ok(Self::Wrslatn),
-            "wsg-Telu" => Ok(Self::Wsgtelu),
-            "wsk-Latn" => Ok(Self::Wsklatn),
-            "wuu-Hani" => Ok(Self::Wuuhani),
-            "wuv-Latn" => Ok(Self::Wuvlatn),
-            "wwa-Latn" => Ok(Self::Wwalatn),
-            "xal-Cyrl" => Ok(Self::Xalcyrl),
-            "xav-Latn" => Ok(Self::Xavlatn),
-            "xbi-Latn" => Ok(Self::Xbilatn),
-            "xbr-Latn" => Ok(Self::Xbrlatn),
-            "xed-Latn" => Ok(Self::Xedlatn),
-            "xho-Latn" => Ok(Self::Xholatn),
-            "xla-Latn" => Ok(Self::Xlalatn),
-            "xmf-Geor" => Ok(Self::Xmfgeor),
-            "xmm-Latn" => Ok(Self::Xmmlatn),
-            "xmv-Latn" => Ok(Self::Xmvlatn),
-            "xnn-Latn" => Ok(Self::Xnnlatn),
-            "xog-Latn" => Ok(Self::Xoglatn),
-            "xon-Latn" => Ok(Self::Xonlatn),
-            "xrb-Latn" => Ok(Self::Xrblatn),
-            "xsb-Latn" => Ok(Self::Xsblatn),
-            "xsi-Latn" => Ok(Self::Xsilatn),
-            "xsm-Latn" => Ok(Self::Xsmlatn),
-            "xsr-Deva" => Ok(Self::Xsrdeva),
-            "xsu-Latn" => Ok(Self::Xsulatn),
-            "xtd-Latn" => Ok(Self::Xtdlatn),
-            "xtm-Latn" => Ok(Self::Xtmlatn),
-            "xtn-Latn" => Ok(Self::Xtnlatn),
-            "xum-Latn" => Ok(Self::Xumlatn),
-            "xuo-Latn" => Ok(Self::Xuolatn),
-            "yaa-Latn" => Ok(Self::Yaalatn),
-            "yad-Latn" => Ok(Self::Yadlatn),
-            "yal-Latn" => Ok(Self::Yallatn),
-            "yam-Latn" => Ok(Self::Yamlatn),
-            "yan-Latn" => Ok(Self::Yanlatn),
-            "yao-Latn" => Ok(Self::Yaolatn),
-            "yap-Latn" => Ok(Self::Yaplatn),
-            "yaq-Latn" => Ok(Self::Yaqlatn),
-            "yas-Latn" => Ok(Self::Yaslatn),
-            "yat-Latn" => Ok(Self::Yatlatn),
-            "yaz-Latn" => Ok(Self::Yazlatn),
-            "ybb-Latn" => Ok(Self::Ybblatn),
-            "yby-Latn" => Ok(Self::Ybylatn),
-            "ycn-Latn" => Ok(Self::Ycnlatn),
-            "ydd-Hebr" => Ok(Self::Yddhebr),
-            "yid-Hebr" => Ok(Self::Yidhebr),
-            "yim-Latn" => Ok(Self::Yimlatn),
-            "yka-Latn" => Ok(Self::Ykalatn),
-            "yle-Latn" => Ok(Self::Ylelatn),
-            "yli-Latn" => Ok(Self::Ylilatn),
-            "yml-Latn" => Ok(Self::Ymllatn),
-            "yom-Latn" => Ok(Self::Yomlatn),
-            "yon-Latn" => Ok(Self::Yonlatn),
-            "yor-Latn" => Ok(Self::Yorlatn),
-            "yrb-Latn" => Ok(Self::Yrblatn),
-            "yre-Latn" => Ok(Self::Yrelatn),
-            "yrk-Cyrl" => Ok(Self::Yrkcyrl),
-            "yrl-Latn" => Ok(Self::Yrllatn),
-            "yss-Latn" => Ok(Self::Ysslatn),
-            "yua-Latn" => Ok(Self::Yualatn),
-            "yue-Hani" => Ok(Self::Yuehani),
-            "yuj-Latn" => Ok(Self::Yujlatn),
-            "yup-Latn" => Ok(Self::Yuplatn),
-            "yut-Latn" => Ok(Self::Yutlatn),
-            "yuw-Latn" => Ok(Self::Yuwlatn),
-            "yuz-Latn" => Ok(Self::Yuzlatn),
-            "yva-Latn" => Ok(Self::Yvalatn),
-            "zaa-Latn" => Ok(Self::Zaalatn),
-            "zab-Latn" => Ok(Self::Zablatn),
-            "zac-Latn" => Ok(Self::Zaclatn),
-            "zad-Latn" => Ok(Self::Zadlatn),
-            "zae-Latn" => Ok(Self::Zaelatn),
-            "zai-Latn" => Ok(Self::Zailatn),
-            "zam-Latn" => Ok(Self::Zamlatn),
-            "zao-Latn" => Ok(Self::Zaolatn),
-            "zar-Latn" => Ok(Self::Zarlatn),
-            "zas-Latn" => Ok(Self::Zaslatn),
-            "zat-Latn" => Ok(Self::Zatlatn),
-            "zav-Latn" => Ok(Self::Zavlatn),
-            "zaw-Latn" => Ok(Self::Zawlatn),
-            "zca-Latn" => Ok(Self::Zcalatn),
-            "zdj-Latn" => Ok(Self::Zdjlatn),
-            "zea-Latn" => Ok(Self::Zealatn),
-            "zgh-Tfng" => Ok(Self::Zghtfng),
-            "zho-Hani" => Ok(Self::Zhohani),
-            "zia-Latn" => Ok(Self::Zialatn),
-            "ziw-Latn" => Ok(Self::Ziwlatn),
-            "zlm-Latn" => Ok(Self::Zlmlatn),
-            "zne-Latn" => Ok(Self::Znelatn),
-            "zoc-Latn" => Ok(Self::Zoclatn),
-            "zom-Latn" => Ok(Self::Zomlatn),
-            "zos-Latn" => Ok(Self::Zoslatn),
-            "zpa-Latn" => Ok(Self::Zpalatn),
-            "zpc-Latn" => Ok(Self::Zpclatn),
-            "zpg-Latn" => Ok(Self::Zpglatn),
-            "zpi-Latn" => Ok(Self::Zpilatn),
-            "zpj-Latn" => Ok(Self::Zpjlatn),
-            "zpl-Latn" => Ok(Self::Zpllatn),
-            "zpm-Latn" => Ok(Self::Zpmlatn),
-            "zpo-Latn" => Ok(Self::Zpolatn),
-            "zpq-Latn" => Ok(Self::Zpqlatn),
-            "zpt-Latn" => Ok(Self::Zptlatn),
-            "zpu-Latn" => Ok(Self::Zpulatn),
-            "zpv-Latn" => Ok(Self::Zpvlatn),
-            "zpz-Latn" => Ok(Self::Zpzlatn),
-            "zsm-Arab" => Ok(Self::Zsmarab),
-            "zsm-Latn" => Ok(Self::Zsmlatn),
-            "zsr-Latn" => Ok(Self::Zsrlatn),
-            "ztq-Latn" => Ok(Self::Ztqlatn),
-            "zty-Latn" => Ok(Self::Ztylatn),
-            "zul-Latn" => Ok(Self::Zullatn),
-            "zxx-Arab" => Ok(Self::Zxxarab),
-            "zxx-Latn" => Ok(Self::Zxxlatn),
-            "zxx-Zzzz" => Ok(Self::Zxxzzzz),
-            "zyb-Latn" => Ok(Self::Zyblatn),
-            "zyp-Latn" => Ok(Self::Zyplatn),
-            "zza-Latn" => Ok(Self::Zzalatn),
-            "aai" => Ok(Self::Aai),
-            "aak" => Ok(Self::Aak),
-            "aau" => Ok(Self::Aau),
-            "aaz" => Ok(Self::Aaz),
-            "aba" => Ok(Self::Aba),
-            "abk" => Ok(Self::Abk),
-            "abn" => Ok(Self::Abn),
-            "abq" => Ok(Self::Abq),
-            "abt" => Ok(Self::Abt),
-            "abx" => Ok(Self::Abx),
-            "aby" => Ok(Self::Aby),
-            "abz" => Ok(Self::Abz),
-            "aca" => Ok(Self::Aca),
-            "acd" => Ok(Self::Acd),
-            "ace" => Ok(Self::Ace),
-            "acf" => Ok(Self::Acf),
-            "ach" => Ok(Self::Ach),
-            "acm" => Ok(Self::Acm),
-            "acn" => Ok(Self::Acn),
-            "acq" => Ok(Self::Acq),
-            "acr" => Ok(Self::Acr),
-            "acu" => Ok(Self::Acu),
-            "ada" => Ok(Self::Ada),
-            "ade" => Ok(Self::Ade),
-            "adh" => Ok(Self::Adh),
-            "adi" => Ok(Self::Adi),
-            "adj" => Ok(Self::Adj),
-            "adl" => Ok(Self::Adl),
-            "ady" => Ok(Self::Ady),
-            "adz" => Ok(Self::Adz),
-            "aeb" => Ok(Self::Aeb),
-            "aer" => Ok(Self::Aer),
-            "aeu" => Ok(Self::Aeu),
-            "aey" => Ok(Self::Aey),
-            "afb" => Ok(Self::Afb),
-            "afh" => Ok(Self::Afh),
-            "afr" => Ok(Self::Afr),
-            "agd" => Ok(Self::Agd),
-            "agg" => Ok(Self::Agg),
-            "agm" => Ok(Self::Agm),
-            "agn" => Ok(Self::Agn),
-            "agr" => Ok(Self::Agr),
-            "agt" => Ok(Self::Agt),
-            "agu" => Ok(Self::Agu),
-            "agw" => Ok(Self::Agw),
-            "agx" => Ok(Self::Agx),
-            "aha" => Ok(Self::Aha),
-            "ahk" => Ok(Self::Ahk),
-            "aia" => Ok(Self::Aia),
-            "aii" => Ok(Self::Aii),
-            "aim" => Ok(Self::Aim),
-            "ain" => Ok(Self::Ain),
-            "ajg" => Ok(Self::Ajg),
-            "aji" => Ok(Self::Aji),
-            "ajp" => Ok(Self::Ajp),
-            "ajz" => Ok(Self::Ajz),
-            "aka" => Ok(Self::Aka),
-            "akb" => Ok(Self::Akb),
-            "ake" => Ok(Self::Ake),
-            "akh" => Ok(Self::Akh),
-            "akl" => Ok(Self::Akl),
-            "akp" => Ok(Self::Akp),
-            "ald" => Ok(Self::Ald),
-            "alj" => Ok(Self::Alj),
-            "aln" => Ok(Self::Aln),
-            "alp" => Ok(Self::Alp),
-            "alq" => Ok(Self::Alq),
-            "als" => Ok(Self::Als),
-            "alt" => Ok(Self::Alt),
-            "aly" => Ok(Self::Aly),
-            "alz" => Ok(Self::Alz),
-            "ame" => Ok(Self::Ame),
-            "amf" => Ok(Self::Amf),
-            "amh" => Ok(Self::Amh),
-            "ami" => Ok(Self::Ami),
-            "amk" => Ok(Self::Amk),
-            "amm" => Ok(Self::Amm),
-            "amn" => Ok(Self::Amn),
-            "amp" => Ok(Self::Amp),
-            "amr" => Ok(Self::Amr),
-            "amu" => Ok(Self::Amu),
-            "amx" => Ok(Self::Amx),
-            "ang" => Ok(Self::Ang),
-            "anm" => Ok(Self::Anm),
-            "ann" => Ok(Self::Ann),
-            "anv" => Ok(Self::Anv),
-            "any" => Ok(Self::Any),
-            "aoc" => Ok(Self::Aoc),
-            "aoi" => Ok(Self::Aoi),
-            "aoj" => Ok(Self::Aoj),
-            "aom" => Ok(Self::Aom),
-            "aon" => Ok(Self::Aon),
-            "aoz" => Ok(Self::Aoz),
-            "apb" => Ok(Self::Apb),
-            "apc" => Ok(Self::Apc),
-            "ape" => Ok(Self::Ape),
-            "apn" => Ok(Self::Apn),
-            "apr" => Ok(Self::Apr),
-            "apt" => Ok(Self::Apt),
-            "apu" => Ok(Self::Apu),
-            "apw" => Ok(Self::Apw),
-            "apy" => Ok(Self::Apy),
-            "apz" => Ok(Self::Apz),
-            "ara" => Ok(Self::Ara),
-            "arb" => Ok(Self::Arb),
-            "are" => Ok(Self::Are),
-            "arg" => Ok(Self::Arg),
-            "arh" => Ok(Self::Arh),
-            "arl" => Ok(Self::Arl),
-            "arn" => Ok(Self::Arn),
-            "arp" => Ok(Self::Arp),
-            "arq" => Ok(Self::Arq),
-            "ars" => Ok(Self::Ars),
-            "ary" => Ok(Self::Ary),
-            "arz" => Ok(Self::Arz),
-            "asg" => Ok(Self::Asg),
-            "asm" => Ok(Self::Asm),
-            "aso" => Ok(Self::Aso),
-            "ast" => Ok(Self::Ast),
-            "ata" => Ok(Self::Ata),
-            "atb" => Ok(Self::Atb),
-            "atd" => Ok(Self::Atd),
-            "atg" => Ok(Self::Atg),
-            "ati" => Ok(Self::Ati),
-            "att" => Ok(Self::Att),
-            "auc" => Ok(Self::Auc),
-            "aui" => Ok(Self::Aui),
-            "auy" => Ok(Self::Auy),
-            "ava" => Ok(Self::Ava),
-            "avk" => Ok(Self::Avk),
-            "avt" => Ok(Self::Avt),
-            "avu" => Ok(Self::Avu),
-            "awa" => Ok(Self::Awa),
-            "awb" => Ok(Self::Awb),
-            "awi" => Ok(Self::Awi),
-            "awx" => Ok(Self::Awx),
-            "aym" => Ok(Self::Aym),
-            "ayo" => Ok(Self::Ayo),
-            "ayr" => Ok(Self::Ayr),
-            "azb" => Ok(Self::Azb),
-            "aze" => Ok(Self::Aze),
-            "azg" => Ok(Self::Azg),
-            "azj" => Ok(Self::Azj),
-            "azz" => Ok(Self::Azz),
-            "bak" => Ok(Self::Bak),
-            "bal" => Ok(Self::Bal),
-            "bam" => Ok(Self::Bam),
-            "ban" => Ok(Self::Ban),
-            "bao" => Ok(Self::Bao),
-            "bar" => Ok(Self::Bar),
-            "bas" => Ok(Self::Bas),
-            "bav" => Ok(Self::Bav),
-            "bba" => Ok(Self::Bba),
-            "bbb" => Ok(Self::Bbb),
-            "bbc" => Ok(Self::Bbc),
-            "bbj" => Ok(Self::Bbj),
-            "bbr" => Ok(Self::Bbr),
-            "bcc" => Ok(Self::Bcc),
-            "bch" => Ok(Self::Bch),
-            "bci" => Ok(Self::Bci),
-            "bcl" => Ok(Self::Bcl),
-            "bco" => Ok(Self::Bco),
-            "bcw" => Ok(Self::Bcw),
-            "bdd" => Ok(Self::Bdd),
-            "bdh" => Ok(Self::Bdh),
-            "bea" => Ok(Self::Bea),
-            "bef" => Ok(Self::Bef),
-            "bel" => Ok(Self::Bel),
-            "bem" => Ok(Self::Bem),
-            "ben" => Ok(Self::Ben),
-            "beq" => Ok(Self::Beq),
-            "ber" => Ok(Self::Ber),
-            "bex" => Ok(Self::Bex),
-            "bfd" => Ok(Self::Bfd),
-            "bfo" => Ok(Self::Bfo),
-            "bfz" => Ok(Self::Bfz),
-            "bgr" => Ok(Self::Bgr),
-            "bgs" => Ok(Self::Bgs),
-            "bgz" => Ok(Self::Bgz),
-            "bhg" => Ok(Self::Bhg),
-            "bhl" => Ok(Self::Bhl),
-            "bho" => Ok(Self::Bho),
-            "bhp" => Ok(Self::Bhp),
-            "bhw" => Ok(Self::Bhw),
-            "bib" => Ok(Self::Bib),
-            "big" => Ok(Self::Big),
-            "bih" => Ok(Self::Bih),
-            "bik" => Ok(Self::Bik),
-            "bim" => Ok(Self::Bim),
-            "bin" => Ok(Self::Bin),
-            "bis" => Ok(Self::Bis),
-            "biu" => Ok(Self::Biu),
-            "biv" => Ok(Self::Biv),
-            "bjn" => Ok(Self::Bjn),
-            "bjp" => Ok(Self::Bjp),
-            "bjr" => Ok(Self::Bjr),
-            "bjv" => Ok(Self::Bjv),
-            "bkd" => Ok(Self::Bkd),
-            "bkq" => Ok(Self::Bkq),
-            "bku" => Ok(Self::Bku),
-            "bkv" => Ok(Self::Bkv),
-            "bla" => Ok(Self::Bla),
-            "blh" => Ok(Self::Blh),
-            "blw" => Ok(Self::Blw),
-            "blz" => Ok(Self::Blz),
-            "bmb" => Ok(Self::Bmb),
-            "bmh" => Ok(Self::Bmh),
-            "bmk" => Ok(Self::Bmk),
-            "bmq" => Ok(Self::Bmq),
-            "bmr" => Ok(Self::Bmr),
-            "bmu" => Ok(Self::Bmu),
-            "bnj" => Ok(Self::Bnj),
-            "bnp" => Ok(Self::Bnp),
-            "boa" => Ok(Self::Boa),
-            "bod" => Ok(Self::Bod),
-            "boj" => Ok(Self::Boj),
-            "bom" => Ok(Self::Bom),
-            "bon" => Ok(Self::Bon),
-            "bos" => Ok(Self::Bos),
-            "bov" => Ok(Self::Bov),
-            "box" => Ok(Self::Box),
-            "bpr" => Ok(Self::Bpr),
-            "bps" => Ok(Self::Bps),
-            "bpy" => Ok(Self::Bpy),
-            "bqc" => Ok(Self::Bqc),
-            "bqj" => Ok(Self::Bqj),
-            "bqp" => Ok(Self::Bqp),
-            "bre" => Ok(Self::Bre),
-            "bru" => Ok(Self::Bru),
-            "bsc" => Ok(Self::Bsc),
-            "bsn" => Ok(Self::Bsn),
-            "bsp" => Ok(Self::Bsp),
-            "bsq" => Ok(Self::Bsq),
-            "bss" => Ok(Self::Bss),
-            "btd" => Ok(Self::Btd),
-            "btg" => Ok(Self::Btg),
-            "bth" => Ok(Self::Bth),
-            "bts" => Ok(Self::Bts),
-            "btt" => Ok(Self::Btt),
-            "btx" => Ok(Self::Btx),
-            "bua" => Ok(Self::Bua),
-            "bud" => Ok(Self::Bud),
-            "bug" => Ok(Self::Bug),
-            "buk" => Ok(Self::Buk),
-            "bul" => Ok(Self::Bul),
-            "bum" => Ok(Self::Bum),
-            "bus" => Ok(Self::Bus),
-            "bvr" => Ok(Self::Bvr),
-            "bvy" => Ok(Self::Bvy),
-            "bvz" => Ok(Self::Bvz),
-            "bwd" => Ok(Self::Bwd),
-            "bwi" => Ok(Self::Bwi),
-            "bwq" => Ok(Self::Bwq),
-            "bwu" => Ok(Self::Bwu),
-            "bxh" => Ok(Self::Bxh),
-            "bxr" => Ok(Self::Bxr),
-            "byr" => Ok(Self::Byr),
-            "byv" => Ok(Self::Byv),
-            "byx" => Ok(Self::Byx),
-            "bzd" => Ok(Self::Bzd),
-            "bzh" => Ok(Self::Bzh),
-            "bzi" => Ok(Self::Bzi),
-            "bzj" => Ok(Self::Bzj),
-            "bzt" => Ok(Self::Bzt),
-            "caa" => Ok(Self::Caa),
-            "cab" => Ok(Self::Cab),
-            "cac" => Ok(Self::Cac),
-            "caf" => Ok(Self::Caf),
-            "cag" => Ok(Self::Cag),
-            "cak" => Ok(Self::Cak),
-            "cao" => Ok(Self::Cao),
-            "cap" => Ok(Self::Cap),
-            "caq" => Ok(Self::Caq),
-            "car" => Ok(Self::Car),
-            "cas" => Ok(Self::Cas),
-            "cat" => Ok(Self::Cat),
-            "cav" => Ok(Self::Cav),
-            "cax" => Ok(Self::Cax),
-            "cbc" => Ok(Self::Cbc),
-            "cbi" => Ok(Self::Cbi),
-            "cbk" => Ok(Self::Cbk),
-            "cbr" => Ok(Self::Cbr),
-            "cbs" => Ok(Self::Cbs),
-            "cbt" => Ok(Self::Cbt),
-            "cbu" => Ok(Self::Cbu),
-            "cbv" => Ok(Self::Cbv),
-            "cce" => Ok(Self::Cce),
-            "cco" => Ok(Self::Cco),
-            "ceb" => Ok(Self::Ceb),
-            "ceg" => Ok(Self::Ceg),
-            "cek" => Ok(Self::Cek),
-            "ces" => Ok(Self::Ces),
-            "cfm" => Ok(Self::Cfm),
-            "cgc" => Ok(Self::Cgc),
-            "cgg" => Ok(Self::Cgg),
-            "cha" => Ok(Self::Cha),
-            "chd" => Ok(Self::Chd),
-            "che" => Ok(Self::Che),
-            "chf" => Ok(Self::Chf),
-            "chj" => Ok(Self::Chj),
-            "chk" => Ok(Self::Chk),
-            "chn" => Ok(Self::Chn),
-            "cho" => Ok(Self::Cho),
-            "chq" => Ok(Self::Chq),
-            "chr" => Ok(Self::Chr),
-            "chu" => Ok(Self::Chu),
-            "chv" => Ok(Self::Chv),
-            "chw" => Ok(Self::Chw),
-            "chz" => Ok(Self::Chz),
-            "cjk" => Ok(Self::Cjk),
-            "cjo" => Ok(Self::Cjo),
-            "cjp" => Ok(Self::Cjp),
-            "cjs" => Ok(Self::Cjs),
-            "cjv" => Ok(Self::Cjv),
-            "ckb" => Ok(Self::Ckb),
-            "cko" => Ok(Self::Cko),
-            "ckt" => Ok(Self::Ckt),
-            "cle" => Ok(Self::Cle),
-            "clu" => Ok(Self::Clu),
-            "cly" => Ok(Self::Cly),
-            "cme" => Ok(Self::Cme),
-            "cmi" => Ok(Self::Cmi),
-            "cmn" => Ok(Self::Cmn),
-            "cmo" => Ok(Self::Cmo),
-            "cnh" => Ok(Self::Cnh),
-            "cni" => Ok(Self::Cni),
-            "cnl" => Ok(Self::Cnl),
-            "cnt" => Ok(Self::Cnt),
-            "cnw" => Ok(Self::Cnw),
-            "coe" => Ok(Self::Coe),
-            "cof" => Ok(Self::Cof),
-            "cok" => Ok(Self::Cok),
-            "con" => Ok(Self::Con),
-            "cop" => Ok(Self::Cop),
-            "cor" => Ok(Self::Cor),
-            "cos" => Ok(Self::Cos),
-            "cot" => Ok(Self::Cot),
-            "cpa" => Ok(Self::Cpa),
-            "cpb" => Ok(Self::Cpb),
-            "cpc" => Ok(Self::Cpc),
-            "cpu" => Ok(Self::Cpu),
-            "cpy" => Ok(Self::Cpy),
-            "crh" => Ok(Self::Crh),
-            "cri" => Ok(Self::Cri),
-            "crk" => Ok(Self::Crk),
-            "crm" => Ok(Self::Crm),
-            "crn" => Ok(Self::Crn),
-            "crq" => Ok(Self::Crq),
-            "crs" => Ok(Self::Crs),
-            "crt" => Ok(Self::Crt),
-            "crx" => Ok(Self::Crx),
-            "csb" => Ok(Self::Csb),
-            "csk" => Ok(Self::Csk),
-            "cso" => Ok(Self::Cso),
-            "csw" => Ok(Self::Csw),
-            "csy" => Ok(Self::Csy),
-            "cta" => Ok(Self::Cta),
-            "ctd" => Ok(Self::Ctd),
-            "cto" => Ok(Self::Cto),
-            "ctp" => Ok(Self::Ctp),
-            "ctu" => Ok(Self::Ctu),
-            "cub" => Ok(Self::Cub),
-            "cuc" => Ok(Self::Cuc),
-            "cui" => Ok(Self::Cui),
-            "cuk" => Ok(Self::Cuk),
-            "cul" => Ok(Self::Cul),
-            "cut" => Ok(Self::Cut),
-            "cux" => Ok(Self::Cux),
-            "cwd" => Ok(Self::Cwd),
-            "cwe" => Ok(Self::Cwe),
-            "cwt" => Ok(Self::Cwt),
-            "cya" => Ok(Self::Cya),
-            "cym" => Ok(Self::Cym),
-            "czt" => Ok(Self::Czt),
-            "daa" => Ok(Self::Daa),
-            "dad" => Ok(Self::Dad),
-            "daf" => Ok(Self::Daf),
-            "dah" => Ok(Self::Dah),
-            "dak" => Ok(Self::Dak),
-            "dan" => Ok(Self::Dan),
-            "dar" => Ok(Self::Dar),
-            "ddg" => Ok(Self::Ddg),
-            "ded" => Ok(Self::Ded),
-            "des" => Ok(Self::Des),
-            "deu" => Ok(Self::Deu),
-            "dga" => Ok(Self::Dga),
-            "dgc" => Ok(Self::Dgc),
-            "dgi" => Ok(Self::Dgi),
-            "dgr" => Ok(Self::Dgr),
-            "dgz" => Ok(Self::Dgz),
-            "dhg" => Ok(Self::Dhg),
-            "dhm" => Ok(Self::Dhm),
-            "dhv" => Ok(Self::Dhv),
-            "dig" => Ok(Self::Dig),
-            "dik" => Ok(Self::Dik),
-            "din" => Ok(Self::Din),
-            "dip" => Ok(Self::Dip),
-            "diq" => Ok(Self::Diq),
-            "dis" => Ok(Self::Dis),
-            "diu" => Ok(Self::Diu),
-            "div" => Ok(Self::Div),
-            "dje" => Ok(Self::Dje),
-            "djk" => Ok(Self::Djk),
-            "djr" => Ok(Self::Djr),
-            "dks" => Ok(Self::Dks),
-            "dng" => Ok(Self::Dng),
-            "dnj" => Ok(Self::Dnj),
-            "dob" => Ok(Self::Dob),
-            "dop" => Ok(Self::Dop),
-            "dow" => Ok(Self::Dow),
-            "drg" => Ok(Self::Drg),
-            "drt" => Ok(Self::Drt),
-            "dru" => Ok(Self::Dru),
-            "dsb" => Ok(Self::Dsb),
-            "dtp" => Ok(Self::Dtp),
-            "dts" => Ok(Self::Dts),
-            "dua" => Ok(Self::Dua),
-            "due" => Ok(Self::Due),
-            "dug" => Ok(Self::Dug),
-            "duo" => Ok(Self::Duo),
-            "dur" => Ok(Self::Dur),
-            "dwr" => Ok(Self::Dwr),
-            "dws" => Ok(Self::Dws),
-            "dww" => Ok(Self::Dww),
-            "dyi" => Ok(Self::Dyi),
-            "dyo" => Ok(Self::Dyo),
-            "dyu" => Ok(Self::Dyu),
-            "dzo" => Ok(Self::Dzo),
-            "ebk" => Ok(Self::Ebk),
-            "efi" => Ok(Self::Efi),
-            "egl" => Ok(Self::Egl),
-            "eka" => Ok(Self::Eka),
-            "ekk" => Ok(Self::Ekk),
-            "eko" => Ok(Self::Eko),
-            "ell" => Ok(Self::Ell),
-            "emi" => Ok(Self::Emi),
-            "eml" => Ok(Self::Eml),
-            "emp" => Ok(Self::Emp),
-            "enb" => Ok(Self::Enb),
-            "eng" => Ok(Self::Eng),
-            "enl" => Ok(Self::Enl),
-            "enm" => Ok(Self::Enm),
-            "enx" => Ok(Self::Enx),
-            "epo" => Ok(Self::Epo),
-            "eri" => Ok(Self::Eri),
-            "ese" => Ok(Self::Ese),
-            "esi" => Ok(Self::Esi),
-            "esk" => Ok(Self::Esk),
-            "est" => Ok(Self::Est),
-            "esu" => Ok(Self::Esu),
-            "eto" => Ok(Self::Eto),
-            "etr" => Ok(Self::Etr),
-            "etu" => Ok(Self::Etu),
-            "eus" => Ok(Self::Eus),
-            "eve" => Ok(Self::Eve),
-            "evn" => Ok(Self::Evn),
-            "ewe" => Ok(Self::Ewe),
-            "ewo" => Ok(Self::Ewo),
-            "ext" => Ok(Self::Ext),
-            "eza" => Ok(Self::Eza),
-            "faa" => Ok(Self::Faa),
-            "fad" => Ok(Self::Fad),
-            "fai" => Ok(Self::Fai),
-            "fal" => Ok(Self::Fal),
-            "fan" => Ok(Self::Fan),
-            "fao" => Ok(Self::Fao),
-            "fas" => Ok(Self::Fas),
-            "fat" => Ok(Self::Fat),
-            "ffm" => Ok(Self::Ffm),
-            "fij" => Ok(Self::Fij),
-            "fil" => Ok(Self::Fil),
-            "fin" => Ok(Self::Fin),
-            "fkv" => Ok(Self::Fkv),
-            "fmp" => Ok(Self::Fmp),
-            "fon" => Ok(Self::Fon),
-            "for" => Ok(Self::For),
-            "fra" => Ok(Self::Fra),
-            "frm" => Ok(Self::Frm),
-            "fro" => Ok(Self::Fro),
-            "frr" => Ok(Self::Frr),
-            "fry" => Ok(Self::Fry),
-            "fub" => Ok(Self::Fub),
-            "fud" => Ok(Self::Fud),
-            "fue" => Ok(Self::Fue),
-            "fuf" => Ok(Self::Fuf),
-            "fuh" => Ok(Self::Fuh),
-            "fuq" => Ok(Self::Fuq),
-            "fur" => Ok(Self::Fur),
-            "fuv" => Ok(Self::Fuv),
-            "gaa" => Ok(Self::Gaa),
-            "gag" => Ok(Self::Gag),
-            "gah" => Ok(Self::Gah),
-            "gai" => Ok(Self::Gai),
-            "gam" => Ok(Self::Gam),
-            "gaw" => Ok(Self::Gaw),
-            "gaz" => Ok(Self::Gaz),
-            "gba" => Ok(Self::Gba),
-            "gbi" => Ok(Self::Gbi),
-            "gbo" => Ok(Self::Gbo),
-            "gbr" => Ok(Self::Gbr),
-            "gcf" => Ok(Self::Gcf),
-            "gcr" => Ok(Self::Gcr),
-            "gde" => Ok(Self::Gde),
-            "gdg" => Ok(Self::Gdg),
-            "gdn" => Ok(Self::Gdn),
-            "gdr" => Ok(Self::Gdr),
-            "geb" => Ok(Self::Geb),
-            "gej" => Ok(Self::Gej),
-            "gfk" => Ok(Self::Gfk),
-            "ghe" => Ok(Self::Ghe),
-            "ghs" => Ok(Self::Ghs),
-            "gid" => Ok(Self::Gid),
-            "gil" => Ok(Self::Gil),
-            "giz" => Ok(Self::Giz),
-            "gjn" => Ok(Self::Gjn),
-            "gkn" => Ok(Self::Gkn),
-            "gkp" => Ok(Self::Gkp),
-            "gla" => Ok(Self::Gla),
-            "gle" => Ok(Self::Gle),
-            "glg" => Ok(Self::Glg),
-            "glk" => Ok(Self::Glk),
-            "glv" => Ok(Self::Glv),
-            "gmv" => Ok(Self::Gmv),
-            "gnb" => Ok(Self::Gnb),
-            "gnd" => Ok(Self::Gnd),
-            "gng" => Ok(Self::Gng),
-            "gnn" => Ok(Self::Gnn),
-            "gnw" => Ok(Self::Gnw),
-            "goa" => Ok(Self::Goa),
-            "gof" => Ok(Self::Gof),
-            "gog" => Ok(Self::Gog),
-            "gom" => Ok(Self::Gom),
-            "gor" => Ok(Self::Gor),
-            "gos" => Ok(Self::Gos),
-            "got" => Ok(Self::Got),
-            "gqr" => Ok(Self::Gqr),
-            "grc" => Ok(Self::Grc),
-            "grn" => Ok(Self::Grn),
-            "grt" => Ok(Self::Grt),
-            "gso" => Ok(Self::Gso),
-            "gsw" => Ok(Self::Gsw),
-            "gub" => Ok(Self::Gub),
-            "guc" => Ok(Self::Guc),
-            "gud" => Ok(Self::Gud),
-            "gug" => Ok(Self::Gug),
-            "guh" => Ok(Self::Guh),
-            "gui" => Ok(Self::Gui),
-            "guj" => Ok(Self::Guj),
-            "guk" => Ok(Self::Guk),
-            "gul" => Ok(Self::Gul),
-            "gum" => Ok(Self::Gum),
-            "gun" => Ok(Self::Gun),
-            "guo" => Ok(Self::Guo),
-            "guq" => Ok(Self::Guq),
-            "gur" => Ok(Self::Gur),
-            "guw" => Ok(Self::Guw),
-            "gux" => Ok(Self::Gux),
-            "guz" => Ok(Self::Guz),
-            "gvc" => Ok(Self::Gvc),
-            "gvf" => Ok(Self::Gvf),
-            "gvl" => Ok(Self::Gvl),
-            "gvn" => Ok(Self::Gvn),
-            "gwi" => Ok(Self::Gwi),
-            "gxx" => Ok(Self::Gxx),
-            "gya" => Ok(Self::Gya),
-            "gym" => Ok(Self::Gym),
-            "gyr" => Ok(Self::Gyr),
-            "hae" => Ok(Self::Hae),
-            "hag" => Ok(Self::Hag),
-            "hak" => Ok(Self::Hak),
-            "hat" => Ok(Self::Hat),
-            "hau" => Ok(Self::Hau),
-            "hav" => Ok(Self::Hav),
-            "haw" => Ok(Self::Haw),
-            "hay" => Ok(Self::Hay),
-            "hbo" => Ok(Self::Hbo),
-            "hbs" => Ok(Self::Hbs),
-            "hch" => Ok(Self::Hch),
-            "heb" => Ok(Self::Heb),
-            "heg" => Ok(Self::Heg),
-            "heh" => Ok(Self::Heh),
-            "her" => Ok(Self::Her),
-            "hif" => Ok(Self::Hif),
-            "hig" => Ok(Self::Hig),
-            "hil" => Ok(Self::Hil),
-            "hin" => Ok(Self::Hin),
-            "hix" => Ok(Self::Hix),
-            "hla" => Ok(Self::Hla),
-            "hlt" => Ok(Self::Hlt),
-            "hmn" => Ok(Self::Hmn),
-            "hmo" => Ok(Self::Hmo),
-            "hmr" => Ok(Self::Hmr),
-            "hne" => Ok(Self::Hne),
-            "hnj" => Ok(Self::Hnj),
-            "hnn" => Ok(Self::Hnn),
-            "hns" => Ok(Self::Hns),
-            "hoc" => Ok(Self::Hoc),
-            "hop" => Ok(Self::Hop),
-            "hot" => Ok(Self::Hot),
-            "hra" => Ok(Self::Hra),
-            "hrv" => Ok(Self::Hrv),
-            "hrx" => Ok(Self::Hrx),
-            "hsb" => Ok(Self::Hsb),
-            "hto" => Ok(Self::Hto),
-            "hub" => Ok(Self::Hub),
-            "hui" => Ok(Self::Hui),
-            "hun" => Ok(Self::Hun),
-            "hus" => Ok(Self::Hus),
-            "huu" => Ok(Self::Huu),
-            "huv" => Ok(Self::Huv),
-            "hvn" => Ok(Self::Hvn),
-            "hwc" => Ok(Self::Hwc),
-            "hye" => Ok(Self::Hye),
-            "hyw" => Ok(Self::Hyw),
-            "ian" => Ok(Self::Ian),
-            "iba" => Ok(Self::Iba),
-            "ibg" => Ok(Self::Ibg),
-            "ibo" => Ok(Self::Ibo),
-            "icr" => Ok(Self::Icr),
-            "ido" => Ok(Self::Ido),
-            "idu" => Ok(Self::Idu),
-            "ifa" => Ok(Self::Ifa),
-            "ifb" => Ok(Self::Ifb),
-            "ife" => Ok(Self::Ife),
-            "ifk" => Ok(Self::Ifk),
-            "ifu" => Ok(Self::Ifu),
-            "ify" => Ok(Self::Ify),
-            "ige" => Ok(Self::Ige),
-            "ign" => Ok(Self::Ign),
-            "igs" => Ok(Self::Igs),
-            "iii" => Ok(Self::Iii),
-            "ijc" => Ok(Self::Ijc),
-            "ike" => Ok(Self::Ike),
-            "ikk" => Ok(Self::Ikk),
-            "ikw" => Ok(Self::Ikw),
-            "ilb" => Ok(Self::Ilb),
-            "ile" => Ok(Self::Ile),
-            "ilo" => Ok(Self::Ilo),
-            "imo" => Ok(Self::Imo),
-            "ina" => Ok(Self::Ina),
-            "inb" => Ok(Self::Inb),
-            "ind" => Ok(Self::Ind),
-            "ino" => Ok(Self::Ino),
-            "iou" => Ok(Self::Iou),
-            "ipi" => Ok(Self::Ipi),
-            "iqw" => Ok(Self::Iqw),
-            "iri" => Ok(Self::Iri),
-            "irk" => Ok(Self::Irk),
-            "iry" => Ok(Self::Iry),
-            "isd" => Ok(Self::Isd),
-            "ish" => Ok(Self::Ish),
-            "isl" => Ok(Self::Isl),
-            "iso" => Ok(Self::Iso),
-            "ita" => Ok(Self::Ita),
-            "its" => Ok(Self::Its),
-            "itv" => Ok(Self::Itv),
-            "ium" => Ok(Self::Ium),
-            "ivb" => Ok(Self::Ivb),
-            "ivv" => Ok(Self::Ivv),
-            "iws" => Ok(Self::Iws),
-            "ixl" => Ok(Self::Ixl),
-            "izh" => Ok(Self::Izh),
-            "izr" => Ok(Self::Izr),
-            "izz" => Ok(Self::Izz),
-            "jac" => Ok(Self::Jac),
-            "jae" => Ok(Self::Jae),
-            "jam" => Ok(Self::Jam),
-            "jav" => Ok(Self::Jav),
-            "jbo" => Ok(Self::Jbo),
-            "jbu" => Ok(Self::Jbu),
-            "jdt" => Ok(Self::Jdt),
-            "jic" => Ok(Self::Jic),
-            "jiv" => Ok(Self::Jiv),
-            "jmc" => Ok(Self::Jmc),
-            "jmx" => Ok(Self::Jmx),
-            "jpa" => Ok(Self::Jpa),
-            "jpn" => Ok(Self::Jpn),
-            "jra" => Ok(Self::Jra),
-            "jvn" => Ok(Self::Jvn),
-            "kaa" => Ok(Self::Kaa),
-            "kab" => Ok(Self::Kab),
-            "kac" => Ok(Self::Kac),
-            "kal" => Ok(Self::Kal),
-            "kam" => Ok(Self::Kam),
-            "kan" => Ok(Self::Kan),
-            "kao" => Ok(Self::Kao),
-            "kap" => Ok(Self::Kap),
-            "kaq" => Ok(Self::Kaq),
-            "kas" => Ok(Self::Kas),
-            "kat" => Ok(Self::Kat),
-            "kaz" => Ok(Self::Kaz),
-            "kbc" => Ok(Self::Kbc),
-            "kbd" => Ok(Self::Kbd),
-            "kbh" => Ok(Self::Kbh),
-            "kbm" => Ok(Self::Kbm),
-            "kbp" => Ok(Self::Kbp),
-            "kbq" => Ok(Self::Kbq),
-            "kbr" => Ok(Self::Kbr),
-            "kck" => Ok(Self::Kck),
-            "kdc" => Ok(Self::Kdc),
-            "kde" => Ok(Self::Kde),
-            "kdh" => Ok(Self::Kdh),
-            "kdi" => Ok(Self::Kdi),
-            "kdj" => Ok(Self::Kdj),
-            "kdl" => Ok(Self::Kdl),
-            "kea" => Ok(Self::Kea),
-            "kei" => Ok(Self::Kei),
-            "kek" => Ok(Self::Kek),
-            "ken" => Ok(Self::Ken),
-            "kew" => Ok(Self::Kew),
-            "kex" => Ok(Self::Kex),
-            "kez" => Ok(Self::Kez),
-            "kff" => Ok(Self::Kff),
-            "kgf" => Ok(Self::Kgf),
-            "kgk" => Ok(Self::Kgk),
-            "kgp" => Ok(Self::Kgp),
-            "kha" => Ok(Self::Kha),
-            "khk" => Ok(Self::Khk),
-            "khm" => Ok(Self::Khm),
-            "khs" => Ok(Self::Khs),
-            "khy" => Ok(Self::Khy),
-            "khz" => Ok(Self::Khz),
-            "kia" => Ok(Self::Kia),
-            "kik" => Ok(Self::Kik),
-            "kin" => Ok(Self::Kin),
-            "kir" => Ok(Self::Kir),
-            "kiu" => Ok(Self::Kiu),
-            "kix" => Ok(Self::Kix),
-            "kjb" => Ok(Self::Kjb),
-            "kje" => Ok(Self::Kje),
-            "kjh" => Ok(Self::Kjh),
-            "kjs" => Ok(Self::Kjs),
-            "kkc" => Ok(Self::Kkc),
-            "kki" => Ok(Self::Kki),
-            "kkj" => Ok(Self::Kkj),
-            "kkl" => Ok(Self::Kkl),
-            "kln" => Ok(Self::Kln),
-            "klt" => Ok(Self::Klt),
-            "klv" => Ok(Self::Klv),
-            "kma" => Ok(Self::Kma),
-            "kmb" => Ok(Self::Kmb),
-            "kmg" => Ok(Self::Kmg),
-            "kmh" => Ok(Self::Kmh),
-            "kmk" => Ok(Self::Kmk),
-            "kmm" => Ok(Self::Kmm),
-            "kmo" => Ok(Self::Kmo),
-            "kmr" => Ok(Self::Kmr),
-            "kms" => Ok(Self::Kms),
-            "kmu" => Ok(Self::Kmu),
-            "knc" => Ok(Self::Knc),
-            "kne" => Ok(Self::Kne),
-            "knf" => Ok(Self::Knf),
-            "kng" => Ok(Self::Kng),
-            "knj" => Ok(Self::Knj),
-            "knk" => Ok(Self::Knk),
-            "kno" => Ok(Self::Kno),
-            "knv" => Ok(Self::Knv),
-            "knx" => Ok(Self::Knx),
-            "kny" => Ok(Self::Kny),
-            "kog" => Ok(Self::Kog),
-            "koi" => Ok(Self::Koi),
-            "kom" => Ok(Self::Kom),
-            "kon" => Ok(Self::Kon),
-            "koo" => Ok(Self::Koo),
-            "kor" => Ok(Self::Kor),
-            "kos" => Ok(Self::Kos),
-            "kpf" => Ok(Self::Kpf),
-            "kpg" => Ok(Self::Kpg),
-            "kpj" => Ok(Self::Kpj),
-            "kpr" => Ok(Self::Kpr),
-            "kpv" => Ok(Self::Kpv),
-            "kpw" => Ok(Self::Kpw),
-            "kpx" => Ok(Self::Kpx),
-            "kpz" => Ok(Self::Kpz),
-            "kqc" => Ok(Self::Kqc),
-            "kqe" => Ok(Self::Kqe),
-            "kqf" => Ok(Self::Kqf),
-            "kql" => Ok(Self::Kql),
-            "kqn" => Ok(Self::Kqn),
-            "kqo" => Ok(Self::Kqo),
-            "kqp" => Ok(Self::Kqp),
-            "kqs" => Ok(Self::Kqs),
-            "kqw" => Ok(Self::Kqw),
-            "kqy" => Ok(Self::Kqy),
-            "krc" => Ok(Self::Krc),
-            "kri" => Ok(Self::Kri),
-            "krj" => Ok(Self::Krj),
-            "krl" => Ok(Self::Krl),
-            "kru" => Ok(Self::Kru),
-            "ksb" => Ok(Self::Ksb),
-            "ksc" => Ok(Self::Ksc),
-            "ksd" => Ok(Self::Ksd),
-            "ksf" => Ok(Self::Ksf),
-            "ksh" => Ok(Self::Ksh),
-            "ksj" => Ok(Self::Ksj),
-            "ksp" => Ok(Self::Ksp),
-            "ksr" => Ok(Self::Ksr),
-            "kss" => Ok(Self::Kss),
-            "ksw" => Ok(Self::Ksw),
-            "ktb" => Ok(Self::Ktb),
-            "ktj" => Ok(Self::Ktj),
-            "ktm" => Ok(Self::Ktm),
-            "kto" => Ok(Self::Kto),
-            "ktu" => Ok(Self::Ktu),
-            "kua" => Ok(Self::Kua),
-            "kub" => Ok(Self::Kub),
-            "kud" => Ok(Self::Kud),
-            "kue" => Ok(Self::Kue),
-            "kuj" => Ok(Self::Kuj),
-            "kum" => Ok(Self::Kum),
-            "kup" => Ok(Self::Kup),
-            "kus" => Ok(Self::Kus),
-            "kvj" => Ok(Self::Kvj),
-            "kvn" => Ok(Self::Kvn),
-            "kwd" => Ok(Self::Kwd),
-            "kwf" => Ok(Self::Kwf),
-            "kwi" => Ok(Self::Kwi),
-            "kwj" => Ok(Self::Kwj),
-            "kwn" => Ok(Self::Kwn),
-            "kwy" => Ok(Self::Kwy),
-            "kxc" => Ok(Self::Kxc),
-            "kxm" => Ok(Self::Kxm),
-            "kxw" => Ok(Self::Kxw),
-            "kyc" => Ok(Self::Kyc),
-            "kyf" => Ok(Self::Kyf),
-            "kyg" => Ok(Self::Kyg),
-            "kyq" => Ok(Self::Kyq),
-            "kyu" => Ok(Self::Kyu),
-            "kyz" => Ok(Self::Kyz),
-            "kze" => Ok(Self::Kze),
-            "kzf" => Ok(Self::Kzf),
-            "kzj" => Ok(Self::Kzj),
-            "kzn" => Ok(Self::Kzn),
-            "lac" => Ok(Self::Lac),
-            "lad" => Ok(Self::Lad),
-            "lai" => Ok(Self::Lai),
-            "laj" => Ok(Self::Laj),
-            "lam" => Ok(Self::Lam),
-            "lao" => Ok(Self::Lao),
-            "las" => Ok(Self::Las),
-            "lat" => Ok(Self::Lat),
-            "lav" => Ok(Self::Lav),
-            "lbb" => Ok(Self::Lbb),
-            "lbe" => Ok(Self::Lbe),
-            "lbj" => Ok(Self::Lbj),
-            "lbk" => Ok(Self::Lbk),
-            "lch" => Ok(Self::Lch),
-            "lcm" => Ok(Self::Lcm),
-            "ldi" => Ok(Self::Ldi),
-            "ldn" => Ok(Self::Ldn),
-            "lea" => Ok(Self::Lea),
-            "led" => Ok(Self::Led),
-            "lee" => Ok(Self::Lee),
-            "lef" => Ok(Self::Lef),
-            "leh" => Ok(Self::Leh),
-            "lem" => Ok(Self::Lem),
-            "leu" => Ok(Self::Leu),
-            "lew" => Ok(Self::Lew),
-            "lex" => Ok(Self::Lex),
-            "lez" => Ok(Self::Lez),
-            "lfn" => Ok(Self::Lfn),
-            "lgm" => Ok(Self::Lgm),
-            "lhi" => Ok(Self::Lhi),
-            "lhm" => Ok(Self::Lhm),
-            "lhu" => Ok(Self::Lhu),
-            "lia" => Ok(Self::Lia),
-            "lid" => Ok(Self::Lid),
-            "lif" => Ok(Self::Lif),
-            "lij" => Ok(Self::Lij),
-            "lim" => Ok(Self::Lim),
-            "lin" => Ok(Self::Lin),
-            "lip" => Ok(Self::Lip),
-            "lir" => Ok(Self::Lir),
-            "lit" => Ok(Self::Lit),
-            "liv" => Ok(Self::Liv),
-            "ljp" => Ok(Self::Ljp),
-            "llb" => Ok(Self::Llb),
-            "lld" => Ok(Self::Lld),
-            "lln" => Ok(Self::Lln),
-            "lmk" => Ok(Self::Lmk),
-            "lmo" => Ok(Self::Lmo),
-            "lmp" => Ok(Self::Lmp),
-            "lob" => Ok(Self::Lob),
-            "loe" => Ok(Self::Loe),
-            "log" => Ok(Self::Log),
-            "lol" => Ok(Self::Lol),
-            "lom" => Ok(Self::Lom),
-            "loq" => Ok(Self::Loq),
-            "loz" => Ok(Self::Loz),
-            "lsi" => Ok(Self::Lsi),
-            "lsm" => Ok(Self::Lsm),
-            "ltg" => Ok(Self::Ltg),
-            "ltz" => Ok(Self::Ltz),
-            "lua" => Ok(Self::Lua),
-            "lub" => Ok(Self::Lub),
-            "lue" => Ok(Self::Lue),
-            "lug" => Ok(Self::Lug),
-            "lun" => Ok(Self::Lun),
-            "luo" => Ok(Self::Luo),
-            "lus" => Ok(Self::Lus),
-            "lut" => Ok(Self::Lut),
-            "lvs" => Ok(Self::Lvs),
-            "lwo" => Ok(Self::Lwo),
-            "lww" => Ok(Self::Lww),
-            "lzh" => Ok(Self::Lzh),
-            "lzz" => Ok(Self::Lzz),
-            "maa" => Ok(Self::Maa),
-            "mad" => Ok(Self::Mad),
-            "maf" => Ok(Self::Maf),
-            "mag" => Ok(Self::Mag),
-            "mah" => Ok(Self::Mah),
-            "mai" => Ok(Self::Mai),
-            "maj" => Ok(Self::Maj),
-            "mak" => Ok(Self::Mak),
-            "mal" => Ok(Self::Mal),
-            "mam" => Ok(Self::Mam),
-            "maq" => Ok(Self::Maq),
-            "mar" => Ok(Self::Mar),
-            "mas" => Ok(Self::Mas),
-            "mau" => Ok(Self::Mau),
-            "mav" => Ok(Self::Mav),
-            "maw" => Ok(Self::Maw),
-            "max" => Ok(Self::Max),
-            "maz" => Ok(Self::Maz),
-            "mbb" => Ok(Self::Mbb),
-            "mbc" => Ok(Self::Mbc),
-            "mbd" => Ok(Self::Mbd),
-            "mbf" => Ok(Self::Mbf),
-            "mbh" => Ok(Self::Mbh),
-            "mbi" => Ok(Self::Mbi),
-            "mbj" => Ok(Self::Mbj),
-            "mbl" => Ok(Self::Mbl),
-            "mbs" => Ok(Self::Mbs),
-            "mbt" => Ok(Self::Mbt),
-            "mca" => Ok(Self::Mca),
-            "mcb" => Ok(Self::Mcb),
-            "mcd" => Ok(Self::Mcd),
-            "mcf" => Ok(Self::Mcf),
-            "mck" => Ok(Self::Mck),
-            "mcn" => Ok(Self::Mcn),
-            "mco" => Ok(Self::Mco),
-            "mcp" => Ok(Self::Mcp),
-            "mcq" => Ok(Self::Mcq),
-            "mcu" => Ok(Self::Mcu),
-            "mda" => Ok(Self::Mda),
-            "mdf" => Ok(Self::Mdf),
-            "mdy" => Ok(Self::Mdy),
-            "med" => Ok(Self::Med),
-            "mee" => Ok(Self::Mee),
-            "meh" => Ok(Self::Meh),
-            "mej" => Ok(Self::Mej),
-            "mek" => Ok(Self::Mek),
-            "men" => Ok(Self::Men),
-            "meq" => Ok(Self::Meq),
-            "mer" => Ok(Self::Mer),
-            "meu" => Ok(Self::Meu),
-            "mev" => Ok(Self::Mev),
-            "mfa" => Ok(Self::Mfa),
-            "mfe" => Ok(Self::Mfe),
-            "mfh" => Ok(Self::Mfh),
-            "mfi" => Ok(Self::Mfi),
-            "mfk" => Ok(Self::Mfk),
-            "mfq" => Ok(Self::Mfq),
-            "mfy" => Ok(Self::Mfy),
-            "mfz" => Ok(Self::Mfz),
-            "mgc" => Ok(Self::Mgc),
-            "mgh" => Ok(Self::Mgh),
-            "mgm" => Ok(Self::Mgm),
-            "mgo" => Ok(Self::Mgo),
-            "mgr" => Ok(Self::Mgr),
-            "mgv" => Ok(Self::Mgv),
-            "mhi" => Ok(Self::Mhi),
-            "mhl" => Ok(Self::Mhl),
-            "mhr" => Ok(Self::Mhr),
-            "mhw" => Ok(Self::Mhw),
-            "mhx" => Ok(Self::Mhx),
-            "mhy" => Ok(Self::Mhy),
-            "mib" => Ok(Self::Mib),
-            "mic" => Ok(Self::Mic),
-            "mie" => Ok(Self::Mie),
-            "mif" => Ok(Self::Mif),
-            "mig" => Ok(Self::Mig),
-            "mih" => Ok(Self::Mih),
-            "mik" => Ok(Self::Mik),
-            "mil" => Ok(Self::Mil),
-            "min" => Ok(Self::Min),
-            "mio" => Ok(Self::Mio),
-            "miq" => Ok(Self::Miq),
-            "mir" => Ok(Self::Mir),
-            "mit" => Ok(Self::Mit),
-            "miy" => Ok(Self::Miy),
-            "miz" => Ok(Self::Miz),
-            "mjc" => Ok(Self::Mjc),
-            "mjw" => Ok(Self::Mjw),
-            "mkd" => Ok(Self::Mkd),
-            "mkl" => Ok(Self::Mkl),
-            "mkn" => Ok(Self::Mkn),
-            "mks" => Ok(Self::Mks),
-            "mkz" => Ok(Self::Mkz),
-            "mlg" => Ok(Self::Mlg),
-            "mlh" => Ok(Self::Mlh),
-            "mlp" => Ok(Self::Mlp),
-            "mlt" => Ok(Self::Mlt),
-            "mlu" => Ok(Self::Mlu),
-            "mmn" => Ok(Self::Mmn),
-            "mmo" => Ok(Self::Mmo),
-            "mmx" => Ok(Self::Mmx),
-            "mna" => Ok(Self::Mna),
-            "mnb" => Ok(Self::Mnb),
-            "mnf" => Ok(Self::Mnf),
-            "mni" => Ok(Self::Mni),
-            "mnk" => Ok(Self::Mnk),
-            "mnx" => Ok(Self::Mnx),
-            "mny" => Ok(Self::Mny),
-            "moa" => Ok(Self::Moa),
-            "moc" => Ok(Self::Moc),
-            "mog" => Ok(Self::Mog),
-            "moh" => Ok(Self::Moh),
-            "mon" => Ok(Self::Mon),
-            "mop" => Ok(Self::Mop),
-            "mor" => Ok(Self::Mor),
-            "mos" => Ok(Self::Mos),
-            "mox" => Ok(Self::Mox),
-            "mpg" => Ok(Self::Mpg),
-            "mph" => Ok(Self::Mph),
-            "mpm" => Ok(Self::Mpm),
-            "mpp" => Ok(Self::Mpp),
-            "mps" => Ok(Self::Mps),
-            "mpt" => Ok(Self::Mpt),
-            "mpx" => Ok(Self::Mpx),
-            "mqb" => Ok(Self::Mqb),
-            "mqj" => Ok(Self::Mqj),
-            "mqy" => Ok(Self::Mqy),
-            "mrg" => Ok(Self::Mrg),
-            "mri" => Ok(Self::Mri),
-            "mrj" => Ok(Self::Mrj),
-            "mrq" => Ok(Self::Mrq),
-            "mrv" => Ok(Self::Mrv),
-            "mrw" => Ok(Self::Mrw),
-            "msa" => Ok(Self::Msa),
-            "msb" => Ok(Self::Msb),
-            "msc" => Ok(Self::Msc),
-            "mse" => Ok(Self::Mse),
-            "msk" => Ok(Self::Msk),
-            "msm" => Ok(Self::Msm),
-            "msy" => Ok(Self::Msy),
-            "mta" => Ok(Self::Mta),
-            "mtg" => Ok(Self::Mtg),
-            "mti" => Ok(Self::Mti),
-            "mtj" => Ok(Self::Mtj),
-            "mto" => Ok(Self::Mto),
-            "mtp" => Ok(Self::Mtp),
-            "mua" => Ok(Self::Mua),
-            "mug" => Ok(Self::Mug),
-            "muh" => Ok(Self::Muh),
-            "mur" => Ok(Self::Mur),
-            "mus" => Ok(Self::Mus),
-            "mux" => Ok(Self::Mux),
-            "muy" => Ok(Self::Muy),
-            "mva" => Ok(Self::Mva),
-            "mvn" => Ok(Self::Mvn),
-            "mvp" => Ok(Self::Mvp),
-            "mwc" => Ok(Self::Mwc),
-            "mwf" => Ok(Self::Mwf),
-            "mwl" => Ok(Self::Mwl),
-            "mwm" => Ok(Self::Mwm),
-            "mwn" => Ok(Self::Mwn),
-            "mwp" => Ok(Self::Mwp),
-            "mwq" => Ok(Self::Mwq),
-            "mwv" => Ok(Self::Mwv),
-            "mww" => Ok(Self::Mww),
-            "mxb" => Ok(Self::Mxb),
-            "mxp" => Ok(Self::Mxp),
-            "mxq" => Ok(Self::Mxq),
-            "mxt" => Ok(Self::Mxt),
-            "mxv" => Ok(Self::Mxv),
-            "mya" => Ok(Self::Mya),
-            "myb" => Ok(Self::Myb),
-            "myk" => Ok(Self::Myk),
-            "myu" => Ok(Self::Myu),
-            "myv" => Ok(Self::Myv),
-            "myw" => Ok(Self::Myw),
-            "myx" => Ok(Self::Myx),
-            "myy" => Ok(Self::Myy),
-            "mza" => Ok(Self::Mza),
-            "mzh" => Ok(Self::Mzh),
-            "mzk" => Ok(Self::Mzk),
-            "mzl" => Ok(Self::Mzl),
-            "mzm" => Ok(Self::Mzm),
-            "mzn" => Ok(Self::Mzn),
-            "mzw" => Ok(Self::Mzw),
-            "mzz" => Ok(Self::Mzz),
-            "nab" => Ok(Self::Nab),
-            "naf" => Ok(Self::Naf),
-            "nah" => Ok(Self::Nah),
-            "nak" => Ok(Self::Nak),
-            "nan" => Ok(Self::Nan),
-            "nap" => Ok(Self::Nap),
-            "naq" => Ok(Self::Naq),
-            "nas" => Ok(Self::Nas),
-            "nav" => Ok(Self::Nav),
-            "naw" => Ok(Self::Naw),
-            "nba" => Ok(Self::Nba),
-            "nbc" => Ok(Self::Nbc),
-            "nbe" => Ok(Self::Nbe),
-            "nbl" => Ok(Self::Nbl),
-            "nbq" => Ok(Self::Nbq),
-            "nbu" => Ok(Self::Nbu),
-            "nca" => Ok(Self::Nca),
-            "nch" => Ok(Self::Nch),
-            "ncj" => Ok(Self::Ncj),
-            "ncl" => Ok(Self::Ncl),
-            "nct" => Ok(Self::Nct),
-            "ncu" => Ok(Self::Ncu),
-            "ncx" => Ok(Self::Ncx),
-            "ndc" => Ok(Self::Ndc),
-            "nde" => Ok(Self::Nde),
-            "ndh" => Ok(Self::Ndh),
-            "ndi" => Ok(Self::Ndi),
-            "ndj" => Ok(Self::Ndj),
-            "ndo" => Ok(Self::Ndo),
-            "ndp" => Ok(Self::Ndp),
-            "nds" => Ok(Self::Nds),
-            "ndz" => Ok(Self::Ndz),
-            "neb" => Ok(Self::Neb),
-            "nep" => Ok(Self::Nep),
-            "new" => Ok(Self::New),
-            "nfa" => Ok(Self::Nfa),
-            "nfr" => Ok(Self::Nfr),
-            "ngb" => Ok(Self::Ngb),
-            "ngc" => Ok(Self::Ngc),
-            "ngl" => Ok(Self::Ngl),
-            "ngp" => Ok(Self::Ngp),
-            "ngu" => Ok(Self::Ngu),
-            "nhd" => Ok(Self::Nhd),
-            "nhe" => Ok(Self::Nhe),
-            "nhg" => Ok(Self::Nhg),
-            "nhi" => Ok(Self::Nhi),
-            "nhk" => Ok(Self::Nhk),
-            "nho" => Ok(Self::Nho),
-            "nhr" => Ok(Self::Nhr),
-            "nhu" => Ok(Self::Nhu),
-            "nhw" => Ok(Self::Nhw),
-            "nhx" => Ok(Self::Nhx),
-            "nhy" => Ok(Self::Nhy),
-            "nia" => Ok(Self::Nia),
-            "nif" => Ok(Self::Nif),
-            "nii" => Ok(Self::Nii),
-            "nij" => Ok(Self::Nij),
-            "nim" => Ok(Self::Nim),
-            "nin" => Ok(Self::Nin),
-            "niq" => Ok(Self::Niq),
-            "niu" => Ok(Self::Niu),
-            "niy" => Ok(Self::Niy),
-            "njb" => Ok(Self::Njb),
-            "njm" => Ok(Self::Njm),
-            "njn" => Ok(Self::Njn),
-            "njo" => Ok(Self::Njo),
-            "njz" => Ok(Self::Njz),
-            "nka" => Ok(Self::Nka),
-            "nki" => Ok(Self::Nki),
-            "nko" => Ok(Self::Nko),
-            "nla" => Ok(Self::Nla),
-            "nlc" => Ok(Self::Nlc),
-            "nld" => Ok(Self::Nld),
-            "nma" => Ok(Self::Nma),
-            "nmf" => Ok(Self::Nmf),
-            "nmh" => Ok(Self::Nmh),
-            "nmo" => Ok(Self::Nmo),
-            "nmw" => Ok(Self::Nmw),
-            "nmz" => Ok(Self::Nmz),
-            "nnb" => Ok(Self::Nnb),
-            "nng" => Ok(Self::Nng),
-            "nnh" => Ok(Self::Nnh),
-            "nnl" => Ok(Self::Nnl),
-            "nno" => Ok(Self::Nno),
-            "nnp" => Ok(Self::Nnp),
-            "nnq" => Ok(Self::Nnq),
-            "nnw" => Ok(Self::Nnw),
-            "noa" => Ok(Self::Noa),
-            "nob" => Ok(Self::Nob),
-            "nog" => Ok(Self::Nog),
-            "nop" => Ok(Self::Nop),
-            "nor" => Ok(Self::Nor),
-            "not" => Ok(Self::Not),
-            "nou" => Ok(Self::Nou),
-            "nov" => Ok(Self::Nov),
-            "nph" => Ok(Self::Nph),
-            "npi" => Ok(Self::Npi),
-            "npl" => Ok(Self::Npl),
-            "npo" => Ok(Self::Npo),
-            "npy" => Ok(Self::Npy),
-            "nre" => Ok(Self::Nre),
-            "nrf" => Ok(Self::Nrf),
-            "nri" => Ok(Self::Nri),
-            "nsa" => Ok(Self::Nsa),
-            "nse" => Ok(Self::Nse),
-            "nsm" => Ok(Self::Nsm),
-            "nsn" => Ok(Self::Nsn),
-            "nso" => Ok(Self::Nso),
-            "nss" => Ok(Self::Nss),
-            "nst" => Ok(Self::Nst),
-            "nsu" => Ok(Self::Nsu),
-            "ntp" => Ok(Self::Ntp),
-            "ntr" => Ok(Self::Ntr),
-            "nus" => Ok(Self::Nus),
-            "nuy" => Ok(Self::Nuy),
-            "nvm" => Ok(Self::Nvm),
-            "nwb" => Ok(Self::Nwb),
-            "nwi" => Ok(Self::Nwi),
-            "nwx" => Ok(Self::Nwx),
-            "nxd" => Ok(Self::Nxd),
-            "nya" => Ok(Self::Nya),
-            "nyf" => Ok(Self::Nyf),
-            "nyk" => Ok(Self::Nyk),
-            "nyn" => Ok(Self::Nyn),
-            "nyo" => Ok(Self::Nyo),
-            "nyu" => Ok(Self::Nyu),
-            "nyy" => Ok(Self::Nyy),
-            "nzb" => Ok(Self::Nzb),
-            "nzi" => Ok(Self::Nzi),
-            "nzm" => Ok(Self::Nzm),
-            "obo" => Ok(Self::Obo),
-            "oci" => Ok(Self::Oci),
-            "ogo" => Ok(Self::Ogo),
-            "ojb" => Ok(Self::Ojb),
-            "oji" => Ok(Self::Oji),
-            "ojs" => Ok(Self::Ojs),
-            "oke" => Ok(Self::Oke),
-            "okv" => Ok(Self::Okv),
-            "old" => Ok(Self::Old),
-            "omw" => Ok(Self::Omw),
-            "ong" => Ok(Self::Ong),
-            "ons" => Ok(Self::Ons),
-            "ood" => Ok(Self::Ood),
-            "opm" => Ok(Self::Opm),
-            "ori" => Ok(Self::Ori),
-            "orm" => Ok(Self::Orm),
-            "orv" => Ok(Self::Orv),
-            "ory" => Ok(Self::Ory),
-            "oss" => Ok(Self::Oss),
-            "ota" => Ok(Self::Ota),
-            "ote" => Ok(Self::Ote),
-            "otm" => Ok(Self::Otm),
-            "otn" => Ok(Self::Otn),
-            "otq" => Ok(Self::Otq),
-            "ots" => Ok(Self::Ots),
-            "otw" => Ok(Self::Otw),
-            "oym" => Ok(Self::Oym),
-            "ozm" => Ok(Self::Ozm),
-            "pab" => Ok(Self::Pab),
-            "pad" => Ok(Self::Pad),
-            "pag" => Ok(Self::Pag),
-            "pah" => Ok(Self::Pah),
-            "pam" => Ok(Self::Pam),
-            "pan" => Ok(Self::Pan),
-            "pao" => Ok(Self::Pao),
-            "pap" => Ok(Self::Pap),
-            "pau" => Ok(Self::Pau),
-            "pbb" => Ok(Self::Pbb),
-            "pbc" => Ok(Self::Pbc),
-            "pbi" => Ok(Self::Pbi),
-            "pbl" => Ok(Self::Pbl),
-            "pbt" => Ok(Self::Pbt),
-            "pcd" => Ok(Self::Pcd),
-            "pck" => Ok(Self::Pck),
-            "pcm" => Ok(Self::Pcm),
-            "pdc" => Ok(Self::Pdc),
-            "pdt" => Ok(Self::Pdt),
-            "pem" => Ok(Self::Pem),
-            "pes" => Ok(Self::Pes),
-            "pfe" => Ok(Self::Pfe),
-            "pfl" => Ok(Self::Pfl),
-            "phm" => Ok(Self::Phm),
-            "pib" => Ok(Self::Pib),
-            "pid" => Ok(Self::Pid),
-            "pio" => Ok(Self::Pio),
-            "pir" => Ok(Self::Pir),
-            "pis" => Ok(Self::Pis),
-            "pjt" => Ok(Self::Pjt),
-            "pkb" => Ok(Self::Pkb),
-            "plg" => Ok(Self::Plg),
-            "pls" => Ok(Self::Pls),
-            "plt" => Ok(Self::Plt),
-            "plu" => Ok(Self::Plu),
-            "plw" => Ok(Self::Plw),
-            "pma" => Ok(Self::Pma),
-            "pmf" => Ok(Self::Pmf),
-            "pms" => Ok(Self::Pms),
-            "pmx" => Ok(Self::Pmx),
-            "pnb" => Ok(Self::Pnb),
-            "pne" => Ok(Self::Pne),
-            "poe" => Ok(Self::Poe),
-            "poh" => Ok(Self::Poh),
-            "poi" => Ok(Self::Poi),
-            "pol" => Ok(Self::Pol),
-            "pon" => Ok(Self::Pon),
-            "por" => Ok(Self::Por),
-            "pot" => Ok(Self::Pot),
-            "pov" => Ok(Self::Pov),
-            "poy" => Ok(Self::Poy),
-            "ppk" => Ok(Self::Ppk),
-            "ppl" => Ok(Self::Ppl),
-            "ppo" => Ok(Self::Ppo),
-            "pps" => Ok(Self::Pps),
-            "prf" => Ok(Self::Prf),
-            "prg" => Ok(Self::Prg),
-            "pri" => Ok(Self::Pri),
-            "prk" => Ok(Self::Prk),
-            "prs" => Ok(Self::Prs),
-            "pse" => Ok(Self::Pse),
-            "ptp" => Ok(Self::Ptp),
-            "ptu" => Ok(Self::Ptu),
-            "pua" => Ok(Self::Pua),
-            "pus" => Ok(Self::Pus),
-            "pwg" => Ok(Self::Pwg),
-            "pww" => Ok(Self::Pww),
-            "qub" => Ok(Self::Qub),
-            "quc" => Ok(Self::Quc),
-            "que" => Ok(Self::Que),
-            "quf" => Ok(Self::Quf),
-            "qug" => Ok(Self::Qug),
-            "quh" => Ok(Self::Quh),
-            "qul" => Ok(Self::Qul),
-            "qup" => Ok(Self::Qup),
-            "qus" => Ok(Self::Qus),
-            "quw" => Ok(Self::Quw),
-            "quy" => Ok(Self::Quy),
-            "quz" => Ok(Self::Quz),
-            "qva" => Ok(Self::Qva),
-            "qvc" => Ok(Self::Qvc),
-            "qve" => Ok(Self::Qve),
-            "qvh" => Ok(Self::Qvh),
-            "qvi" => Ok(Self::Qvi),
-            "qvm" => Ok(Self::Qvm),
-            "qvn" => Ok(Self::Qvn),
-            "qvo" => Ok(Self::Qvo),
-            "qvs" => Ok(Self::Qvs),
-            "qvw" => Ok(Self::Qvw),
-            "qvz" => Ok(Self::Qvz),
-            "qwh" => Ok(Self::Qwh),
-            "qxh" => Ok(Self::Qxh),
-            "qxl" => Ok(Self::Qxl),
-            "qxn" => Ok(Self::Qxn),
-            "qxo" => Ok(Self::Qxo),
-            "qxr" => Ok(Self::Qxr),
-            "qya" => Ok(Self::Qya),
-            "rad" => Ok(Self::Rad),
-            "rai" => Ok(Self::Rai),
-            "rap" => Ok(Self::Rap),
-            "rar" => Ok(Self::Rar),
-            "rcf" => Ok(Self::Rcf),
-            "rhg" => Ok(Self::Rhg),
-            "ria" => Ok(Self::Ria),
-            "rif" => Ok(Self::Rif),
-            "rim" => Ok(Self::Rim),
-            "rkb" => Ok(Self::Rkb),
-            "rmc" => Ok(Self::Rmc),
-            "rme" => Ok(Self::Rme),
-            "rml" => Ok(Self::Rml),
-            "rmn" => Ok(Self::Rmn),
-            "rmo" => Ok(Self::Rmo),
-            "rmq" => Ok(Self::Rmq),
-            "rmy" => Ok(Self::Rmy),
-            "rnd" => Ok(Self::Rnd),
-            "rng" => Ok(Self::Rng),
-            "rnl" => Ok(Self::Rnl),
-            "roh" => Ok(Self::Roh),
-            "rom" => Ok(Self::Rom),
-            "ron" => Ok(Self::Ron),
-            "roo" => Ok(Self::Roo),
-            "rop" => Ok(Self::Rop),
-            "rro" => Ok(Self::Rro),
-            "rtm" => Ok(Self::Rtm),
-            "rub" => Ok(Self::Rub),
-            "rue" => Ok(Self::Rue),
-            "ruf" => Ok(Self::Ruf),
-            "run" => Ok(Self::Run),
-            "rup" => Ok(Self::Rup),
-            "rus" => Ok(Self::Rus),
-            "rwo" => Ok(Self::Rwo),
-            "sab" => Ok(Self::Sab),
-            "sag" => Ok(Self::Sag),
-            "sah" => Ok(Self::Sah),
-            "san" => Ok(Self::San),
-            "sas" => Ok(Self::Sas),
-            "sat" => Ok(Self::Sat),
-            "sba" => Ok(Self::Sba),
-            "sbd" => Ok(Self::Sbd),
-            "sbe" => Ok(Self::Sbe),
-            "sbl" => Ok(Self::Sbl),
-            "sbs" => Ok(Self::Sbs),
-            "sby" => Ok(Self::Sby),
-            "sck" => Ok(Self::Sck),
-            "scn" => Ok(Self::Scn),
-            "sco" => Ok(Self::Sco),
-            "sda" => Ok(Self::Sda),
-            "sdh" => Ok(Self::Sdh),
-            "sdo" => Ok(Self::Sdo),
-            "seh" => Ok(Self::Seh),
-            "ses" => Ok(Self::Ses),
-            "sey" => Ok(Self::Sey),
-            "sfw" => Ok(Self::Sfw),
-            "sgb" => Ok(Self::Sgb),
-            "sgh" => Ok(Self::Sgh),
-            "sgs" => Ok(Self::Sgs),
-            "sgw" => Ok(Self::Sgw),
-            "sgz" => Ok(Self::Sgz),
-            "shi" => Ok(Self::Shi),
-            "shk" => Ok(Self::Shk),
-            "shn" => Ok(Self::Shn),
-            "shp" => Ok(Self::Shp),
-            "shr" => Ok(Self::Shr),
-            "shs" => Ok(Self::Shs),
-            "shu" => Ok(Self::Shu),
-            "shy" => Ok(Self::Shy),
-            "sid" => Ok(Self::Sid),
-            "sig" => Ok(Self::Sig),
-            "sil" => Ok(Self::Sil),
-            "sim" => Ok(Self::Sim),
-            "sin" => Ok(Self::Sin),
-            "sja" => Ok(Self::Sja),
-            "sjn" => Ok(Self::Sjn),
-            "skg" => Ok(Self::Skg),
-            "skr" => Ok(Self::Skr),
-            "sld" => Ok(Self::Sld),
-            "slk" => Ok(Self::Slk),
-            "sll" => Ok(Self::Sll),
-            "slv" => Ok(Self::Slv),
-            "sma" => Ok(Self::Sma),
-            "sme" => Ok(Self::Sme),
-            "smk" => Ok(Self::Smk),
-            "sml" => Ok(Self::Sml),
-            "smo" => Ok(Self::Smo),
-            "smt" => Ok(Self::Smt),
-            "sna" => Ok(Self::Sna),
-            "snc" => Ok(Self::Snc),
-            "snd" => Ok(Self::Snd),
-            "snf" => Ok(Self::Snf),
-            "snn" => Ok(Self::Snn),
-            "snp" => Ok(Self::Snp),
-            "snw" => Ok(Self::Snw),
-            "sny" => Ok(Self::Sny),
-            "soe" => Ok(Self::Soe),
-            "som" => Ok(Self::Som),
-            "sop" => Ok(Self::Sop),
-            "soq" => Ok(Self::Soq),
-            "sot" => Ok(Self::Sot),
-            "soy" => Ok(Self::Soy),
-            "spa" => Ok(Self::Spa),
-            "spl" => Ok(Self::Spl),
-            "spm" => Ok(Self::Spm),
-            "spp" => Ok(Self::Spp),
-            "sps" => Ok(Self::Sps),
-            "spy" => Ok(Self::Spy),
-            "sqi" => Ok(Self::Sqi),
-            "srd" => Ok(Self::Srd),
-            "sri" => Ok(Self::Sri),
-            "srm" => Ok(Self::Srm),
-            "srn" => Ok(Self::Srn),
-            "srp" => Ok(Self::Srp),
-            "srq" => Ok(Self::Srq),
-            "srr" => Ok(Self::Srr),
-            "ssd" => Ok(Self::Ssd),
-            "ssg" => Ok(Self::Ssg),
-            "ssw" => Ok(Self::Ssw),
-            "ssx" => Ok(Self::Ssx),
-            "stn" => Ok(Self::Stn),
-            "stp" => Ok(Self::Stp),
-            "stq" => Ok(Self::Stq),
-            "sua" => Ok(Self::Sua),
-            "suc" => Ok(Self::Suc),
-            "sue" => Ok(Self::Sue),
-            "suk" => Ok(Self::Suk),
-            "sun" => Ok(Self::Sun),
-            "sur" => Ok(Self::Sur),
-            "sus" => Ok(Self::Sus),
-            "sux" => Ok(Self::Sux),
-            "suz" => Ok(Self::Suz),
-            "swa" => Ok(Self::Swa),
-            "swb" => Ok(Self::Swb),
-            "swc" => Ok(Self::Swc),
-            "swe" => Ok(Self::Swe),
-            "swg" => Ok(Self::Swg),
-            "swh" => Ok(Self::Swh),
-            "swk" => Ok(Self::Swk),
-            "swp" => Ok(Self::Swp),
-            "sxb" => Ok(Self::Sxb),
-            "sxn" => Ok(Self::Sxn),
-            "syb" => Ok(Self::Syb),
-            "syc" => Ok(Self::Syc),
-            "szb" => Ok(Self::Szb),
-            "szl" => Ok(Self::Szl),
-            "tab" => Ok(Self::Tab),
-            "tac" => Ok(Self::Tac),
-            "tah" => Ok(Self::Tah),
-            "taj" => Ok(Self::Taj),
-            "tam" => Ok(Self::Tam),
-            "tap" => Ok(Self::Tap),
-            "taq" => Ok(Self::Taq),
-            "tar" => Ok(Self::Tar),
-            "tat" => Ok(Self::Tat),
-            "tav" => Ok(Self::Tav),
-            "taw" => Ok(Self::Taw),
-            "tbc" => Ok(Self::Tbc),
-            "tbg" => Ok(Self::Tbg),
-            "tbk" => Ok(Self::Tbk),
-            "tbl" => Ok(Self::Tbl),
-            "tbo" => Ok(Self::Tbo),
-            "tby" => Ok(Self::Tby),
-            "tbz" => Ok(Self::Tbz),
-            "tca" => Ok(Self::Tca),
-            "tcc" => Ok(Self::Tcc),
-            "tcf" => Ok(Self::Tcf),
-            "tcs" => Ok(Self::Tcs),
-            "tcy" => Ok(Self::Tcy),
-            "tcz" => Ok(Self::Tcz),
-            "tdt" => Ok(Self::Tdt),
-            "tdx" => Ok(Self::Tdx),
-            "ted" => Ok(Self::Ted),
-            "tee" => Ok(Self::Tee),
-            "tel" => Ok(Self::Tel),
-            "tem" => Ok(Self::Tem),
-            "teo" => Ok(Self::Teo),
-            "ter" => Ok(Self::Ter),
-            "tet" => Ok(Self::Tet),
-            "tew" => Ok(Self::Tew),
-            "tfr" => Ok(Self::Tfr),
-            "tgk" => Ok(Self::Tgk),
-            "tgl" => Ok(Self::Tgl),
-            "tgo" => Ok(Self::Tgo),
-            "tgp" => Ok(Self::Tgp),
-            "tha" => Ok(Self::Tha),
-            "thk" => Ok(Self::Thk),
-            "thv" => Ok(Self::Thv),
-            "tif" => Ok(Self::Tif),
-            "tig" => Ok(Self::Tig),
-            "tih" => Ok(Self::Tih),
-            "tik" => Ok(Self::Tik),
-            "tim" => Ok(Self::Tim),
-            "tir" => Ok(Self::Tir),
-            "tiv" => Ok(Self::Tiv),
-            "tiy" => Ok(Self::Tiy),
-            "tke" => Ok(Self::Tke),
-            "tkl" => Ok(Self::Tkl),
-            "tkr" => Ok(Self::Tkr),
-            "tku" => Ok(Self::Tku),
-            "tlb" => Ok(Self::Tlb),
-            "tlf" => Ok(Self::Tlf),
-            "tlh" => Ok(Self::Tlh),
-            "tlj" => Ok(Self::Tlj),
-            "tll" => Ok(Self::Tll),
-            "tly" => Ok(Self::Tly),
-            "tmd" => Ok(Self::Tmd),
-            "tmr" => Ok(Self::Tmr),
-            "tna" => Ok(Self::Tna),
-            "tnc" => Ok(Self::Tnc),
-            "tnk" => Ok(Self::Tnk),
-            "tnn" => Ok(Self::Tnn),
-            "tnp" => Ok(Self::Tnp),
-            "tob" => Ok(Self::Tob),
-            "toc" => Ok(Self::Toc),
-            "tod" => Ok(Self::Tod),
-            "tog" => Ok(Self::Tog),
-            "toh" => Ok(Self::Toh),
-            "toi" => Ok(Self::Toi),
-            "toj" => Ok(Self::Toj),
-            "tok" => Ok(Self::Tok),
-            "ton" => Ok(Self::Ton),
-            "too" => Ok(Self::Too),
-            "top" => Ok(Self::Top),
-            "tos" => Ok(Self::Tos),
-            "tpa" => Ok(Self::Tpa),
-            "tpi" => Ok(Self::Tpi),
-            "tpm" => Ok(Self::Tpm),
-            "tpp" => Ok(Self::Tpp),
-            "tpt" => Ok(Self::Tpt),
-            "tpw" => Ok(Self::Tpw),
-            "tpz" => Ok(Self::Tpz),
-            "tqb" => Ok(Self::Tqb),
-            "trc" => Ok(Self::Trc),
-            "trn" => Ok(Self::Trn),
-            "tro" => Ok(Self::Tro),
-            "trp" => Ok(Self::Trp),
-            "trq" => Ok(Self::Trq),
-            "tsc" => Ok(Self::Tsc),
-            "tsg" => Ok(Self::Tsg),
-            "tsn" => Ok(Self::Tsn),
-            "tso" => Ok(Self::Tso),
-            "tsw" => Ok(Self::Tsw),
-            "tsz" => Ok(Self::Tsz),
-            "ttc" => Ok(Self::Ttc),
-            "tte" => Ok(Self::Tte),
-            "ttj" => Ok(Self::Ttj),
-            "ttq" => Ok(Self::Ttq),
-            "tts" => Ok(Self::Tts),
-            "tuc" => Ok(Self::Tuc),
-            "tue" => Ok(Self::Tue),
-            "tuf" => Ok(Self::Tuf),
-            "tui" => Ok(Self::Tui),
-            "tuk" => Ok(Self::Tuk),
-            "tum" => Ok(Self::Tum),
-            "tuo" => Ok(Self::Tuo),
-            "tur" => Ok(Self::Tur),
-            "tuv" => Ok(Self::Tuv),
-            "tvk" => Ok(Self::Tvk),
-            "tvl" => Ok(Self::Tvl),
-            "twi" => Ok(Self::Twi),
-            "twu" => Ok(Self::Twu),
-            "twx" => Ok(Self::Twx),
-            "txq" => Ok(Self::Txq),
-            "txu" => Ok(Self::Txu),
-            "tyv" => Ok(Self::Tyv),
-            "tzh" => Ok(Self::Tzh),
-            "tzj" => Ok(Self::Tzj),
-            "tzl" => Ok(Self::Tzl),
-            "tzm" => Ok(Self::Tzm),
-            "tzo" => Ok(Self::Tzo),
-            "ubr" => Ok(Self::Ubr),
-            "ubu" => Ok(Self::Ubu),
-            "udm" => Ok(Self::Udm),
-            "udu" => Ok(Self::Udu),
-            "uig" => Ok(Self::Uig),
-            "ukr" => Ok(Self::Ukr),
-            "umb" => Ok(Self::Umb),
-            "upv" => Ok(Self::Upv),
-            "ura" => Ok(Self::Ura),
-            "urb" => Ok(Self::Urb),
-            "urd" => Ok(Self::Urd),
-            "urh" => Ok(Self::Urh),
-            "uri" => Ok(Self::Uri),
-            "urk" => Ok(Self::Urk),
-            "urt" => Ok(Self::Urt),
-            "usa" => Ok(Self::Usa),
-            "usp" => Ok(Self::Usp),
-            "uvh" => Ok(Self::Uvh),
-            "uvl" => Ok(Self::Uvl),
-            "uzb" => Ok(Self::Uzb),
-            "uzn" => Ok(Self::Uzn),
-            "vag" => Ok(Self::Vag),
-            "vap" => Ok(Self::Vap),
-            "var" => Ok(Self::Var),
-            "vec" => Ok(Self::Vec),
-            "ven" => Ok(Self::Ven),
-            "vgt" => Ok(Self::Vgt),
-            "vid" => Ok(Self::Vid),
-            "vie" => Ok(Self::Vie),
-            "viv" => Ok(Self::Viv),
-            "vls" => Ok(Self::Vls),
-            "vmk" => Ok(Self::Vmk),
-            "vmw" => Ok(Self::Vmw),
-            "vmy" => Ok(Self::Vmy),
-            "vol" => Ok(Self::Vol),
-            "vro" => Ok(Self::Vro),
-            "vun" => Ok(Self::Vun),
-            "vut" => Ok(Self::Vut),
-            "waj" => Ok(Self::Waj),
-            "wal" => Ok(Self::Wal),
-            "wap" => Ok(Self::Wap),
-            "war" => Ok(Self::War),
-            "wat" => Ok(Self::Wat),
-            "way" => Ok(Self::Way),
-            "wba" => Ok(Self::Wba),
-            "wbm" => Ok(Self::Wbm),
-            "wbp" => Ok(Self::Wbp),
-            "wca" => Ok(Self::Wca),
-            "wed" => Ok(Self::Wed),
-            "wer" => Ok(Self::Wer),
-            "wes" => Ok(Self::Wes),
-            "wew" => Ok(Self::Wew),
-            "whg" => Ok(Self::Whg),
-            "whk" => Ok(Self::Whk),
-            "wib" => Ok(Self::Wib),
-            "wim" => Ok(Self::Wim),
-            "wiu" => Ok(Self::Wiu),
-            "wln" => Ok(Self::Wln),
-            "wls" => Ok(Self::Wls),
-            "wlv" => Ok(Self::Wlv),
-            "wmt" => Ok(Self::Wmt),
-            "wmw" => Ok(Self::Wmw),
-            "wnc" => Ok(Self::Wnc),
-            "wnu" => Ok(Self::Wnu),
-            "wob" => Ok(Self::Wob),
-            "wol" => Ok(Self::Wol),
-            "wos" => Ok(Self::Wos),
-            "wrk" => Ok(Self::Wrk),
-            "wrs" => Ok(Self::Wrs),
-            "wsk" => Ok(Self::Wsk),
-            "wuu" => Ok(Self::Wuu),
-            "wuv" => Ok(Self::Wuv),
-            "wwa" => Ok(Self::Wwa),
-            "xal" => Ok(Self::Xal),
-            "xav" => Ok(Self::Xav),
-            "xbi" => Ok(Self::Xbi),
-            "xbr" => Ok(Self::Xbr),
-            "xed" => Ok(Self::Xed),
-            "xho" => Ok(Self::Xho),
-            "xla" => Ok(Self::Xla),
-            "xmf" => Ok(Self::Xmf),
-            "xmv" => Ok(Self::Xmv),
-            "xnn" => Ok(Self::Xnn),
-            "xog" => Ok(Self::Xog),
-            "xon" => Ok(Self::Xon),
-            "xpe" => Ok(Self::Xpe),
-            "xrb" => Ok(Self::Xrb),
-            "xsb" => Ok(Self::Xsb),
-            "xsi" => Ok(Self::Xsi),
-            "xsm" => Ok(Self::Xsm),
-            "xsr" => Ok(Self::Xsr),
-            "xsu" => Ok(Self::Xsu),
-            "xtd" => Ok(Self::Xtd),
-            "xtm" => Ok(Self::Xtm),
-            "xtn" => Ok(Self::Xtn),
-            "xuo" => Ok(Self::Xuo),
-            "yaa" => Ok(Self::Yaa),
-            "yad" => Ok(Self::Yad),
-            "yal" => Ok(Self::Yal),
-            "yam" => Ok(Self::Yam),
-            "yan" => Ok(Self::Yan),
-            "yao" => Ok(Self::Yao),
-            "yap" => Ok(Self::Yap),
-            "yaq" => Ok(Self::Yaq),
-            "ybb" => Ok(Self::Ybb),
-            "yby" => Ok(Self::Yby),
-            "ycn" => Ok(Self::Ycn),
-            "ydd" => Ok(Self::Ydd),
-            "yid" => Ok(Self::Yid),
-            "yim" => Ok(Self::Yim),
-            "yka" => Ok(Self::Yka),
-            "yle" => Ok(Self::Yle),
-            "yli" => Ok(Self::Yli),
-            "yml" => Ok(Self::Yml),
-            "yom" => Ok(Self::Yom),
-            "yon" => Ok(Self::Yon),
-            "yor" => Ok(Self::Yor),
-            "yrb" => Ok(Self::Yrb),
-            "yre" => Ok(Self::Yre),
-            "yrk" => Ok(Self::Yrk),
-            "yrl" => Ok(Self::Yrl),
-            "yss" => Ok(Self::Yss),
-            "yua" => Ok(Self::Yua),
-            "yue" => Ok(Self::Yue),
-            "yuj" => Ok(Self::Yuj),
-            "yup" => Ok(Self::Yup),
-            "yut" => Ok(Self::Yut),
-            "yuw" => Ok(Self::Yuw),
-            "yuz" => Ok(Self::Yuz),
-            "yva" => Ok(Self::Yva),
-            "zaa" => Ok(Self::Zaa),
-            "zab" => Ok(Self::Zab),
-            "zac" => Ok(Self::Zac),
-            "zad" => Ok(Self::Zad),
-            "zae" => Ok(Self::Zae),
-            "zai" => Ok(Self::Zai),
-            "zam" => Ok(Self::Zam),
-            "zao" => Ok(Self::Zao),
-            "zar" => Ok(Self::Zar),
-            "zas" => Ok(Self::Zas),
-            "zat" => Ok(Self::Zat),
-            "zav" => Ok(Self::Zav),
-            "zaw" => Ok(Self::Zaw),
-            "zca" => Ok(Self::Zca),
-            "zdj" => Ok(Self::Zdj),
-            "zea" => Ok(Self::Zea),
-            "zgh" => Ok(Self::Zgh),
-            "zho" => Ok(Self::Zho),
-            "zia" => Ok(Self::Zia),
-            "ziw" => Ok(Self::Ziw),
-            "zlm" => Ok(Self::Zlm),
-            "zne" => Ok(Self::Zne),
-            "zom" => Ok(Self::Zom),
-            "zos" => Ok(Self::Zos),
-            "zpa" => Ok(Self::Zpa),
-            "zpc" => Ok(Self::Zpc),
-            "zpd" => Ok(Self::Zpd),
-            "zpf" => Ok(Self::Zpf),
-            "zpg" => Ok(Self::Zpg),
-            "zpi" => Ok(Self::Zpi),
-            "zpj" => Ok(Self::Zpj),
-            "zpl" => Ok(Self::Zpl),
-            "zpm" => Ok(Self::Zpm),
-            "zpo" => Ok(Self::Zpo),
-            "zpq" => Ok(Self::Zpq),
-            "zpt" => Ok(Self::Zpt),
-            "zpu" => Ok(Self::Zpu),
-            "zpv" => Ok(Self::Zpv),
-            "zpz" => Ok(Self::Zpz),
-            "zsm" => Ok(Self::Zsm),
-            "zsr" => Ok(Self::Zsr),
-            "ztq" => Ok(Self::Ztq),
-            "zty" => Ok(Self::Zty),
-            "zul" => Ok(Self::Zul),
-            "zyb" => Ok(Self::Zyb),
-            "zyp" => Ok(Self::Zyp),
-            "zza" => Ok(Self::Zza),
-            "multi" => Ok(Self::Multi),
-            other => Err(Error::UnknownLang(other.to_string())),
-        }
+    /// Get the ISO 639-1 code, if the language has one.
+    pub fn iso639_1(self) -> Option<&'static str> {
+        self.info().iso639_1
+    }
+
+    /// Get the ISO 15924 script code.
+    ///
+    /// For languages without explicit script (e.g. `eng`), this is the usual script of the language, if known.
+    pub fn script(self) -> Option<&'static str> {
+        self.info().script
+    }
+
+    /// Get the English name of the language.
+    pub fn english_name(self) -> &'static str {
+        self.info().name
+    }
+
+    /// Get the ISO 639-3 code of the macrolanguage the language belongs to (e.g. `zho` for `cmn`), if any.
+    pub fn macrolanguage(self) -> Option<&'static str> {
+        self.info().macrolanguage
     }
 }
 
@@ -11907,10 +73,93 @@ impl Display for Lang {
     }
 }
 
+impl Lang {
+    /// Get the language (ISO 639-3) and optional script subtags.
+    fn subtags(self) -> (&'static str, Option<&'static str>) {
+        let tag = self.to_static();
+        match tag.split_once('-') {
+            Some((lang, script)) => (lang, Some(script)),
+            None => (tag, None),
+        }
+    }
+
+    /// Parse a language tag, normalizing equivalent forms:
+    /// - ISO 639-1 codes are mapped to ISO 639-3 (`en` is read as `eng`),
+    /// - case is normalized (`ENG-latn` is read as `eng-Latn`),
+    /// - region and other subtags are ignored (`en-US` is read as `eng`),
+    /// - if there is no variant for the language/script pair, the script-less variant is used.
+    ///
+    /// Use [FromStr] for exact matching.
+    pub fn from_tag(tag: &str) -> Result<Self, Error> {
+        let unknown = || Error::UnknownLang(tag.to_string());
+        let parsed = LanguageTag::parse_and_normalize(tag).map_err(|_| unknown())?;
+
+        let lang = parsed.primary_language();
+        let lang = match ISO_639_1.binary_search_by_key(&lang, |(iso1, _)| iso1) {
+            Ok(idx) => ISO_639_1[idx].1,
+            Err(_) => lang,
+        };
+
+        if let Some(script) = parsed.script() {
+            if let Ok(l) = Self::from_str(&format!("{}-{}", lang, script)) {
+                return Ok(l);
+            }
+        }
+        Self::from_str(lang).map_err(|_| unknown())
+    }
+
+    /// Returns true if both languages are equivalent,
+    /// meaning that they have the same language and either the same script or at least one without script
+    /// (`eng` and `eng-Latn` are equivalent, `srp-Cyrl` and `srp-Latn` are not).
+    pub fn is_equivalent(self, other: Self) -> bool {
+        let (lang, script) = self.subtags();
+        let (other_lang, other_script) = other.subtags();
+        lang == other_lang && (script.is_none() || other_script.is_none() || script == other_script)
+    }
+}
+
+impl From<Lang> for LanguageTag<String> {
+    fn from(lang: Lang) -> Self {
+        LanguageTag::parse(lang.to_static().to_string())
+            .expect("Lang variants are valid language tags")
+    }
+}
+
+impl<T: Deref<Target = str>> TryFrom<&LanguageTag<T>> for Lang {
+    type Error = Error;
+
+    /// See [Lang::from_tag].
+    fn try_from(tag: &LanguageTag<T>) -> Result<Self, Self::Error> {
+        Self::from_tag(tag.as_str())
+    }
+}
+
+/// (De)serialization of [Lang] as language tags (e.g. `eng-Latn`), for use with `#[serde(with = "oscar_io::lang::as_tag")]`.
+///
+/// Deserialization uses [Lang::from_tag], and fails on unknown languages.
+/// Serialization writes the canonical tag of the [Lang] (see [Lang::to_static]),
+/// so equivalent forms are not kept: `en` is written back as `eng`.
+pub mod as_tag {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    use super::Lang;
+
+    pub fn serialize<S: Serializer>(lang: &Lang, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(lang.to_static())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Lang, D::Error> {
+        let tag = String::deserialize(deserializer)?;
+        Lang::from_tag(&tag).map_err(|_| de::Error::custom(format!("unknown language: {}", tag)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
+    use oxilangtag::LanguageTag;
+
     use super::Lang;
 
     #[test]
@@ -11924,10 +173,80 @@ mod tests {
 
     #[test]
     fn test_cbr() {
-        let cbk_language = Lang::Cbk;
-        assert_eq!(&cbk_language.to_string(), "cbk");
+        let cbr_language = Lang::Cbr;
+        assert_eq!(&cbr_language.to_string(), "cbr");
 
-        let cbk_string = "cbr";
-        assert_eq!(Lang::from_str(cbk_string).unwrap(), Lang::Cbk);
+        let cbr_string = "cbr";
+        assert_eq!(Lang::from_str(cbr_string).unwrap(), Lang::Cbr);
+    }
+
+    #[test]
+    fn test_from_tag() {
+        assert_eq!(Lang::from_tag("en").unwrap(), Lang::Eng);
+        assert_eq!(Lang::from_tag("eng").unwrap(), Lang::Eng);
+        assert_eq!(Lang::from_tag("en-US").unwrap(), Lang::Eng);
+        assert_eq!(Lang::from_tag("eng-Latn").unwrap(), Lang::Englatn);
+        assert_eq!(Lang::from_tag("EN-latn").unwrap(), Lang::Englatn);
+        assert_eq!(Lang::from_tag("sr-Cyrl").unwrap(), Lang::Srpcyrl);
+        assert!(matches!(
+            Lang::from_tag("xx"),
+            Err(crate::error::Error::UnknownLang(_))
+        ));
+        assert!(Lang::from_tag("not a tag").is_err());
+    }
+
+    #[test]
+    fn test_language_tag() {
+        let tag = LanguageTag::parse("fr".to_string()).unwrap();
+        assert_eq!(Lang::try_from(&tag).unwrap(), Lang::Fra);
+
+        for lang in [Lang::Englatn, Lang::Eng, Lang::Multi] {
+            let tag: LanguageTag<String> = lang.into();
+            assert_eq!(Lang::try_from(&tag).unwrap(), lang);
+        }
+    }
+
+    #[test]
+    fn test_equivalent() {
+        assert!(Lang::Eng.is_equivalent(Lang::Englatn));
+        assert!(Lang::Englatn.is_equivalent(Lang::from_tag("en").unwrap()));
+        assert!(Lang::Srp.is_equivalent(Lang::Srpcyrl));
+        assert!(!Lang::Srpcyrl.is_equivalent(Lang::Srplatn));
+        assert!(!Lang::Eng.is_equivalent(Lang::Fra));
+    }
+
+    #[test]
+    fn test_iso_639_1_sorted() {
+        assert!(super::ISO_639_1.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_iso_639_1_known() {
+        // every ISO 639-1 code must map to an existing language
+        for (iso1, iso3) in super::ISO_639_1.iter() {
+            assert_eq!(Lang::from_tag(iso1).unwrap(), Lang::from_str(iso3).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        assert_eq!(Lang::all().count(), 3956);
+        for lang in Lang::all() {
+            assert_eq!(Lang::from_str(lang.to_static()).unwrap(), lang);
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        assert_eq!(Lang::Englatn.iso639_3(), "eng");
+        assert_eq!(Lang::Englatn.iso639_1(), Some("en"));
+        assert_eq!(Lang::Englatn.script(), Some("Latn"));
+        assert_eq!(Lang::Eng.script(), Some("Latn"));
+        assert_eq!(Lang::Englatn.english_name(), "English");
+        assert_eq!(Lang::Englatn.macrolanguage(), None);
+
+        assert_eq!(Lang::Cmnhani.macrolanguage(), Some("zho"));
+        assert_eq!(Lang::Cbr.english_name(), "Cashibo-Cacataibo");
+        assert_eq!(Lang::Multi.iso639_3(), "mul");
     }
 }
@@ -1,8 +1,13 @@
 #![doc = include_str!("../README.md")]
 pub mod common;
+pub mod compression;
+pub mod detect;
 pub mod error;
 pub mod lang;
+pub mod migrate;
 pub mod oscar_doc;
+pub mod oscar_v1;
+pub mod oscar_v1_1;
 
 pub mod v3;
 
@@ -0,0 +1,158 @@
+/*! Streaming schema migrations between OSCAR v2 (22.01) and v3 (23.01) corpora.
+
+Both directions read and write JSONLines, one document at a time.
+Upgrading is lossless, while downgrading drops v3-only fields (see [DroppedField]),
+which are counted in the returned [MigrationReport].
+!*/
+use std::{
+    collections::HashMap,
+    io::{BufRead, Write},
+};
+
+use crate::detect::{AnyDocument, DetectingReader};
+use crate::error::Error;
+use crate::oscar_doc::{self, DroppedField};
+
+/// Summary of a migration.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    documents: usize,
+    dropped: HashMap<DroppedField, usize>,
+}
+
+impl MigrationReport {
+    /// Get the number of migrated documents.
+    pub fn documents(&self) -> usize {
+        self.documents
+    }
+
+    /// Get the number of documents that had a value for each dropped field.
+    pub fn dropped(&self) -> &HashMap<DroppedField, usize> {
+        &self.dropped
+    }
+
+    /// Returns true if no field has been dropped.
+    pub fn is_lossless(&self) -> bool {
+        self.dropped.is_empty()
+    }
+}
+
+/// Read a v2 corpus from `r` and write it as v3 to `w`.
+///
+/// Fails if `r` is not a v2 corpus (see [crate::detect]).
+pub fn v2_to_v3<R: BufRead, W: Write>(r: R, w: W) -> Result<MigrationReport, Error> {
+    let mut reader = DetectingReader::new(r);
+    let mut writer = oscar_doc::Writer::new(w);
+    let mut report = MigrationReport::default();
+
+    for doc in &mut reader {
+        let doc = match doc? {
+            AnyDocument::V2(doc) => doc,
+            other => {
+                return Err(Error::Custom(format!(
+                    "expected a v2 corpus, found {:?}",
+                    other.version()
+                )))
+            }
+        };
+        writer.write(&doc.into())?;
+        report.documents += 1;
+    }
+
+    writer.flush()?;
+    Ok(report)
+}
+
+/// Read a v3 corpus from `r` and write it as v2 to `w`.
+///
+/// Dropped fields are reported in the returned [MigrationReport].
+pub fn v3_to_v2<R: BufRead, W: Write>(r: R, mut w: W) -> Result<MigrationReport, Error> {
+    let mut report = MigrationReport::default();
+
+    for doc in oscar_doc::Reader::new(r) {
+        let (doc, dropped) = oscar_doc::Document::from_v3(doc?)?;
+        for field in dropped {
+            *report.dropped.entry(field).or_default() += 1;
+        }
+
+        let doc_str = serde_json::to_string(&doc)? + "\n";
+        w.write_all(doc_str.as_bytes())?;
+        report.documents += 1;
+    }
+
+    w.flush()?;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::BufReader};
+
+    use crate::detect::{DetectingReader, SchemaVersion};
+    use crate::oscar_doc::{self, DroppedField};
+    use crate::v3::Document;
+
+    use super::{v2_to_v3, v3_to_v2};
+
+    #[test]
+    fn test_v2_to_v3() {
+        let f = File::open("tests/res/data.jsonl").unwrap();
+        let mut buf = Vec::new();
+        let report = v2_to_v3(BufReader::new(f), &mut buf).unwrap();
+        assert_eq!(report.documents(), 63);
+        assert!(report.is_lossless());
+
+        let mut r = DetectingReader::new(buf.as_slice());
+        assert_eq!(r.version().unwrap(), Some(SchemaVersion::V3));
+
+        // v2 -> v3 -> v2 is lossless
+        let mut v2_buf = Vec::new();
+        let report = v3_to_v2(buf.as_slice(), &mut v2_buf).unwrap();
+        assert!(report.is_lossless());
+
+        let expected: Vec<oscar_doc::Document> = std::fs::read_to_string("tests/res/data.jsonl")
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        let got: Vec<oscar_doc::Document> = std::str::from_utf8(&v2_buf)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_v2_to_v3_wrong_version() {
+        let f = File::open("tests/res/data.jsonl").unwrap();
+        let mut buf = Vec::new();
+        v2_to_v3(BufReader::new(f), &mut buf).unwrap();
+
+        assert!(v2_to_v3(buf.as_slice(), Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_v3_to_v2_report() {
+        let f = File::open("tests/res/data.jsonl").unwrap();
+        let mut docs: Vec<Document> = DetectingReader::new(BufReader::new(f))
+            .into_v3()
+            .map(|x| x.unwrap())
+            .collect();
+        docs[0].metadata_mut().set_tlsh(Some("T1".to_string()));
+        docs[1].metadata_mut().set_tlsh(Some("T2".to_string()));
+        docs[1].metadata_mut().set_harmful_pp(Some(100.0));
+
+        let mut buf = Vec::new();
+        oscar_doc::Writer::new(&mut buf)
+            .write_multiple(&docs)
+            .unwrap();
+
+        let report = v3_to_v2(buf.as_slice(), Vec::new()).unwrap();
+        assert_eq!(report.documents(), 63);
+        assert!(!report.is_lossless());
+        assert_eq!(report.dropped().get(&DroppedField::Tlsh), Some(&2));
+        assert_eq!(report.dropped().get(&DroppedField::HarmfulPp), Some(&1));
+        assert_eq!(report.dropped().get(&DroppedField::Categories), None);
+    }
+}
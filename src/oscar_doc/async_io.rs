@@ -0,0 +1,261 @@
+/*! Asynchronous reader and writer (`async` feature), on top of [tokio].
+
+[AsyncDocReader] is a [Stream] of documents over an [AsyncBufRead], and [AsyncDocWriter] writes documents to an [AsyncWrite].
+Both support gzip (and zstd with the `zstd` feature) through [async_compression].
+!*/
+use std::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use async_compression::{
+    tokio::{bufread::GzipDecoder, write::GzipEncoder},
+    Level,
+};
+use flate2::Compression;
+use futures_core::Stream;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader, Lines};
+
+use crate::{error::Error, v3::Document};
+
+/// Asynchronous document reader.
+/// The inner type has to implement [AsyncBufRead].
+pub struct AsyncDocReader<R: AsyncBufRead + Unpin> {
+    lines: Lines<R>,
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncDocReader<R> {
+    /// Create a new [AsyncDocReader].
+    pub fn new(r: R) -> Self {
+        Self { lines: r.lines() }
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncDocReader<BufReader<GzipDecoder<R>>> {
+    /// Create a new [AsyncDocReader] on gzipped data.
+    ///
+    /// Multi-member files (e.g. concatenated `.gz` files) are read until the last member.
+    pub fn from_gzip(r: R) -> Self {
+        let mut dec = GzipDecoder::new(r);
+        dec.multiple_members(true);
+        Self::new(BufReader::new(dec))
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl<R: AsyncBufRead + Unpin>
+    AsyncDocReader<BufReader<async_compression::tokio::bufread::ZstdDecoder<R>>>
+{
+    /// Create a new [AsyncDocReader] on zstd-compressed data.
+    ///
+    /// Multi-frame files (e.g. concatenated `.zst` files) are read until the last frame.
+    pub fn from_zstd(r: R) -> Self {
+        let mut dec = async_compression::tokio::bufread::ZstdDecoder::new(r);
+        dec.multiple_members(true);
+        Self::new(BufReader::new(dec))
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> Stream for AsyncDocReader<R> {
+    type Item = Result<Document, Error>;
+
+    /// Yields [Result]<[Document], [Error]>.
+    /// Errors can be either [serde_json::Error] if the format is invalid, or [std::io::Error] if there has been some IO Error.
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let item = match ready!(Pin::new(&mut self.lines).poll_next_line(cx)) {
+            Ok(Some(line)) => Some(serde_json::from_str(&line).map_err(Error::from)),
+            // stop if nothing is read
+            Ok(None) => None,
+            Err(e) => Some(Err(e.into())),
+        };
+        Poll::Ready(item)
+    }
+}
+
+/// Asynchronous document writer.
+/// The inner type has to implement [AsyncWrite].
+pub struct AsyncDocWriter<W: AsyncWrite + Unpin> {
+    w: W,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncDocWriter<W> {
+    /// Create a new [AsyncDocWriter].
+    pub fn new(w: W) -> Self {
+        Self { w }
+    }
+
+    /// Serializes the document as a [String], adds a newline and writes it to the inner writer.
+    ///
+    /// Does not call [Self::flush], so be careful of calling it after writing
+    pub async fn write(&mut self, doc: &Document) -> Result<(), Error> {
+        let write_bytes = serde_json::to_string(doc)? + "\n";
+        self.w.write_all(write_bytes.as_bytes()).await?;
+
+        Ok(())
+    }
+
+    /// calls [Self::write] for each document, returning an error if there's any failure, then calls [Self::flush].
+    pub async fn write_multiple(&mut self, docs: &[Document]) -> Result<(), Error> {
+        for doc in docs {
+            self.write(doc).await?;
+        }
+        self.flush().await
+    }
+
+    /// Flushes the inner writer.
+    pub async fn flush(&mut self) -> Result<(), Error> {
+        Ok(self.w.flush().await?)
+    }
+
+    /// Shuts the inner writer down (see [AsyncWriteExt::shutdown]) and returns it.
+    ///
+    /// For compressed writers, this writes the trailer/ends the frame.
+    pub async fn finish(mut self) -> Result<W, Error> {
+        self.w.shutdown().await?;
+        Ok(self.w)
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncDocWriter<GzipEncoder<W>> {
+    /// Create a new [AsyncDocWriter] that gzips documents at the provided compression level.
+    ///
+    /// **[Self::finish] has to be called** to write the gzip trailer, since nothing is written on drop.
+    pub fn from_gzip(w: W, level: Compression) -> Self {
+        Self::new(GzipEncoder::with_quality(
+            w,
+            Level::Precise(level.level() as i32),
+        ))
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl<W: AsyncWrite + Unpin> AsyncDocWriter<async_compression::tokio::write::ZstdEncoder<W>> {
+    /// Create a new [AsyncDocWriter] that compresses documents with zstd.
+    ///
+    /// `level` is the zstd compression level (`0` being zstd's default).
+    ///
+    /// **[Self::finish] has to be called** to end the zstd frame, since nothing is written on drop.
+    pub fn from_zstd(w: W, level: i32) -> Self {
+        let level = if level == 0 {
+            Level::Default
+        } else {
+            Level::Precise(level)
+        };
+        Self::new(async_compression::tokio::write::ZstdEncoder::with_quality(
+            w, level,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::BufReader};
+
+    use flate2::Compression;
+    use futures_util::StreamExt;
+    use tokio::io::{duplex, BufReader as AsyncBufReader};
+
+    use super::{AsyncDocReader, AsyncDocWriter};
+    use crate::{error::Error, oscar_doc::Reader, v3::Document};
+
+    fn get_docs() -> Vec<Document> {
+        let f = File::open("tests/res/data.jsonl").unwrap();
+        Reader::new(BufReader::new(f))
+            .map(|doc| doc.unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_duplex_roundtrip() {
+        let docs = get_docs();
+        // small buffer so that reads and writes are interleaved
+        let (w, r) = duplex(1024);
+
+        let to_write = docs.clone();
+        let writer = tokio::spawn(async move {
+            let mut w = AsyncDocWriter::new(w);
+            w.write_multiple(&to_write).await.unwrap();
+            w.finish().await.unwrap();
+        });
+
+        let read: Vec<Document> = AsyncDocReader::new(AsyncBufReader::new(r))
+            .map(|doc| doc.unwrap())
+            .collect()
+            .await;
+        writer.await.unwrap();
+
+        assert_eq!(read, docs);
+    }
+
+    #[tokio::test]
+    async fn test_duplex_gzip() {
+        let docs = get_docs();
+        let (w, r) = duplex(1024);
+
+        let to_write = docs.clone();
+        let writer = tokio::spawn(async move {
+            let mut w = AsyncDocWriter::from_gzip(w, Compression::fast());
+            w.write_multiple(&to_write).await.unwrap();
+            w.finish().await.unwrap();
+        });
+
+        let read: Vec<Document> = AsyncDocReader::from_gzip(AsyncBufReader::new(r))
+            .map(|doc| doc.unwrap())
+            .collect()
+            .await;
+        writer.await.unwrap();
+
+        assert_eq!(read, docs);
+    }
+
+    #[tokio::test]
+    async fn test_sync_gzip_compat() {
+        let docs = get_docs();
+
+        // two gzip members, written by the sync writer
+        let mut compressed = vec![];
+        for chunk in docs.chunks(docs.len() / 2 + 1) {
+            let mut w = crate::oscar_doc::Writer::from_gzip(vec![], Compression::fast());
+            w.write_multiple(chunk).unwrap();
+            compressed.extend(w.finish().unwrap());
+        }
+
+        let read: Vec<Document> = AsyncDocReader::from_gzip(compressed.as_slice())
+            .map(|doc| doc.unwrap())
+            .collect()
+            .await;
+        assert_eq!(read, docs);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[tokio::test]
+    async fn test_duplex_zstd() {
+        let docs = get_docs();
+        let (w, r) = duplex(1024);
+
+        let to_write = docs.clone();
+        let writer = tokio::spawn(async move {
+            let mut w = AsyncDocWriter::from_zstd(w, 0);
+            w.write_multiple(&to_write).await.unwrap();
+            w.finish().await.unwrap();
+        });
+
+        let read: Vec<Document> = AsyncDocReader::from_zstd(AsyncBufReader::new(r))
+            .map(|doc| doc.unwrap())
+            .collect()
+            .await;
+        writer.await.unwrap();
+
+        assert_eq!(read, docs);
+    }
+
+    #[tokio::test]
+    async fn test_bad_format() {
+        let mut r = AsyncDocReader::new(r#"{"foo": "bar"}"#.as_bytes());
+        match r.next().await {
+            Some(Err(Error::SerdeJson(_))) => (),
+            x => panic!("wrong return: {:?}", x),
+        }
+        assert!(r.next().await.is_none());
+    }
+}
@@ -0,0 +1,487 @@
+/*! Byte-offset index, for random access into (gzipped) files.
+
+An [Index] holds, for each record of a file, where to start reading it:
+- for uncompressed files, the byte offset of the record,
+- for gzipped files, the byte offset of the gzip member holding the record, and the offset of the record in the decompressed member.
+
+Random access into gzipped files is then as fast as their members are small:
+files written as numerous concatenated members (e.g. one member per batch of documents) are the best fit,
+and can be written with [crate::oscar_doc::Writer::from_gzip_members].
+Single-member files (such as the ones written by [crate::oscar_doc::Writer::from_gzip]) can be indexed,
+but every record is then fetched by decoding the file from its start.
+Records are expected not to span several members.
+
+Indices are persisted as JSON in a sidecar file (see [Index::sidecar_path]), and used by [IndexedReader].
+They hold the size and modification time of the indexed file, to detect outdated indices.
+!*/
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use flate2::bufread::{GzDecoder, MultiGzDecoder};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use super::reader::DocReader;
+use crate::{compression::Compression, error::Error, v3::Document};
+
+/// Extension appended to indexed file names to get their sidecar index.
+pub const INDEX_EXTENSION: &str = "idx";
+
+/// Location of a record in an indexed file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexEntry {
+    /// Byte offset in the file where reading starts (gzip member start for gzipped files).
+    pub file_offset: u64,
+    /// Number of decompressed bytes to skip from `file_offset` (always `0` for uncompressed files).
+    pub decoded_offset: u64,
+    /// `warc-record-id` header, if present.
+    pub record_id: Option<String>,
+    /// `warc-target-uri` header, if present.
+    pub url: Option<String>,
+}
+
+/// Byte-offset index of a file, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "IndexSer", into = "IndexSer")]
+pub struct Index {
+    source_len: u64,
+    source_modified: Option<SystemTime>,
+    entries: Vec<IndexEntry>,
+    by_record_id: HashMap<String, usize>,
+    by_url: HashMap<String, usize>,
+}
+
+/// Serializable version of [Index], without lookup tables.
+#[derive(Serialize, Deserialize)]
+struct IndexSer {
+    source_len: u64,
+    // missing in older indices, which are then considered outdated
+    #[serde(default)]
+    source_modified: Option<SystemTime>,
+    entries: Vec<IndexEntry>,
+}
+
+impl From<IndexSer> for Index {
+    fn from(i: IndexSer) -> Self {
+        Self::new(i.source_len, i.source_modified, i.entries)
+    }
+}
+
+impl From<Index> for IndexSer {
+    fn from(i: Index) -> Self {
+        Self {
+            source_len: i.source_len,
+            source_modified: i.source_modified,
+            entries: i.entries,
+        }
+    }
+}
+
+impl Index {
+    fn new(source_len: u64, source_modified: Option<SystemTime>, entries: Vec<IndexEntry>) -> Self {
+        let mut by_record_id = HashMap::new();
+        let mut by_url = HashMap::new();
+        // keep the first occurrence of duplicates
+        for (idx, entry) in entries.iter().enumerate() {
+            if let Some(record_id) = &entry.record_id {
+                by_record_id.entry(record_id.clone()).or_insert(idx);
+            }
+            if let Some(url) = &entry.url {
+                by_url.entry(url.clone()).or_insert(idx);
+            }
+        }
+
+        Self {
+            source_len,
+            source_modified,
+            entries,
+            by_record_id,
+            by_url,
+        }
+    }
+
+    /// Build the index of the file at `path`, reading it entirely.
+    ///
+    /// Only uncompressed and gzipped files are supported.
+    /// A warning is logged for single-member gzipped files holding several records, since they can't be read efficiently.
+    pub fn build(path: &Path) -> Result<Self, Error> {
+        let mut br = BufReader::new(File::open(path)?);
+        let metadata = br.get_ref().metadata()?;
+        let source_len = metadata.len();
+        let source_modified = metadata.modified().ok();
+
+        let mut entries = vec![];
+        match Compression::detect(br.fill_buf()?) {
+            Compression::None => {
+                let mut r = DocReader::new(br);
+                while let Some(record) = r.next_metadata() {
+                    let record = record?;
+                    entries.push(IndexEntry {
+                        file_offset: record.offset(),
+                        decoded_offset: 0,
+                        record_id: record.warc_id().map(String::from),
+                        url: record.url().map(String::from),
+                    });
+                }
+            }
+            Compression::Gzip => {
+                // decode members one by one, the inner reader being left at the start of the next one.
+                let mut nb_members = 0;
+                while !br.fill_buf()?.is_empty() {
+                    nb_members += 1;
+                    let file_offset = br.stream_position()?;
+                    let mut r = DocReader::new(BufReader::new(GzDecoder::new(&mut br)));
+                    while let Some(record) = r.next_metadata() {
+                        let record = record?;
+                        entries.push(IndexEntry {
+                            file_offset,
+                            decoded_offset: record.offset(),
+                            record_id: record.warc_id().map(String::from),
+                            url: record.url().map(String::from),
+                        });
+                    }
+                }
+
+                if nb_members == 1 && entries.len() > 1 {
+                    warn!(
+                        "{:?} is a single gzip member: random access will decode it from its start. \
+                        Consider writing it with Writer::from_gzip_members.",
+                        path
+                    );
+                }
+            }
+            other => {
+                return Err(Error::Custom(format!(
+                    "{:?} files can't be indexed (only uncompressed and gzip are supported)",
+                    other
+                )))
+            }
+        }
+
+        Ok(Self::new(source_len, source_modified, entries))
+    }
+
+    /// Get the sidecar index path of the file at `path` (`path` with an appended `.idx`).
+    pub fn sidecar_path(path: &Path) -> PathBuf {
+        let mut sidecar = path.as_os_str().to_owned();
+        sidecar.push(".");
+        sidecar.push(INDEX_EXTENSION);
+        PathBuf::from(sidecar)
+    }
+
+    /// Load an index from `index_path`.
+    pub fn load(index_path: &Path) -> Result<Self, Error> {
+        let br = BufReader::new(File::open(index_path)?);
+        Ok(serde_json::from_reader(br)?)
+    }
+
+    /// Save the index to `index_path`.
+    pub fn save(&self, index_path: &Path) -> Result<(), Error> {
+        let mut bw = BufWriter::new(File::create(index_path)?);
+        serde_json::to_writer(&mut bw, self)?;
+        io::Write::flush(&mut bw)?;
+        Ok(())
+    }
+
+    /// Check that the index has been built from the file at `path`, comparing file sizes and modification times.
+    ///
+    /// Copying a file usually changes its modification time, making its index outdated.
+    pub fn is_up_to_date(&self, path: &Path) -> Result<bool, Error> {
+        let metadata = std::fs::metadata(path)?;
+        Ok(metadata.len() == self.source_len && metadata.modified().ok() == self.source_modified)
+    }
+
+    /// Get the number of indexed records.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the index holds no records.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get a reference to the index entries, in file order.
+    pub fn entries(&self) -> &[IndexEntry] {
+        &self.entries
+    }
+
+    /// Get the position of the (first) record with the provided `warc-record-id`.
+    ///
+    /// Ids are compared as they are written, e.g. `<urn:uuid:...>`.
+    pub fn position(&self, record_id: &str) -> Option<usize> {
+        self.by_record_id.get(record_id).copied()
+    }
+
+    /// Get the position of the (first) record with the provided url.
+    pub fn position_by_url(&self, url: &str) -> Option<usize> {
+        self.by_url.get(url).copied()
+    }
+}
+
+/// Reader fetching single documents of a file using its [Index].
+pub struct IndexedReader {
+    file: BufReader<File>,
+    compression: Compression,
+    index: Index,
+}
+
+impl IndexedReader {
+    /// Open the file at `path` with its sidecar index.
+    ///
+    /// If the sidecar index does not exist, can't be read or is not up to date, the index is built and saved.
+    /// Failing to save the index (e.g. in a read-only folder) is logged, and the index is only kept in memory.
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        let index_path = Index::sidecar_path(path);
+        let index = match Index::load(&index_path) {
+            Ok(index) if index.is_up_to_date(path)? => index,
+            Ok(_) | Err(Error::Io(_)) | Err(Error::SerdeJson(_)) => {
+                let index = Index::build(path)?;
+                if let Err(e) = index.save(&index_path) {
+                    warn!("could not save index to {:?}: {:?}", index_path, e);
+                }
+                index
+            }
+            Err(e) => return Err(e),
+        };
+
+        Self::with_index(path, index)
+    }
+
+    /// Open the file at `path` with the provided index.
+    ///
+    /// Fails if the index is not up to date (see [Index::is_up_to_date]).
+    pub fn with_index(path: &Path, index: Index) -> Result<Self, Error> {
+        if !index.is_up_to_date(path)? {
+            return Err(Error::Custom(format!("index of {:?} is outdated", path)));
+        }
+
+        let mut file = BufReader::new(File::open(path)?);
+        let compression = Compression::detect(file.fill_buf()?);
+        Ok(Self {
+            file,
+            compression,
+            index,
+        })
+    }
+
+    /// Get a reference to the index.
+    pub fn index(&self) -> &Index {
+        &self.index
+    }
+
+    /// Get the document with the provided `warc-record-id` (see [Index::position]).
+    pub fn get(&mut self, record_id: &str) -> Result<Option<Document>, Error> {
+        match self.index.position(record_id) {
+            Some(n) => self.get_nth(n),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the document with the provided url (see [Index::position_by_url]).
+    pub fn get_by_url(&mut self, url: &str) -> Result<Option<Document>, Error> {
+        match self.index.position_by_url(url) {
+            Some(n) => self.get_nth(n),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the `n`-th document of the file, returning `Ok(None)` if there are not enough documents.
+    ///
+    /// For gzipped files, the member holding the document is decoded up to it (see the [module documentation](self)).
+    pub fn get_nth(&mut self, n: usize) -> Result<Option<Document>, Error> {
+        let Some(entry) = self.index.entries.get(n) else {
+            return Ok(None);
+        };
+
+        self.file.seek(SeekFrom::Start(entry.file_offset))?;
+        let doc = match self.compression {
+            Compression::Gzip => {
+                let mut dec = BufReader::new(MultiGzDecoder::new(&mut self.file));
+                let skipped =
+                    io::copy(&mut (&mut dec).take(entry.decoded_offset), &mut io::sink())?;
+                if skipped != entry.decoded_offset {
+                    return Err(Error::Custom(format!("record {n} is out of bounds")));
+                }
+                DocReader::new(dec).next()
+            }
+            _ => DocReader::new(&mut self.file).next(),
+        };
+
+        doc.transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::BufReader};
+
+    use flate2::Compression;
+    use tempfile::TempDir;
+
+    use std::time::SystemTime;
+
+    use super::{Index, IndexedReader};
+    use crate::{
+        oscar_doc::{Reader, Writer},
+        v3::Document,
+    };
+
+    fn get_docs() -> Vec<Document> {
+        let f = File::open("tests/res/data.jsonl").unwrap();
+        Reader::new(BufReader::new(f))
+            .map(|doc| doc.unwrap())
+            .collect()
+    }
+
+    fn check_reader(r: &mut IndexedReader, docs: &[Document]) {
+        assert_eq!(r.index().len(), docs.len());
+        // random-ish order
+        for n in (0..docs.len()).rev().step_by(3) {
+            assert_eq!(r.get_nth(n).unwrap().as_ref(), Some(&docs[n]));
+        }
+        assert!(r.get_nth(docs.len()).unwrap().is_none());
+
+        let record_id = docs[10].warc_id().unwrap();
+        assert_eq!(r.get(&record_id).unwrap().as_ref(), Some(&docs[10]));
+        assert!(r.get("<urn:uuid:nope>").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_uncompressed() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.jsonl");
+        let docs = get_docs();
+        Writer::new(File::create(&path).unwrap())
+            .write_multiple(&docs)
+            .unwrap();
+
+        let mut r = IndexedReader::open(&path).unwrap();
+        check_reader(&mut r, &docs);
+
+        let url = docs[5].url().unwrap();
+        assert_eq!(r.get_by_url(&url).unwrap().as_ref(), Some(&docs[5]));
+    }
+
+    #[test]
+    fn test_gzip_members() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.jsonl.gz");
+        let docs = get_docs();
+
+        // one member per 10 documents
+        let mut compressed = vec![];
+        for chunk in docs.chunks(10) {
+            let mut w = Writer::from_gzip(vec![], Compression::fast());
+            w.write_multiple(chunk).unwrap();
+            compressed.extend(w.finish().unwrap());
+        }
+        std::fs::write(&path, compressed).unwrap();
+
+        let mut r = IndexedReader::open(&path).unwrap();
+        let member_offsets: Vec<u64> = r.index().entries().iter().map(|e| e.file_offset).collect();
+        assert_eq!(member_offsets[0], 0);
+        assert!(member_offsets[10] > 0);
+        assert_eq!(member_offsets[10], member_offsets[19]);
+        assert_eq!(r.index().entries()[10].decoded_offset, 0);
+        check_reader(&mut r, &docs);
+
+        // sidecar is reused
+        let index_path = Index::sidecar_path(&path);
+        assert!(index_path.exists());
+        assert_eq!(&Index::load(&index_path).unwrap(), r.index());
+        let mut r = IndexedReader::open(&path).unwrap();
+        check_reader(&mut r, &docs);
+    }
+
+    #[test]
+    fn test_outdated() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.jsonl");
+        let docs = get_docs();
+        Writer::new(File::create(&path).unwrap())
+            .write_multiple(&docs[..5])
+            .unwrap();
+        let index = Index::build(&path).unwrap();
+        index.save(&Index::sidecar_path(&path)).unwrap();
+        assert_eq!(index.len(), 5);
+
+        Writer::new(File::create(&path).unwrap())
+            .write_multiple(&docs)
+            .unwrap();
+        assert!(!index.is_up_to_date(&path).unwrap());
+        assert!(IndexedReader::with_index(&path, index).is_err());
+
+        // outdated sidecar is rebuilt
+        let r = IndexedReader::open(&path).unwrap();
+        assert_eq!(r.index().len(), docs.len());
+    }
+
+    #[test]
+    fn test_gzip_writer_members() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.jsonl.gz");
+        let docs = get_docs();
+
+        let mut w =
+            Writer::from_gzip_members(File::create(&path).unwrap(), Compression::fast(), 10);
+        w.write_multiple(&docs).unwrap();
+        w.finish().unwrap();
+
+        let mut r = IndexedReader::open(&path).unwrap();
+        let mut member_offsets: Vec<u64> =
+            r.index().entries().iter().map(|e| e.file_offset).collect();
+        member_offsets.dedup();
+        assert_eq!(member_offsets.len(), 7);
+        check_reader(&mut r, &docs);
+    }
+
+    #[test]
+    fn test_modified() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.jsonl");
+        Writer::new(File::create(&path).unwrap())
+            .write_multiple(&get_docs())
+            .unwrap();
+
+        let mut index = Index::build(&path).unwrap();
+        assert!(index.is_up_to_date(&path).unwrap());
+
+        // same size, other modification time
+        index.source_modified = Some(SystemTime::UNIX_EPOCH);
+        assert!(!index.is_up_to_date(&path).unwrap());
+
+        // indices without modification time are outdated
+        index.source_modified = None;
+        assert!(!index.is_up_to_date(&path).unwrap());
+    }
+
+    #[test]
+    fn test_sidecar_errors() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.jsonl");
+        let docs = get_docs();
+        Writer::new(File::create(&path).unwrap())
+            .write_multiple(&docs)
+            .unwrap();
+
+        // truncated sidecar is rebuilt and overwritten
+        let index_path = Index::sidecar_path(&path);
+        std::fs::write(&index_path, "{\"source_len\":").unwrap();
+        let mut r = IndexedReader::open(&path).unwrap();
+        check_reader(&mut r, &docs);
+        assert_eq!(&Index::load(&index_path).unwrap(), r.index());
+
+        // sidecar that can't be written: index is kept in memory
+        std::fs::remove_file(&index_path).unwrap();
+        std::fs::create_dir(&index_path).unwrap();
+        let mut r = IndexedReader::open(&path).unwrap();
+        check_reader(&mut r, &docs);
+        assert!(index_path.is_dir());
+    }
+}
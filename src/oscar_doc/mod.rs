@@ -1,16 +1,50 @@
 //! OSCAR Schema v2 (OSCAR 22.01) types, readers and writers.
 //!
 //! Each document is materialized by a [Document], holding [Metadata], [WarcHeaders] and `content` (that is a [String]).
+#[cfg(feature = "async")]
+pub mod async_io;
+pub mod index;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+#[cfg(feature = "parquet")]
+pub mod parquet;
 mod reader;
 mod types;
 mod writer;
 
+#[cfg(feature = "async")]
+pub use self::async_io::AsyncDocReader as AsyncReader;
+#[cfg(feature = "async")]
+pub use self::async_io::AsyncDocWriter as AsyncWriter;
+#[cfg(feature = "rayon")]
+pub use self::parallel::ParallelReader;
+#[cfg(feature = "parquet")]
+pub use self::parquet::ParquetDocReader as ParquetReader;
+#[cfg(feature = "parquet")]
+pub use self::parquet::ParquetDocWriter as ParquetWriter;
+pub use index::{Index, IndexedReader};
 #[cfg(feature = "avro")]
 pub use reader::AvroDocReader as AvroReader;
 pub use reader::DocReader as Reader;
-pub use reader::SplitFileIter as SplitReader;
+pub use reader::MetadataReader;
+pub use reader::SplitDocReader as SplitReader;
+#[allow(deprecated)]
+pub use reader::SplitFileIter;
+#[allow(deprecated)]
+pub use reader::SplitFolderFileIter;
+#[allow(deprecated)]
 pub use reader::SplitFolderFileIter as SplitFolderReader;
+pub use reader::SplitSource;
 pub use types::Document;
+pub use types::DroppedField;
 pub use types::Metadata;
 pub use types::WarcHeaders;
+#[cfg(feature = "avro")]
+pub use writer::avro_schema;
+#[cfg(feature = "avro")]
+pub use writer::AvroDocWriter as AvroWriter;
 pub use writer::DocWriter as Writer;
+pub use writer::GzMemberEncoder;
+pub use writer::SplitDocWriter as SplitWriter;
+#[cfg(feature = "avro")]
+pub use writer::AVRO_SCHEMA;
@@ -0,0 +1,454 @@
+/*! Parallel readers (`rayon` feature).
+
+[ParallelReader] reads the files of a folder concurrently on its own [rayon] thread pool,
+either as a [ParallelIterator] ([ParallelReader::install]) or as a sequential [Iterator] ([ParallelReader::iter]),
+optionally keeping the file order.
+
+[open_pipelined] decodes a single (compressed) file on a dedicated thread,
+while documents are parsed on the calling one.
+!*/
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead, Read},
+    path::{Path, PathBuf},
+    sync::mpsc::{sync_channel, Receiver, SyncSender},
+    thread,
+};
+
+use rayon::{
+    iter::plumbing::UnindexedConsumer,
+    prelude::{IntoParallelRefIterator, ParallelIterator},
+    ThreadPool, ThreadPoolBuilder,
+};
+
+use super::reader::{folder_files, DocReader};
+use crate::{compression, error::Error, v3::Document};
+
+/// Size of the chunks sent by the decoding thread of [open_pipelined].
+const PIPELINE_CHUNK_SIZE: u64 = 1 << 20;
+
+/// Number of chunks buffered by [open_pipelined].
+const PIPELINE_CHUNKS: usize = 4;
+
+/// Number of documents buffered per file by [ParallelReader::iter].
+const DOCS_BUFFER: usize = 256;
+
+type DocResult = Result<Document, Error>;
+type DocIterBox = Box<dyn Iterator<Item = DocResult> + Send>;
+
+/// [BufRead] receiving chunks decoded on another thread, see [open_pipelined].
+pub struct PipelinedRead {
+    rx: Receiver<io::Result<Vec<u8>>>,
+    chunk: Vec<u8>,
+    pos: usize,
+}
+
+impl Read for PipelinedRead {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let nb_read = available.len().min(buf.len());
+        buf[..nb_read].copy_from_slice(&available[..nb_read]);
+        self.consume(nb_read);
+        Ok(nb_read)
+    }
+}
+
+impl BufRead for PipelinedRead {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos == self.chunk.len() {
+            match self.rx.recv() {
+                Ok(Ok(chunk)) => {
+                    self.chunk = chunk;
+                    self.pos = 0;
+                }
+                Ok(Err(e)) => return Err(e),
+                // the decoding thread stops on EOF
+                Err(_) => return Ok(&[]),
+            }
+        }
+        Ok(&self.chunk[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.chunk.len());
+    }
+}
+
+/// Read chunks from `r` and send them until EOF, an error or until the receiver is dropped.
+fn decode(mut r: Box<dyn BufRead + Send>, tx: SyncSender<io::Result<Vec<u8>>>) {
+    loop {
+        let mut chunk = Vec::new();
+        match (&mut r).take(PIPELINE_CHUNK_SIZE).read_to_end(&mut chunk) {
+            Ok(0) => return,
+            Ok(_) => {
+                if tx.send(Ok(chunk)).is_err() {
+                    return;
+                }
+            }
+            Err(e) => {
+                let _ = tx.send(Err(e));
+                return;
+            }
+        }
+    }
+}
+
+/// Open the file at `path`, detecting its compression (see [crate::compression]).
+///
+/// The file is read and decoded on a dedicated thread, while documents are parsed on the calling one.
+pub fn open_pipelined(path: &Path) -> Result<DocReader<PipelinedRead>, Error> {
+    let r = compression::open(path)?;
+    let (tx, rx) = sync_channel(PIPELINE_CHUNKS);
+    thread::Builder::new()
+        .name("oscar-io-decoder".to_string())
+        .spawn(move || decode(r, tx))?;
+
+    Ok(DocReader::new(PipelinedRead {
+        rx,
+        chunk: Vec::new(),
+        pos: 0,
+    }))
+}
+
+/// Open a file, returning its documents or the opening error.
+fn file_docs(path: &Path, pipelined: bool) -> DocIterBox {
+    let reader = if pipelined {
+        open_pipelined(path).map(|r| Box::new(r) as DocIterBox)
+    } else {
+        DocReader::open(path).map(|r| Box::new(r) as DocIterBox)
+    };
+
+    match reader {
+        Ok(r) => r,
+        Err(e) => Box::new(std::iter::once(Err(e))),
+    }
+}
+
+/// Builder for [ParallelReader].
+#[derive(Debug, Default, Clone)]
+pub struct ParallelReaderBuilder {
+    threads: Option<usize>,
+    ordered: bool,
+    pipelined: bool,
+}
+
+impl ParallelReaderBuilder {
+    /// Create a new [ParallelReaderBuilder].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of threads reading files.
+    ///
+    /// Defaults to [rayon]'s default (`RAYON_NUM_THREADS` or the number of CPUs).
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    /// Yield documents in file order in [ParallelReader::iter]. Defaults to `false`.
+    pub fn ordered(mut self, ordered: bool) -> Self {
+        self.ordered = ordered;
+        self
+    }
+
+    /// Decode each file on a dedicated thread (see [open_pipelined]). Defaults to `false`.
+    pub fn pipelined(mut self, pipelined: bool) -> Self {
+        self.pipelined = pipelined;
+        self
+    }
+
+    /// Build a [ParallelReader] reading files of `folder` (see [crate::oscar_doc::SplitSource::Folder]).
+    ///
+    /// Fails if the folder can't be read or is empty, or if the thread pool can't be built.
+    pub fn build(self, folder: &Path) -> Result<ParallelReader, Error> {
+        let mut pool = ThreadPoolBuilder::new().thread_name(|idx| format!("oscar-io-reader-{idx}"));
+        if let Some(threads) = self.threads {
+            pool = pool.num_threads(threads);
+        }
+
+        Ok(ParallelReader {
+            files: folder_files(folder)?,
+            pool: pool.build()?,
+            ordered: self.ordered,
+            pipelined: self.pipelined,
+        })
+    }
+}
+
+/// Reader for corpora split in numerous files, reading them in parallel.
+///
+/// Errors when opening a file are yielded, and the file is skipped.
+pub struct ParallelReader {
+    files: Vec<PathBuf>,
+    pool: ThreadPool,
+    ordered: bool,
+    pipelined: bool,
+}
+
+impl ParallelReader {
+    /// Create a new [ParallelReaderBuilder].
+    pub fn builder() -> ParallelReaderBuilder {
+        ParallelReaderBuilder::new()
+    }
+
+    /// Create a new [ParallelReader] with default settings.
+    pub fn from_folder(folder: &Path) -> Result<Self, Error> {
+        Self::builder().build(folder)
+    }
+
+    /// Get the files that are read, in file order.
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// Get a [ParallelIterator] over the documents of every file.
+    ///
+    /// It runs on the current pool, use [ParallelReader::install] to run it on the reader's one.
+    /// As with any [rayon] iterator, `collect` keeps file order regardless of [ParallelReaderBuilder::ordered].
+    pub fn par_iter(&self) -> ParDocs<'_> {
+        ParDocs {
+            files: &self.files,
+            pipelined: self.pipelined,
+        }
+    }
+
+    /// Run `op` on [ParallelReader::par_iter] within the reader's thread pool.
+    ///
+    /// ```no_run
+    /// # use std::path::Path;
+    /// # use oscar_io::oscar_doc::ParallelReader;
+    /// use rayon::prelude::*;
+    ///
+    /// let reader = ParallelReader::builder().threads(4).build(Path::new("corpus/")).unwrap();
+    /// let nb_docs = reader.install(|docs| docs.filter(Result::is_ok).count());
+    /// ```
+    pub fn install<T, OP>(&self, op: OP) -> T
+    where
+        T: Send,
+        OP: FnOnce(ParDocs<'_>) -> T + Send,
+    {
+        self.pool.install(|| op(self.par_iter()))
+    }
+
+    /// Get a sequential [Iterator] over the documents of every file, read in the reader's thread pool.
+    ///
+    /// In ordered mode, files are read ahead (as many as there are threads) and yielded in file order.
+    /// Otherwise, documents are yielded as soon as they are read.
+    ///
+    /// This must not be called from within the reader's thread pool.
+    pub fn iter(&self) -> DocIter<'_> {
+        let mut files = self.files.iter();
+        let channels = if self.ordered {
+            let receivers = files
+                .by_ref()
+                .take(self.pool.current_num_threads())
+                .map(|path| self.spawn_file(path, DOCS_BUFFER))
+                .collect();
+            Channels::Ordered(receivers)
+        } else {
+            let (tx, rx) = sync_channel(DOCS_BUFFER * self.pool.current_num_threads());
+            for path in files.by_ref() {
+                self.send_file(path, tx.clone());
+            }
+            Channels::Unordered(rx)
+        };
+
+        DocIter {
+            reader: self,
+            files,
+            channels,
+        }
+    }
+
+    /// Read the file at `path` in the pool, returning the receiving end of its documents.
+    fn spawn_file(&self, path: &Path, bound: usize) -> Receiver<DocResult> {
+        let (tx, rx) = sync_channel(bound);
+        self.send_file(path, tx);
+        rx
+    }
+
+    /// Read the file at `path` in the pool, sending its documents to `tx`.
+    fn send_file(&self, path: &Path, tx: SyncSender<DocResult>) {
+        let path = path.to_path_buf();
+        let pipelined = self.pipelined;
+        self.pool.spawn(move || {
+            for doc in file_docs(&path, pipelined) {
+                // stop if the iterator has been dropped
+                if tx.send(doc).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+/// [ParallelIterator] over documents, see [ParallelReader::par_iter].
+pub struct ParDocs<'a> {
+    files: &'a [PathBuf],
+    pipelined: bool,
+}
+
+impl<'a> ParallelIterator for ParDocs<'a> {
+    type Item = DocResult;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        let pipelined = self.pipelined;
+        self.files
+            .par_iter()
+            .flat_map_iter(move |path| file_docs(path, pipelined))
+            .drive_unindexed(consumer)
+    }
+}
+
+enum Channels {
+    /// One channel per file being read, in file order.
+    Ordered(VecDeque<Receiver<DocResult>>),
+    /// A channel shared by every file.
+    Unordered(Receiver<DocResult>),
+}
+
+/// Sequential iterator over documents, see [ParallelReader::iter].
+pub struct DocIter<'a> {
+    reader: &'a ParallelReader,
+    // files that are not being read yet
+    files: std::slice::Iter<'a, PathBuf>,
+    channels: Channels,
+}
+
+impl<'a> Iterator for DocIter<'a> {
+    type Item = DocResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.channels {
+            Channels::Unordered(rx) => rx.recv().ok(),
+            Channels::Ordered(receivers) => loop {
+                match receivers.front()?.recv() {
+                    Ok(doc) => return Some(doc),
+                    // current file is done, start reading the next one
+                    Err(_) => {
+                        receivers.pop_front();
+                        if let Some(path) = self.files.next() {
+                            receivers.push_back(self.reader.spawn_file(path, DOCS_BUFFER));
+                        }
+                    }
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, path::Path};
+
+    use flate2::Compression;
+    use rayon::prelude::*;
+    use tempfile::TempDir;
+
+    use super::{open_pipelined, ParallelReader};
+    use crate::{
+        oscar_doc::{Reader, Writer},
+        v3::Document,
+    };
+
+    /// Write `nb_files` gzipped files of 100 documents each, and return them in file order.
+    fn write_files(dir: &Path, nb_files: usize) -> Vec<Document> {
+        let mut all_docs = vec![];
+        for file_idx in 0..nb_files {
+            let f = File::open("tests/res/data.jsonl").unwrap();
+            let docs: Vec<Document> = Reader::new(std::io::BufReader::new(f))
+                .map(|doc| {
+                    let mut doc = doc.unwrap();
+                    doc.set_content(format!("{file_idx}: {}", doc.content()));
+                    doc
+                })
+                .collect();
+
+            let out = File::create(dir.join(format!("{file_idx:02}.jsonl.gz"))).unwrap();
+            let mut w = Writer::from_gzip(out, Compression::fast());
+            w.write_multiple(&docs).unwrap();
+            w.finish().unwrap();
+            all_docs.extend(docs);
+        }
+
+        all_docs
+    }
+
+    #[test]
+    fn test_ordered_iter() {
+        let dir = TempDir::new().unwrap();
+        let docs = write_files(dir.path(), 5);
+
+        for pipelined in [false, true] {
+            let reader = ParallelReader::builder()
+                .threads(2)
+                .ordered(true)
+                .pipelined(pipelined)
+                .build(dir.path())
+                .unwrap();
+            let read: Vec<Document> = reader.iter().map(|doc| doc.unwrap()).collect();
+            assert_eq!(read, docs);
+        }
+    }
+
+    #[test]
+    fn test_unordered_iter() {
+        let dir = TempDir::new().unwrap();
+        let docs = write_files(dir.path(), 5);
+
+        let reader = ParallelReader::builder()
+            .threads(3)
+            .build(dir.path())
+            .unwrap();
+        let mut read: Vec<String> = reader
+            .iter()
+            .map(|doc| doc.unwrap().content().clone())
+            .collect();
+        let mut expected: Vec<String> = docs.iter().map(|doc| doc.content().clone()).collect();
+        read.sort();
+        expected.sort();
+        assert_eq!(read, expected);
+    }
+
+    #[test]
+    fn test_par_iter() {
+        let dir = TempDir::new().unwrap();
+        let docs = write_files(dir.path(), 5);
+
+        let reader = ParallelReader::builder()
+            .threads(4)
+            .pipelined(true)
+            .build(dir.path())
+            .unwrap();
+        let read: Vec<Document> = reader.install(|docs| docs.map(|doc| doc.unwrap()).collect());
+        assert_eq!(read, docs);
+    }
+
+    #[test]
+    fn test_open_error() {
+        let dir = TempDir::new().unwrap();
+        write_files(dir.path(), 1);
+        let reader = ParallelReader::from_folder(dir.path()).unwrap();
+        std::fs::remove_file(&reader.files()[0]).unwrap();
+
+        let read: Vec<_> = reader.iter().collect();
+        assert_eq!(read.len(), 1);
+        assert!(read[0].is_err());
+    }
+
+    #[test]
+    fn test_pipelined() {
+        let dir = TempDir::new().unwrap();
+        let docs = write_files(dir.path(), 1);
+
+        let read: Vec<Document> = open_pipelined(&dir.path().join("00.jsonl.gz"))
+            .unwrap()
+            .map(|doc| doc.unwrap())
+            .collect();
+        assert_eq!(read, docs);
+    }
+}
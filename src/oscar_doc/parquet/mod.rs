@@ -0,0 +1,10 @@
+//! Parquet readers/writers for OSCAR Schema v3 [crate::v3::Document]s.
+//!
+//! Available with the `parquet` feature.
+//! See [schema] for the layout of the Parquet files.
+mod reader;
+pub mod schema;
+mod writer;
+
+pub use reader::ParquetDocReader;
+pub use writer::ParquetDocWriter;
@@ -0,0 +1,69 @@
+/*! Parquet Reader.
+
+Provides a way to read [Document]s from a Parquet file written by [super::ParquetDocWriter].
+!*/
+use std::collections::VecDeque;
+
+use parquet::{
+    arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder},
+    file::reader::ChunkReader,
+};
+
+use crate::error::Error;
+use crate::v3::Document;
+
+use super::schema;
+
+/// Parquet document reader.
+///
+/// Can be built from any [ChunkReader] (e.g. [std::fs::File]).
+/// Documents are decoded one record batch at a time.
+pub struct ParquetDocReader {
+    batches: ParquetRecordBatchReader,
+    docs: VecDeque<Document>,
+}
+
+impl ParquetDocReader {
+    /// Create a new [ParquetDocReader], using the default batch size.
+    pub fn new<R: ChunkReader + 'static>(r: R) -> Result<Self, Error> {
+        let batches = ParquetRecordBatchReaderBuilder::try_new(r)?.build()?;
+        Ok(Self {
+            batches,
+            docs: VecDeque::new(),
+        })
+    }
+
+    /// Create a new [ParquetDocReader] that decodes at most `batch_size` documents at a time.
+    pub fn with_batch_size<R: ChunkReader + 'static>(
+        r: R,
+        batch_size: usize,
+    ) -> Result<Self, Error> {
+        let batches = ParquetRecordBatchReaderBuilder::try_new(r)?
+            .with_batch_size(batch_size)
+            .build()?;
+        Ok(Self {
+            batches,
+            docs: VecDeque::new(),
+        })
+    }
+}
+
+impl Iterator for ParquetDocReader {
+    type Item = Result<Document, Error>;
+
+    /// Yields [Result]<[Document], [Error]>.
+    /// Errors can be either parquet/arrow errors, or [Error::Custom] if the file does not follow [schema::schema].
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.docs.is_empty() {
+            match self.batches.next()? {
+                Ok(batch) => match schema::from_record_batch(&batch) {
+                    Ok(docs) => self.docs.extend(docs),
+                    Err(e) => return Some(Err(e)),
+                },
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+
+        self.docs.pop_front().map(Ok)
+    }
+}
@@ -0,0 +1,379 @@
+/*! Arrow schema for [Document]s and conversions between [Document]s and [RecordBatch]es.
+
+The schema is the following (`?` denotes a nullable field):
+
+```text
+content: utf8
+warc_headers: map<utf8, utf8>
+metadata: struct {
+    identification: struct { label: utf8, prob: float32 }
+    harmful_pp?: float32
+    tlsh?: utf8
+    quality_warnings?: list<utf8>
+    categories?: list<utf8>
+    sentence_identifications: list<struct { label: utf8, prob: float32 }?>
+    extensions?: utf8
+}
+```
+
+WARC header names are the lowercase names provided by [WarcHeader]'s [std::fmt::Display] implementation,
+and values are (lossily) converted to UTF-8, as it is done for JSONLines serialization.
+Metadata extensions are stored as a JSON object, and are null when there is none.
+!*/
+use std::{collections::HashMap, sync::Arc};
+
+use arrow_array::{
+    builder::{
+        Float32Builder, ListBuilder, MapBuilder, MapFieldNames, StringBuilder, StructBuilder,
+    },
+    Array, ArrayRef, Float32Array, ListArray, MapArray, RecordBatch, StringArray, StructArray,
+};
+use arrow_schema::{DataType, Field, Fields, Schema, SchemaRef};
+use oxilangtag::LanguageTag;
+use warc::WarcHeader;
+
+use crate::common::Identification;
+use crate::error::Error;
+use crate::v3::{Document, Metadata};
+
+fn identification_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("label", DataType::Utf8, false),
+        Field::new("prob", DataType::Float32, false),
+    ])
+}
+
+fn string_list_item() -> Field {
+    Field::new("item", DataType::Utf8, true)
+}
+
+fn identification_list_item() -> Field {
+    Field::new("item", DataType::Struct(identification_fields()), true)
+}
+
+fn map_field_names() -> MapFieldNames {
+    MapFieldNames {
+        entry: "entries".to_string(),
+        key: "key".to_string(),
+        value: "value".to_string(),
+    }
+}
+
+fn warc_headers_type() -> DataType {
+    let entries = Field::new(
+        "entries",
+        DataType::Struct(Fields::from(vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Utf8, false),
+        ])),
+        false,
+    );
+    DataType::Map(Arc::new(entries), false)
+}
+
+fn metadata_fields() -> Fields {
+    Fields::from(vec![
+        Field::new(
+            "identification",
+            DataType::Struct(identification_fields()),
+            false,
+        ),
+        Field::new("harmful_pp", DataType::Float32, true),
+        Field::new("tlsh", DataType::Utf8, true),
+        Field::new(
+            "quality_warnings",
+            DataType::List(Arc::new(string_list_item())),
+            true,
+        ),
+        Field::new(
+            "categories",
+            DataType::List(Arc::new(string_list_item())),
+            true,
+        ),
+        Field::new(
+            "sentence_identifications",
+            DataType::List(Arc::new(identification_list_item())),
+            false,
+        ),
+        Field::new("extensions", DataType::Utf8, true),
+    ])
+}
+
+/// Get the Arrow schema used to store [Document]s.
+pub fn schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("content", DataType::Utf8, false),
+        Field::new("warc_headers", warc_headers_type(), false),
+        Field::new("metadata", DataType::Struct(metadata_fields()), false),
+    ]))
+}
+
+fn identification_builder() -> StructBuilder {
+    StructBuilder::new(
+        identification_fields(),
+        vec![
+            Box::new(StringBuilder::new()),
+            Box::new(Float32Builder::new()),
+        ],
+    )
+}
+
+/// Appends an identification to an identification [StructBuilder].
+/// Appends a null if `id` is `None`.
+fn append_identification(builder: &mut StructBuilder, id: Option<&Identification<String>>) {
+    let (label, prob) = match id {
+        Some(id) => (id.label().as_str(), *id.prob()),
+        None => ("", 0.0),
+    };
+    builder
+        .field_builder::<StringBuilder>(0)
+        .expect("identification label builder is a StringBuilder")
+        .append_value(label);
+    builder
+        .field_builder::<Float32Builder>(1)
+        .expect("identification prob builder is a Float32Builder")
+        .append_value(prob);
+    builder.append(id.is_some());
+}
+
+fn append_string_list<T: std::fmt::Display>(
+    builder: &mut ListBuilder<StringBuilder>,
+    list: Option<&Vec<T>>,
+) {
+    match list {
+        Some(list) => {
+            for item in list {
+                builder.values().append_value(item.to_string());
+            }
+            builder.append(true);
+        }
+        None => builder.append(false),
+    }
+}
+
+/// Incremental builder of [RecordBatch]es following [schema], appending [Document]s directly into Arrow columns.
+pub struct RecordBatchBuilder {
+    content: StringBuilder,
+    warc_headers: MapBuilder<StringBuilder, StringBuilder>,
+    identification: StructBuilder,
+    harmful_pp: Float32Builder,
+    tlsh: StringBuilder,
+    quality_warnings: ListBuilder<StringBuilder>,
+    categories: ListBuilder<StringBuilder>,
+    sentence_identifications: ListBuilder<StructBuilder>,
+    extensions: StringBuilder,
+    len: usize,
+}
+
+impl Default for RecordBatchBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RecordBatchBuilder {
+    pub fn new() -> Self {
+        Self {
+            content: StringBuilder::new(),
+            warc_headers: MapBuilder::new(
+                Some(map_field_names()),
+                StringBuilder::new(),
+                StringBuilder::new(),
+            )
+            .with_values_field(Field::new("value", DataType::Utf8, false)),
+            identification: identification_builder(),
+            harmful_pp: Float32Builder::new(),
+            tlsh: StringBuilder::new(),
+            quality_warnings: ListBuilder::new(StringBuilder::new()).with_field(string_list_item()),
+            categories: ListBuilder::new(StringBuilder::new()).with_field(string_list_item()),
+            sentence_identifications: ListBuilder::new(identification_builder())
+                .with_field(identification_list_item()),
+            extensions: StringBuilder::new(),
+            len: 0,
+        }
+    }
+
+    /// Get the number of documents appended since the last [Self::finish].
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no document has been appended since the last [Self::finish].
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends a document.
+    pub fn append(&mut self, doc: &Document) -> Result<(), Error> {
+        // serialize extensions first, so that nothing is appended on error
+        let metadata = doc.metadata();
+        let extensions = if metadata.extensions().is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(metadata.extensions())?)
+        };
+
+        self.content.append_value(doc.content());
+
+        // sort headers so that output is deterministic
+        let mut headers: Vec<(String, &Vec<u8>)> = doc
+            .warc_headers()
+            .iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        headers.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        for (key, value) in headers {
+            self.warc_headers.keys().append_value(key);
+            self.warc_headers
+                .values()
+                .append_value(String::from_utf8_lossy(value));
+        }
+        self.warc_headers.append(true)?;
+
+        append_identification(&mut self.identification, Some(doc.identification()));
+        self.harmful_pp.append_option(metadata.harmful_pp());
+        self.tlsh.append_option(metadata.tlsh());
+        append_string_list(&mut self.quality_warnings, metadata.annotation());
+        append_string_list(&mut self.categories, metadata.categories());
+        for id in metadata.sentence_identifications() {
+            append_identification(self.sentence_identifications.values(), id.as_ref());
+        }
+        self.sentence_identifications.append(true);
+        self.extensions.append_option(extensions);
+
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Builds a [RecordBatch] from the appended documents, and resets the builder.
+    pub fn finish(&mut self) -> Result<RecordBatch, Error> {
+        self.len = 0;
+        let metadata = StructArray::try_new(
+            metadata_fields(),
+            vec![
+                Arc::new(self.identification.finish()) as ArrayRef,
+                Arc::new(self.harmful_pp.finish()),
+                Arc::new(self.tlsh.finish()),
+                Arc::new(self.quality_warnings.finish()),
+                Arc::new(self.categories.finish()),
+                Arc::new(self.sentence_identifications.finish()),
+                Arc::new(self.extensions.finish()),
+            ],
+            None,
+        )?;
+
+        Ok(RecordBatch::try_new(
+            schema(),
+            vec![
+                Arc::new(self.content.finish()),
+                Arc::new(self.warc_headers.finish()),
+                Arc::new(metadata),
+            ],
+        )?)
+    }
+}
+
+/// Converts a slice of [Document]s into a [RecordBatch] following [schema].
+pub fn to_record_batch(docs: &[Document]) -> Result<RecordBatch, Error> {
+    let mut builder = RecordBatchBuilder::new();
+    for doc in docs {
+        builder.append(doc)?;
+    }
+    builder.finish()
+}
+
+/// Get a named child of a struct-like array, downcasted to `T`.
+fn column<'a, T: 'static>(columns: &'a StructArray, name: &str) -> Result<&'a T, Error> {
+    columns
+        .column_by_name(name)
+        .and_then(|c| c.as_any().downcast_ref::<T>())
+        .ok_or_else(|| Error::Custom(format!("parquet: missing or mistyped column `{name}`")))
+}
+
+fn identification_at(ids: &StructArray, idx: usize) -> Result<Identification<String>, Error> {
+    let label = column::<StringArray>(ids, "label")?.value(idx);
+    let prob = column::<Float32Array>(ids, "prob")?.value(idx);
+    let label = LanguageTag::parse(label.to_string())
+        .map_err(|e| Error::Custom(format!("parquet: invalid label {label:?}: {e}")))?;
+    Ok(Identification::new(label, prob))
+}
+
+fn string_list_at<T: From<String>>(list: &ListArray, idx: usize) -> Result<Option<Vec<T>>, Error> {
+    if list.is_null(idx) {
+        return Ok(None);
+    }
+    let values = list.value(idx);
+    let values = values
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .ok_or_else(|| Error::Custom("parquet: list items are not strings".to_string()))?;
+    Ok(Some(
+        values
+            .iter()
+            .flatten()
+            .map(|s| s.to_string().into())
+            .collect(),
+    ))
+}
+
+/// Converts a [RecordBatch] following [schema] back into [Document]s.
+pub fn from_record_batch(batch: &RecordBatch) -> Result<Vec<Document>, Error> {
+    let columns = StructArray::from(batch.clone());
+    let content = column::<StringArray>(&columns, "content")?;
+    let warc_headers = column::<MapArray>(&columns, "warc_headers")?;
+    let metadata = column::<StructArray>(&columns, "metadata")?;
+
+    let identification = column::<StructArray>(metadata, "identification")?;
+    let harmful_pp = column::<Float32Array>(metadata, "harmful_pp")?;
+    let tlsh = column::<StringArray>(metadata, "tlsh")?;
+    let quality_warnings = column::<ListArray>(metadata, "quality_warnings")?;
+    let categories = column::<ListArray>(metadata, "categories")?;
+    let sentence_identifications = column::<ListArray>(metadata, "sentence_identifications")?;
+    let extensions = column::<StringArray>(metadata, "extensions")?;
+
+    let mut docs = Vec::with_capacity(batch.num_rows());
+    for idx in 0..batch.num_rows() {
+        let entries = warc_headers.value(idx);
+        let keys = column::<StringArray>(&entries, "key")?;
+        let values = column::<StringArray>(&entries, "value")?;
+        let headers: HashMap<WarcHeader, Vec<u8>> = keys
+            .iter()
+            .zip(values.iter())
+            .filter_map(|(k, v)| Some((WarcHeader::from(k?), v?.as_bytes().to_vec())))
+            .collect();
+
+        let sentence_ids = sentence_identifications.value(idx);
+        let sentence_ids = sentence_ids
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .ok_or_else(|| {
+                Error::Custom("parquet: sentence identifications are not structs".to_string())
+            })?;
+        let sentence_ids = (0..sentence_ids.len())
+            .map(|i| {
+                if sentence_ids.is_null(i) {
+                    Ok(None)
+                } else {
+                    identification_at(sentence_ids, i).map(Some)
+                }
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut meta = Metadata::new(&identification_at(identification, idx)?, &sentence_ids);
+        if !harmful_pp.is_null(idx) {
+            meta.set_harmful_pp(Some(harmful_pp.value(idx)));
+        }
+        if !tlsh.is_null(idx) {
+            meta.set_tlsh(Some(tlsh.value(idx).to_string()));
+        }
+        meta.set_annotation(string_list_at(quality_warnings, idx)?);
+        meta.set_categories(string_list_at(categories, idx)?);
+        if !extensions.is_null(idx) {
+            *meta.extensions_mut() = serde_json::from_str(extensions.value(idx))?;
+        }
+
+        docs.push(Document::new(content.value(idx).to_string(), headers, meta));
+    }
+
+    Ok(docs)
+}
@@ -0,0 +1,166 @@
+//! Parquet document writer.
+use std::io::Write;
+
+use parquet::{arrow::ArrowWriter, basic::Compression, file::properties::WriterProperties};
+
+use crate::error::Error;
+use crate::v3::Document;
+
+use super::schema::{self, RecordBatchBuilder};
+
+/// Default number of documents per row group.
+pub const DEFAULT_ROW_GROUP_SIZE: usize = 1024;
+
+/// Parquet document writer.
+///
+/// Documents are appended to Arrow columns and written as a row group each time `row_group_size` documents have been written.
+///
+/// **[Self::finish] has to be called** to write the Parquet footer, otherwise the file won't be readable.
+pub struct ParquetDocWriter<W: Write + Send> {
+    w: ArrowWriter<W>,
+    batch: RecordBatchBuilder,
+    row_group_size: usize,
+}
+
+impl<W: Write + Send> ParquetDocWriter<W> {
+    /// Create a new [ParquetDocWriter] with [DEFAULT_ROW_GROUP_SIZE] documents per row group.
+    pub fn new(writer: W) -> Result<Self, Error> {
+        Self::with_row_group_size(writer, DEFAULT_ROW_GROUP_SIZE)
+    }
+
+    /// Create a new [ParquetDocWriter] with `row_group_size` documents per row group.
+    ///
+    /// Returns an error if `row_group_size` is 0.
+    pub fn with_row_group_size(writer: W, row_group_size: usize) -> Result<Self, Error> {
+        if row_group_size == 0 {
+            return Err(Error::Custom(
+                "parquet: row group size must be > 0".to_string(),
+            ));
+        }
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .set_max_row_group_size(row_group_size)
+            .build();
+        let w = ArrowWriter::try_new(writer, schema::schema(), Some(props))?;
+        Ok(Self {
+            w,
+            batch: RecordBatchBuilder::new(),
+            row_group_size,
+        })
+    }
+
+    /// Appends the document to the current row group, writing it if it is full.
+    pub fn write(&mut self, doc: &Document) -> Result<(), Error> {
+        self.batch.append(doc)?;
+        if self.batch.len() >= self.row_group_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// calls [Self::write] for each document, returning an error if there's any failure.
+    pub fn write_multiple(&mut self, docs: &[Document]) -> Result<(), Error> {
+        for doc in docs {
+            self.write(doc)?;
+        }
+        Ok(())
+    }
+
+    /// Writes buffered documents (if any) and closes the current row group.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        if !self.batch.is_empty() {
+            let batch = self.batch.finish()?;
+            self.w.write(&batch)?;
+        }
+        Ok(self.w.flush()?)
+    }
+
+    /// Writes buffered documents and the Parquet footer, returning the inner writer.
+    pub fn finish(mut self) -> Result<W, Error> {
+        self.flush()?;
+        Ok(self.w.into_inner()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::BufReader};
+
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+
+    use crate::oscar_doc::writer::tests::get_full_docs;
+    use crate::oscar_doc::{ParquetReader, Reader};
+    use crate::v3::Document;
+
+    use super::ParquetDocWriter;
+
+    fn get_docs() -> Vec<Document> {
+        let f = File::open("tests/res/data.jsonl").unwrap();
+        let r = BufReader::new(f);
+        let cr = Reader::new(r);
+        cr.map(|x| x.unwrap()).collect()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let docs = get_docs();
+        let dst = tempfile::tempfile().unwrap();
+
+        let mut dw = ParquetDocWriter::new(dst).unwrap();
+        dw.write_multiple(&docs).unwrap();
+        let dst = dw.finish().unwrap();
+
+        let reader = ParquetReader::new(dst).unwrap();
+        let docs_from_reader: Vec<Document> = reader.map(|x| x.unwrap()).collect();
+
+        assert!(!docs.is_empty());
+        assert_eq!(docs, docs_from_reader);
+    }
+
+    #[test]
+    fn test_roundtrip_full() {
+        // full documents are split across row groups, with null fields in between
+        let mut docs = get_full_docs();
+        docs.extend(get_docs());
+        docs.extend(get_full_docs());
+        let dst = tempfile::tempfile().unwrap();
+
+        let mut dw = ParquetDocWriter::with_row_group_size(dst, 2).unwrap();
+        dw.write_multiple(&docs).unwrap();
+        let dst = dw.finish().unwrap();
+
+        let reader = ParquetReader::new(dst).unwrap();
+        let docs_from_reader: Vec<Document> = reader.map(|x| x.unwrap()).collect();
+        assert_eq!(docs, docs_from_reader);
+    }
+
+    #[test]
+    fn test_row_group_size() {
+        let docs = get_docs();
+        let dst = tempfile::tempfile().unwrap();
+
+        let mut dw = ParquetDocWriter::with_row_group_size(dst, 10).unwrap();
+        for doc in &docs {
+            dw.write(doc).unwrap();
+        }
+        let dst = dw.finish().unwrap();
+
+        // 63 documents in 10-document row groups
+        let metadata = SerializedFileReader::new(dst.try_clone().unwrap())
+            .unwrap()
+            .metadata()
+            .clone();
+        assert_eq!(metadata.num_row_groups(), 7);
+        assert_eq!(metadata.row_group(6).num_rows(), 3);
+
+        // read with a batch size that is not aligned with row groups
+        let reader = ParquetReader::with_batch_size(dst, 4).unwrap();
+        let docs_from_reader: Vec<Document> = reader.map(|x| x.unwrap()).collect();
+        assert_eq!(docs, docs_from_reader);
+    }
+
+    #[test]
+    fn test_zero_row_group_size() {
+        assert!(ParquetDocWriter::with_row_group_size(vec![], 0).is_err());
+    }
+}
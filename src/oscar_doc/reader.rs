@@ -1,34 +1,112 @@
 /*! OSCAR Schema v2 (22.01) Reader.
 
-   Provides a way to read [Document]s from a [BufRead].
-
-   TODO: Find a way to provide some reading of splitted corpora.
+   Provides a way to read [Document]s from a [BufRead], and from corpora split in numerous files ([SplitDocReader]).
 * !*/
 #[cfg(feature = "avro")]
 use avro_rs::Reader;
 use flate2::bufread::MultiGzDecoder;
 use log::info;
+#[cfg(feature = "avro")]
+use std::io::Read;
 use std::{
-    fs::File,
-    io::{BufRead, BufReader},
+    io::{BufRead, BufReader, Seek, SeekFrom},
     path::{Path, PathBuf},
 };
 
+use crate::compression;
 use crate::error::Error;
 
 // use super::types::Document;
-use crate::v3::Document;
+#[cfg(feature = "avro")]
+use super::writer::AvroDocument;
+use crate::v3::{Document, DocumentRef, MetadataRecord};
 
 /// Document reader.
 /// The inner type has to implement [BufRead].
+///
+/// Besides iterating on owned [Document]s, [DocReader::next_ref] yields [DocumentRef]s
+/// borrowing from an internal line buffer that is reused between calls,
+/// and [DocReader::next_metadata] yields [MetadataRecord]s without parsing content.
 pub struct DocReader<R: BufRead> {
     r: R,
+    buf: String,
+    // byte offset of the next line
+    pos: u64,
 }
 
 impl<R: BufRead> DocReader<R> {
     /// Create a new [DocReader].
     pub fn new(r: R) -> Self {
-        DocReader { r }
+        DocReader {
+            r,
+            buf: String::new(),
+            pos: 0,
+        }
+    }
+
+    /// Read the next line into the internal buffer, returning `false` on EOF.
+    fn read_line(&mut self) -> Result<bool, Error> {
+        self.buf.clear();
+        let read = self.r.read_line(&mut self.buf)?;
+        self.pos += read as u64;
+        Ok(read != 0)
+    }
+
+    /// Get the metadata of the next document, skipping its content and sentence identifications.
+    ///
+    /// The record holds its byte offset in the (decompressed) stream,
+    /// that can be used with [DocReader::seek] to read the full document.
+    pub fn next_metadata(&mut self) -> Option<Result<MetadataRecord, Error>> {
+        let offset = self.pos;
+        match self.read_line() {
+            Ok(false) => None,
+            Ok(true) => Some(
+                serde_json::from_str(&self.buf)
+                    .map(|mut record: MetadataRecord| {
+                        record.set_offset(offset);
+                        record
+                    })
+                    .map_err(Error::from),
+            ),
+            Err(e) => Some(Err(e)),
+        }
+    }
+
+    /// Turn the reader into an iterator over [MetadataRecord]s (see [DocReader::next_metadata]).
+    pub fn into_metadata(self) -> MetadataReader<R> {
+        MetadataReader { r: self }
+    }
+
+    /// Get the next document, borrowing its content and headers from the internal buffer.
+    ///
+    /// Since the buffer is overwritten on each call, the returned [DocumentRef] has to be dropped
+    /// (or converted using [DocumentRef::into_owned]) before reading the next one:
+    ///
+    /// ```
+    /// # use oscar_io::oscar_doc::Reader;
+    /// # let data: &[u8] = b"";
+    /// let mut reader = Reader::new(data);
+    /// while let Some(doc) = reader.next_ref() {
+    ///     let doc = doc.unwrap();
+    ///     println!("{:?}", doc.url());
+    /// }
+    /// ```
+    pub fn next_ref(&mut self) -> Option<Result<DocumentRef<'_>, Error>> {
+        match self.read_line() {
+            Ok(false) => None,
+            Ok(true) => Some(DocumentRef::from_json(&self.buf)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+impl<R: BufRead + Seek> DocReader<R> {
+    /// Move to the byte `offset`, usually obtained from [MetadataRecord::offset].
+    ///
+    /// The next read document is the one starting at `offset`.
+    pub fn seek(&mut self, offset: u64) -> Result<(), Error> {
+        self.pos = self.r.seek(SeekFrom::Start(offset))?;
+        Ok(())
     }
 }
 
@@ -40,27 +118,70 @@ impl<R: BufRead> DocReader<BufReader<MultiGzDecoder<R>>> {
     }
 }
 
+impl DocReader<Box<dyn BufRead + Send>> {
+    /// Open the file at `path`, detecting its compression from its first bytes.
+    ///
+    /// See [crate::compression] for supported compressions.
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        Ok(DocReader::new(compression::open(path)?))
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl<R: BufRead> DocReader<BufReader<zstd::stream::read::Decoder<'static, R>>> {
+    /// Create a new [DocReader] on zstd-compressed data.
+    ///
+    /// Multi-frame files (e.g. concatenated `.zst` files) are read until the last frame.
+    pub fn from_zstd(r: R) -> Result<Self, Error> {
+        let dec = zstd::stream::read::Decoder::with_buffer(r)?;
+        let br = BufReader::new(dec);
+        Ok(DocReader::new(br))
+    }
+}
+
 impl<R: BufRead> Iterator for DocReader<R> {
     type Item = Result<Document, Error>;
 
     /// Yields [Result]<[Document], [Error]>.
     /// Errors can be either [serde_json::Error] if the format is invalid, or [std::io::Error] if there has been some IO Error.
     fn next(&mut self) -> Option<Self::Item> {
-        let mut s = String::new();
-        match self.r.read_line(&mut s) {
+        match self.read_line() {
             // stop if nothing is read
-            Ok(0) => None,
-            Ok(_) => {
+            Ok(false) => None,
+            Ok(true) => {
                 // Attempt to deserialize, map error to custom error enum if it fails
                 let result: Result<Document, Error> =
-                    serde_json::from_str(&s).map_err(|x| x.into());
+                    serde_json::from_str(&self.buf).map_err(|x| x.into());
                 Some(result)
             }
-            Err(e) => Some(Err(e.into())),
+            Err(e) => Some(Err(e)),
         }
     }
 }
 
+/// Iterator over [MetadataRecord]s, see [DocReader::into_metadata].
+pub struct MetadataReader<R: BufRead> {
+    r: DocReader<R>,
+}
+
+impl<R: BufRead> MetadataReader<R> {
+    /// Get back the inner [DocReader].
+    pub fn into_inner(self) -> DocReader<R> {
+        self.r
+    }
+}
+
+impl<R: BufRead> Iterator for MetadataReader<R> {
+    type Item = Result<MetadataRecord, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.r.next_metadata()
+    }
+}
+
+/// Avro document reader.
+///
+/// Reads files written by [crate::oscar_doc::AvroWriter], or any Avro file whose schema is compatible with [crate::oscar_doc::AVRO_SCHEMA].
 #[cfg(feature = "avro")]
 pub struct AvroDocReader<'a, R> {
     r: Reader<'a, R>,
@@ -68,9 +189,12 @@ pub struct AvroDocReader<'a, R> {
 
 #[cfg(feature = "avro")]
 impl<'a, R: Read> AvroDocReader<'a, R> {
-    pub fn new(r: R) -> Self {
-        let r = Reader::new(r).unwrap();
-        Self { r }
+    /// Create a new [AvroDocReader].
+    ///
+    /// Reads the Avro header, returning an error if it is invalid.
+    pub fn new(r: R) -> Result<Self, Error> {
+        let r = Reader::new(r)?;
+        Ok(Self { r })
     }
 }
 
@@ -82,8 +206,8 @@ impl<'a, R: Read> Iterator for AvroDocReader<'a, R> {
         match self.r.next() {
             // if we properly get a record, try to get document form it.
             // Otherwise, return error.
-            Some(Ok(value)) => match avro_rs::from_value(&value) {
-                Ok(d) => Some(Ok(d)),
+            Some(Ok(value)) => match avro_rs::from_value::<AvroDocument>(&value) {
+                Ok(d) => Some(d.try_into()),
                 Err(e) => Some(Err(e.into())),
             },
             Some(Err(e)) => Some(Err(e.into())),
@@ -92,229 +216,325 @@ impl<'a, R: Read> Iterator for AvroDocReader<'a, R> {
     }
 }
 
-/// In the case where we have multiple splits for a given subcorpus.
-pub struct SplitFileIter {
-    //path to the directory
-    //file names
-    //counter
-    base_path: PathBuf,
-    file_name_start: String,
-    file_name_end: String,
-    file_name_extension: String,
-    counter_start: usize,
-    counter: usize,
-    current_file: Option<DocReader<BufReader<File>>>,
+/// Where to find the files of a split corpus.
+pub enum SplitSource {
+    /// Files named `{file_name_start}{counter}{file_name_end}{file_name_extension}` in `base_path`,
+    /// with `counter` starting at `counter_start`.
+    ///
+    /// If `nb_splits` is [None], `counter` is incremented until a file is not found.
+    /// Otherwise, exactly `nb_splits` files are read, and a missing one is an error.
+    Pattern {
+        base_path: PathBuf,
+        file_name_start: String,
+        file_name_end: String,
+        file_name_extension: String,
+        counter_start: usize,
+        nb_splits: Option<usize>,
+    },
+    /// Every file (max-depth 1) in a folder, in lexicographic order.
+    /// If the path is a file, it is the only one that is read.
+    Folder(PathBuf),
 }
-impl SplitFileIter {
+
+/// Get the files (max-depth 1) of a folder, in lexicographic order.
+///
+/// If the path is a file, it is the only one returned.
+/// Fails if the folder can't be read or is empty.
+pub(crate) fn folder_files(folder: &Path) -> Result<Vec<PathBuf>, Error> {
+    // check if path is file
+    // if it is, return a vec with only one path
+    if folder.is_file() {
+        return Ok(vec![folder.to_path_buf()]);
+    }
+
+    // read files (max-depth 1) and add them to vector
+    let mut files = vec![];
+    for dir in std::fs::read_dir(folder)? {
+        let dir = dir?.path();
+        if dir.is_file() {
+            files.push(dir);
+        }
+    }
+
+    if files.is_empty() {
+        return Err(Error::Custom(format!("No files found in {:?}", folder)));
+    }
+
+    // sort to be deterministic
+    files.sort_unstable();
+    Ok(files)
+}
+
+/// Discovery state of split files.
+enum SplitFiles {
+    Pattern {
+        base_path: PathBuf,
+        file_name_start: String,
+        file_name_end: String,
+        file_name_extension: String,
+        counter_start: usize,
+        counter: usize,
+        nb_splits: Option<usize>,
+    },
+    Folder {
+        // reversed so that pop is practical
+        files: Vec<PathBuf>,
+        nb_files: usize,
+        files_done: usize,
+    },
+}
+
+impl SplitFiles {
+    fn from_source(source: SplitSource) -> Result<Self, Error> {
+        match source {
+            SplitSource::Pattern {
+                base_path,
+                file_name_start,
+                file_name_end,
+                file_name_extension,
+                counter_start,
+                nb_splits,
+            } => Ok(Self::Pattern {
+                base_path,
+                file_name_start,
+                file_name_end,
+                file_name_extension,
+                counter_start,
+                counter: counter_start,
+                nb_splits,
+            }),
+            SplitSource::Folder(folder) => Self::from_folder(&folder),
+        }
+    }
+
+    fn from_folder(folder: &Path) -> Result<Self, Error> {
+        // reverse so that it goes last...first
+        let mut files = folder_files(folder)?;
+        files.reverse();
+        let nb_files = files.len();
+
+        Ok(Self::Folder {
+            files,
+            nb_files,
+            files_done: 0,
+        })
+    }
+
+    /// Opens the next file using `opener`.
+    ///
+    /// Returns [None] when there are no files left.
+    fn open_next<R: BufRead>(
+        &mut self,
+        opener: fn(&Path) -> Result<DocReader<R>, Error>,
+    ) -> Option<Result<DocReader<R>, Error>> {
+        match self {
+            Self::Pattern {
+                base_path,
+                file_name_start,
+                file_name_end,
+                file_name_extension,
+                counter_start,
+                counter,
+                nb_splits,
+            } => {
+                // stop once the expected number of files has been read.
+                if let Some(nb_splits) = nb_splits {
+                    if *counter - *counter_start >= *nb_splits {
+                        return None;
+                    }
+                }
+
+                let filename = format!(
+                    "{}{}{}{}",
+                    file_name_start, counter, file_name_end, file_name_extension
+                );
+                let full_path = base_path.join(filename);
+
+                // skip the file even on error, so that a faulty file does not get reopened indefinitely.
+                let is_first = counter == counter_start;
+                *counter += 1;
+
+                match opener(&full_path) {
+                    Ok(dr) => Some(Ok(dr)),
+
+                    // if the error is a NotFound, then we just arrived at the end,
+                    // unless no file has been opened yet, meaning that the provided path was not found,
+                    // or a number of files is expected.
+                    Err(Error::Io(e))
+                        if e.kind() == std::io::ErrorKind::NotFound
+                            && !is_first
+                            && nb_splits.is_none() =>
+                    {
+                        None
+                    }
+                    Err(e) => Some(Err(e)),
+                }
+            }
+            Self::Folder {
+                files,
+                nb_files,
+                files_done,
+            } => {
+                let next_file_path = files.pop()?;
+                *files_done += 1;
+                info!("Reading file {}/{}", files_done, nb_files);
+                Some(opener(&next_file_path))
+            }
+        }
+    }
+}
+
+/// Reader for corpora split in numerous files.
+///
+/// Files are found following a [SplitSource], and opened with a provided `opener`.
+/// By default, files are opened with [DocReader::open], which detects compression.
+pub struct SplitDocReader<R: BufRead = Box<dyn BufRead + Send>> {
+    files: SplitFiles,
+    opener: fn(&Path) -> Result<DocReader<R>, Error>,
+    current_file: Option<DocReader<R>>,
+}
+
+impl SplitDocReader {
+    /// Create a new [SplitDocReader] reading files following a [SplitSource::Pattern].
     pub fn new(
         base_path: PathBuf,
         file_name_start: &str,
         file_name_end: &str,
         file_name_extension: &str,
         counter_start: usize,
-    ) -> SplitFileIter {
-        SplitFileIter {
-            base_path,
-            file_name_start: file_name_start.to_string(),
-            file_name_end: file_name_end.to_string(),
-            file_name_extension: file_name_extension.to_string(),
-            counter_start,
-            counter: counter_start,
+    ) -> Self {
+        Self {
+            files: SplitFiles::Pattern {
+                base_path,
+                file_name_start: file_name_start.to_string(),
+                file_name_end: file_name_end.to_string(),
+                file_name_extension: file_name_extension.to_string(),
+                counter_start,
+                counter: counter_start,
+                nb_splits: None,
+            },
+            opener: DocReader::open,
             current_file: None,
         }
     }
 
+    /// Create a new [SplitDocReader] from a [SplitSource], opening files with [DocReader::open].
+    ///
+    /// Returns an error if the source is a folder that can't be read or is empty.
+    pub fn from_source(source: SplitSource) -> Result<Self, Error> {
+        Self::with_opener(source, DocReader::open)
+    }
+
+    /// Create a new [SplitDocReader] reading files following a [SplitSource::Folder].
+    ///
+    /// Returns an error if the folder can't be read or is empty.
+    pub fn from_folder(folder: &Path) -> Result<Self, Error> {
+        Self::from_source(SplitSource::Folder(folder.to_path_buf()))
+    }
+}
+
+impl<R: BufRead> SplitDocReader<R> {
+    /// Create a new [SplitDocReader] from a [SplitSource], using `opener` to open each file.
+    ///
+    /// Returns an error if the source is a folder that can't be read or is empty.
+    pub fn with_opener(
+        source: SplitSource,
+        opener: fn(&Path) -> Result<DocReader<R>, Error>,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            files: SplitFiles::from_source(source)?,
+            opener,
+            current_file: None,
+        })
+    }
+}
+
+impl<R: BufRead> SplitDocReader<R> {
+    /// Open the next file, replacing the current one.
+    ///
+    /// Returns a [std::io::ErrorKind::NotFound] error if there are no files left.
+    #[deprecated(note = "files are opened as needed when iterating")]
     pub fn rotate_file(&mut self) -> Result<(), Error> {
-        let filename = self.file_name_start.to_owned()
-            + &self.counter.to_string()
-            + &self.file_name_end
-            + &self.file_name_extension;
-        let mut full_path = self.base_path.clone();
-        full_path.push(filename);
-
-        match File::open(full_path) {
-            // everything is ok, we return a bufreader
-            Ok(f) => {
-                let br = BufReader::new(f);
-                let dr = DocReader::new(br);
-                self.counter += 1;
-                self.current_file = Some(dr);
+        match self.files.open_next(self.opener) {
+            Some(Ok(file)) => {
+                self.current_file = Some(file);
                 Ok(())
             }
-
-            // if the error is a NotFound, then we just arrived at the end
-            // if not, there has been a problem.
-            Err(e) => Err(e.into()),
+            Some(Err(e)) => Err(e),
+            None => Err(Error::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "no split file left",
+            ))),
         }
     }
 }
 
-// TODO: Check with gzipped
-impl Iterator for SplitFileIter {
-    // type Item = Result<BufReader<File>, Error>;
+impl<R: BufRead> Iterator for SplitDocReader<R> {
     type Item = Result<Document, Error>;
 
     /// Iterator on documents that is seamlessly iterating on file splits.
-    /// **Currently uses some recursion and shouldn't (but may) recurse indefinitely.**
+    ///
+    /// Errors when opening a file are returned, and the file is skipped on the next call.
     fn next(&mut self) -> Option<Self::Item> {
-        // check if current file is None or not
-        match &mut self.current_file {
-            // if current file is none, attempt to rotate file
-            None => {
-                match self.rotate_file() {
-                    //if rotation went well, we can try again to call next()
-                    // TODO: remove potential infinite recursion
-                    Ok(()) => self.next(),
-
-                    // if rotating went wrong, check if it's because of not found (end of split files) or other error
-                    Err(e) => match e {
-                        // if ioerror, check if it's because of a not found or not
-                        Error::Io(ioerror) => {
-                            // check not found condition and ensure that files have been rotated at least once
-                            // or it could mean that the base provided path was not found.
-                            if ioerror.kind() == std::io::ErrorKind::NotFound
-                                && self.counter > self.counter_start
-                            {
-                                None
-
-                            // If the error is not NotFound, return the error
-                            } else {
-                                Some(Err(ioerror.into()))
-                            }
-                        }
-
-                        // return the error for non io errors
-                        other => Some(Err(other)),
-                    },
+        loop {
+            // if there is an already opened file, get next document.
+            // if next document is none (=EOF), close file.
+            if let Some(file) = &mut self.current_file {
+                match file.next() {
+                    Some(doc_result) => return Some(doc_result),
+                    None => self.current_file = None,
                 }
             }
 
-            // if there is an already opened file, get next document.
-            // if next document is none (=EOF), close file by setting to None and
-            // recursively call next.
-            Some(file) => match file.next() {
-                Some(doc_result) => Some(doc_result),
-                None => {
-                    // close file and try to open a new one
-                    self.current_file = None;
-                    self.next()
-                }
-            },
+            // attempt to open the next file, stopping if there's none left.
+            match self.files.open_next(self.opener)? {
+                Ok(file) => self.current_file = Some(file),
+                Err(e) => return Some(Err(e)),
+            }
         }
     }
 }
 
-pub struct SplitFolderFileIter {
-    current_file: Option<DocReader<BufReader<File>>>,
-    files: Vec<PathBuf>,
-    nb_files: usize,
-    files_done: usize,
-    //files: Box<dyn Iterator<Item = PathBuf>>,
-}
+/// Former name of [SplitDocReader].
+#[deprecated(note = "use SplitDocReader (re-exported as SplitReader)")]
+pub type SplitFileIter = SplitDocReader;
 
+/// Former folder-based split reader, now a [SplitDocReader] reading a [SplitSource::Folder].
+#[deprecated(note = "use SplitDocReader::from_folder (re-exported as SplitReader)")]
+pub struct SplitFolderFileIter(SplitDocReader);
+
+#[allow(deprecated)]
 impl SplitFolderFileIter {
-    /// Create a new Self. If folder is a fie, the vector will be only populated with it.
+    /// Create a new reader on every file of `folder`.
+    ///
+    /// Forwards to [SplitDocReader::from_folder].
     pub fn new(folder: &Path) -> Result<Self, Error> {
-        // check if path is file
-        // if it is, return a vec with only one path
-        if folder.is_file() {
-            Ok(Self {
-                current_file: None,
-                files: vec![folder.to_path_buf()],
-                nb_files: 1,
-                files_done: 0,
-            })
-        } else {
-            match std::fs::read_dir(folder) {
-                Ok(read_dir) => {
-                    // read files (max-depth 1) and add them to vector
-                    let mut files = vec![];
-                    for dir in read_dir {
-                        let dir = dir?.path();
-                        if dir.is_file() {
-                            files.push(dir);
-                        }
-                    }
-
-                    if files.is_empty() {
-                        return Err(Error::Custom(format!("No files found in {:?}", folder)));
-                    }
-                    // sort to be deterministic
-                    files.sort_unstable();
-
-                    // reverse so that it goes last...first
-                    // and pop is more practical
-                    files.reverse();
-                    let nb_files = files.len();
-
-                    Ok(Self {
-                        current_file: None,
-                        files,
-                        nb_files,
-                        files_done: 0,
-                    })
-                }
-                Err(e) => Err(e.into()),
-            }
-        }
+        SplitDocReader::from_folder(folder).map(Self)
     }
 
+    /// Open the next file, replacing the current one.
+    ///
+    /// Returns [None] if there are no files left.
     pub fn open_next_file(&mut self) -> Option<Result<(), Error>> {
-        let next_file_path = self.files.pop();
-
-        if let Some(next_file_path) = next_file_path {
-            match File::open(next_file_path) {
-                // everything is ok, we return a bufreader
-                Ok(f) => {
-                    let br = BufReader::new(f);
-                    let dr = DocReader::new(br);
-                    self.current_file = Some(dr);
-                    self.files_done += 1;
-                    info!("Reading file {}/{}", self.files_done, self.nb_files);
-                    Some(Ok(()))
-                }
-
-                // if the error is a NotFound, then we just arrived at the end
-                // if not, there has been a problem.
-                Err(e) => Some(Err(e.into())),
-            }
-        } else {
-            None
-        }
+        let reader = &mut self.0;
+        let file = reader.files.open_next(reader.opener)?;
+        Some(file.map(|file| reader.current_file = Some(file)))
     }
 }
 
+#[allow(deprecated)]
 impl Iterator for SplitFolderFileIter {
     type Item = Result<Document, Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match &mut self.current_file {
-            // if current file is none, attempt to rotate file
-            None => {
-                match self.open_next_file() {
-                    //if rotation went well, we can try again to call next()
-                    // TODO: remove potential infinite recursion
-                    Some(Ok(())) => self.next(),
-                    Some(Err(e)) => Some(Err(e)),
-                    None => None
-                    // None => Some(Err(Error::Custom(
-                    //     "Something went wrong when trying to open the first file..".to_string(),
-                    // ))),
-                }
-            }
+        self.0.next()
+    }
+}
 
-            // if there is an already opened file, get next document.
-            // if next document is none (=EOF), close file by setting to None and
-            // recursively call next.
-            Some(file) => match file.next() {
-                Some(doc_result) => Some(doc_result),
-                None => {
-                    // close file and try to open a new one
-                    self.current_file = None;
-                    self.next()
-                }
-            },
-        }
+#[allow(deprecated)]
+impl From<SplitFolderFileIter> for SplitDocReader {
+    fn from(r: SplitFolderFileIter) -> Self {
+        r.0
     }
 }
 
@@ -324,7 +544,10 @@ mod tests {
     use std::io::{BufReader, Cursor, Write};
 
     use super::DocReader;
-    use crate::{error::Error, v3::Document};
+    use crate::{
+        error::Error,
+        v3::{Document, MetadataRecord},
+    };
     use flate2::{write::GzEncoder, Compression};
 
     fn get_samples() -> &'static str {
@@ -350,11 +573,52 @@ mod tests {
         let content = r#"{"foo": "bar"}"#;
         let mut r = DocReader::new(content.as_bytes());
         match r.next() {
-            Some(Err(Error::SerdeJson(_))) => assert!(true),
+            Some(Err(Error::SerdeJson(_))) => (),
             x => panic!("wrong return: {:?}", x),
         }
     }
 
+    #[test]
+    fn test_next_ref() {
+        let content = get_samples();
+        let documents: Vec<Document> = DocReader::new(content.as_bytes())
+            .map(|x| x.unwrap())
+            .collect();
+
+        let mut r = DocReader::new(content.as_bytes());
+        let mut documents_from_ref = vec![];
+        while let Some(doc) = r.next_ref() {
+            let doc = doc.unwrap();
+            assert_eq!(doc.url(), Some("http://accueil-enfants-d-un-meme-pere.be/"));
+            documents_from_ref.push(doc.into_owned());
+        }
+
+        assert_eq!(documents, documents_from_ref);
+    }
+
+    #[test]
+    fn test_metadata() {
+        let content = get_samples();
+        let documents: Vec<Document> = DocReader::new(content.as_bytes())
+            .map(|x| x.unwrap())
+            .collect();
+
+        let records: Vec<MetadataRecord> = DocReader::new(content.as_bytes())
+            .into_metadata()
+            .map(|x| x.unwrap())
+            .collect();
+        assert_eq!(records.len(), 5);
+
+        // fetch documents back from their offsets, in reverse order
+        let mut r = DocReader::new(Cursor::new(content.as_bytes()));
+        for (record, doc) in records.iter().zip(&documents).rev() {
+            assert_eq!(record.identification(), doc.identification());
+            assert_eq!(record.url(), doc.url().as_deref());
+            r.seek(record.offset()).unwrap();
+            assert_eq!(&r.next().unwrap().unwrap(), doc);
+        }
+    }
+
     #[test]
     fn test_compressed_data() {
         let content = get_samples();
@@ -368,7 +632,7 @@ mod tests {
         let mut compressed_content = vec![];
         {
             let mut enc = GzEncoder::new(&mut compressed_content, Compression::fast());
-            enc.write(content.as_bytes()).unwrap();
+            enc.write_all(content.as_bytes()).unwrap();
         }
 
         let c = Cursor::new(&mut compressed_content);
@@ -377,4 +641,26 @@ mod tests {
 
         assert_eq!(documents.unwrap(), documents_from_compressed.unwrap())
     }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_zstd_multiframe() {
+        let content = get_samples();
+
+        let r = DocReader::new(content.as_bytes());
+        let documents: Vec<Document> = r.map(|x| x.unwrap()).collect();
+
+        // compress each line as a separate frame
+        let mut compressed_content = vec![];
+        for line in content.lines() {
+            let frame = zstd::encode_all(format!("{line}\n").as_bytes(), 3).unwrap();
+            compressed_content.extend(frame);
+        }
+
+        let r = DocReader::from_zstd(compressed_content.as_slice()).unwrap();
+        let documents_from_compressed: Vec<Document> = r.map(|x| x.unwrap()).collect();
+
+        assert_eq!(documents.len(), 5);
+        assert_eq!(documents, documents_from_compressed);
+    }
 }
@@ -1,12 +1,14 @@
 use serde::{Deserialize, Serialize};
+use warc::WarcHeader;
 
 use crate::common::Identification;
+use crate::error::Error;
 
 use super::{Metadata, WarcHeaders};
 
 /// A Document is a structure holding content, WARC headers and OSCAR-specific metadata.
 /// - TODO: Change warc_headers from [RawRecordHeader] to [warc::Record] with [warc::EmptyBody]?
-/// This way we shouldn't have to parse strings or use unwrap on [RawRecordHeader].
+///   This way we shouldn't have to parse strings or use unwrap on [RawRecordHeader].
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct Document {
     content: String,
@@ -23,8 +25,6 @@ impl Document {
         }
     }
 
-    /// Instantiate a Document from a record and a related metadata.
-
     /// Get a reference to the Document's identification
     pub fn identification(&self) -> &Identification<String> {
         self.metadata().identification()
@@ -56,7 +56,171 @@ impl Document {
     }
 }
 
+/// Upgrades a v2 [Document] into a [crate::v3::Document].
+///
+/// `annotation` becomes `quality_warnings`, and fields that do not exist in v2 (`harmful_pp`, `tlsh`, `categories`) are left empty.
+impl From<Document> for crate::v3::Document {
+    fn from(d: Document) -> Self {
+        let warc_headers: crate::v3::WarcHeaders = d
+            .warc_headers
+            .into_iter()
+            .map(|(k, v)| (WarcHeader::from(k), v.into_bytes()))
+            .collect();
+
+        let mut metadata = crate::v3::Metadata::new(
+            d.metadata.identification(),
+            d.metadata.sentence_identifications(),
+        );
+        metadata.set_annotation(
+            d.metadata
+                .annotation()
+                .map(|annotation| annotation.iter().map(|a| a.as_str().into()).collect()),
+        );
+
+        Self::new(d.content, warc_headers, metadata)
+    }
+}
+
+/// v3 fields that have no v2 counterpart, and are dropped when downgrading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DroppedField {
+    HarmfulPp,
+    Tlsh,
+    Categories,
+    /// Metadata extensions (see [crate::v3::Metadata::extension]).
+    Extensions,
+}
+
+impl Document {
+    /// Downgrade a [crate::v3::Document], returning the fields that could not be represented in v2.
+    ///
+    /// `quality_warnings` becomes `annotation`.
+    /// Fails if a WARC header value is not valid UTF-8.
+    pub fn from_v3(d: crate::v3::Document) -> Result<(Self, Vec<DroppedField>), Error> {
+        let identification = d.identification().clone();
+        let (content, warc_headers, metadata) = d.into_parts();
+
+        let mut dropped = Vec::new();
+        if metadata.harmful_pp().is_some() {
+            dropped.push(DroppedField::HarmfulPp);
+        }
+        if metadata.tlsh().is_some() {
+            dropped.push(DroppedField::Tlsh);
+        }
+        if metadata.categories().is_some() {
+            dropped.push(DroppedField::Categories);
+        }
+        if !metadata.extensions().is_empty() {
+            dropped.push(DroppedField::Extensions);
+        }
+
+        let warc_headers = warc_headers
+            .into_iter()
+            .map(|(k, v)| Ok((k.to_string(), String::from_utf8(v)?)))
+            .collect::<Result<WarcHeaders, Error>>()?;
+
+        let metadata = Metadata::new(
+            &identification,
+            &metadata
+                .annotation()
+                .map(|annotation| annotation.iter().map(|a| a.to_string()).collect()),
+            metadata.sentence_identifications(),
+        );
+
+        Ok((Self::new(content, warc_headers, metadata), dropped))
+    }
+}
+
+/// Downgrades a [crate::v3::Document] into a v2 [Document].
+///
+/// Fields listed in [DroppedField] are silently dropped, use [Document::from_v3] to know which ones were.
+impl TryFrom<crate::v3::Document> for Document {
+    type Error = Error;
+
+    fn try_from(d: crate::v3::Document) -> Result<Self, Self::Error> {
+        Self::from_v3(d).map(|(d, _)| d)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    // TODO
+    use std::collections::HashMap;
+
+    use oxilangtag::LanguageTag;
+    use warc::WarcHeader;
+
+    use crate::common::Identification;
+    use crate::v3::{self, Category, QualityWarning};
+
+    use super::{Document, DroppedField};
+
+    fn get_v3() -> v3::Document {
+        let id = Identification::new(LanguageTag::parse("fr".to_string()).unwrap(), 0.9);
+        let mut metadata = v3::Metadata::new(&id, &[Some(id.clone()), None]);
+        metadata.set_annotation(Some(vec![QualityWarning::ShortSentences]));
+
+        let mut headers = HashMap::new();
+        headers.insert(WarcHeader::RecordID, b"<urn:uuid:1>".to_vec());
+        headers.insert(
+            WarcHeader::Unknown("warc-identified-content-language".to_string()),
+            b"fra".to_vec(),
+        );
+
+        v3::Document::new("Bonjour\nau revoir".to_string(), headers, metadata)
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let doc = get_v3();
+        let (v2, dropped) = Document::from_v3(doc.clone()).unwrap();
+        assert!(dropped.is_empty());
+        assert_eq!(
+            v2.warc_headers().get("warc-record-id"),
+            Some(&"<urn:uuid:1>".to_string())
+        );
+        assert_eq!(
+            v2.metadata().annotation(),
+            Some(&vec!["short_sentences".to_string()])
+        );
+
+        let v3: v3::Document = v2.into();
+        assert_eq!(doc, v3);
+    }
+
+    #[test]
+    fn test_dropped() {
+        let mut doc = get_v3();
+        doc.metadata_mut().set_harmful_pp(Some(12.5));
+        doc.metadata_mut()
+            .set_categories(Some(vec![Category::Adult]));
+        doc.metadata_mut()
+            .set_extension("kenlm_pp", &100.0)
+            .unwrap();
+
+        let (v2, dropped) = Document::from_v3(doc.clone()).unwrap();
+        assert_eq!(
+            dropped,
+            vec![
+                DroppedField::HarmfulPp,
+                DroppedField::Categories,
+                DroppedField::Extensions
+            ]
+        );
+
+        let v3: v3::Document = v2.into();
+        assert_eq!(v3.metadata().harmful_pp(), None);
+        assert_eq!(v3.metadata().categories(), None);
+        assert!(v3.metadata().extensions().is_empty());
+        assert_eq!(v3.content(), doc.content());
+    }
+
+    #[test]
+    fn test_invalid_header() {
+        let mut doc = get_v3();
+        let (content, mut headers, metadata) = doc.into_parts();
+        headers.insert(WarcHeader::TargetURI, vec![0xff, 0xfe]);
+        doc = v3::Document::new(content, headers, metadata);
+
+        assert!(Document::try_from(doc).is_err());
+    }
 }
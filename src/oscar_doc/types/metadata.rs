@@ -44,6 +44,11 @@ impl Metadata {
     pub fn annotation(&self) -> Option<&Vec<String>> {
         self.annotation.as_ref()
     }
+
+    /// Get a reference to the metadata's sentence identifications.
+    pub fn sentence_identifications(&self) -> &[Option<Identification<String>>] {
+        self.sentence_identifications.as_ref()
+    }
 }
 
 impl Default for Metadata {
@@ -8,5 +8,5 @@ mod metadata;
 pub type WarcHeaders = HashMap<String, String>;
 use std::collections::HashMap;
 
-pub use document::Document;
+pub use document::{Document, DroppedField};
 pub use metadata::Metadata;
@@ -1,16 +1,30 @@
-//! Document writer. Does only implement simple write for now.
-use std::io::Write;
+//! Document writers, either on a single [Write] ([DocWriter]) or on a rotating set of files ([SplitDocWriter]).
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+#[cfg(feature = "avro")]
+use std::{collections::HashMap, sync::OnceLock};
 
+#[cfg(feature = "avro")]
+use avro_rs::{Codec, Schema};
+use flate2::{write::GzEncoder, Compression};
+#[cfg(feature = "avro")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "avro")]
+use warc::WarcHeader;
+
+#[cfg(feature = "avro")]
+use crate::common::Identification;
 use crate::error::Error;
 
 use crate::v3::Document;
+#[cfg(feature = "avro")]
+use crate::v3::{Category, Metadata, QualityWarning};
 
 pub struct DocWriter<W: Write> {
     w: W,
 }
 
-//TODO: DocWriter on GzEncoder
-
 impl<W: Write> DocWriter<W> {
     pub fn new(writer: W) -> Self {
         Self { w: writer }
@@ -41,17 +55,530 @@ impl<W: Write> DocWriter<W> {
     }
 }
 
+impl<W: Write> DocWriter<GzEncoder<W>> {
+    /// Create a new [DocWriter] that gzips documents at the provided compression level.
+    ///
+    /// The gzip trailer is written either by [Self::finish] or when the writer is dropped.
+    /// Prefer [Self::finish], since errors are ignored on drop.
+    pub fn from_gzip(w: W, level: Compression) -> Self {
+        Self::new(GzEncoder::new(w, level))
+    }
+
+    /// Writes the gzip trailer and returns the inner writer.
+    pub fn finish(self) -> Result<W, Error> {
+        Ok(self.w.finish()?)
+    }
+}
+
+/// State of a [GzMemberEncoder].
+enum Member<W: Write> {
+    /// Between two members.
+    Idle(W),
+    Writing(GzEncoder<W>),
+}
+
+/// Gzip encoder that starts a new gzip member every `member_docs` documents (i.e. lines).
+///
+/// The output is a regular (multi-member) gzip file, see [DocWriter::from_gzip_members].
+pub struct GzMemberEncoder<W: Write> {
+    // None only if finishing a member failed
+    member: Option<Member<W>>,
+    level: Compression,
+    member_docs: u64,
+    docs_in_member: u64,
+}
+
+impl<W: Write> GzMemberEncoder<W> {
+    fn new(w: W, level: Compression, member_docs: u64) -> Self {
+        Self {
+            member: Some(Member::Idle(w)),
+            level,
+            member_docs: member_docs.max(1),
+            docs_in_member: 0,
+        }
+    }
+
+    fn poisoned() -> std::io::Error {
+        std::io::Error::other("writer is unusable after a failed gzip member finalization")
+    }
+
+    /// Get the current member encoder, starting a new member if needed.
+    fn encoder(&mut self) -> std::io::Result<&mut GzEncoder<W>> {
+        if let Some(Member::Idle(_)) = self.member {
+            if let Some(Member::Idle(w)) = self.member.take() {
+                self.member = Some(Member::Writing(GzEncoder::new(w, self.level)));
+            }
+        }
+
+        match &mut self.member {
+            Some(Member::Writing(enc)) => Ok(enc),
+            _ => Err(Self::poisoned()),
+        }
+    }
+
+    /// Write the trailer of the current member, if any.
+    fn finish_member(&mut self) -> std::io::Result<()> {
+        self.docs_in_member = 0;
+        self.member = match self.member.take() {
+            Some(Member::Writing(enc)) => Some(Member::Idle(enc.finish()?)),
+            member => member,
+        };
+        Ok(())
+    }
+
+    /// Writes the trailer of the current member and returns the inner writer.
+    pub fn finish(mut self) -> std::io::Result<W> {
+        match self.member.take() {
+            Some(Member::Writing(enc)) => enc.finish(),
+            Some(Member::Idle(w)) => Ok(w),
+            None => Err(Self::poisoned()),
+        }
+    }
+}
+
+impl<W: Write> Write for GzMemberEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        // write up to the end of the current document at most
+        let end = buf
+            .iter()
+            .position(|b| *b == b'\n')
+            .map_or(buf.len(), |pos| pos + 1);
+        let written = self.encoder()?.write(&buf[..end])?;
+
+        if written == end && buf[end - 1] == b'\n' {
+            self.docs_in_member += 1;
+            if self.docs_in_member >= self.member_docs {
+                self.finish_member()?;
+            }
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match &mut self.member {
+            Some(Member::Writing(enc)) => enc.flush(),
+            Some(Member::Idle(w)) => w.flush(),
+            None => Err(Self::poisoned()),
+        }
+    }
+}
+
+impl<W: Write> DocWriter<GzMemberEncoder<W>> {
+    /// Create a new [DocWriter] that gzips documents, starting a new gzip member every `member_docs` documents.
+    ///
+    /// Files are read as any gzip file, and smaller members make random access faster
+    /// with [crate::oscar_doc::IndexedReader], since records are fetched by decoding their member from its start.
+    /// The last member is finished by [Self::finish] or when the writer is dropped.
+    pub fn from_gzip_members(w: W, level: Compression, member_docs: u64) -> Self {
+        Self::new(GzMemberEncoder::new(w, level, member_docs))
+    }
+
+    /// Writes the trailer of the last gzip member and returns the inner writer.
+    pub fn finish(self) -> Result<W, Error> {
+        Ok(self.w.finish()?)
+    }
+}
+
+/// File currently written by a [SplitDocWriter].
+enum SplitFile {
+    Plain(BufWriter<File>),
+    Gzip(GzEncoder<BufWriter<File>>),
+}
+
+impl SplitFile {
+    /// Writes the gzip trailer if needed and flushes the file.
+    fn finish(self) -> Result<(), Error> {
+        let mut w = match self {
+            Self::Plain(w) => w,
+            Self::Gzip(w) => w.finish()?,
+        };
+        Ok(w.flush()?)
+    }
+}
+
+impl Write for SplitFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Plain(w) => w.write(buf),
+            Self::Gzip(w) => w.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Plain(w) => w.flush(),
+            Self::Gzip(w) => w.flush(),
+        }
+    }
+}
+
+/// Rotating document writer.
+///
+/// Documents are written in `dst`, in files named `{prefix}_part_{n}.jsonl` (or `.jsonl.gz` if gzipped), `n` starting at 1.
+/// These can be read back by [crate::oscar_doc::SplitReader::new] with `{prefix}_part_` as `file_name_start`.
+///
+/// A new file is created when writing the next document would exceed `max_bytes` (uncompressed),
+/// or when the current file already holds `max_docs` documents.
+/// Documents are never split across files, meaning that a document larger than `max_bytes` is written alone in its file.
+pub struct SplitDocWriter {
+    dst: PathBuf,
+    prefix: String,
+    compression: Option<Compression>,
+    max_bytes: Option<u64>,
+    max_docs: Option<u64>,
+    current_file: Option<SplitFile>,
+    bytes_written: u64,
+    docs_written: u64,
+    files: Vec<PathBuf>,
+}
+
+impl SplitDocWriter {
+    /// Create a new uncompressed [SplitDocWriter].
+    /// Note that nothing is created/written unless a write is performed.
+    pub fn new(dst: &Path, prefix: &str, max_bytes: Option<u64>, max_docs: Option<u64>) -> Self {
+        Self {
+            dst: dst.to_path_buf(),
+            prefix: prefix.to_string(),
+            compression: None,
+            max_bytes,
+            max_docs,
+            current_file: None,
+            bytes_written: 0,
+            docs_written: 0,
+            files: Vec::new(),
+        }
+    }
+
+    /// Create a new [SplitDocWriter] that gzips files at the provided compression level.
+    /// Note that nothing is created/written unless a write is performed.
+    pub fn from_gzip(
+        dst: &Path,
+        prefix: &str,
+        max_bytes: Option<u64>,
+        max_docs: Option<u64>,
+        level: Compression,
+    ) -> Self {
+        Self {
+            compression: Some(level),
+            ..Self::new(dst, prefix, max_bytes, max_docs)
+        }
+    }
+
+    /// Finishes the current file (if any) and creates the next one.
+    pub fn create_next_file(&mut self) -> Result<(), Error> {
+        if let Some(file) = self.current_file.take() {
+            file.finish()?;
+        }
+
+        let extension = match self.compression {
+            Some(_) => "jsonl.gz",
+            None => "jsonl",
+        };
+        let path = self.dst.join(format!(
+            "{}_part_{}.{}",
+            self.prefix,
+            self.files.len() + 1,
+            extension
+        ));
+
+        let w = BufWriter::new(File::create(&path)?);
+        self.current_file = Some(match self.compression {
+            Some(level) => SplitFile::Gzip(GzEncoder::new(w, level)),
+            None => SplitFile::Plain(w),
+        });
+        self.files.push(path);
+        self.bytes_written = 0;
+        self.docs_written = 0;
+        Ok(())
+    }
+
+    /// Check if writing `nb_bytes` more would go over the limits of the current file.
+    fn is_full(&self, nb_bytes: u64) -> bool {
+        // always allow at least one document per file
+        if self.docs_written == 0 {
+            return false;
+        }
+        let too_many_bytes = self
+            .max_bytes
+            .is_some_and(|max| self.bytes_written + nb_bytes > max);
+        let too_many_docs = self.max_docs.is_some_and(|max| self.docs_written >= max);
+        too_many_bytes || too_many_docs
+    }
+
+    /// Serializes the document and writes it, rotating files if needed.
+    pub fn write(&mut self, doc: &Document) -> Result<(), Error> {
+        let write_bytes = serde_json::to_string(doc)? + "\n";
+        let nb_bytes = write_bytes.len() as u64;
+
+        if self.current_file.is_none() || self.is_full(nb_bytes) {
+            self.create_next_file()?;
+        }
+
+        if let Some(file) = &mut self.current_file {
+            file.write_all(write_bytes.as_bytes())?;
+        }
+        self.bytes_written += nb_bytes;
+        self.docs_written += 1;
+
+        Ok(())
+    }
+
+    /// calls [Self::write] for each document, returning an error if there's any failure, then calls [Self::flush].
+    pub fn write_multiple(&mut self, docs: &[Document]) -> Result<(), Error> {
+        for doc in docs {
+            self.write(doc)?;
+        }
+        self.flush()
+    }
+
+    /// Flushes the current file, if any.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        match &mut self.current_file {
+            Some(file) => Ok(file.flush()?),
+            None => Ok(()),
+        }
+    }
+
+    /// Get the paths of the files that have been created so far.
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// Finishes the current file (writing the gzip trailer if needed), and returns the paths of the created files.
+    pub fn finish(mut self) -> Result<Vec<PathBuf>, Error> {
+        if let Some(file) = self.current_file.take() {
+            file.finish()?;
+        }
+        Ok(std::mem::take(&mut self.files))
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl<W: Write> DocWriter<zstd::stream::write::Encoder<'static, W>> {
+    /// Create a new [DocWriter] that compresses documents with zstd.
+    ///
+    /// `level` is the zstd compression level (`0` being zstd's default), and `workers` is
+    /// the number of compression threads (`0` disables multithreading).
+    ///
+    /// **[Self::finish] has to be called** to end the zstd frame: contrary to [Self::from_gzip], nothing is written on drop.
+    pub fn from_zstd(w: W, level: i32, workers: u32) -> Result<Self, Error> {
+        let mut enc = zstd::stream::write::Encoder::new(w, level)?;
+        if workers > 0 {
+            enc.multithread(workers)?;
+        }
+        Ok(Self::new(enc))
+    }
+
+    /// Ends the zstd frame and returns the inner writer.
+    pub fn finish(self) -> Result<W, Error> {
+        Ok(self.w.finish()?)
+    }
+}
+
+/// Avro schema of [Document]s, as written by [AvroDocWriter].
+///
+/// Field order follows the (de)serialization order of [Document] and [crate::v3::Metadata].
+/// Sentence identifications use their own record type since named type references are not supported by [avro_rs].
+/// Metadata extensions are stored as a JSON object in `extensions`, which is null when there is none.
+#[cfg(feature = "avro")]
+pub const AVRO_SCHEMA: &str = r#"{
+    "type": "record",
+    "name": "Document",
+    "namespace": "oscar_corpus.v3",
+    "fields": [
+        {"name": "content", "type": "string"},
+        {"name": "warc_headers", "type": {"type": "map", "values": "string"}},
+        {"name": "metadata", "type": {
+            "type": "record",
+            "name": "Metadata",
+            "fields": [
+                {"name": "identification", "type": {
+                    "type": "record",
+                    "name": "Identification",
+                    "fields": [
+                        {"name": "label", "type": "string"},
+                        {"name": "prob", "type": "float"}
+                    ]
+                }},
+                {"name": "harmful_pp", "type": ["null", "float"]},
+                {"name": "tlsh", "type": ["null", "string"]},
+                {"name": "quality_warnings", "type": ["null", {"type": "array", "items": "string"}]},
+                {"name": "categories", "type": ["null", {"type": "array", "items": "string"}]},
+                {"name": "sentence_identifications", "type": {"type": "array", "items": ["null", {
+                    "type": "record",
+                    "name": "SentenceIdentification",
+                    "fields": [
+                        {"name": "label", "type": "string"},
+                        {"name": "prob", "type": "float"}
+                    ]
+                }]}},
+                {"name": "extensions", "type": ["null", "string"], "default": null}
+            ]
+        }}
+    ]
+}"#;
+
+/// Get the parsed [AVRO_SCHEMA].
+#[cfg(feature = "avro")]
+pub fn avro_schema() -> &'static Schema {
+    static SCHEMA: OnceLock<Schema> = OnceLock::new();
+    SCHEMA.get_or_init(|| Schema::parse_str(AVRO_SCHEMA).expect("AVRO_SCHEMA is a valid schema"))
+}
+
+/// [Document] as stored in Avro (see [AVRO_SCHEMA]).
+#[cfg(feature = "avro")]
+#[derive(Serialize, Deserialize)]
+pub(crate) struct AvroDocument {
+    content: String,
+    warc_headers: HashMap<WarcHeader, String>,
+    metadata: AvroMetadata,
+}
+
+/// [Metadata] as stored in Avro, with extensions serialized to JSON.
+#[cfg(feature = "avro")]
+#[derive(Serialize, Deserialize)]
+struct AvroMetadata {
+    identification: Identification<String>,
+    harmful_pp: Option<f32>,
+    tlsh: Option<String>,
+    quality_warnings: Option<Vec<QualityWarning>>,
+    categories: Option<Vec<Category>>,
+    sentence_identifications: Vec<Option<Identification<String>>>,
+    extensions: Option<String>,
+}
+
+#[cfg(feature = "avro")]
+impl TryFrom<&Document> for AvroDocument {
+    type Error = Error;
+
+    fn try_from(doc: &Document) -> Result<Self, Self::Error> {
+        let warc_headers = doc
+            .warc_headers()
+            .iter()
+            .map(|(k, v)| (k.clone(), String::from_utf8_lossy(v).into_owned()))
+            .collect();
+
+        let metadata = doc.metadata();
+        let extensions = if metadata.extensions().is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(metadata.extensions())?)
+        };
+
+        Ok(Self {
+            content: doc.content().clone(),
+            warc_headers,
+            metadata: AvroMetadata {
+                identification: doc.identification().clone(),
+                harmful_pp: metadata.harmful_pp(),
+                tlsh: metadata.tlsh().cloned(),
+                quality_warnings: metadata.annotation().cloned(),
+                categories: metadata.categories().cloned(),
+                sentence_identifications: metadata.sentence_identifications().to_vec(),
+                extensions,
+            },
+        })
+    }
+}
+
+#[cfg(feature = "avro")]
+impl TryFrom<AvroDocument> for Document {
+    type Error = Error;
+
+    fn try_from(doc: AvroDocument) -> Result<Self, Self::Error> {
+        let avro_meta = doc.metadata;
+        let mut metadata = Metadata::new(
+            &avro_meta.identification,
+            &avro_meta.sentence_identifications,
+        );
+        metadata.set_harmful_pp(avro_meta.harmful_pp);
+        metadata.set_tlsh(avro_meta.tlsh);
+        metadata.set_annotation(avro_meta.quality_warnings);
+        metadata.set_categories(avro_meta.categories);
+        if let Some(extensions) = avro_meta.extensions {
+            *metadata.extensions_mut() = serde_json::from_str(&extensions)?;
+        }
+
+        let warc_headers: HashMap<WarcHeader, Vec<u8>> = doc
+            .warc_headers
+            .into_iter()
+            .map(|(k, v)| (k, v.into_bytes()))
+            .collect();
+
+        Ok(Document::new(doc.content, warc_headers, metadata))
+    }
+}
+
+/// Avro document writer, using [AVRO_SCHEMA].
+///
+/// Documents are buffered in blocks by the inner [avro_rs::Writer],
+/// so be sure to call [Self::flush] or [Self::into_inner] when done.
+#[cfg(feature = "avro")]
+pub struct AvroDocWriter<W: Write> {
+    w: avro_rs::Writer<'static, W>,
+}
+
+#[cfg(feature = "avro")]
+impl<W: Write> AvroDocWriter<W> {
+    /// Create a new [AvroDocWriter] without compression.
+    pub fn new(writer: W) -> Self {
+        Self::with_codec(writer, Codec::Null)
+    }
+
+    /// Create a new [AvroDocWriter] using the provided [Codec] to compress blocks.
+    pub fn with_codec(writer: W, codec: Codec) -> Self {
+        Self {
+            w: avro_rs::Writer::with_codec(avro_schema(), writer, codec),
+        }
+    }
+
+    /// Serializes and appends the document to the current block.
+    pub fn write(&mut self, doc: &Document) -> Result<(), Error> {
+        self.w.append_ser(AvroDocument::try_from(doc)?)?;
+        Ok(())
+    }
+
+    /// calls [Self::write] for each document, returning an error if there's any failure, then calls [Self::flush].
+    pub fn write_multiple(&mut self, docs: &[Document]) -> Result<(), Error> {
+        for doc in docs {
+            self.write(doc)?;
+        }
+        self.flush()
+    }
+
+    /// Writes the current block to the inner writer and flushes it.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.w.flush()?;
+        Ok(())
+    }
+
+    /// Flushes and returns the inner writer.
+    pub fn into_inner(self) -> Result<W, Error> {
+        Ok(self.w.into_inner()?)
+    }
+}
+
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use std::{
         fs::File,
         io::{BufReader, Cursor},
     };
 
-    use crate::oscar_doc::Reader;
-    use crate::v3::Document;
+    use flate2::Compression;
+
+    use crate::oscar_doc::{Reader, SplitReader};
+    use oxilangtag::LanguageTag;
+    use warc::WarcHeader;
+
+    use crate::common::Identification;
+    use crate::v3::{Category, Document, QualityWarning};
 
-    use super::DocWriter;
+    use super::{DocWriter, SplitDocWriter};
 
     fn get_docs() -> Vec<Document> {
         let f = File::open("tests/res/data.jsonl").unwrap();
@@ -60,6 +587,56 @@ mod tests {
         cr.map(|x| x.unwrap()).collect()
     }
 
+    /// Documents with every optional metadata field set (including extensions and missing sentence identifications),
+    /// and with empty quality warnings.
+    pub(crate) fn get_full_docs() -> Vec<Document> {
+        let id = |label: &str, prob| {
+            Identification::new(LanguageTag::parse(label.to_string()).unwrap(), prob)
+        };
+
+        let mut full = Document::builder()
+            .content("Hello world\nBonjour le monde\n1234")
+            .identification(id("en", 0.6))
+            .sentence_identifications(vec![Some(id("en", 0.9)), Some(id("fr", 0.8)), None])
+            .header(
+                WarcHeader::RecordID,
+                "<urn:uuid:00000000-0000-0000-0000-000000000001>",
+            )
+            .header(WarcHeader::TargetURI, "https://example.com/")
+            .harmful_pp(12.5)
+            .tlsh("T1A9B1")
+            .quality_warnings(vec![
+                QualityWarning::Header,
+                QualityWarning::other("custom"),
+            ])
+            .categories(vec![Category::Blog, Category::other("custom")])
+            .build()
+            .unwrap();
+        full.metadata_mut()
+            .set_extension("kenlm_pp", &123.5f32)
+            .unwrap();
+        full.metadata_mut()
+            .set_extension(
+                "toxicity",
+                &serde_json::json!({"score": 0.1, "model": "foo"}),
+            )
+            .unwrap();
+
+        let empty_lists = Document::builder()
+            .content("Hi")
+            .identification(id("en", 1.0))
+            .sentence_identifications(vec![None])
+            .header(
+                WarcHeader::RecordID,
+                "<urn:uuid:00000000-0000-0000-0000-000000000002>",
+            )
+            .quality_warnings(vec![])
+            .build()
+            .unwrap();
+
+        vec![full, empty_lists]
+    }
+
     #[test]
     fn test_write_simple() {
         let mut writer = vec![];
@@ -82,8 +659,8 @@ mod tests {
         // map results to Ok, crashing if Error
         let docs_from_reader: Vec<Document> = reader.map(|x| x.unwrap()).collect();
 
-        assert!(docs.len() != 0);
-        assert!(docs_from_reader.len() != 0);
+        assert!(!docs.is_empty());
+        assert!(!docs_from_reader.is_empty());
         assert_eq!(docs, docs_from_reader);
     }
 
@@ -105,8 +682,270 @@ mod tests {
         // map results to Ok, crashing if Error
         let docs_from_reader: Vec<Document> = reader.map(|x| x.unwrap()).collect();
 
-        assert!(docs.len() != 0);
-        assert!(docs_from_reader.len() != 0);
+        assert!(!docs.is_empty());
+        assert!(!docs_from_reader.is_empty());
+        assert_eq!(docs, docs_from_reader);
+    }
+
+    #[test]
+    fn test_write_full() {
+        let docs = get_full_docs();
+
+        let mut writer = vec![];
+        DocWriter::new(&mut writer).write_multiple(&docs).unwrap();
+
+        let docs_from_reader: Vec<Document> =
+            Reader::new(writer.as_slice()).map(|x| x.unwrap()).collect();
+        assert_eq!(docs, docs_from_reader);
+    }
+
+    #[test]
+    fn test_write_gzip() {
+        let docs = get_docs();
+
+        let mut dw = DocWriter::from_gzip(vec![], Compression::fast());
+        dw.write_multiple(&docs).unwrap();
+        let writer = dw.finish().unwrap();
+
+        let reader = Reader::from_gzip(writer.as_slice());
+        let docs_from_reader: Vec<Document> = reader.map(|x| x.unwrap()).collect();
+
+        assert!(!docs.is_empty());
+        assert_eq!(docs, docs_from_reader);
+    }
+
+    #[test]
+    fn test_write_gzip_drop() {
+        let docs = get_docs();
+
+        // trailer should be written when the writer goes out of scope
+        let mut writer = vec![];
+        {
+            let mut dw = DocWriter::from_gzip(&mut writer, Compression::default());
+            for doc in &docs {
+                dw.write(doc).unwrap();
+            }
+        }
+
+        let reader = Reader::from_gzip(writer.as_slice());
+        let docs_from_reader: Vec<Document> = reader.map(|x| x.unwrap()).collect();
+
+        assert_eq!(docs, docs_from_reader);
+    }
+
+    #[test]
+    fn test_write_gzip_members() {
+        let docs = get_docs();
+
+        let mut dw = DocWriter::from_gzip_members(vec![], Compression::fast(), 10);
+        dw.write_multiple(&docs).unwrap();
+        let writer = dw.finish().unwrap();
+
+        // 63 documents make 7 members, each starting with the gzip magic bytes
+        let mut nb_members = 0;
+        let mut r = writer.as_slice();
+        while !r.is_empty() {
+            assert_eq!(&r[..2], &[0x1f, 0x8b]);
+            let mut dec = flate2::bufread::GzDecoder::new(r);
+            std::io::copy(&mut dec, &mut std::io::sink()).unwrap();
+            r = dec.into_inner();
+            nb_members += 1;
+        }
+        assert_eq!(nb_members, 7);
+
+        let reader = Reader::from_gzip(writer.as_slice());
+        let docs_from_reader: Vec<Document> = reader.map(|x| x.unwrap()).collect();
+        assert_eq!(docs, docs_from_reader);
+    }
+
+    #[test]
+    fn test_split_write_docs() {
+        let docs = get_docs();
+        let dst = tempfile::tempdir().unwrap();
+
+        let mut dw = SplitDocWriter::new(dst.path(), "data", None, Some(10));
+        dw.write_multiple(&docs).unwrap();
+        let files = dw.finish().unwrap();
+
+        // 63 documents in 10-document files
+        assert_eq!(files.len(), 7);
+        assert_eq!(files[0], dst.path().join("data_part_1.jsonl"));
+
+        let reader = SplitReader::new(dst.path().to_path_buf(), "data_part_", "", ".jsonl", 1);
+        let docs_from_reader: Vec<Document> = reader.map(|x| x.unwrap()).collect();
+        assert_eq!(docs, docs_from_reader);
+    }
+
+    #[test]
+    fn test_split_write_bytes_gzip() {
+        let docs = get_docs();
+        let dst = tempfile::tempdir().unwrap();
+        let max_bytes = 20_000;
+
+        let mut dw = SplitDocWriter::from_gzip(
+            dst.path(),
+            "data",
+            Some(max_bytes),
+            None,
+            Compression::fast(),
+        );
+        for doc in &docs {
+            dw.write(doc).unwrap();
+        }
+        let files = dw.finish().unwrap();
+        assert!(files.len() > 1);
+
+        // each file is under the limit, unless it holds a single, larger document
+        for file in &files {
+            let docs_from_file: Vec<Document> =
+                Reader::from_gzip(BufReader::new(File::open(file).unwrap()))
+                    .map(|x| x.unwrap())
+                    .collect();
+            let size: usize = docs_from_file
+                .iter()
+                .map(|doc| serde_json::to_string(doc).unwrap().len() + 1)
+                .sum();
+            assert!(size as u64 <= max_bytes || docs_from_file.len() == 1);
+        }
+
+        let reader = SplitReader::new(dst.path().to_path_buf(), "data_part_", "", ".jsonl.gz", 1);
+        let docs_from_reader: Vec<Document> = reader.map(|x| x.unwrap()).collect();
+        assert_eq!(docs, docs_from_reader);
+    }
+
+    #[test]
+    fn test_split_write_empty() {
+        let dst = tempfile::tempdir().unwrap();
+        let dw = SplitDocWriter::new(dst.path(), "data", Some(10), Some(10));
+        assert!(dw.finish().unwrap().is_empty());
+        assert_eq!(std::fs::read_dir(dst.path()).unwrap().count(), 0);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_write_zstd() {
+        let docs = get_docs();
+
+        for workers in [0, 2] {
+            let mut dw = DocWriter::from_zstd(vec![], 3, workers).unwrap();
+            dw.write_multiple(&docs).unwrap();
+            let writer = dw.finish().unwrap();
+
+            let reader = Reader::from_zstd(writer.as_slice()).unwrap();
+            let docs_from_reader: Vec<Document> = reader.map(|x| x.unwrap()).collect();
+
+            assert!(!docs.is_empty());
+            assert_eq!(docs, docs_from_reader);
+        }
+    }
+
+    #[test]
+    fn test_write_extensions() {
+        // data.jsonl is v2, and its `annotation` is read as `quality_warnings`
+        let mut docs = get_docs();
+        assert!(docs
+            .iter()
+            .all(|doc| doc.metadata().extensions().is_empty()));
+        assert!(docs.iter().any(|doc| doc.metadata().annotation().is_some()));
+        assert!(docs[0]
+            .metadata_mut()
+            .set_extension("annotation", &["header"])
+            .is_err());
+        docs[0]
+            .metadata_mut()
+            .set_extension("kenlm_pp", &1234.5f32)
+            .unwrap();
+        docs[1]
+            .metadata_mut()
+            .set_extension(
+                "toxicity",
+                &serde_json::json!({"score": 0.1, "model": "foo"}),
+            )
+            .unwrap();
+
+        let mut writer = vec![];
+        DocWriter::new(&mut writer).write_multiple(&docs).unwrap();
+
+        let first_line = writer.split(|b| *b == b'\n').next().unwrap();
+        let raw: serde_json::Value = serde_json::from_slice(first_line).unwrap();
+        assert_eq!(raw["metadata"]["kenlm_pp"], 1234.5);
+
+        let docs_from_reader: Vec<Document> =
+            Reader::new(writer.as_slice()).map(|x| x.unwrap()).collect();
+        assert_eq!(docs, docs_from_reader);
+        assert_eq!(
+            docs_from_reader[0]
+                .metadata()
+                .extension::<f32>("kenlm_pp")
+                .unwrap(),
+            Some(1234.5)
+        );
+        assert_eq!(
+            docs_from_reader[1]
+                .metadata()
+                .extension::<serde_json::Value>("toxicity")
+                .unwrap()
+                .unwrap()["model"],
+            "foo"
+        );
+    }
+
+    #[cfg(feature = "avro")]
+    #[test]
+    fn test_avro_roundtrip() {
+        use crate::oscar_doc::AvroReader;
+
+        use super::AvroDocWriter;
+
+        let mut docs = get_docs();
+        docs.extend(get_full_docs());
+
+        let mut dw = AvroDocWriter::new(vec![]);
+        dw.write_multiple(&docs).unwrap();
+        let writer = dw.into_inner().unwrap();
+
+        let reader = AvroReader::new(writer.as_slice()).unwrap();
+        let docs_from_reader: Vec<Document> = reader.map(|x| x.unwrap()).collect();
+
+        assert!(!docs.is_empty());
+        assert_eq!(docs, docs_from_reader);
+    }
+
+    #[cfg(feature = "avro")]
+    #[test]
+    fn test_avro_roundtrip_compressed() {
+        use avro_rs::Codec;
+
+        use crate::oscar_doc::AvroReader;
+
+        use super::AvroDocWriter;
+
+        let mut docs = get_full_docs();
+        docs.extend(get_docs());
+
+        let mut dw = AvroDocWriter::with_codec(vec![], Codec::Snappy);
+        for doc in &docs {
+            dw.write(doc).unwrap();
+        }
+        let writer = dw.into_inner().unwrap();
+
+        let reader = AvroReader::new(writer.as_slice()).unwrap();
+        let docs_from_reader: Vec<Document> = reader.map(|x| x.unwrap()).collect();
+
         assert_eq!(docs, docs_from_reader);
     }
+
+    #[cfg(feature = "avro")]
+    #[test]
+    fn test_avro_bad_header() {
+        use crate::error::Error;
+        use crate::oscar_doc::AvroReader;
+
+        let content = r#"{"foo": "bar"}"#;
+        match AvroReader::new(content.as_bytes()) {
+            Err(Error::Avro(_)) => (),
+            Err(e) => panic!("wrong error: {:?}", e),
+            Ok(_) => panic!("bad header should not be accepted"),
+        }
+    }
 }
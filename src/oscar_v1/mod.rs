@@ -0,0 +1,13 @@
+//! OSCAR v1 (OSCAR 2019) readers and writers.
+//!
+//! OSCAR v1 is distributed as one text file per language (`lang.txt`, possibly compressed),
+//! with documents separated by blank lines and no metadata.
+//!
+//! Documents are materialized as [crate::v3::Document]s with empty WARC headers, and identified as the file language
+//! (both at document and line level) with a probability of `1.0`.
+mod reader;
+mod writer;
+
+pub(crate) use reader::lang_from_path;
+pub use reader::Reader;
+pub use writer::Writer;
@@ -0,0 +1,157 @@
+/*! OSCAR v1 Reader.
+
+Provides a way to read [Document]s from a [BufRead] of blank-line separated documents.
+!*/
+use std::{io::BufRead, path::Path};
+
+use oxilangtag::LanguageTag;
+
+use crate::common::Identification;
+use crate::compression;
+use crate::error::Error;
+use crate::v3::{Document, Metadata, WarcHeaders};
+
+/// OSCAR v1 document reader.
+/// The inner type has to implement [BufRead].
+pub struct Reader<R: BufRead> {
+    r: R,
+    lang: LanguageTag<String>,
+}
+
+impl<R: BufRead> Reader<R> {
+    /// Create a new [Reader]. Documents will be identified as `lang`.
+    pub fn new(r: R, lang: LanguageTag<String>) -> Self {
+        Self { r, lang }
+    }
+
+    /// Build a [Document] from its lines.
+    fn to_document(&self, lines: Vec<String>) -> Document {
+        let id = Identification::new(self.lang.clone(), 1.0);
+        let sentence_ids = vec![Some(id.clone()); lines.len()];
+        let metadata = Metadata::new(&id, &sentence_ids);
+        Document::new(lines.join("\n"), WarcHeaders::new(), metadata)
+    }
+}
+
+/// Infer the language from a file name (e.g. `fr.txt` or `fr.txt.gz` are `fr`).
+pub(crate) fn lang_from_path(path: &Path) -> Result<LanguageTag<String>, Error> {
+    let lang = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.split('.').next())
+        .ok_or_else(|| Error::Custom(format!("No language in file name {:?}", path)))?;
+    LanguageTag::parse(lang.to_string())
+        .map_err(|e| Error::Custom(format!("Invalid language in {:?}: {}", path, e)))
+}
+
+impl Reader<Box<dyn BufRead + Send>> {
+    /// Open the file at `path`, detecting its compression.
+    ///
+    /// The language is inferred from the file name (e.g. `fr.txt` or `fr.txt.gz` are read as `fr`).
+    pub fn from_path(path: &Path) -> Result<Self, Error> {
+        let lang = lang_from_path(path)?;
+        Ok(Self::new(compression::open(path)?, lang))
+    }
+}
+
+impl<R: BufRead> Iterator for Reader<R> {
+    type Item = Result<Document, Error>;
+
+    /// Yields [Result]<[Document], [Error]>.
+    /// Consecutive blank lines are treated as a single document separator.
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut lines = vec![];
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match self.r.read_line(&mut line) {
+                // stop at EOF
+                Ok(0) => break,
+                Ok(_) => {
+                    let line = line.trim_end_matches(['\n', '\r']);
+                    if line.is_empty() {
+                        // skip leading blank lines, stop at the end of the document
+                        if lines.is_empty() {
+                            continue;
+                        }
+                        break;
+                    }
+                    lines.push(line.to_string());
+                }
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+
+        if lines.is_empty() {
+            None
+        } else {
+            Some(Ok(self.to_document(lines)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::GzEncoder, Compression};
+    use oxilangtag::LanguageTag;
+
+    use super::Reader;
+    use crate::v3::Document;
+
+    fn get_sample() -> &'static str {
+        "\nBonjour,\nceci est un document.\n\n\nUn autre document.\r\n\r\nLe dernier\ndocument\nsans saut de ligne"
+    }
+
+    #[test]
+    fn test_read() {
+        let r = Reader::new(
+            get_sample().as_bytes(),
+            LanguageTag::parse("fr".to_string()).unwrap(),
+        );
+        let docs: Vec<Document> = r.map(|x| x.unwrap()).collect();
+
+        assert_eq!(docs.len(), 3);
+        assert_eq!(docs[0].content(), "Bonjour,\nceci est un document.");
+        assert_eq!(docs[1].content(), "Un autre document.");
+        assert_eq!(
+            docs[2].content(),
+            "Le dernier\ndocument\nsans saut de ligne"
+        );
+
+        for doc in &docs {
+            assert!(doc.warc_headers().is_empty());
+            assert_eq!(doc.identification().label().as_str(), "fr");
+            assert_eq!(
+                doc.content().lines().count(),
+                doc.metadata().sentence_identifications().len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_empty() {
+        let mut r = Reader::new(
+            "\n\n".as_bytes(),
+            LanguageTag::parse("fr".to_string()).unwrap(),
+        );
+        assert!(r.next().is_none());
+    }
+
+    #[test]
+    fn test_from_path() {
+        let dst = tempfile::tempdir().unwrap();
+        let path = dst.path().join("br.txt.gz");
+        let mut enc = GzEncoder::new(std::fs::File::create(&path).unwrap(), Compression::fast());
+        enc.write_all(get_sample().as_bytes()).unwrap();
+        enc.finish().unwrap();
+
+        let docs: Vec<Document> = Reader::from_path(&path)
+            .unwrap()
+            .map(|x| x.unwrap())
+            .collect();
+        assert_eq!(docs.len(), 3);
+        assert_eq!(docs[0].identification().label().as_str(), "br");
+    }
+}
@@ -0,0 +1,112 @@
+//! OSCAR v1 writer.
+use std::io::Write;
+
+use crate::error::Error;
+use crate::v3::Document;
+
+/// OSCAR v1 document writer.
+///
+/// Writes document content followed by a blank line.
+/// Blank lines inside documents are dropped, since they would be read as document separators.
+pub struct Writer<W: Write> {
+    w: W,
+}
+
+impl<W: Write> Writer<W> {
+    pub fn new(writer: W) -> Self {
+        Self { w: writer }
+    }
+
+    /// Writes the document's non-blank lines, followed by a blank line.
+    ///
+    /// Documents with no non-blank lines are skipped.
+    /// Does not call [Self::flush], so be careful of calling it after writing
+    pub fn write(&mut self, doc: &Document) -> Result<(), Error> {
+        let mut write_bytes = String::with_capacity(doc.content().len() + 2);
+        for line in doc.content().lines().filter(|line| !line.trim().is_empty()) {
+            write_bytes.push_str(line);
+            write_bytes.push('\n');
+        }
+
+        if !write_bytes.is_empty() {
+            write_bytes.push('\n');
+            self.w.write_all(write_bytes.as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    /// calls [Self::write] for each document, returning an error if there's any failure, then calls [Self::flush].
+    pub fn write_multiple(&mut self, docs: &[Document]) -> Result<(), Error> {
+        for doc in docs {
+            self.write(doc)?;
+        }
+        self.flush()
+    }
+
+    /// Maps to [std::io::Write::flush] method on the inner writer.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        Ok(self.w.flush()?)
+    }
+
+    /// Returns the inner writer.
+    pub fn into_inner(self) -> W {
+        self.w
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::BufReader};
+
+    use oxilangtag::LanguageTag;
+
+    use crate::oscar_v1::Reader;
+    use crate::v3::Document;
+
+    use super::Writer;
+
+    fn get_docs() -> Vec<Document> {
+        let f = File::open("tests/res/data.jsonl").unwrap();
+        crate::oscar_doc::Reader::new(BufReader::new(f))
+            .map(|x| x.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_roundtrip_content() {
+        let docs = get_docs();
+
+        let mut w = Writer::new(vec![]);
+        w.write_multiple(&docs).unwrap();
+        let written = w.into_inner();
+
+        let r = Reader::new(
+            written.as_slice(),
+            LanguageTag::parse("en".to_string()).unwrap(),
+        );
+        let docs_from_reader: Vec<Document> = r.map(|x| x.unwrap()).collect();
+
+        // only content (without blank lines) is kept
+        assert_eq!(docs.len(), docs_from_reader.len());
+        for (doc, doc_from_reader) in docs.iter().zip(docs_from_reader.iter()) {
+            let expected: Vec<&str> = doc
+                .content()
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .collect();
+            let got: Vec<&str> = doc_from_reader.content().lines().collect();
+            assert_eq!(expected, got);
+        }
+    }
+
+    #[test]
+    fn test_skip_blank() {
+        let mut doc = get_docs().remove(0);
+        doc.set_content("\n  \n".to_string());
+
+        let mut w = Writer::new(vec![]);
+        w.write(&doc).unwrap();
+        assert!(w.into_inner().is_empty());
+    }
+}
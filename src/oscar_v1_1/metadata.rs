@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use warc::WarcHeader;
+
+/// OSCAR v1.1 metadata entry.
+///
+/// Links a document's WARC headers to its location in the text file.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Metadata {
+    headers: HashMap<WarcHeader, String>,
+    offset: usize,
+    nb_sentences: usize,
+}
+
+impl Metadata {
+    pub fn new(headers: HashMap<WarcHeader, String>, offset: usize, nb_sentences: usize) -> Self {
+        Self {
+            headers,
+            offset,
+            nb_sentences,
+        }
+    }
+
+    /// Get a reference to the document's WARC headers.
+    pub fn headers(&self) -> &HashMap<WarcHeader, String> {
+        &self.headers
+    }
+
+    /// Get the (0-based) line offset of the document in the text file.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Get the number of lines of the document.
+    pub fn nb_sentences(&self) -> usize {
+        self.nb_sentences
+    }
+}
@@ -0,0 +1,16 @@
+//! OSCAR v1.1 (OSCAR 21.09) readers and writers.
+//!
+//! OSCAR v1.1 is distributed as two files per language:
+//! - `lang.txt`, holding documents separated by blank lines,
+//! - `lang_meta.jsonl`, holding a [Metadata] entry per document, with the WARC headers of the document,
+//!   the (0-based) line `offset` of the document in `lang.txt`, and its number of lines (`nb_sentences`).
+//!
+//! Documents are materialized as [crate::v3::Document]s, identified as the file language
+//! (both at document and line level) with a probability of `1.0`.
+mod metadata;
+mod reader;
+mod writer;
+
+pub use metadata::Metadata;
+pub use reader::Reader;
+pub use writer::Writer;
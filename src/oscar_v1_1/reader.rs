@@ -0,0 +1,218 @@
+/*! OSCAR v1.1 Reader.
+
+Joins a text file and its metadata file into [Document]s.
+Metadata files can either be JSONLines or JSON arrays with one entry per line.
+!*/
+use std::{io::BufRead, path::Path};
+
+use oxilangtag::LanguageTag;
+
+use crate::common::Identification;
+use crate::compression;
+use crate::error::Error;
+use crate::oscar_v1::lang_from_path;
+use crate::v3::{self, Document};
+
+use super::Metadata;
+
+/// OSCAR v1.1 document reader.
+/// Both text and metadata inner types have to implement [BufRead].
+pub struct Reader<T: BufRead, M: BufRead> {
+    text: T,
+    meta: M,
+    lang: LanguageTag<String>,
+    // index of the next line to be read in text
+    line: usize,
+}
+
+impl<T: BufRead, M: BufRead> Reader<T, M> {
+    /// Create a new [Reader]. Documents will be identified as `lang`.
+    pub fn new(text: T, meta: M, lang: LanguageTag<String>) -> Self {
+        Self {
+            text,
+            meta,
+            lang,
+            line: 0,
+        }
+    }
+
+    /// Get the next metadata entry, skipping JSON array delimiters.
+    fn next_metadata(&mut self) -> Option<Result<Metadata, Error>> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match self.meta.read_line(&mut line) {
+                Ok(0) => return None,
+                Ok(_) => match line.trim() {
+                    "[" | "]" | "" => continue,
+                    entry => {
+                        let entry = entry.strip_suffix(',').unwrap_or(entry);
+                        return Some(serde_json::from_str(entry).map_err(Error::SerdeJson));
+                    }
+                },
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+    }
+
+    /// Read a line from text, failing on EOF.
+    fn read_text_line(&mut self, buf: &mut String) -> Result<(), Error> {
+        buf.clear();
+        if self.text.read_line(buf)? == 0 {
+            return Err(Error::Custom(format!(
+                "unexpected end of text file at line {}",
+                self.line
+            )));
+        }
+        self.line += 1;
+        Ok(())
+    }
+
+    /// Get the lines of the document described by `metadata`.
+    fn read_lines(&mut self, metadata: &Metadata) -> Result<Vec<String>, Error> {
+        if metadata.offset() < self.line {
+            return Err(Error::Custom(format!(
+                "metadata offset {} is before current line {}",
+                metadata.offset(),
+                self.line
+            )));
+        }
+
+        let mut buf = String::new();
+
+        // skip separators
+        while self.line < metadata.offset() {
+            self.read_text_line(&mut buf)?;
+        }
+
+        let mut lines = Vec::with_capacity(metadata.nb_sentences());
+        for _ in 0..metadata.nb_sentences() {
+            self.read_text_line(&mut buf)?;
+            lines.push(buf.trim_end_matches(['\n', '\r']).to_string());
+        }
+
+        Ok(lines)
+    }
+
+    /// Build a [Document] from its metadata and lines.
+    fn to_document(&self, metadata: Metadata, lines: Vec<String>) -> Document {
+        let id = Identification::new(self.lang.clone(), 1.0);
+        let sentence_ids = vec![Some(id.clone()); lines.len()];
+        let warc_headers: v3::WarcHeaders = metadata
+            .headers()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.as_bytes().to_vec()))
+            .collect();
+        Document::new(
+            lines.join("\n"),
+            warc_headers,
+            v3::Metadata::new(&id, &sentence_ids),
+        )
+    }
+}
+
+impl Reader<Box<dyn BufRead + Send>, Box<dyn BufRead + Send>> {
+    /// Open a text file and its metadata file, detecting their compression.
+    ///
+    /// The language is inferred from the text file name (e.g. `fr.txt` or `fr.txt.gz` are read as `fr`).
+    pub fn from_paths(text: &Path, meta: &Path) -> Result<Self, Error> {
+        let lang = lang_from_path(text)?;
+        Ok(Self::new(
+            compression::open(text)?,
+            compression::open(meta)?,
+            lang,
+        ))
+    }
+}
+
+impl<T: BufRead, M: BufRead> Iterator for Reader<T, M> {
+    type Item = Result<Document, Error>;
+
+    /// Yields [Result]<[Document], [Error]>, following metadata entries.
+    fn next(&mut self) -> Option<Self::Item> {
+        let metadata = match self.next_metadata()? {
+            Ok(metadata) => metadata,
+            Err(e) => return Some(Err(e)),
+        };
+
+        Some(
+            self.read_lines(&metadata)
+                .map(|lines| self.to_document(metadata, lines)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use oxilangtag::LanguageTag;
+    use warc::WarcHeader;
+
+    use super::Reader;
+    use crate::{error::Error, v3::Document};
+
+    fn get_text() -> &'static str {
+        "first document\nwith two lines\n\nsecond document\n\nthird\ndocument\nhere\n"
+    }
+
+    fn get_meta() -> &'static str {
+        r#"[
+{"headers":{"warc-record-id":"<urn:uuid:1>","warc-target-uri":"https://foo.bar/1"},"offset":0,"nb_sentences":2},
+{"headers":{"warc-record-id":"<urn:uuid:2>"},"offset":3,"nb_sentences":1},
+{"headers":{"warc-record-id":"<urn:uuid:3>"},"offset":5,"nb_sentences":3}
+]"#
+    }
+
+    #[test]
+    fn test_read() {
+        let r = Reader::new(
+            get_text().as_bytes(),
+            get_meta().as_bytes(),
+            LanguageTag::parse("en".to_string()).unwrap(),
+        );
+        let docs: Vec<Document> = r.map(|x| x.unwrap()).collect();
+
+        assert_eq!(docs.len(), 3);
+        assert_eq!(docs[0].content(), "first document\nwith two lines");
+        assert_eq!(docs[1].content(), "second document");
+        assert_eq!(docs[2].content(), "third\ndocument\nhere");
+
+        assert_eq!(docs[0].url(), Some("https://foo.bar/1".to_string()));
+        assert_eq!(
+            docs[2].warc_headers().get(&WarcHeader::RecordID),
+            Some(&b"<urn:uuid:3>".to_vec())
+        );
+        for doc in &docs {
+            assert_eq!(
+                doc.content().lines().count(),
+                doc.metadata().sentence_identifications().len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_truncated_text() {
+        let meta = r#"{"headers":{},"offset":0,"nb_sentences":10}"#;
+        let mut r = Reader::new(
+            get_text().as_bytes(),
+            meta.as_bytes(),
+            LanguageTag::parse("en".to_string()).unwrap(),
+        );
+        match r.next() {
+            Some(Err(Error::Custom(_))) => (),
+            x => panic!("wrong return: {:?}", x),
+        }
+    }
+
+    #[test]
+    fn test_overlapping_offsets() {
+        let meta = r#"{"headers":{},"offset":0,"nb_sentences":2}
+{"headers":{},"offset":1,"nb_sentences":1}"#;
+        let mut r = Reader::new(
+            get_text().as_bytes(),
+            meta.as_bytes(),
+            LanguageTag::parse("en".to_string()).unwrap(),
+        );
+        assert!(r.next().unwrap().is_ok());
+        assert!(r.next().unwrap().is_err());
+    }
+}
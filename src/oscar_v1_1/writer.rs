@@ -0,0 +1,148 @@
+//! OSCAR v1.1 writer.
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use oxilangtag::LanguageTag;
+
+use crate::error::Error;
+use crate::v3::Document;
+
+use super::Metadata;
+
+/// OSCAR v1.1 document writer.
+///
+/// Writes document content followed by a blank line in the text writer,
+/// and a [Metadata] entry per document in the metadata writer.
+/// Blank lines inside documents are dropped, since they would be read as document separators.
+pub struct Writer<T: Write, M: Write> {
+    text: T,
+    meta: M,
+    // index of the next line to be written in text
+    offset: usize,
+}
+
+impl<T: Write, M: Write> Writer<T, M> {
+    pub fn new(text: T, meta: M) -> Self {
+        Self {
+            text,
+            meta,
+            offset: 0,
+        }
+    }
+
+    /// Writes the document's non-blank lines and its metadata.
+    ///
+    /// Documents with no non-blank lines are skipped.
+    /// Does not call [Self::flush], so be careful of calling it after writing
+    pub fn write(&mut self, doc: &Document) -> Result<(), Error> {
+        let lines: Vec<&str> = doc
+            .content()
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+        if lines.is_empty() {
+            return Ok(());
+        }
+
+        let headers = doc
+            .warc_headers()
+            .iter()
+            .map(|(k, v)| (k.clone(), String::from_utf8_lossy(v).into_owned()))
+            .collect();
+        let metadata = Metadata::new(headers, self.offset, lines.len());
+
+        let mut text_bytes = lines.join("\n");
+        text_bytes.push_str("\n\n");
+        self.text.write_all(text_bytes.as_bytes())?;
+
+        let meta_bytes = serde_json::to_string(&metadata)? + "\n";
+        self.meta.write_all(meta_bytes.as_bytes())?;
+
+        // account for the blank separator line
+        self.offset += lines.len() + 1;
+
+        Ok(())
+    }
+
+    /// calls [Self::write] for each document, returning an error if there's any failure, then calls [Self::flush].
+    pub fn write_multiple(&mut self, docs: &[Document]) -> Result<(), Error> {
+        for doc in docs {
+            self.write(doc)?;
+        }
+        self.flush()
+    }
+
+    /// Flushes both text and metadata writers.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.text.flush()?;
+        Ok(self.meta.flush()?)
+    }
+
+    /// Returns the inner text and metadata writers.
+    pub fn into_inner(self) -> (T, M) {
+        (self.text, self.meta)
+    }
+}
+
+impl Writer<BufWriter<File>, BufWriter<File>> {
+    /// Create `lang.txt` and `lang_meta.jsonl` in `dst`.
+    pub fn create(dst: &Path, lang: &LanguageTag<String>) -> Result<Self, Error> {
+        let text = File::create(dst.join(format!("{}.txt", lang)))?;
+        let meta = File::create(dst.join(format!("{}_meta.jsonl", lang)))?;
+        Ok(Self::new(BufWriter::new(text), BufWriter::new(meta)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::BufReader};
+
+    use oxilangtag::LanguageTag;
+
+    use crate::oscar_v1_1::Reader;
+    use crate::v3::Document;
+
+    use super::Writer;
+
+    fn get_docs() -> Vec<Document> {
+        let f = File::open("tests/res/data.jsonl").unwrap();
+        crate::oscar_doc::Reader::new(BufReader::new(f))
+            .map(|x| x.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let docs = get_docs();
+        let dst = tempfile::tempdir().unwrap();
+        let lang = LanguageTag::parse("en".to_string()).unwrap();
+
+        let mut w = Writer::create(dst.path(), &lang).unwrap();
+        w.write_multiple(&docs).unwrap();
+        drop(w);
+
+        let r = Reader::from_paths(
+            &dst.path().join("en.txt"),
+            &dst.path().join("en_meta.jsonl"),
+        )
+        .unwrap();
+        let docs_from_reader: Vec<Document> = r.map(|x| x.unwrap()).collect();
+
+        // content (without blank lines) and headers are kept
+        assert_eq!(docs.len(), docs_from_reader.len());
+        for (doc, doc_from_reader) in docs.iter().zip(docs_from_reader.iter()) {
+            let expected: Vec<&str> = doc
+                .content()
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .collect();
+            let got: Vec<&str> = doc_from_reader.content().lines().collect();
+            assert_eq!(expected, got);
+            assert_eq!(doc.warc_headers(), doc_from_reader.warc_headers());
+            assert_eq!(doc_from_reader.identification().label(), &lang);
+        }
+    }
+}
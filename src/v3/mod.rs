@@ -3,7 +3,14 @@ mod types;
 mod writer;
 
 pub use reader::Reader;
+pub use types::builder::DocumentBuilder;
 pub use types::document::Document;
+pub use types::document::Extensions;
 pub use types::document::Metadata;
+pub use types::document::PROJECTION_KEPT_THRESHOLD;
+pub use types::document_ref::DocumentRef;
+pub use types::headers::{WarcHeaders, WarcHeadersBuilder, IDENTIFIED_CONTENT_LANGUAGE};
+pub use types::metadata_record::MetadataRecord;
+pub use types::quality::{Category, QualityWarning};
 pub use writer::Writer;
 pub use writer::WriterTrait;
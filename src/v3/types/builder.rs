@@ -0,0 +1,231 @@
+/*! Validating builder for [Document]s.
+
+```
+use oxilangtag::LanguageTag;
+use oscar_io::common::Identification;
+use oscar_io::v3::{Document, QualityWarning};
+use warc::WarcHeader;
+
+let en = Identification::new(LanguageTag::parse("en".to_string()).unwrap(), 0.9);
+let doc = Document::builder()
+    .content("Hello\nWorld")
+    .identification(en.clone())
+    .sentence_identifications(vec![Some(en.clone()), Some(en)])
+    .header(WarcHeader::RecordID, "<urn:uuid:f9d8c1b0-3b1e-4e2a-9a3c-1d2e3f4a5b6c>")
+    .add_quality_warning(QualityWarning::Tiny)
+    .build()
+    .unwrap();
+assert!(doc.metadata().has_warning(QualityWarning::Tiny));
+```
+!*/
+use warc::WarcHeader;
+
+use crate::error::Error;
+
+use super::document::{Document, Identification, Metadata};
+use super::headers::WarcHeaders;
+use super::quality::{Category, QualityWarning};
+
+/// Builder for [Document]s. See [DocumentBuilder::build] for the checked invariants.
+#[derive(Debug, Default, Clone)]
+pub struct DocumentBuilder {
+    content: String,
+    warc_headers: WarcHeaders,
+    identification: Option<Identification>,
+    sentence_identifications: Vec<Option<Identification>>,
+    harmful_pp: Option<f32>,
+    tlsh: Option<String>,
+    quality_warnings: Option<Vec<QualityWarning>>,
+    categories: Option<Vec<Category>>,
+}
+
+impl DocumentBuilder {
+    /// Create a new [DocumentBuilder].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the content.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = content.into();
+        self
+    }
+
+    /// Set the WARC headers, replacing any previously set header.
+    pub fn warc_headers(mut self, warc_headers: WarcHeaders) -> Self {
+        self.warc_headers = warc_headers;
+        self
+    }
+
+    /// Set a WARC header.
+    pub fn header(mut self, header: WarcHeader, value: impl Into<Vec<u8>>) -> Self {
+        self.warc_headers.insert(header, value.into());
+        self
+    }
+
+    /// Set the document-level identification.
+    pub fn identification(mut self, identification: Identification) -> Self {
+        self.identification = Some(identification);
+        self
+    }
+
+    /// Set the line-level identifications.
+    pub fn sentence_identifications(
+        mut self,
+        sentence_identifications: Vec<Option<Identification>>,
+    ) -> Self {
+        self.sentence_identifications = sentence_identifications;
+        self
+    }
+
+    /// Set the harmful perplexity.
+    pub fn harmful_pp(mut self, harmful_pp: f32) -> Self {
+        self.harmful_pp = Some(harmful_pp);
+        self
+    }
+
+    /// Set the TLSH hash.
+    pub fn tlsh(mut self, tlsh: impl Into<String>) -> Self {
+        self.tlsh = Some(tlsh.into());
+        self
+    }
+
+    /// Set the quality warnings, replacing any previously set warning.
+    pub fn quality_warnings(mut self, quality_warnings: Vec<QualityWarning>) -> Self {
+        self.quality_warnings = Some(quality_warnings);
+        self
+    }
+
+    /// Add a quality warning.
+    pub fn add_quality_warning(mut self, quality_warning: QualityWarning) -> Self {
+        self.quality_warnings
+            .get_or_insert_with(Vec::new)
+            .push(quality_warning);
+        self
+    }
+
+    /// Set the categories, replacing any previously set category.
+    pub fn categories(mut self, categories: Vec<Category>) -> Self {
+        self.categories = Some(categories);
+        self
+    }
+
+    /// Add a category.
+    pub fn add_category(mut self, category: Category) -> Self {
+        self.categories.get_or_insert_with(Vec::new).push(category);
+        self
+    }
+
+    /// Build the [Document], checking that:
+    /// - the document-level identification is set,
+    /// - all probabilities are in `[0, 1]`,
+    /// - there is a sentence identification per content line,
+    /// - the WARC record ID is present.
+    pub fn build(self) -> Result<Document, Error> {
+        let identification = self.identification.ok_or(Error::MissingIdentification)?;
+
+        let probs = std::iter::once(&identification)
+            .chain(self.sentence_identifications.iter().flatten())
+            .map(|id| *id.prob());
+        for prob in probs {
+            if !(0.0..=1.0).contains(&prob) {
+                return Err(Error::InvalidProbability(prob));
+            }
+        }
+
+        if !self.warc_headers.contains_key(&WarcHeader::RecordID) {
+            return Err(Error::MissingWarcHeader(WarcHeader::RecordID));
+        }
+
+        let mut metadata = Metadata::new(&identification, &self.sentence_identifications);
+        metadata.set_harmful_pp(self.harmful_pp);
+        metadata.set_tlsh(self.tlsh);
+        metadata.set_annotation(self.quality_warnings);
+        metadata.set_categories(self.categories);
+
+        let doc = Document::new(self.content, self.warc_headers, metadata);
+        doc.check_sentences()?;
+        Ok(doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use oxilangtag::LanguageTag;
+    use warc::WarcHeader;
+
+    use super::DocumentBuilder;
+    use crate::common::Identification;
+    use crate::error::Error;
+    use crate::v3::{Category, Document, QualityWarning};
+
+    fn id(prob: f32) -> Identification<String> {
+        Identification::new(LanguageTag::parse("en".to_string()).unwrap(), prob)
+    }
+
+    fn get_builder() -> DocumentBuilder {
+        Document::builder()
+            .content("foo\nbar")
+            .identification(id(0.9))
+            .sentence_identifications(vec![Some(id(0.9)), None])
+            .header(WarcHeader::RecordID, "<urn:uuid:1>")
+    }
+
+    #[test]
+    fn test_build() {
+        let doc = get_builder()
+            .harmful_pp(12.5)
+            .tlsh("T1")
+            .add_quality_warning(QualityWarning::Tiny)
+            .add_category(Category::Blog)
+            .build()
+            .unwrap();
+
+        assert_eq!(doc.content(), "foo\nbar");
+        assert_eq!(doc.warc_id().unwrap(), "<urn:uuid:1>");
+        assert_eq!(doc.identification(), &id(0.9));
+        assert_eq!(doc.metadata().harmful_pp(), Some(12.5));
+        assert_eq!(doc.metadata().tlsh(), Some(&"T1".to_string()));
+        assert!(doc.metadata().has_warning(QualityWarning::Tiny));
+        assert!(doc.metadata().has_category(Category::Blog));
+    }
+
+    #[test]
+    fn test_invalid_prob() {
+        assert!(matches!(
+            get_builder().identification(id(1.5)).build(),
+            Err(Error::InvalidProbability(_))
+        ));
+        assert!(matches!(
+            get_builder()
+                .sentence_identifications(vec![Some(id(f32::NAN)), None])
+                .build(),
+            Err(Error::InvalidProbability(_))
+        ));
+    }
+
+    #[test]
+    fn test_sentence_count() {
+        assert!(matches!(
+            get_builder().content("foo\nbar\nbaz").build(),
+            Err(Error::SentenceCountMismatch {
+                lines: 3,
+                identifications: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_missing() {
+        assert!(matches!(
+            get_builder().warc_headers(Default::default()).build(),
+            Err(Error::MissingWarcHeader(WarcHeader::RecordID))
+        ));
+        assert!(matches!(
+            DocumentBuilder::new()
+                .header(WarcHeader::RecordID, "<urn:uuid:1>")
+                .build(),
+            Err(Error::MissingIdentification)
+        ));
+    }
+}
@@ -3,17 +3,42 @@ use std::collections::HashMap;
 
 use oxilangtag::LanguageTag;
 
+use serde::de::{self, DeserializeOwned, MapAccess, Visitor};
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
+use serde_json::Value;
 use warc::BufferedBody;
 use warc::Record;
 use warc::WarcHeader;
 
 use crate::common::Identification as IdentificationGen;
+use crate::error::Error;
+use crate::lang::Lang;
 
-type Identification = IdentificationGen<String>;
+use super::builder::DocumentBuilder;
+use super::headers::WarcHeaders;
+use super::quality::{Category, QualityWarning};
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub(crate) type Identification = IdentificationGen<String>;
+
+/// Metadata keys that are not part of the schema (see [Metadata::extension]).
+pub type Extensions = serde_json::Map<String, Value>;
+
+/// Keys of the [Metadata] fields, that can't be used as extension keys.
+///
+/// `annotation` is the v2 name of `quality_warnings`, and is read as such.
+const METADATA_KEYS: [&str; 7] = [
+    "identification",
+    "harmful_pp",
+    "tlsh",
+    "quality_warnings",
+    "annotation",
+    "categories",
+    "sentence_identifications",
+];
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
 
 /// OSCAR-specific metadata
 // TODO: make it a HashMap
@@ -24,13 +49,19 @@ type Identification = IdentificationGen<String>;
 /// - `quality_warnings` (ex-annotation) contains tags for some length/content based quality filters
 /// - `categories` contains categories based on the url of the document. Uses the ut1 blocklist as a base.
 /// - `sentence_identifiations` contains line-level identifications.
+///
+/// Any other key is kept in the [Extensions] map, so that downstream annotations
+/// (perplexities, toxicity scores, ...) survive a read/write cycle.
+/// The v2 `annotation` key is read as `quality_warnings`.
 pub struct Metadata {
     identification: Identification,
     harmful_pp: Option<f32>,
     tlsh: Option<String>,
-    quality_warnings: Option<Vec<String>>,
-    categories: Option<Vec<String>>,
+    quality_warnings: Option<Vec<QualityWarning>>,
+    categories: Option<Vec<Category>>,
     sentence_identifications: Vec<Option<Identification>>,
+    #[serde(flatten)]
+    extensions: Extensions,
 }
 
 impl Metadata {
@@ -45,34 +76,61 @@ impl Metadata {
             quality_warnings: None,
             categories: None,
             sentence_identifications: sentence_identifications.to_owned(),
+            extensions: Extensions::new(),
         }
     }
 
-    pub fn add_annotation(&mut self, annotation: String) {
+    /// Get a reference to the metadata's identification.
+    pub fn identification(&self) -> &Identification {
+        &self.identification
+    }
+
+    pub fn add_annotation(&mut self, annotation: impl Into<QualityWarning>) {
+        let annotation = annotation.into();
         match &mut self.quality_warnings {
             Some(anno) => anno.push(annotation),
             None => self.quality_warnings = Some(vec![annotation]),
         }
     }
 
-    pub fn categories(&self) -> Option<&Vec<String>> {
+    pub fn categories(&self) -> Option<&Vec<Category>> {
         self.categories.as_ref()
     }
-    pub fn add_category(&mut self, category: String) {
+    pub fn add_category(&mut self, category: impl Into<Category>) {
+        let category = category.into();
         match &mut self.categories {
             Some(cat) => cat.push(category),
             None => self.categories = Some(vec![category]),
         }
     }
-    pub fn set_categories(&mut self, categories: Option<Vec<String>>) {
+    pub fn set_categories(&mut self, categories: Option<Vec<Category>>) {
         self.categories = categories;
     }
 
+    /// Returns true if the document has been tagged with `category`.
+    pub fn has_category(&self, category: Category) -> bool {
+        self.categories
+            .as_ref()
+            .is_some_and(|categories| categories.contains(&category))
+    }
+
     /// Get a reference to the metadata's annotation.
-    pub fn annotation(&self) -> Option<&Vec<String>> {
+    pub fn annotation(&self) -> Option<&Vec<QualityWarning>> {
         self.quality_warnings.as_ref()
     }
 
+    /// Set the metadata's annotation.
+    pub fn set_annotation(&mut self, annotation: Option<Vec<QualityWarning>>) {
+        self.quality_warnings = annotation;
+    }
+
+    /// Returns true if the document has the `warning` quality warning.
+    pub fn has_warning(&self, warning: QualityWarning) -> bool {
+        self.quality_warnings
+            .as_ref()
+            .is_some_and(|warnings| warnings.contains(&warning))
+    }
+
     /// Get a reference to the metadata's sentence identifications.
     pub fn sentence_identifications(&self) -> &[Option<Identification>] {
         self.sentence_identifications.as_ref()
@@ -92,6 +150,52 @@ impl Metadata {
     pub fn set_tlsh(&mut self, tlsh: Option<String>) {
         self.tlsh = tlsh;
     }
+
+    /// Get a reference to the metadata's extensions.
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    /// Get a mutable reference to the metadata's extensions.
+    ///
+    /// Keys clashing with a metadata field would be serialized twice,
+    /// use [Metadata::set_extension] to have them checked.
+    pub fn extensions_mut(&mut self) -> &mut Extensions {
+        &mut self.extensions
+    }
+
+    /// Get the extension at `key`, deserialized as `T`.
+    ///
+    /// Returns `Ok(None)` if there is no such extension, and an error if it can't be deserialized as `T`.
+    pub fn extension<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, Error> {
+        self.extensions
+            .get(key)
+            .map(|value| T::deserialize(value).map_err(Error::from))
+            .transpose()
+    }
+
+    /// Set the extension at `key`, returning the previous value if any.
+    ///
+    /// Fails if `key` is the name of a metadata field or if `value` can't be serialized.
+    pub fn set_extension<T: Serialize>(
+        &mut self,
+        key: impl Into<String>,
+        value: &T,
+    ) -> Result<Option<Value>, Error> {
+        let key = key.into();
+        if METADATA_KEYS.contains(&key.as_str()) {
+            return Err(Error::Custom(format!(
+                "`{key}` is a metadata field and can't be used as an extension"
+            )));
+        }
+        let value = serde_json::to_value(value)?;
+        Ok(self.extensions.insert(key, value))
+    }
+
+    /// Remove the extension at `key`, returning it if it was present.
+    pub fn remove_extension(&mut self, key: &str) -> Option<Value> {
+        self.extensions.remove(key)
+    }
 }
 
 impl Default for Metadata {
@@ -109,18 +213,136 @@ impl Default for Metadata {
                 LanguageTag::parse("en".to_string()).unwrap(),
                 1.0,
             ))],
+            extensions: Extensions::new(),
+        }
+    }
+}
+
+/// Metadata field, as found in a serialized [Metadata].
+enum MetadataField {
+    Identification,
+    HarmfulPp,
+    Tlsh,
+    QualityWarnings,
+    Categories,
+    SentenceIdentifications,
+    Extension(String),
+}
+
+impl<'de> Deserialize<'de> for MetadataField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl<'de> Visitor<'de> for FieldVisitor {
+            type Value = MetadataField;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a metadata key")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(match v {
+                    "identification" => MetadataField::Identification,
+                    "harmful_pp" => MetadataField::HarmfulPp,
+                    "tlsh" => MetadataField::Tlsh,
+                    "quality_warnings" | "annotation" => MetadataField::QualityWarnings,
+                    "categories" => MetadataField::Categories,
+                    "sentence_identifications" => MetadataField::SentenceIdentifications,
+                    other => MetadataField::Extension(other.to_string()),
+                })
+            }
         }
+
+        deserializer.deserialize_identifier(FieldVisitor)
     }
 }
 
-// TODO make this a newtype so that getters can do the u8 conversion
-pub type WarcHeaders = HashMap<WarcHeader, Vec<u8>>;
+/// Deserializes fields directly from the map,
+/// rather than buffering it as `#[serde(flatten)]` would.
+impl<'de> Deserialize<'de> for Metadata {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MetadataVisitor;
+
+        impl<'de> Visitor<'de> for MetadataVisitor {
+            type Value = Metadata;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("document metadata")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                /// Set a field, failing if it has already been set.
+                fn set<T, E: de::Error>(
+                    field: &mut Option<T>,
+                    value: T,
+                    name: &'static str,
+                ) -> Result<(), E> {
+                    match field.replace(value) {
+                        Some(_) => Err(E::duplicate_field(name)),
+                        None => Ok(()),
+                    }
+                }
+
+                let mut identification = None;
+                let mut harmful_pp = None;
+                let mut tlsh = None;
+                let mut quality_warnings = None;
+                let mut categories = None;
+                let mut sentence_identifications = None;
+                let mut extensions = Extensions::new();
+
+                while let Some(field) = map.next_key()? {
+                    match field {
+                        MetadataField::Identification => {
+                            set(&mut identification, map.next_value()?, "identification")?
+                        }
+                        MetadataField::HarmfulPp => {
+                            set(&mut harmful_pp, map.next_value()?, "harmful_pp")?
+                        }
+                        MetadataField::Tlsh => set(&mut tlsh, map.next_value()?, "tlsh")?,
+                        MetadataField::QualityWarnings => {
+                            set(&mut quality_warnings, map.next_value()?, "quality_warnings")?
+                        }
+                        MetadataField::Categories => {
+                            set(&mut categories, map.next_value()?, "categories")?
+                        }
+                        MetadataField::SentenceIdentifications => set(
+                            &mut sentence_identifications,
+                            map.next_value()?,
+                            "sentence_identifications",
+                        )?,
+                        MetadataField::Extension(key) => {
+                            extensions.insert(key, map.next_value()?);
+                        }
+                    }
+                }
+
+                Ok(Metadata {
+                    identification: identification
+                        .ok_or_else(|| de::Error::missing_field("identification"))?,
+                    harmful_pp: harmful_pp.flatten(),
+                    tlsh: tlsh.flatten(),
+                    quality_warnings: quality_warnings.flatten(),
+                    categories: categories.flatten(),
+                    sentence_identifications: sentence_identifications
+                        .ok_or_else(|| de::Error::missing_field("sentence_identifications"))?,
+                    extensions,
+                })
+            }
+        }
+
+        deserializer.deserialize_map(MetadataVisitor)
+    }
+}
 
 pub type WarcHeadersSer = HashMap<WarcHeader, String>;
 
+/// Minimum proportion of text (in chars) kept by [Document::project_to_language] without adding a quality warning.
+pub const PROJECTION_KEPT_THRESHOLD: f32 = 0.5;
+
 /// A Document is a structure holding content, WARC headers and OSCAR-specific metadata.
 /// - TODO: Change warc_headers from [RawRecordHeader] to [warc::Record] with [warc::EmptyBody]?
-/// This way we shouldn't have to parse strings or use unwrap on [RawRecordHeader].
+///   This way we shouldn't have to parse strings or use unwrap on [RawRecordHeader].
 #[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[serde(from = "DocumentSer", into = "DocumentSer")]
 pub struct Document {
@@ -175,10 +397,15 @@ impl From<DocumentSer> for Document {
 }
 
 impl Document {
-    pub fn new(content: String, warc_headers: WarcHeaders, metadata: Metadata) -> Self {
+    /// Create a new [DocumentBuilder].
+    pub fn builder() -> DocumentBuilder {
+        DocumentBuilder::new()
+    }
+
+    pub fn new(content: String, warc_headers: impl Into<WarcHeaders>, metadata: Metadata) -> Self {
         Self {
             content,
-            warc_headers,
+            warc_headers: warc_headers.into(),
             metadata,
         }
     }
@@ -190,7 +417,7 @@ impl Document {
     pub fn from_record(record: Record<BufferedBody>, metadata: Metadata) -> Self {
         let (header, body) = record.into_raw_parts();
         let content = String::from_utf8_lossy(&body).into_owned();
-        let warc_headers = header.headers;
+        let warc_headers = header.headers.into();
 
         Self {
             content,
@@ -210,8 +437,13 @@ impl Document {
     }
 
     /// get warc record id
-    pub fn warc_id(&self) -> Cow<str> {
-        String::from_utf8_lossy(self.warc_headers.get(&WarcHeader::RecordID).unwrap())
+    ///
+    /// Fails if the document has no record id. See [WarcHeaders::record_id] for a parsed version.
+    pub fn warc_id(&self) -> Result<Cow<'_, str>, Error> {
+        self.warc_headers
+            .get(&WarcHeader::RecordID)
+            .map(|id| String::from_utf8_lossy(id))
+            .ok_or(Error::MissingWarcHeader(WarcHeader::RecordID))
     }
 
     /// Get a reference to the document's warc headers.
@@ -240,6 +472,152 @@ impl Document {
     pub fn set_content(&mut self, content: String) {
         self.content = content;
     }
+
+    /// Consume the document, returning its content, warc headers and metadata.
+    pub fn into_parts(self) -> (String, WarcHeaders, Metadata) {
+        (self.content, self.warc_headers, self.metadata)
+    }
+
+    /// Iterate over content lines along with their identification.
+    ///
+    /// Lines are split using [str::lines], so `\r\n` line endings are supported.
+    /// Lines without a matching sentence identification (see [Self::check_sentences]) are yielded with `None`.
+    pub fn sentences(&self) -> impl Iterator<Item = (&str, Option<&Identification>)> {
+        let ids = &self.metadata.sentence_identifications;
+        self.content
+            .lines()
+            .enumerate()
+            .map(move |(idx, line)| (line, ids.get(idx).and_then(Option::as_ref)))
+    }
+
+    /// Check that there is a sentence identification for each content line.
+    pub fn check_sentences(&self) -> Result<(), Error> {
+        let lines = self.content.lines().count();
+        let identifications = self.metadata.sentence_identifications.len();
+        if lines == identifications {
+            Ok(())
+        } else {
+            Err(Error::SentenceCountMismatch {
+                lines,
+                identifications,
+            })
+        }
+    }
+
+    /// Keep only the lines (and their identifications) for which `f` returns `true`.
+    ///
+    /// Lines are joined back with `\n`.
+    /// Fails without modifying the document if lines and identifications are not aligned.
+    pub fn retain_sentences<F>(&mut self, mut f: F) -> Result<(), Error>
+    where
+        F: FnMut(&str, Option<&Identification>) -> bool,
+    {
+        self.filter_map_sentences(|line, id| f(line, id).then(|| line.to_string()))
+    }
+
+    /// Rewrite each line with `f`, keeping identifications.
+    ///
+    /// Lines are joined back with `\n`.
+    /// Fails without modifying the document if lines and identifications are not aligned,
+    /// or if a rewritten line contains a line break.
+    pub fn map_sentences<F>(&mut self, mut f: F) -> Result<(), Error>
+    where
+        F: FnMut(&str, Option<&Identification>) -> String,
+    {
+        self.filter_map_sentences(|line, id| Some(f(line, id)))
+    }
+
+    /// Rewrite each line with `f`, removing lines (and their identifications) for which `f` returns `None`.
+    ///
+    /// See [Self::retain_sentences] and [Self::map_sentences].
+    pub fn filter_map_sentences<F>(&mut self, mut f: F) -> Result<(), Error>
+    where
+        F: FnMut(&str, Option<&Identification>) -> Option<String>,
+    {
+        self.check_sentences()?;
+
+        let mut lines = Vec::new();
+        let mut ids = Vec::new();
+        for (line, id) in self.sentences() {
+            if let Some(new_line) = f(line, id) {
+                if new_line.contains(['\n', '\r']) {
+                    return Err(Error::Custom(format!(
+                        "rewritten line contains a line break: {:?}",
+                        new_line
+                    )));
+                }
+                lines.push(new_line);
+                ids.push(id.cloned());
+            }
+        }
+
+        self.content = lines.join("\n");
+        self.metadata.sentence_identifications = ids;
+        Ok(())
+    }
+
+    /// Get a new document holding only the lines identified as `lang` with a probability of at least `min_prob`.
+    ///
+    /// Labels are compared as [Lang]s (see [Lang::from_tag] and [Lang::is_equivalent]),
+    /// so that `en`, `eng` and `eng-Latn` all match each other. Lines with unknown labels are dropped.
+    /// The document identification is the average of the kept lines' probabilities, weighted by their length (in chars),
+    /// and a [QualityWarning::MostlyDropped] warning is added if less than [PROJECTION_KEPT_THRESHOLD] of the text is kept.
+    ///
+    /// Returns `Ok(None)` if no line is kept, and fails if `lang` is unknown or if lines and identifications are not aligned.
+    pub fn project_to_language(
+        &self,
+        lang: &LanguageTag<String>,
+        min_prob: f32,
+    ) -> Result<Option<Self>, Error> {
+        let target = Lang::try_from(lang)?;
+        self.check_sentences()?;
+
+        let mut total_len = 0;
+        let mut kept_len = 0;
+        let mut weighted_prob = 0.0;
+        let mut lines = Vec::new();
+        let mut ids = Vec::new();
+        for (line, id) in self.sentences() {
+            let len = line.chars().count();
+            total_len += len;
+
+            let id = id.filter(|id| {
+                *id.prob() >= min_prob && id.lang().is_ok_and(|l| l.is_equivalent(target))
+            });
+            if let Some(id) = id {
+                kept_len += len;
+                weighted_prob += id.prob() * len as f32;
+                lines.push(line);
+                ids.push(Some(id.clone()));
+            }
+        }
+
+        if lines.is_empty() {
+            return Ok(None);
+        }
+
+        // fall back to a plain average if all kept lines are empty
+        let prob = if kept_len > 0 {
+            weighted_prob / kept_len as f32
+        } else {
+            ids.iter().flatten().map(|id| id.prob()).sum::<f32>() / ids.len() as f32
+        };
+
+        let mut metadata = self.metadata.clone();
+        metadata.identification = Identification::new(lang.clone(), prob);
+        metadata.sentence_identifications = ids;
+        if (kept_len as f32) < total_len as f32 * PROJECTION_KEPT_THRESHOLD
+            && !metadata.has_warning(QualityWarning::MostlyDropped)
+        {
+            metadata.add_annotation(QualityWarning::MostlyDropped);
+        }
+
+        Ok(Some(Self::new(
+            lines.join("\n"),
+            self.warc_headers.clone(),
+            metadata,
+        )))
+    }
 }
 
 /// custom debug implementation that converts:
@@ -264,9 +642,150 @@ impl std::fmt::Debug for Document {
 
 #[cfg(test)]
 mod tests {
+    use oxilangtag::LanguageTag;
     use warc::{Record, WarcHeader};
 
-    use super::{Document, Metadata};
+    use super::{Document, Identification, Metadata, QualityWarning, WarcHeaders};
+    use crate::error::Error;
+
+    fn get_doc() -> Document {
+        let en = Identification::new(LanguageTag::parse("en".to_string()).unwrap(), 0.9);
+        let fr = Identification::new(LanguageTag::parse("fr".to_string()).unwrap(), 0.8);
+        let metadata = Metadata::new(&en, &[Some(en.clone()), None, Some(fr)]);
+        Document::new(
+            "Hello\r\n1234\r\nBonjour".to_string(),
+            WarcHeaders::new(),
+            metadata,
+        )
+    }
+
+    #[test]
+    fn test_sentences() {
+        let doc = get_doc();
+        doc.check_sentences().unwrap();
+
+        let sentences: Vec<(&str, Option<&str>)> = doc
+            .sentences()
+            .map(|(line, id)| (line, id.map(|id| id.label().as_str())))
+            .collect();
+        assert_eq!(
+            sentences,
+            vec![
+                ("Hello", Some("en")),
+                ("1234", None),
+                ("Bonjour", Some("fr"))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_sentences() {
+        let mut doc = get_doc();
+        doc.set_content("Hello\n1234\nBonjour\nextra".to_string());
+        assert!(matches!(
+            doc.check_sentences(),
+            Err(Error::SentenceCountMismatch {
+                lines: 4,
+                identifications: 3
+            })
+        ));
+
+        // operations fail without modifying the document
+        let before = doc.clone();
+        assert!(doc.retain_sentences(|_, id| id.is_some()).is_err());
+        assert_eq!(doc, before);
+    }
+
+    #[test]
+    fn test_retain_sentences() {
+        let mut doc = get_doc();
+        doc.retain_sentences(|_, id| id.is_some()).unwrap();
+        assert_eq!(doc.content(), "Hello\nBonjour");
+        assert_eq!(doc.metadata().sentence_identifications().len(), 2);
+        doc.check_sentences().unwrap();
+    }
+
+    #[test]
+    fn test_project_to_language() {
+        let en = LanguageTag::parse("en".to_string()).unwrap();
+        let fr = LanguageTag::parse("fr".to_string()).unwrap();
+        let id = |tag: &LanguageTag<String>, prob| Some(Identification::new(tag.clone(), prob));
+        let metadata = Metadata::new(
+            &Identification::new(en.clone(), 0.5),
+            &[id(&en, 1.0), id(&fr, 0.9), id(&en, 0.5), None, id(&en, 0.2)],
+        );
+        let doc = Document::new(
+            "Hello world\nBonjour le monde\nHi\n1234\nYo".to_string(),
+            WarcHeaders::new(),
+            metadata,
+        );
+
+        let projected = doc.project_to_language(&en, 0.4).unwrap().unwrap();
+        assert_eq!(projected.content(), "Hello world\nHi");
+        projected.check_sentences().unwrap();
+        assert_eq!(projected.identification().label(), &en);
+        // (11 * 1.0 + 2 * 0.5) / 13
+        assert!((projected.identification().prob() - 12.0 / 13.0).abs() < 1e-6);
+        // 13 chars kept out of 35
+        assert!(projected
+            .metadata()
+            .has_warning(QualityWarning::MostlyDropped));
+
+        let projected = doc.project_to_language(&fr, 0.0).unwrap().unwrap();
+        assert_eq!(projected.content(), "Bonjour le monde");
+        assert_eq!(projected.identification().prob(), &0.9);
+
+        let de = LanguageTag::parse("de".to_string()).unwrap();
+        assert!(doc.project_to_language(&de, 0.0).unwrap().is_none());
+
+        // equivalent forms of a language are matched
+        for tag in ["eng", "eng-Latn", "en-US"] {
+            let tag = LanguageTag::parse(tag.to_string()).unwrap();
+            let projected = doc.project_to_language(&tag, 0.4).unwrap().unwrap();
+            assert_eq!(projected.content(), "Hello world\nHi");
+            assert_eq!(projected.identification().label(), &tag);
+        }
+
+        let unknown = LanguageTag::parse("xx".to_string()).unwrap();
+        assert!(matches!(
+            doc.project_to_language(&unknown, 0.0),
+            Err(Error::UnknownLang(_))
+        ));
+    }
+
+    #[test]
+    fn test_project_to_language_kept() {
+        let doc = get_doc();
+        let en = LanguageTag::parse("EN".to_string()).unwrap();
+        let projected = doc.project_to_language(&en, 0.0).unwrap().unwrap();
+        assert_eq!(projected.content(), "Hello");
+        // 5 chars kept out of 16
+        assert!(projected
+            .metadata()
+            .has_warning(QualityWarning::MostlyDropped));
+
+        let mut doc = get_doc();
+        doc.retain_sentences(|_, id| id.is_some()).unwrap();
+        doc.map_sentences(|line, _| line.repeat(2)).unwrap();
+        let fr = LanguageTag::parse("fr".to_string()).unwrap();
+        let projected = doc.project_to_language(&fr, 0.0).unwrap().unwrap();
+        // 14 chars kept out of 24
+        assert!(!projected
+            .metadata()
+            .has_warning(QualityWarning::MostlyDropped));
+    }
+
+    #[test]
+    fn test_map_sentences() {
+        let mut doc = get_doc();
+        doc.map_sentences(|line, _| line.to_uppercase()).unwrap();
+        assert_eq!(doc.content(), "HELLO\n1234\nBONJOUR");
+        doc.check_sentences().unwrap();
+
+        let before = doc.clone();
+        assert!(doc.map_sentences(|line, _| format!("{}\n", line)).is_err());
+        assert_eq!(doc, before);
+    }
 
     #[test]
     fn test_from_record() {
@@ -281,9 +800,9 @@ mod tests {
 
         let (headers, body) = record.into_raw_parts();
         assert_eq!(doc.content(), &String::from_utf8_lossy(&body).into_owned());
-        assert_eq!(doc.warc_headers(), &headers.headers);
+        assert_eq!(**doc.warc_headers(), headers.headers);
         assert_eq!(
-            doc.warc_id(),
+            doc.warc_id().unwrap(),
             String::from_utf8_lossy(headers.headers.get(&WarcHeader::RecordID).unwrap())
                 .into_owned()
         );
@@ -301,4 +820,65 @@ mod tests {
 
         println!("{:?}", m2);
     }
+
+    #[test]
+    fn test_extensions() {
+        let mut m = Metadata::default();
+        assert_eq!(m.extension::<f32>("kenlm_pp").unwrap(), None);
+
+        m.set_extension("kenlm_pp", &123.5f32).unwrap();
+        m.set_extension("dedup_cluster", &vec![1u64, 2]).unwrap();
+        assert!(matches!(
+            m.set_extension("tlsh", &"T1"),
+            Err(Error::Custom(_))
+        ));
+
+        assert_eq!(m.extension::<f32>("kenlm_pp").unwrap(), Some(123.5));
+        assert_eq!(
+            m.extension::<Vec<u64>>("dedup_cluster").unwrap(),
+            Some(vec![1, 2])
+        );
+        assert!(matches!(
+            m.extension::<String>("kenlm_pp"),
+            Err(Error::SerdeJson(_))
+        ));
+
+        let serialized = serde_json::to_value(&m).unwrap();
+        assert_eq!(serialized["kenlm_pp"], 123.5);
+        let m2: Metadata = serde_json::from_value(serialized).unwrap();
+        assert_eq!(m, m2);
+
+        assert!(m.remove_extension("kenlm_pp").is_some());
+        assert_eq!(m.extensions().len(), 1);
+    }
+
+    #[test]
+    fn test_deserialize_annotation() {
+        let m: Metadata = serde_json::from_str(
+            r#"{"identification":{"label":"en","prob":0.5},"annotation":["header"],"harmful_pp":null,"foo":1,"sentence_identifications":[null]}"#,
+        )
+        .unwrap();
+        assert!(m.has_warning(QualityWarning::Header));
+        assert_eq!(m.harmful_pp(), None);
+        assert_eq!(m.extensions().len(), 1);
+        assert_eq!(m.extension::<u32>("foo").unwrap(), Some(1));
+        assert!(matches!(
+            m.clone().set_extension("annotation", &["header"]),
+            Err(Error::Custom(_))
+        ));
+
+        // `annotation` is written back as `quality_warnings`
+        let serialized = serde_json::to_value(&m).unwrap();
+        assert_eq!(
+            serialized["quality_warnings"],
+            serde_json::json!(["header"])
+        );
+        assert!(serialized.get("annotation").is_none());
+
+        assert!(serde_json::from_str::<Metadata>(r#"{"sentence_identifications":[]}"#).is_err());
+        assert!(serde_json::from_str::<Metadata>(
+            r#"{"identification":{"label":"en","prob":0.5},"tlsh":"a","tlsh":"b","sentence_identifications":[]}"#
+        )
+        .is_err());
+    }
 }
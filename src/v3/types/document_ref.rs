@@ -0,0 +1,129 @@
+/*! Borrowed version of [Document].
+
+A [DocumentRef] borrows its content and WARC header values from the serialized line it is deserialized from,
+avoiding allocations when scanning corpora (see [crate::oscar_doc::Reader::next_ref]).
+Strings containing JSON escapes (e.g. line breaks in content) can't be borrowed and are owned instead.
+!*/
+use std::{borrow::Cow, collections::HashMap};
+
+use serde::Deserialize;
+use warc::WarcHeader;
+
+use super::document::{Document, Identification, Metadata};
+use crate::error::Error;
+
+/// [str] borrowed from the input when possible.
+///
+/// Needed because serde only borrows [Cow] when it is directly a field, and not within a map.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(transparent)]
+struct CowStr<'a>(#[serde(borrow)] Cow<'a, str>);
+
+/// Borrowed version of [Document].
+///
+/// Metadata is still deserialized into an owned [Metadata].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DocumentRef<'a> {
+    #[serde(borrow)]
+    content: Cow<'a, str>,
+    #[serde(borrow)]
+    warc_headers: HashMap<WarcHeader, CowStr<'a>>,
+    metadata: Metadata,
+}
+
+impl<'a> DocumentRef<'a> {
+    /// Deserialize a [DocumentRef] from a JSON line, borrowing from it.
+    pub fn from_json(s: &'a str) -> Result<Self, Error> {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    /// Get a reference to the content
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Get a warc header value.
+    pub fn warc_header(&self, header: &WarcHeader) -> Option<&str> {
+        self.warc_headers.get(header).map(|v| v.0.as_ref())
+    }
+
+    /// Iterate over warc headers.
+    pub fn warc_headers(&self) -> impl Iterator<Item = (&WarcHeader, &str)> {
+        self.warc_headers.iter().map(|(k, v)| (k, v.0.as_ref()))
+    }
+
+    /// get warc record id
+    pub fn warc_id(&self) -> Result<&str, Error> {
+        self.warc_header(&WarcHeader::RecordID)
+            .ok_or(Error::MissingWarcHeader(WarcHeader::RecordID))
+    }
+
+    /// shorthand to get url
+    pub fn url(&self) -> Option<&str> {
+        self.warc_header(&WarcHeader::TargetURI)
+    }
+
+    /// Get a reference to the Document's identification
+    pub fn identification(&self) -> &Identification {
+        self.metadata.identification()
+    }
+
+    /// Get a reference to the document's metadata.
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Get an owned [Document], copying borrowed content and headers.
+    pub fn into_owned(self) -> Document {
+        let warc_headers: HashMap<WarcHeader, Vec<u8>> = self
+            .warc_headers
+            .into_iter()
+            .map(|(k, v)| (k, v.0.into_owned().into_bytes()))
+            .collect();
+
+        Document::new(self.content.into_owned(), warc_headers, self.metadata)
+    }
+}
+
+impl<'a> From<DocumentRef<'a>> for Document {
+    fn from(d: DocumentRef<'a>) -> Self {
+        d.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, collections::HashMap};
+
+    use warc::WarcHeader;
+
+    use super::DocumentRef;
+    use crate::v3::{Document, Metadata};
+
+    #[test]
+    fn test_borrow() {
+        let headers = HashMap::from([(WarcHeader::TargetURI, b"https://example.com".to_vec())]);
+        let doc = Document::new("foo bar".to_string(), headers, Metadata::default());
+        let line = serde_json::to_string(&doc).unwrap();
+
+        let doc_ref = DocumentRef::from_json(&line).unwrap();
+        assert!(matches!(doc_ref.content, Cow::Borrowed(_)));
+        assert_eq!(doc_ref.url(), doc.url().as_deref());
+        assert_eq!(doc_ref.into_owned(), doc);
+    }
+
+    #[test]
+    fn test_escaped() {
+        let doc = Document::new(
+            "foo\nbar \"baz\"".to_string(),
+            HashMap::new(),
+            Metadata::default(),
+        );
+        let line = serde_json::to_string(&doc).unwrap();
+
+        let doc_ref = DocumentRef::from_json(&line).unwrap();
+        assert!(matches!(doc_ref.content, Cow::Owned(_)));
+        assert_eq!(doc_ref.content(), "foo\nbar \"baz\"");
+        assert_eq!(Document::from(doc_ref), doc);
+    }
+}
@@ -0,0 +1,301 @@
+/*! WARC headers of a [crate::v3::Document].
+
+Header values are stored as raw bytes, and typed getters parse them on demand.
+Malformed values are reported as [Error::InvalidWarcHeader] rather than panicking.
+!*/
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use oxilangtag::LanguageTag;
+use url::Url;
+use uuid::Uuid;
+use warc::WarcHeader;
+
+use crate::error::Error;
+
+/// Name of the header holding languages identified by CommonCrawl (e.g. `eng,fra`).
+pub const IDENTIFIED_CONTENT_LANGUAGE: &str = "warc-identified-content-language";
+
+/// WARC headers, indexed by [WarcHeader].
+///
+/// Derefs to the inner [HashMap] so that raw values can still be accessed directly.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WarcHeaders(HashMap<WarcHeader, Vec<u8>>);
+
+impl WarcHeaders {
+    /// Create empty [WarcHeaders].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new [WarcHeadersBuilder].
+    pub fn builder() -> WarcHeadersBuilder {
+        WarcHeadersBuilder::default()
+    }
+
+    /// Get the inner [HashMap].
+    pub fn into_inner(self) -> HashMap<WarcHeader, Vec<u8>> {
+        self.0
+    }
+
+    /// Get a header value as a [str].
+    ///
+    /// Returns `Ok(None)` if the header is not present, and an error if it is not valid UTF-8.
+    pub fn get_str(&self, header: &WarcHeader) -> Result<Option<&str>, Error> {
+        self.0
+            .get(header)
+            .map(|v| {
+                std::str::from_utf8(v)
+                    .map_err(|e| Error::InvalidWarcHeader(header.clone(), e.to_string()))
+            })
+            .transpose()
+    }
+
+    /// Get a mandatory header value as a [str], failing if it is not present.
+    fn get_required_str(&self, header: WarcHeader) -> Result<&str, Error> {
+        self.get_str(&header)?
+            .ok_or(Error::MissingWarcHeader(header))
+    }
+
+    /// Get the record id (`WARC-Record-ID`, formatted as `<urn:uuid:...>`).
+    pub fn record_id(&self) -> Result<Uuid, Error> {
+        let raw = self.get_required_str(WarcHeader::RecordID)?;
+        let uuid = raw
+            .trim_start_matches('<')
+            .trim_end_matches('>')
+            .trim_start_matches("urn:uuid:");
+        Uuid::parse_str(uuid)
+            .map_err(|e| Error::InvalidWarcHeader(WarcHeader::RecordID, e.to_string()))
+    }
+
+    /// Get the record date (`WARC-Date`).
+    pub fn date(&self) -> Result<DateTime<Utc>, Error> {
+        let raw = self.get_required_str(WarcHeader::Date)?;
+        DateTime::parse_from_rfc3339(raw)
+            .map(|date| date.with_timezone(&Utc))
+            .map_err(|e| Error::InvalidWarcHeader(WarcHeader::Date, e.to_string()))
+    }
+
+    /// Get the target URI (`WARC-Target-URI`).
+    pub fn target_uri(&self) -> Result<Option<Url>, Error> {
+        self.get_str(&WarcHeader::TargetURI)?
+            .map(|raw| {
+                Url::parse(raw)
+                    .map_err(|e| Error::InvalidWarcHeader(WarcHeader::TargetURI, e.to_string()))
+            })
+            .transpose()
+    }
+
+    /// Get the content length (`Content-Length`).
+    pub fn content_length(&self) -> Result<Option<u64>, Error> {
+        self.get_str(&WarcHeader::ContentLength)?
+            .map(|raw| {
+                raw.trim().parse().map_err(|e: std::num::ParseIntError| {
+                    Error::InvalidWarcHeader(WarcHeader::ContentLength, e.to_string())
+                })
+            })
+            .transpose()
+    }
+
+    /// Get the languages identified by CommonCrawl (`WARC-Identified-Content-Language`).
+    ///
+    /// Returns an empty [Vec] if the header is not present.
+    pub fn identified_languages(&self) -> Result<Vec<LanguageTag<String>>, Error> {
+        let header = WarcHeader::from(IDENTIFIED_CONTENT_LANGUAGE);
+        match self.get_str(&header)? {
+            Some(raw) => raw
+                .split(',')
+                .map(str::trim)
+                .filter(|lang| !lang.is_empty())
+                .map(|lang| {
+                    LanguageTag::parse(lang.to_string())
+                        .map_err(|e| Error::InvalidWarcHeader(header.clone(), e.to_string()))
+                })
+                .collect(),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+impl Deref for WarcHeaders {
+    type Target = HashMap<WarcHeader, Vec<u8>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for WarcHeaders {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<HashMap<WarcHeader, Vec<u8>>> for WarcHeaders {
+    fn from(headers: HashMap<WarcHeader, Vec<u8>>) -> Self {
+        Self(headers)
+    }
+}
+
+impl FromIterator<(WarcHeader, Vec<u8>)> for WarcHeaders {
+    fn from_iter<T: IntoIterator<Item = (WarcHeader, Vec<u8>)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for WarcHeaders {
+    type Item = (WarcHeader, Vec<u8>);
+    type IntoIter = std::collections::hash_map::IntoIter<WarcHeader, Vec<u8>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a WarcHeaders {
+    type Item = (&'a WarcHeader, &'a Vec<u8>);
+    type IntoIter = std::collections::hash_map::Iter<'a, WarcHeader, Vec<u8>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Builder for [WarcHeaders].
+#[derive(Debug, Default, Clone)]
+pub struct WarcHeadersBuilder {
+    headers: HashMap<WarcHeader, Vec<u8>>,
+}
+
+impl WarcHeadersBuilder {
+    /// Set a raw header value.
+    pub fn header(mut self, header: WarcHeader, value: impl Into<Vec<u8>>) -> Self {
+        self.headers.insert(header, value.into());
+        self
+    }
+
+    /// Set the record id, formatted as `<urn:uuid:...>`.
+    pub fn record_id(self, id: Uuid) -> Self {
+        self.header(
+            WarcHeader::RecordID,
+            format!("<urn:uuid:{}>", id.to_hyphenated()),
+        )
+    }
+
+    /// Set the record date.
+    pub fn date(self, date: DateTime<Utc>) -> Self {
+        self.header(
+            WarcHeader::Date,
+            date.to_rfc3339_opts(SecondsFormat::Secs, true),
+        )
+    }
+
+    /// Set the target URI.
+    pub fn target_uri(self, uri: &Url) -> Self {
+        self.header(WarcHeader::TargetURI, uri.as_str())
+    }
+
+    /// Set the content length.
+    pub fn content_length(self, length: u64) -> Self {
+        self.header(WarcHeader::ContentLength, length.to_string())
+    }
+
+    /// Set the languages identified by CommonCrawl.
+    pub fn identified_languages(self, langs: &[LanguageTag<String>]) -> Self {
+        let langs: Vec<&str> = langs.iter().map(|lang| lang.as_str()).collect();
+        self.header(
+            WarcHeader::from(IDENTIFIED_CONTENT_LANGUAGE),
+            langs.join(","),
+        )
+    }
+
+    /// Build the [WarcHeaders].
+    pub fn build(self) -> WarcHeaders {
+        WarcHeaders(self.headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use oxilangtag::LanguageTag;
+    use url::Url;
+    use uuid::Uuid;
+    use warc::WarcHeader;
+
+    use super::WarcHeaders;
+    use crate::error::Error;
+
+    #[test]
+    fn test_builder_roundtrip() {
+        let id = Uuid::parse_str("f9d8c1b0-3b1e-4e2a-9a3c-1d2e3f4a5b6c").unwrap();
+        let date = Utc.with_ymd_and_hms(2021, 9, 16, 11, 7, 14).unwrap();
+        let url = Url::parse("https://oscar-project.org/").unwrap();
+        let langs = vec![
+            LanguageTag::parse("eng".to_string()).unwrap(),
+            LanguageTag::parse("fra".to_string()).unwrap(),
+        ];
+
+        let headers = WarcHeaders::builder()
+            .record_id(id)
+            .date(date)
+            .target_uri(&url)
+            .content_length(1234)
+            .identified_languages(&langs)
+            .build();
+
+        assert_eq!(
+            headers.get(&WarcHeader::RecordID),
+            Some(&b"<urn:uuid:f9d8c1b0-3b1e-4e2a-9a3c-1d2e3f4a5b6c>".to_vec())
+        );
+        assert_eq!(
+            headers.get(&WarcHeader::Date),
+            Some(&b"2021-09-16T11:07:14Z".to_vec())
+        );
+        assert_eq!(headers.record_id().unwrap(), id);
+        assert_eq!(headers.date().unwrap(), date);
+        assert_eq!(headers.target_uri().unwrap(), Some(url));
+        assert_eq!(headers.content_length().unwrap(), Some(1234));
+        assert_eq!(headers.identified_languages().unwrap(), langs);
+    }
+
+    #[test]
+    fn test_missing() {
+        let headers = WarcHeaders::new();
+        assert!(matches!(
+            headers.record_id(),
+            Err(Error::MissingWarcHeader(WarcHeader::RecordID))
+        ));
+        assert!(matches!(
+            headers.date(),
+            Err(Error::MissingWarcHeader(WarcHeader::Date))
+        ));
+        assert_eq!(headers.target_uri().unwrap(), None);
+        assert_eq!(headers.content_length().unwrap(), None);
+        assert!(headers.identified_languages().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalid() {
+        let headers = WarcHeaders::builder()
+            .header(WarcHeader::RecordID, "<urn:uuid:nope>")
+            .header(WarcHeader::ContentLength, "-1")
+            .header(WarcHeader::TargetURI, vec![0xff, 0xfe])
+            .build();
+        assert!(matches!(
+            headers.record_id(),
+            Err(Error::InvalidWarcHeader(WarcHeader::RecordID, _))
+        ));
+        assert!(matches!(
+            headers.content_length(),
+            Err(Error::InvalidWarcHeader(WarcHeader::ContentLength, _))
+        ));
+        assert!(matches!(
+            headers.target_uri(),
+            Err(Error::InvalidWarcHeader(WarcHeader::TargetURI, _))
+        ));
+    }
+}
@@ -0,0 +1,107 @@
+/*! Lightweight metadata of a serialized [crate::v3::Document].
+
+A [MetadataRecord] only deserializes the document identification, harmful perplexity, quality warnings and a few WARC headers.
+Other fields (most notably `content` and `sentence_identifications`) are skipped without being allocated,
+and can be fetched later using the byte offset of the record (see [crate::oscar_doc::Reader::seek]).
+!*/
+use serde::Deserialize;
+
+use super::document::Identification;
+use super::quality::QualityWarning;
+
+/// WARC headers kept in a [MetadataRecord].
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+struct RecordHeaders {
+    #[serde(rename = "warc-record-id")]
+    record_id: Option<String>,
+    #[serde(rename = "warc-target-uri")]
+    url: Option<String>,
+}
+
+/// Metadata fields kept in a [MetadataRecord].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct RecordMetadata {
+    identification: Identification,
+    harmful_pp: Option<f32>,
+    #[serde(alias = "annotation")]
+    quality_warnings: Option<Vec<QualityWarning>>,
+}
+
+/// Lightweight metadata of a serialized document, along with the byte offset of the full record.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MetadataRecord {
+    #[serde(skip)]
+    offset: u64,
+    // content is skipped as an unknown field, using serde's IgnoredAny
+    #[serde(default)]
+    warc_headers: RecordHeaders,
+    metadata: RecordMetadata,
+}
+
+impl MetadataRecord {
+    /// Set the byte offset of the record.
+    pub(crate) fn set_offset(&mut self, offset: u64) {
+        self.offset = offset;
+    }
+
+    /// Get the byte offset of the full record, in the (decompressed) stream it has been read from.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Get the warc record id, if present.
+    pub fn warc_id(&self) -> Option<&str> {
+        self.warc_headers.record_id.as_deref()
+    }
+
+    /// Get the url, if present.
+    pub fn url(&self) -> Option<&str> {
+        self.warc_headers.url.as_deref()
+    }
+
+    /// Get a reference to the document's identification.
+    pub fn identification(&self) -> &Identification {
+        &self.metadata.identification
+    }
+
+    /// Get the document's harmful perplexity.
+    pub fn harmful_pp(&self) -> Option<f32> {
+        self.metadata.harmful_pp
+    }
+
+    /// Get a reference to the document's quality warnings.
+    pub fn quality_warnings(&self) -> Option<&Vec<QualityWarning>> {
+        self.metadata.quality_warnings.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use warc::WarcHeader;
+
+    use super::MetadataRecord;
+    use crate::v3::{Document, Metadata, QualityWarning};
+
+    #[test]
+    fn test_from_document() {
+        let headers = HashMap::from([(WarcHeader::TargetURI, b"https://example.com".to_vec())]);
+        let mut metadata = Metadata::default();
+        metadata.set_harmful_pp(Some(12.5));
+        metadata.add_annotation(QualityWarning::Header);
+        let doc = Document::new("foo\nbar".to_string(), headers, metadata);
+
+        let record: MetadataRecord =
+            serde_json::from_str(&serde_json::to_string(&doc).unwrap()).unwrap();
+        assert_eq!(record.offset(), 0);
+        assert_eq!(record.url(), Some("https://example.com"));
+        assert_eq!(record.warc_id(), None);
+        assert_eq!(record.identification(), doc.identification());
+        assert_eq!(record.harmful_pp(), Some(12.5));
+        assert_eq!(
+            record.quality_warnings(),
+            Some(&vec![QualityWarning::Header])
+        );
+    }
+}
@@ -1 +1,6 @@
+pub(crate) mod builder;
 pub(crate) mod document;
+pub(crate) mod document_ref;
+pub(crate) mod headers;
+pub(crate) mod metadata_record;
+pub(crate) mod quality;
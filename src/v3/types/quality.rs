@@ -0,0 +1,262 @@
+/*! Typed quality warnings and categories.
+
+Both are (de)serialized as plain strings, so that existing files can be read,
+and unknown values are kept in an `Other` variant.
+Values are compared by their string representation, so that `Other("header")` equals `Header`.
+!*/
+use std::{
+    convert::Infallible,
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Generates a string-backed enum with an `Other(String)` escape hatch,
+/// along with conversions from and to [String].
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident { $($(#[$vmeta:meta])* $variant:ident => $value:literal,)* }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        #[serde(from = "String", into = "String")]
+        pub enum $name {
+            $($(#[$vmeta])* $variant,)*
+            /// Unknown value.
+            ///
+            /// Prefer [Self::other] or [From<String>], that map known values to their variant.
+            Other(String),
+        }
+
+        impl $name {
+            /// Create a value from a string, mapping known values to their variant.
+            pub fn other(s: impl Into<String>) -> Self {
+                Self::from(s.into())
+            }
+
+            /// Get the string representation, as found in corpus files.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Other(s) => s,
+                }
+            }
+
+            /// Returns true if the value is not a known one.
+            pub fn is_other(&self) -> bool {
+                match self {
+                    Self::Other(s) => matches!(Self::from(s.as_str()), Self::Other(_)),
+                    _ => false,
+                }
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.as_str() == other.as_str()
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl Hash for $name {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.as_str().hash(state)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::from(s))
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(s: &str) -> Self {
+                match s {
+                    $($value => Self::$variant,)*
+                    other => Self::Other(other.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(s: String) -> Self {
+                match Self::from(s.as_str()) {
+                    Self::Other(_) => Self::Other(s),
+                    known => known,
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(v: $name) -> Self {
+                match v {
+                    $name::Other(s) => s,
+                    known => known.as_str().to_string(),
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// Length and content based quality warnings (ex-annotations).
+    QualityWarning {
+        /// Document has a low number of lines.
+        Tiny => "tiny",
+        /// Document has a high proportion of short lines.
+        ShortSentences => "short_sentences",
+        /// Document has a high proportion of short lines at its beginning.
+        Header => "header",
+        /// Document has a high proportion of short lines at its end.
+        Footer => "footer",
+        /// Document has a high proportion of punctuation.
+        Noisy => "noisy",
+        /// Document has been flagged as adult content.
+        Adult => "adult",
+        /// Most of the document has been dropped when projecting it to a single language.
+        MostlyDropped => "mostly_dropped",
+    }
+}
+
+string_enum! {
+    /// URL-based categories, from the [UT1 blocklist](https://dsi.ut-capitole.fr/blacklists/).
+    Category {
+        Adult => "adult",
+        Agressif => "agressif",
+        Arjel => "arjel",
+        AssociationsReligieuses => "associations_religieuses",
+        Astrology => "astrology",
+        AudioVideo => "audio-video",
+        Bank => "bank",
+        Bitcoin => "bitcoin",
+        Blog => "blog",
+        Celebrity => "celebrity",
+        Chat => "chat",
+        Child => "child",
+        Cleaning => "cleaning",
+        Cooking => "cooking",
+        Cryptojacking => "cryptojacking",
+        DangerousMaterial => "dangerous_material",
+        Dating => "dating",
+        Ddos => "ddos",
+        Dialer => "dialer",
+        Doh => "doh",
+        Download => "download",
+        Drogue => "drogue",
+        EducationalGames => "educational_games",
+        Filehosting => "filehosting",
+        Financial => "financial",
+        Forums => "forums",
+        Gambling => "gambling",
+        Games => "games",
+        Hacking => "hacking",
+        Jobsearch => "jobsearch",
+        Lingerie => "lingerie",
+        Malware => "malware",
+        Manga => "manga",
+        Marketingware => "marketingware",
+        MixedAdult => "mixed_adult",
+        MobilePhone => "mobile-phone",
+        Phishing => "phishing",
+        Press => "press",
+        Publicite => "publicite",
+        Radio => "radio",
+        Reaffected => "reaffected",
+        Redirector => "redirector",
+        RemoteControl => "remote-control",
+        Sect => "sect",
+        SexualEducation => "sexual_education",
+        Shopping => "shopping",
+        Shortener => "shortener",
+        SocialNetworks => "social_networks",
+        Special => "special",
+        Sports => "sports",
+        Stalkerware => "stalkerware",
+        StrictRedirector => "strict_redirector",
+        StrongRedirector => "strong_redirector",
+        Translation => "translation",
+        Tricheur => "tricheur",
+        Update => "update",
+        Violence => "violence",
+        Vpn => "vpn",
+        Warez => "warez",
+        Webhosting => "webhosting",
+        Webmail => "webmail",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v3::Metadata;
+
+    use super::{Category, QualityWarning};
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(QualityWarning::from("header"), QualityWarning::Header);
+        assert_eq!(
+            QualityWarning::from("short_sentence"),
+            QualityWarning::other("short_sentence")
+        );
+        assert!(QualityWarning::from("short_sentence").is_other());
+        assert_eq!(Category::from("mobile-phone"), Category::MobilePhone);
+        assert_eq!("footer".parse(), Ok(QualityWarning::Footer));
+    }
+
+    #[test]
+    fn test_other_known() {
+        assert_eq!(QualityWarning::other("header"), QualityWarning::Header);
+        assert!(!QualityWarning::other("header").is_other());
+
+        // raw construction of a known value still compares equal
+        let raw = QualityWarning::Other("header".to_string());
+        assert!(!raw.is_other());
+        assert_eq!(raw, QualityWarning::Header);
+
+        let mut metadata = Metadata::default();
+        metadata.add_annotation(QualityWarning::Other("header".to_string()));
+        assert!(metadata.has_warning(QualityWarning::Header));
+    }
+
+    #[test]
+    fn test_serde() {
+        let warnings = vec![QualityWarning::Tiny, QualityWarning::other("foo")];
+        let ser = serde_json::to_string(&warnings).unwrap();
+        assert_eq!(ser, r#"["tiny","foo"]"#);
+
+        let de: Vec<QualityWarning> = serde_json::from_str(&ser).unwrap();
+        assert_eq!(de, warnings);
+
+        let de: Vec<Category> = serde_json::from_str(r#"["adult","social_networks"]"#).unwrap();
+        assert_eq!(de, vec![Category::Adult, Category::SocialNetworks]);
+    }
+
+    #[test]
+    fn test_has_warning() {
+        let mut metadata = Metadata::default();
+        assert!(!metadata.has_warning(QualityWarning::Header));
+
+        metadata.add_annotation(QualityWarning::Header);
+        metadata.add_annotation("short_sentence".to_string());
+        assert!(metadata.has_warning(QualityWarning::Header));
+        assert!(!metadata.has_warning(QualityWarning::ShortSentences));
+        assert!(metadata.has_warning(QualityWarning::other("short_sentence")));
+
+        metadata.add_category("adult");
+        assert!(metadata.has_category(Category::Adult));
+        assert!(!metadata.has_category(Category::Blog));
+    }
+}
@@ -10,7 +10,8 @@ use std::{fs::File, io::Write, path::PathBuf};
 ///
 /// Implements [std::io::Write]
 ///
-/// *Note:* Contrary to TextWriter, [MetaWriter] has no limit and new file creation has to be triggered manually by invoking [MetaWriter::create_next_file].
+/// *Note:* [MetaWriter] has no limit by itself and new file creation has to be triggered manually by invoking [MetaWriter::create_next_file].
+/// [super::Writer] does so when its size limit is reached.
 pub struct MetaWriter {
     lang: LanguageTag<String>,
     dst: PathBuf,
@@ -88,13 +89,10 @@ impl Write for MetaWriter {
             let bytes_written = file.write(buf)?;
             Ok(bytes_written)
         } else {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "Could not write to file {} for lang {}",
-                    self.nb_files, self.lang
-                ),
-            ))
+            Err(std::io::Error::other(format!(
+                "Could not write to file {} for lang {}",
+                self.nb_files, self.lang
+            )))
         }
     }
 
@@ -1,8 +1,7 @@
-/*! Text&Metadata writer for a given language.
+/*! Document writer for a given language.
 
-Holds writing and rotating on both text and metadata files for a given language.
-Supports writing of numerous [MergedPiece], given that their identification are the same.
-Identification is checked too, preventing the writing of differently identified [MergedPiece] into a given language writer.
+Holds writing and rotating on metadata files for a given language.
+Supports writing of numerous [Document]s, rotating files when they reach the size limit.
 !*/
 use std::io::Write;
 use std::path::Path;
@@ -17,6 +16,32 @@ use super::{MetaWriter, WriterTrait};
 
 pub struct WriterDoc {
     handle: MetaWriter,
+    size_limit: Option<u64>,
+    bytes_written: u64,
+}
+
+impl WriterDoc {
+    /// Writes a single serialized document, rotating the file beforehand if it would exceed `size_limit`.
+    ///
+    /// Rotation only happens at document boundaries, so a document larger than `size_limit` is written alone in its file.
+    fn write_doc(&mut self, piece: &Document) -> Result<(), error::Error> {
+        let mut piece_str = serde_json::to_string(piece)?;
+        piece_str.push('\n');
+        let nb_bytes = piece_str.len() as u64;
+
+        if let Some(size_limit) = self.size_limit {
+            // bytes_written > 0 ensures that there's a current file that holds at least one document.
+            if self.bytes_written > 0 && self.bytes_written + nb_bytes > size_limit {
+                self.handle.create_next_file()?;
+                self.bytes_written = 0;
+            }
+        }
+
+        self.handle.write_all(piece_str.as_bytes())?;
+        self.bytes_written += nb_bytes;
+
+        Ok(())
+    }
 }
 
 impl WriterTrait for WriterDoc {
@@ -24,35 +49,35 @@ impl WriterTrait for WriterDoc {
     /// Create a new Writer for provided language.
     /// Files will be written at the root of the `dst` file, and shouldn't exceed `size_limit`.
     ///
-    /// _See [TextWriter] to have an explanation about the *shouldn't*._
+    /// _Files only rotate between documents: a document that is larger than `size_limit` will be written alone in a file that exceeds the limit, hence the *shouldn't*._
     fn new(
         dst: &Path,
         lang: LanguageTag<String>,
-        _size_limit: Option<u64>,
+        size_limit: Option<u64>,
     ) -> Result<Self, error::Error> {
         Ok(Self {
             handle: MetaWriter::new(dst, lang),
+            size_limit,
+            bytes_written: 0,
         })
     }
-    /// writes the provided [MergedPiece], checking language identification.
+    /// writes the provided [Document]s, rotating files if needed.
     fn write(&mut self, pieces: Vec<Document>) -> Result<(), error::Error> {
-        let mut piece_str = String::new();
-        for piece in pieces {
-            piece_str += &serde_json::to_string(&piece)?;
-            piece_str.push('\n');
+        for piece in &pieces {
+            self.write_doc(piece)?;
         }
-        self.handle.write_all(piece_str.as_bytes())?;
 
         Ok(())
     }
 
     fn write_single(&mut self, piece: &Document) -> Result<(), error::Error> {
-        Ok(serde_json::to_writer(&mut self.handle, piece)?)
+        self.write_doc(piece)
     }
     /// Binds to [MetaWriter::close_file].
     /// Closes current metadata file.
     /// TODO: put this in impl Drop?
     fn close_meta(&mut self) -> Result<(), error::Error> {
+        self.bytes_written = 0;
         self.handle.close_file()
     }
 }
@@ -125,6 +150,77 @@ Ecoutez ça va plutôt bien.";
         std::fs::remove_dir_all(dst).unwrap();
     }
 
+    fn get_docs() -> Vec<Document> {
+        let f = File::open("tests/res/data.jsonl").unwrap();
+        crate::oscar_doc::Reader::new(std::io::BufReader::new(f))
+            .map(|x| x.unwrap())
+            .collect()
+    }
+
+    fn read_docs(path: &Path) -> Vec<Document> {
+        let f = File::open(path).unwrap();
+        crate::oscar_doc::Reader::new(std::io::BufReader::new(f))
+            .map(|x| x.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_no_limit() {
+        let docs = get_docs();
+        let dst = tempfile::tempdir().unwrap();
+        let mut wr = WriterDoc::new(
+            dst.path(),
+            LanguageTag::parse("fr".to_string()).unwrap(),
+            None,
+        )
+        .unwrap();
+
+        wr.write(docs.clone()).unwrap();
+        wr.handle.flush().unwrap();
+
+        assert_eq!(std::fs::read_dir(dst.path()).unwrap().count(), 1);
+        assert_eq!(read_docs(&dst.path().join("fr_meta.jsonl")), docs);
+    }
+
+    #[test]
+    fn test_rotation() {
+        let docs = get_docs();
+        let dst = tempfile::tempdir().unwrap();
+        let size_limit = 50_000;
+        let mut wr = WriterDoc::new(
+            dst.path(),
+            LanguageTag::parse("fr".to_string()).unwrap(),
+            Some(size_limit),
+        )
+        .unwrap();
+
+        wr.write(docs[..30].to_vec()).unwrap();
+        for doc in &docs[30..] {
+            wr.write_single(doc).unwrap();
+        }
+        wr.handle.flush().unwrap();
+
+        // first file should have been renamed
+        assert!(!dst.path().join("fr_meta.jsonl").exists());
+
+        let nb_files = std::fs::read_dir(dst.path()).unwrap().count();
+        assert!(nb_files > 1);
+
+        let mut docs_from_parts = vec![];
+        for part in 1..=nb_files {
+            let path = dst.path().join(format!("fr_meta_part_{part}.jsonl"));
+            let docs_from_part = read_docs(&path);
+
+            // files are under the limit, unless they hold a single, larger document
+            let size = std::fs::metadata(&path).unwrap().len();
+            assert!(size <= size_limit || docs_from_part.len() == 1);
+
+            docs_from_parts.extend(docs_from_part);
+        }
+
+        assert_eq!(docs, docs_from_parts);
+    }
+
     #[test]
     fn test_newline_bug() {
         // create a possibly faulty document
@@ -132,7 +228,7 @@ Ecoutez ça va plutôt bien.";
         let headers = HashMap::new();
         let meta = Metadata::new(
             &Identification::new(LanguageTag::parse("en".to_string()).unwrap(), 1.0f32),
-            &*vec![Some(Identification::new(
+            &[Some(Identification::new(
                 LanguageTag::parse("en".to_string()).unwrap(),
                 1.0f32,
             ))],
@@ -161,4 +257,30 @@ Ecoutez ça va plutôt bien.";
 
         assert_eq!(doc, doc_from_ser);
     }
+
+    #[test]
+    fn test_newline_bug_check_sentences() {
+        let id = Identification::new(LanguageTag::parse("en".to_string()).unwrap(), 1.0f32);
+
+        // \r\n line endings are a single line break
+        let doc = Document::new(
+            "hel\r\nlo\r\n".to_string(),
+            HashMap::new(),
+            Metadata::new(&id, &[Some(id.clone()), Some(id.clone())]),
+        );
+        doc.check_sentences().unwrap();
+
+        let doc = Document::new(
+            "hel\r\nlo\r\n".to_string(),
+            HashMap::new(),
+            Metadata::new(&id, &[Some(id.clone())]),
+        );
+        assert!(matches!(
+            doc.check_sentences(),
+            Err(error::Error::SentenceCountMismatch {
+                lines: 2,
+                identifications: 1
+            })
+        ));
+    }
 }
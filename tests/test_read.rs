@@ -1,9 +1,16 @@
-use std::{fs::File, io::BufReader, path::PathBuf};
+use std::{
+    fs::File,
+    io::{BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+use flate2::{write::GzEncoder, Compression};
 
 use oscar_io::v3::Document;
+#[allow(deprecated)]
 use oscar_io::{
     error::Error,
-    oscar_doc::{Reader, SplitFolderReader, SplitReader},
+    oscar_doc::{Reader, SplitFolderReader, SplitReader, SplitSource},
 };
 #[test]
 fn test_hehe() {
@@ -46,12 +53,13 @@ fn test_split_validity() {
     assert!(docs_from_split.iter().all(Result::is_ok));
     let docs_from_split: Vec<Document> = docs_from_split.into_iter().map(|x| x.unwrap()).collect();
 
-    assert!(docs_from_full.len() != 0);
+    assert!(!docs_from_full.is_empty());
     assert_eq!(docs_from_full.len(), docs_from_split.len());
     assert_eq!(docs_from_full, docs_from_split);
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_split_folder() {
     let f = PathBuf::from("tests/res/split/");
     let cr = SplitFolderReader::new(&f).unwrap();
@@ -61,3 +69,179 @@ fn test_split_folder() {
     // let nb_docs = cr.into_iter().count();
     // assert_eq!(nb_docs, 63);
 }
+
+#[test]
+fn test_split_with_opener() {
+    let source = SplitSource::Pattern {
+        base_path: PathBuf::from("tests/res/split/"),
+        file_name_start: "data_part_".to_string(),
+        file_name_end: "".to_string(),
+        file_name_extension: ".jsonl".to_string(),
+        counter_start: 1,
+        nb_splits: None,
+    };
+    let cr = SplitReader::with_opener(source, |path| {
+        Ok(Reader::new(BufReader::new(File::open(path)?)))
+    })
+    .unwrap();
+    let docs: Vec<Document> = cr.map(|x| x.unwrap()).collect();
+    assert_eq!(docs.len(), 63);
+
+    let source = SplitSource::Folder(PathBuf::from("tests/res/split/"));
+    let cr = SplitReader::with_opener(source, |path| {
+        Ok(Reader::new(BufReader::new(File::open(path)?)))
+    })
+    .unwrap();
+    let docs: Vec<Document> = cr.map(|x| x.unwrap()).collect();
+    assert_eq!(docs.len(), 63);
+}
+
+#[test]
+fn test_split_nb_splits() {
+    let source = |nb_splits| SplitSource::Pattern {
+        base_path: PathBuf::from("tests/res/split/"),
+        file_name_start: "data_part_".to_string(),
+        file_name_end: "".to_string(),
+        file_name_extension: ".jsonl".to_string(),
+        counter_start: 1,
+        nb_splits: Some(nb_splits),
+    };
+
+    // reading a subset of the files
+    let docs_subset: Vec<Document> = SplitReader::from_source(source(2))
+        .unwrap()
+        .map(|x| x.unwrap())
+        .collect();
+    let docs_expected: Vec<Document> = SplitReader::new(
+        PathBuf::from("tests/res/split/"),
+        "data_part_",
+        "",
+        ".jsonl",
+        1,
+    )
+    .map(|x| x.unwrap())
+    .take(docs_subset.len())
+    .collect();
+    assert!(!docs_subset.is_empty());
+    assert_eq!(docs_subset, docs_expected);
+
+    // expecting more files than there are is an error
+    let mut cr = SplitReader::from_source(source(8)).unwrap();
+    let last = cr.by_ref().last();
+    match last {
+        Some(Err(Error::Io(e))) => assert_eq!(e.kind(), std::io::ErrorKind::NotFound),
+        x => panic!("wrong return: {:?}", x),
+    }
+    assert!(cr.next().is_none());
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_split_folder_deprecated() {
+    let f = PathBuf::from("tests/res/split/");
+    let cr = SplitFolderReader::new(&f).unwrap();
+    assert_eq!(cr.count(), 63);
+}
+
+#[test]
+fn test_split_from_folder() {
+    let f = PathBuf::from("tests/res/split/");
+    let cr = SplitReader::from_folder(&f).unwrap();
+    assert_eq!(cr.count(), 63);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_split_rotate_file_deprecated() {
+    let f = PathBuf::from("tests/res/split/");
+    let mut cr = SplitReader::new(f, "data_part_", "", ".jsonl", 1);
+
+    // rotating twice skips the first file
+    cr.rotate_file().unwrap();
+    cr.rotate_file().unwrap();
+    let first_file = File::open("tests/res/split/data_part_1.jsonl").unwrap();
+    let nb_docs_first = Reader::new(BufReader::new(first_file)).count();
+    assert_eq!(cr.count(), 63 - nb_docs_first);
+
+    // rotating past the last file is a NotFound error
+    let f = PathBuf::from("tests/res/split/");
+    let mut cr = SplitReader::new(f, "data_part_", "", ".jsonl", 1);
+    for _ in 1..=7 {
+        cr.rotate_file().unwrap();
+    }
+    match cr.rotate_file() {
+        Err(Error::Io(e)) => assert_eq!(e.kind(), std::io::ErrorKind::NotFound),
+        x => panic!("wrong return: {:?}", x),
+    }
+}
+
+#[test]
+fn test_split_not_found() {
+    let f = PathBuf::from("tests/res/does_not_exist/");
+    let mut cr = SplitReader::new(f, "data_part_", "", ".jsonl", 1);
+
+    // first file not being found is an error, then iteration stops.
+    match cr.next() {
+        Some(Err(Error::Io(e))) => assert_eq!(e.kind(), std::io::ErrorKind::NotFound),
+        x => panic!("wrong return: {:?}", x),
+    }
+    assert!(cr.next().is_none());
+
+    assert!(SplitReader::from_folder(&PathBuf::from("tests/res/does_not_exist/")).is_err());
+}
+
+/// Copy split files into `dst`, alternating between plain, gzip and (if enabled) zstd compression.
+fn write_mixed_split(dst: &Path) {
+    for part in 1..=7 {
+        let name = format!("data_part_{part}.jsonl");
+        let content = std::fs::read(PathBuf::from("tests/res/split/").join(&name)).unwrap();
+        let mut f = File::create(dst.join(&name)).unwrap();
+        match part % 3 {
+            1 => {
+                let mut enc = GzEncoder::new(f, Compression::fast());
+                enc.write_all(&content).unwrap();
+                enc.finish().unwrap();
+            }
+            #[cfg(feature = "zstd")]
+            2 => zstd::stream::copy_encode(content.as_slice(), f, 3).unwrap(),
+            _ => f.write_all(&content).unwrap(),
+        }
+    }
+}
+
+#[test]
+fn test_open_detects_compression() {
+    let dst = tempfile::tempdir().unwrap();
+    write_mixed_split(dst.path());
+
+    let from_plain: Vec<Document> = Reader::new(BufReader::new(
+        File::open("tests/res/split/data_part_1.jsonl").unwrap(),
+    ))
+    .map(|x| x.unwrap())
+    .collect();
+    let from_gzip: Vec<Document> = Reader::open(&dst.path().join("data_part_1.jsonl"))
+        .unwrap()
+        .map(|x| x.unwrap())
+        .collect();
+
+    assert!(!from_plain.is_empty());
+    assert_eq!(from_plain, from_gzip);
+}
+
+#[test]
+fn test_split_mixed_compression() {
+    let dst = tempfile::tempdir().unwrap();
+    write_mixed_split(dst.path());
+
+    let f = File::open("tests/res/data.jsonl").unwrap();
+    let docs_from_full: Vec<Document> =
+        Reader::new(BufReader::new(f)).map(|x| x.unwrap()).collect();
+
+    let cr = SplitReader::new(dst.path().to_path_buf(), "data_part_", "", ".jsonl", 1);
+    let docs_from_split: Vec<Document> = cr.map(|x| x.unwrap()).collect();
+    assert_eq!(docs_from_full, docs_from_split);
+
+    let cr = SplitReader::from_folder(dst.path()).unwrap();
+    let docs_from_folder: Vec<Document> = cr.map(|x| x.unwrap()).collect();
+    assert_eq!(docs_from_full, docs_from_folder);
+}